@@ -9,6 +9,7 @@ use managed::queries::query_manageable_info;
 use saleable::coin_helpers::assert_sent_sufficient_coin;
 use saleable::queries::query_saleable_info;
 use shared::application::{ApplicationQueryMsg, AppTaskInfo};
+use shared::goi_manager::{send_inbox_message_msg_to_goi_manager, send_mark_inbox_message_read_msg_to_goi_manager};
 use shared::manage::Manageable;
 use shared::query_response_info::{InfoManagedResponse, InfoResponse, NameResponse};
 use shared::saleable::Saleable;
@@ -102,6 +103,27 @@ pub fn execute(
             STATE.save(deps.storage, &state)?;
             Ok(Response::new())
         },
+        ExecuteMsg::SendMessage { to, content, body, reply_to, expires } => {
+            ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+            let to = deps.api.addr_validate(&to)?;
+            let managable = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            match managable.managing_contract {
+                None => {
+                    panic!("App is currently unmanaged.")
+                }
+                Some(mc) => Ok(send_inbox_message_msg_to_goi_manager(to, content, body, reply_to, expires, mc, Response::new()))
+            }
+        },
+        ExecuteMsg::MarkMessageRead { message_id, read } => {
+            ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+            let managable = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            match managable.managing_contract {
+                None => {
+                    panic!("App is currently unmanaged.")
+                }
+                Some(mc) => Ok(send_mark_inbox_message_read_msg_to_goi_manager(message_id, read, mc, Response::new()))
+            }
+        },
         ExecuteMsg::AddNodeToTask { task_address} => {
             let task =
                 tasks()
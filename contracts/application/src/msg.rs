@@ -1,10 +1,12 @@
-use cosmwasm_std::{Addr, Coin};
+use cosmwasm_std::{Addr, Coin, Timestamp};
 use cw4::Member;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use managed::messages::ManagedExecuteMsg;
+use shared::messaging::InboxContent;
 use shared::task::TaskCreateModel;
+use shared::utils::MessageId;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {
@@ -23,7 +25,9 @@ pub enum ExecuteMsg {
     ManagedServiceMessage {message: ManagedExecuteMsg},
     AddNewTask{ task: TaskCreateModel },
     UpdateTaskCodeId{ task_code_id: Option<u64>},
-    AddNodeToTask { task_address: Addr }
+    AddNodeToTask { task_address: Addr },
+    SendMessage { to: String, content: InboxContent, body: String, reply_to: Option<MessageId>, expires: Option<Timestamp> },
+    MarkMessageRead { message_id: MessageId, read: bool },
 }
 
 
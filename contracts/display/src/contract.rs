@@ -0,0 +1,267 @@
+use cosmwasm_std::{Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, to_binary};
+#[cfg(not
+(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cw2::set_contract_version;
+use cw4_group::state::MEMBERS;
+
+use group_admin::service::list_members;
+use managed::messages::ManagedExecuteMsg;
+use managed::queries::query_manageable_info;
+use saleable::messages::receive::ExecuteMsg as SaleableExecuteMsg;
+use saleable::queries::query_saleable_info;
+use shared::display::{DisplayDetails, DisplayInfo, Heartbeat};
+use shared::goi_manager::{get_minters, on_successful_buy, on_successful_forsale_update, on_successful_init_processing, send_attest_ad_aired_msg_to_goi_manager, send_cancel_broadcast_msg_to_goi_manager, send_display_heartbeat_msg_to_goi_manager, send_inbox_message_msg_to_goi_manager, send_mark_inbox_message_read_msg_to_goi_manager, send_respond_to_ad_msg_to_goi_manager, send_respond_to_broadcast_msg_to_goi_manager, send_update_display_details_msg_to_goi_manager};
+use shared::manage::Manageable;
+use shared::query_response_info::{InfoManagedResponse, NameResponse};
+use shared::saleable::Saleable;
+use shared::utils::BlockTime;
+use shared::utils::general::AssetTypes;
+
+use crate::error::DisplayError;
+use crate::msg::{ExecuteMsg, InstantiateDisplayMsg, QueryMsg};
+use crate::state::{ADMIN, MANAGEABLE_SERVICE, REPORTER, SALEABLE_SERVICE, STATE};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:display";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateDisplayMsg,
+) -> Result<Response, DisplayError> {
+
+    let is_minter = get_minters().iter().any( |a| a == &Addr::unchecked(&info.sender.clone())  );
+
+    match is_minter {
+        true => {
+            if !msg.details.is_valid() {
+                return Err(DisplayError::InvalidDisplayDetails {})
+            }
+            let reporter = match msg.reporter.clone() {
+                Some(r) => Some(deps.api.addr_validate(&r)?),
+                None => None
+            };
+            let state = DisplayInfo::new(_env.contract.address.clone(), msg.name.clone(), msg.details.clone(), BlockTime{
+                height: _env.block.height.clone(),
+                time: _env.block.time.clone(),
+                chain_id: _env.block.chain_id.clone()
+            });
+
+            set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+            STATE.save(deps.storage, &state)?;
+            REPORTER.save(deps.storage, &reporter)?;
+
+            let init_res =
+                MANAGEABLE_SERVICE.init(deps, _env.clone(), info.clone(), msg.managing_contract.clone(), AssetTypes::Display, match msg.members.clone().len() > 0 {
+                    true => Some(msg.members.clone()),
+                    false => None
+                }, Some(SALEABLE_SERVICE), Some(msg.admin.clone()), msg.price, msg.for_sale, Some(msg.name.clone()),  Some(on_successful_init_processing));
+            match init_res {
+                Ok(r) => {
+                    match msg.managing_contract {
+                        Some(mc) => Ok(send_update_display_details_msg_to_goi_manager(msg.details, mc, r)),
+                        None => Ok(r)
+                    }
+                }
+                Err(e) => {
+                    Err(DisplayError::ManagableServiceError(e))
+                }
+            }
+        },
+        false => {
+            return Err(DisplayError::Unauthorized { sender: info.sender })
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    mut deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, DisplayError> {
+
+    match msg {
+
+        ExecuteMsg::ManagedServiceMessage {  message } => {
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let is_buy = matches!(message, ManagedExecuteMsg::Saleable { saleable_msg: SaleableExecuteMsg::Buy {} });
+            let res = MANAGEABLE_SERVICE.exec_msg(deps.branch(),
+                                                  _env.clone(), info.clone(),
+                                                  Some(SALEABLE_SERVICE), message.clone(), manager_info.clone(),
+                                                  Some(on_successful_forsale_update),
+                                                  Some(on_successful_buy) );
+            match res {
+                Ok(r) => {
+                    //the seller's screen key goes with the sale; the new owner sets their own
+                    if is_buy {
+                        REPORTER.save(deps.storage, &None)?;
+                    }
+                    Ok(r)
+                },
+                Err(e) => {
+                    Err(DisplayError::ManagableServiceError(e))
+                }
+            }
+        },
+        ExecuteMsg::UpdateDetails { details } => {
+            update_details(deps, info, details)
+        },
+        ExecuteMsg::SetReporter { reporter } => {
+            ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+            let reporter = match reporter {
+                Some(r) => Some(deps.api.addr_validate(&r)?),
+                None => None
+            };
+            REPORTER.save(deps.storage, &reporter)?;
+            Ok(Response::new())
+        },
+        ExecuteMsg::Heartbeat { online } => {
+            heartbeat(deps, _env, info, online)
+        },
+        ExecuteMsg::RespondToBroadcast { booking_id, accept } => {
+            ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            match manager_info.managing_contract {
+                None => {
+                    panic!("Display is currently unmanaged.")
+                }
+                Some(mc) => Ok(send_respond_to_broadcast_msg_to_goi_manager(booking_id, accept, mc, Response::new()))
+            }
+        },
+        ExecuteMsg::CancelBroadcast { booking_id } => {
+            ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            match manager_info.managing_contract {
+                None => {
+                    panic!("Display is currently unmanaged.")
+                }
+                Some(mc) => Ok(send_cancel_broadcast_msg_to_goi_manager(booking_id, mc, Response::new()))
+            }
+        },
+        ExecuteMsg::RespondToAd { ad_id, accept, attestor } => {
+            ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+            let attestor = match attestor {
+                Some(a) => Some(deps.api.addr_validate(&a)?),
+                None => None
+            };
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            match manager_info.managing_contract {
+                None => {
+                    panic!("Display is currently unmanaged.")
+                }
+                Some(mc) => Ok(send_respond_to_ad_msg_to_goi_manager(ad_id, accept, attestor, mc, Response::new()))
+            }
+        },
+        ExecuteMsg::AttestAdAired { ad_id } => {
+            assert_reporter(deps.as_ref(), &info.sender)?;
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            match manager_info.managing_contract {
+                None => {
+                    panic!("Display is currently unmanaged.")
+                }
+                Some(mc) => Ok(send_attest_ad_aired_msg_to_goi_manager(ad_id, mc, Response::new()))
+            }
+        },
+        ExecuteMsg::SendMessage { to, content, body, reply_to, expires } => {
+            ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+            let to = deps.api.addr_validate(&to)?;
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            match manager_info.managing_contract {
+                None => {
+                    panic!("Display is currently unmanaged.")
+                }
+                Some(mc) => Ok(send_inbox_message_msg_to_goi_manager(to, content, body, reply_to, expires, mc, Response::new()))
+            }
+        },
+        ExecuteMsg::MarkMessageRead { message_id, read } => {
+            ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            match manager_info.managing_contract {
+                None => {
+                    panic!("Display is currently unmanaged.")
+                }
+                Some(mc) => Ok(send_mark_inbox_message_read_msg_to_goi_manager(message_id, read, mc, Response::new()))
+            }
+        },
+    }
+}
+
+
+fn update_details(deps: DepsMut, info: MessageInfo, details: DisplayDetails) -> Result<Response, DisplayError> {
+    ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+    if !details.is_valid() {
+        return Err(DisplayError::InvalidDisplayDetails {})
+    }
+    let mut state = STATE.load(deps.storage)?;
+    state.set_details(details.clone());
+    STATE.save(deps.storage, &state)?;
+
+    let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+    match manager_info.managing_contract {
+        None => {
+            panic!("Display is currently unmanaged.")
+        }
+        Some(mc) => Ok(send_update_display_details_msg_to_goi_manager(details, mc, Response::new()))
+    }
+}
+
+
+//The admin or the screen's reporter key can report
+fn assert_reporter(deps: Deps, sender: &Addr) -> Result<(), DisplayError> {
+    let is_reporter = REPORTER.load(deps.storage)?.map_or(false, |r| r == *sender);
+    match is_reporter || ADMIN.is_admin(deps, sender)? {
+        true => Ok(()),
+        false => Err(DisplayError::Unauthorized { sender: sender.clone() })
+    }
+}
+
+
+fn heartbeat(deps: DepsMut, env: Env, info: MessageInfo, online: bool) -> Result<Response, DisplayError> {
+    assert_reporter(deps.as_ref(), &info.sender)?;
+    let mut state = STATE.load(deps.storage)?;
+    state.last_heartbeat = Some(Heartbeat { online, reported: env.block.time });
+    STATE.save(deps.storage, &state)?;
+
+    let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+    match manager_info.managing_contract {
+        None => {
+            panic!("Display is currently unmanaged.")
+        }
+        Some(mc) => Ok(send_display_heartbeat_msg_to_goi_manager(online, mc, Response::new()))
+    }
+}
+
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::GetName {} => to_binary(&query_name(deps)?),
+        QueryMsg::GetInfo {} => to_binary(&query_info(deps)?),
+        QueryMsg::GetReporter {} => to_binary(&REPORTER.load(deps.storage)?),
+    }
+}
+
+fn query_info(deps: Deps) -> StdResult<InfoManagedResponse<DisplayInfo>> {
+    let state: DisplayInfo = STATE.load(deps.storage)?;
+    let sale_info: Saleable =  (query_saleable_info(deps, SALEABLE_SERVICE)?).info;
+
+    let managed_info: Manageable = {
+        let res =
+        query_manageable_info (deps, MANAGEABLE_SERVICE)?;
+        res.manager
+    };
+    let owners = list_members(deps, None, None, MEMBERS)?;
+    Ok(InfoManagedResponse { data: state, sale_info, managed_info, owners: owners.members, admin: ADMIN.get(deps)? })
+}
+
+fn query_name(deps: Deps) -> StdResult<NameResponse> {
+    let state = STATE.load(deps.storage)?;
+    Ok(NameResponse { name: state.name })
+}
@@ -0,0 +1,37 @@
+use cosmwasm_std::{Addr, StdError};
+use cw_controllers::AdminError;
+use thiserror::Error;
+
+use group_admin::GroupAdminError;
+use managed::error::ManagedServiceError;
+use saleable::error::SaleableItemError;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum DisplayError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+
+    #[error("{0}")]
+    Admin(#[from] AdminError),
+
+    #[error("{0}")]
+    GroupAdminHooksError(#[from] GroupAdminError),
+
+
+    #[error("{0}")]
+    SaleItemErrors (#[from] SaleableItemError),
+
+    #[error("{0}")]
+    ManagableServiceError(#[from] ManagedServiceError),
+
+
+
+    #[error("Unauthorized")]
+    Unauthorized { sender: Addr},
+
+
+    #[error("InvalidDisplayDetails")]
+    InvalidDisplayDetails {},
+
+}
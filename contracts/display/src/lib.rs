@@ -0,0 +1,6 @@
+pub use crate::error::DisplayError;
+
+pub mod contract;
+mod error;
+pub mod msg;
+pub mod state;
@@ -0,0 +1,51 @@
+use cosmwasm_std::{Addr, Coin, Timestamp};
+use cw4::Member;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use managed::messages::ManagedExecuteMsg;
+use shared::display::DisplayDetails;
+use shared::messaging::InboxContent;
+use shared::utils::MessageId;
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InstantiateDisplayMsg {
+    pub name: String,
+    pub admin: String,
+    pub members: Vec<Member>,
+    pub managing_contract: Option<Addr>,
+    pub for_sale: bool,
+    pub price: Option<Coin>,
+    pub details: DisplayDetails,
+    //the screen's own key, allowed to send heartbeats besides the admin
+    pub reporter: Option<String>,
+}
+
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    ManagedServiceMessage {message: ManagedExecuteMsg},
+    UpdateDetails { details: DisplayDetails },
+    SetReporter { reporter: Option<String> },
+    Heartbeat { online: bool },
+    RespondToBroadcast { booking_id: u64, accept: bool },
+    CancelBroadcast { booking_id: u64 },
+    RespondToAd { ad_id: u64, accept: bool, attestor: Option<String> },
+    //reported like a heartbeat, by the admin or the reporter
+    AttestAdAired { ad_id: u64 },
+    SendMessage { to: String, content: InboxContent, body: String, reply_to: Option<MessageId>, expires: Option<Timestamp> },
+    MarkMessageRead { message_id: MessageId, read: bool },
+}
+
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    GetName {},
+    GetInfo {},
+    GetReporter {},
+}
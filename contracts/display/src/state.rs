@@ -0,0 +1,16 @@
+use cosmwasm_std::Addr;
+use cw_controllers::Admin;
+use cw_storage_plus::Item;
+
+use managed::service::ManagedService;
+use saleable::service::SaleableService;
+use shared::display::DisplayInfo;
+
+
+
+pub const STATE: Item<DisplayInfo> = Item::new("state");
+pub const REPORTER: Item<Option<Addr>> = Item::new("reporter");
+
+pub const SALEABLE_SERVICE: SaleableService = SaleableService::new("saleable_service");
+pub const MANAGEABLE_SERVICE: ManagedService = ManagedService::new("manageable_service");
+pub const ADMIN: Admin = Admin::new("admin");
@@ -0,0 +1,211 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::Addr;
+
+use shared::player_attributes::Positions;
+use shared::stats::{PlayerStats, TeamStats};
+
+use crate::lineup::Lineup;
+use crate::simulation::{GameResult, PlayOutcome, PlayType};
+
+
+pub struct BoxScore {
+    pub home: TeamStats,
+    pub away: TeamStats,
+    //every player in either lineup, whether or not they touched the ball
+    pub players: BTreeMap<Addr, PlayerStats>,
+}
+
+
+// Tallies a game's play log. `home` and `away` must be the lineups the game
+// was simulated with; passing plays are credited to the offense's QB.
+pub fn box_score(game: &GameResult, home: &Lineup, away: &Lineup) -> BoxScore {
+    let mut players: BTreeMap<Addr, PlayerStats> = BTreeMap::new();
+    for p in home.players.iter().chain(away.players.iter()) {
+        players.insert(p.player.clone(), PlayerStats { games: 1, position: Some(p.position.clone()), ..PlayerStats::default() });
+    }
+    let mut home_stats = team_result(game.home_score, game.away_score);
+    let mut away_stats = team_result(game.away_score, game.home_score);
+
+    for play in game.plays.iter() {
+        let (offense, team) =
+            match play.offense == game.home {
+                true => (home, &mut home_stats),
+                false => (away, &mut away_stats)
+            };
+        let yards = play.yards as i64;
+        let touchdown = play.outcome == PlayOutcome::Touchdown;
+        let mut credit = |addr: &Option<Addr>, f: &dyn Fn(&mut PlayerStats)| {
+            if let Some(s) = addr.as_ref().and_then(|a| players.get_mut(a)) {
+                f(s);
+            }
+        };
+
+        match (&play.play_type, &play.outcome) {
+            (PlayType::Run, outcome) => {
+                team.rushing_yards += yards;
+                credit(&play.ball_carrier, &|s| {
+                    s.rushing_attempts += 1;
+                    s.rushing_yards += yards;
+                    s.rushing_touchdowns += touchdown as u32;
+                    s.fumbles_lost += (*outcome == PlayOutcome::Fumble) as u32;
+                });
+                if !touchdown {
+                    credit(&play.defender, &|s| s.tackles += 1);
+                }
+                team.turnovers += (*outcome == PlayOutcome::Fumble) as u32;
+            }
+            //a QB sacked in his own end zone is a safety
+            (PlayType::Pass, PlayOutcome::Sack) |
+            (PlayType::Pass, PlayOutcome::Safety) if play.ball_carrier == offense.address_at(Positions::QB) => {
+                team.sacks_allowed += 1;
+                credit(&play.ball_carrier, &|s| s.sacks_taken += 1);
+                credit(&play.defender, &|s| {
+                    s.sacks += 1;
+                    s.tackles += 1;
+                });
+            }
+            (PlayType::Pass, PlayOutcome::Incomplete) => {
+                credit(&offense.address_at(Positions::QB), &|s| s.passing_attempts += 1);
+            }
+            (PlayType::Pass, PlayOutcome::Interception) => {
+                team.turnovers += 1;
+                credit(&offense.address_at(Positions::QB), &|s| {
+                    s.passing_attempts += 1;
+                    s.interceptions_thrown += 1;
+                });
+                credit(&play.defender, &|s| s.interceptions += 1);
+            }
+            (PlayType::Pass, _) => {
+                team.passing_yards += yards;
+                credit(&offense.address_at(Positions::QB), &|s| {
+                    s.passing_attempts += 1;
+                    s.completions += 1;
+                    s.passing_yards += yards;
+                    s.passing_touchdowns += touchdown as u32;
+                });
+                credit(&play.ball_carrier, &|s| {
+                    s.receptions += 1;
+                    s.receiving_yards += yards;
+                    s.receiving_touchdowns += touchdown as u32;
+                });
+                if !touchdown {
+                    credit(&play.defender, &|s| s.tackles += 1);
+                }
+            }
+            (PlayType::FieldGoal, PlayOutcome::FieldGoal { made: true, .. }) => team.field_goals += 1,
+            _ => ()
+        }
+        team.touchdowns += touchdown as u32;
+    }
+
+    BoxScore { home: home_stats, away: away_stats, players }
+}
+
+
+fn team_result(points_for: u32, points_against: u32) -> TeamStats {
+    TeamStats {
+        games: 1,
+        wins: (points_for > points_against) as u32,
+        losses: (points_for < points_against) as u32,
+        ties: (points_for == points_against) as u32,
+        points_for: points_for as u64,
+        points_against: points_against as u64,
+        ..TeamStats::default()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use shared::fixture::PlayCallingStrategy;
+    use shared::player_attributes::{AttrItem, PlayerAttributes};
+
+    use crate::lineup::LineupPlayer;
+    use crate::simulation::{Play, simulate_game};
+
+    use super::*;
+
+    fn lineup(team: &str, positions: Vec<Positions>) -> Lineup {
+        let attr = || AttrItem { value: "6.0".to_string() };
+        Lineup {
+            team: Addr::unchecked(team),
+            players: positions.into_iter().enumerate().map(|(i, position)| LineupPlayer {
+                player: Addr::unchecked(format!("{}{}", team, i)),
+                position,
+                attributes: PlayerAttributes {
+                    hands: attr(),
+                    accuracy: attr(),
+                    speed: attr(),
+                    strength: attr(),
+                    leader: attr(),
+                    pressure_threshold: attr(),
+                    agility: attr(),
+                    football_iq: attr(),
+                    temperament: attr(),
+                    angle_of_view: 90,
+                }
+            }).collect(),
+            strategy: PlayCallingStrategy::Balanced
+        }
+    }
+
+    #[test]
+    fn box_score_matches_the_game() {
+        let home = lineup("home", vec![Positions::QB, Positions::RB, Positions::WR1, Positions::WR2, Positions::S, Positions::LB]);
+        let away = lineup("away", vec![Positions::QB, Positions::RB, Positions::WR1, Positions::WR2, Positions::CB1, Positions::CD]);
+        let game = simulate_game(&home, &away, 11);
+        let score = box_score(&game, &home, &away);
+
+        assert_eq!(12, score.players.len());
+        assert_eq!(game.home_score as u64, score.home.points_for);
+        assert_eq!(score.home.points_for, score.away.points_against);
+        assert_eq!(1, score.home.wins + score.home.losses + score.home.ties);
+
+        //every completion is a reception and all passing yards were caught
+        let (mut completions, mut receptions, mut passing, mut receiving) = (0, 0, 0, 0);
+        for s in score.players.values() {
+            completions += s.completions;
+            receptions += s.receptions;
+            passing += s.passing_yards;
+            receiving += s.receiving_yards;
+        }
+        assert_eq!(completions, receptions);
+        assert_eq!(passing, receiving);
+        assert_eq!(score.home.passing_yards + score.away.passing_yards, passing);
+        let touchdowns = score.home.touchdowns as u64 * 7;
+        assert!(touchdowns <= score.home.points_for);
+    }
+
+    #[test]
+    fn sack_for_a_safety_is_a_sack() {
+        let home = lineup("home", vec![Positions::QB, Positions::WR1]);
+        let away = lineup("away", vec![Positions::TL]);
+        let game = GameResult {
+            home: home.team.clone(),
+            away: away.team.clone(),
+            home_score: 0,
+            away_score: 2,
+            plays: vec![Play {
+                number: 1,
+                quarter: 1,
+                offense: home.team.clone(),
+                down: 1,
+                yards_to_go: 10,
+                yard_line: 3,
+                play_type: PlayType::Pass,
+                ball_carrier: home.address_at(Positions::QB),
+                defender: away.address_at(Positions::TL),
+                yards: -3,
+                outcome: PlayOutcome::Safety,
+                home_score: 0,
+                away_score: 2
+            }]
+        };
+        let score = box_score(&game, &home, &away);
+        let qb = &score.players[&Addr::unchecked("home0")];
+        assert_eq!((1, 0, 0, 0), (qb.sacks_taken, qb.passing_attempts, qb.completions, qb.receptions));
+        assert_eq!(1, score.players[&Addr::unchecked("away0")].sacks);
+        assert_eq!((1, 0), (score.home.sacks_allowed, score.home.passing_yards));
+    }
+}
@@ -0,0 +1,8 @@
+pub use crate::box_score::{BoxScore, box_score};
+pub use crate::lineup::{Lineup, LineupPlayer, Ratings};
+pub use crate::simulation::{GameResult, Play, PlayOutcome, PlayType, simulate_game};
+
+pub mod box_score;
+pub mod lineup;
+pub mod rng;
+pub mod simulation;
@@ -0,0 +1,128 @@
+use cosmwasm_std::Addr;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use shared::fixture::PlayCallingStrategy;
+use shared::player_attributes::{AttrItem, PlayerAttributes, Positions, SideOfBall};
+
+//attribute values are capped at 100.00
+pub const MAX_RATING: i32 = 10_000;
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LineupPlayer {
+    pub player: Addr,
+    pub position: Positions,
+    pub attributes: PlayerAttributes,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Lineup {
+    pub team: Addr,
+    pub players: Vec<LineupPlayer>,
+    pub strategy: PlayCallingStrategy,
+}
+
+impl Lineup {
+    pub fn player_at(&self, position: Positions) -> Option<&LineupPlayer> {
+        self.players.iter().find(|p| p.position == position)
+    }
+
+    //An empty position plays with every rating at zero
+    pub fn ratings_at(&self, position: Positions) -> Ratings {
+        match self.player_at(position) {
+            Some(p) => Ratings::from_attributes(&p.attributes),
+            None => Ratings::default()
+        }
+    }
+
+    pub fn address_at(&self, position: Positions) -> Option<Addr> {
+        self.player_at(position).map(|p| p.player.clone())
+    }
+
+    pub fn side(&self, side: SideOfBall) -> Vec<&LineupPlayer> {
+        self.players.iter().filter(|p| p.position.side_of_ball() == side).collect()
+    }
+}
+
+
+//Attribute values in hundredths ("7.25" is 725) so the simulation never
+//touches floating point, which wasm contracts can't use
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ratings {
+    pub hands: i32,
+    pub accuracy: i32,
+    pub speed: i32,
+    pub strength: i32,
+    pub leader: i32,
+    pub pressure_threshold: i32,
+    pub agility: i32,
+    pub football_iq: i32,
+    pub temperament: i32,
+    pub angle_of_view: i32,
+}
+
+impl Ratings {
+    pub fn from_attributes(attributes: &PlayerAttributes) -> Self {
+        Ratings {
+            hands: parse_rating(&attributes.hands),
+            accuracy: parse_rating(&attributes.accuracy),
+            speed: parse_rating(&attributes.speed),
+            strength: parse_rating(&attributes.strength),
+            leader: parse_rating(&attributes.leader),
+            pressure_threshold: parse_rating(&attributes.pressure_threshold),
+            agility: parse_rating(&attributes.agility),
+            football_iq: parse_rating(&attributes.football_iq),
+            temperament: parse_rating(&attributes.temperament),
+            angle_of_view: attributes.angle_of_view as i32,
+        }
+    }
+}
+
+
+//Unparseable or negative values rate as zero; anything past two decimals is dropped
+pub fn parse_rating(item: &AttrItem) -> i32 {
+    let value = item.value.trim();
+    let (whole, fraction) =
+        match value.split_once('.') {
+            Some((w, f)) => (w, f),
+            None => (value, "")
+        };
+    let whole: i32 =
+        match whole {
+            "" => 0,
+            w => match w.parse::<u32>() {
+                Ok(n) => n.min(MAX_RATING as u32) as i32,
+                Err(_) => return 0
+            }
+        };
+    let fraction: String = fraction.chars().chain("00".chars()).take(2).collect();
+    let fraction: i32 =
+        match fraction.parse::<u32>() {
+            Ok(n) => n as i32,
+            Err(_) => return 0
+        };
+    (whole * 100 + fraction).min(MAX_RATING)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn attr(value: &str) -> AttrItem {
+        AttrItem { value: value.to_string() }
+    }
+
+    #[test]
+    fn ratings_parse_to_hundredths() {
+        assert_eq!(725, parse_rating(&attr("7.25")));
+        assert_eq!(700, parse_rating(&attr(" 7 ")));
+        assert_eq!(750, parse_rating(&attr("7.5")));
+        assert_eq!(712, parse_rating(&attr("7.129")));
+        assert_eq!(0, parse_rating(&attr("-3.0")));
+        assert_eq!(0, parse_rating(&attr("fast")));
+        assert_eq!(MAX_RATING, parse_rating(&attr("250.0")));
+    }
+}
@@ -0,0 +1,37 @@
+//xorshift64; the same seed always produces the same sequence on every machine
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        //xorshift gets stuck on zero
+        Rng { state: seed | 1 }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    //0..n
+    pub fn below(&mut self, n: u64) -> u64 {
+        match n {
+            0 => 0,
+            _ => self.next_u64() % n
+        }
+    }
+
+    //lo..=hi
+    pub fn range(&mut self, lo: i32, hi: i32) -> i32 {
+        lo + self.below((hi - lo + 1) as u64) as i32
+    }
+
+    //true `per_mille` times out of 1000
+    pub fn chance(&mut self, per_mille: i32) -> bool {
+        (self.below(1000) as i32) < per_mille
+    }
+}
@@ -0,0 +1,507 @@
+use cosmwasm_std::Addr;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use shared::fixture::PlayCallingStrategy;
+use shared::player_attributes::Positions;
+
+use crate::lineup::{Lineup, Ratings};
+use crate::rng::Rng;
+
+pub const QUARTERS: u32 = 4;
+pub const PLAYS_PER_QUARTER: u32 = 24;
+
+//yard lines are measured from the offense's own goal line
+const FIELD_LENGTH: i32 = 100;
+const DRIVE_START: i32 = 25;
+const TOUCHBACK: i32 = 20;
+const FIRST_DOWN_YARDS: i32 = 10;
+//end zone depth plus the snap
+const FIELD_GOAL_EXTRA_YARDS: i32 = 17;
+const MAX_FIELD_GOAL_DISTANCE: i32 = 55;
+
+const TOUCHDOWN_POINTS: u32 = 7;
+const FIELD_GOAL_POINTS: u32 = 3;
+const SAFETY_POINTS: u32 = 2;
+
+//rating edge (in hundredths) worth one yard
+const RATING_PER_YARD: i32 = 100;
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum PlayType {
+    Run,
+    Pass,
+    Punt,
+    FieldGoal,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum PlayOutcome {
+    Gain,
+    FirstDown,
+    Touchdown,
+    Incomplete,
+    Sack,
+    Fumble,
+    Interception,
+    Safety,
+    FieldGoal { made: bool, distance: i32 },
+    Punt,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Play {
+    pub number: u32,
+    pub quarter: u32,
+    pub offense: Addr,
+    pub down: u8,
+    pub yards_to_go: i32,
+    //where the ball was snapped, from the offense's goal line
+    pub yard_line: i32,
+    pub play_type: PlayType,
+    pub ball_carrier: Option<Addr>,
+    pub defender: Option<Addr>,
+    pub yards: i32,
+    pub outcome: PlayOutcome,
+    //score after the play
+    pub home_score: u32,
+    pub away_score: u32,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GameResult {
+    pub home: Addr,
+    pub away: Addr,
+    pub home_score: u32,
+    pub away_score: u32,
+    pub plays: Vec<Play>,
+}
+
+impl GameResult {
+    //None on a tie
+    pub fn winner(&self) -> Option<&Addr> {
+        match self.home_score.cmp(&self.away_score) {
+            std::cmp::Ordering::Greater => Some(&self.home),
+            std::cmp::Ordering::Less => Some(&self.away),
+            std::cmp::Ordering::Equal => None
+        }
+    }
+}
+
+
+struct Drive {
+    home_has_ball: bool,
+    yard_line: i32,
+    down: u8,
+    to_go: i32,
+}
+
+impl Drive {
+    fn start(home_has_ball: bool, yard_line: i32) -> Self {
+        Drive {
+            home_has_ball,
+            yard_line,
+            down: 1,
+            to_go: FIRST_DOWN_YARDS.min(FIELD_LENGTH - yard_line),
+        }
+    }
+
+    //the other team takes over where the ball is
+    fn turnover(&self, yard_line: i32) -> Self {
+        Drive::start(!self.home_has_ball, (FIELD_LENGTH - yard_line).max(1).min(FIELD_LENGTH - 1))
+    }
+}
+
+
+fn avg(values: &[i32]) -> i32 {
+    match values.len() {
+        0 => 0,
+        n => values.iter().sum::<i32>() / n as i32
+    }
+}
+
+
+fn clamp(value: i32, lo: i32, hi: i32) -> i32 {
+    value.max(lo).min(hi)
+}
+
+
+struct ResolvedPlay {
+    play_type: PlayType,
+    ball_carrier: Option<Addr>,
+    defender: Option<Addr>,
+    yards: i32,
+    outcome: PlayOutcome,
+}
+
+
+fn offensive_line(offense: &Lineup) -> [Ratings; 3] {
+    [offense.ratings_at(Positions::CO), offense.ratings_at(Positions::GL), offense.ratings_at(Positions::GR)]
+}
+
+
+fn defensive_line(defense: &Lineup) -> [Ratings; 2] {
+    [defense.ratings_at(Positions::TL), defense.ratings_at(Positions::TR)]
+}
+
+
+fn run_play(rng: &mut Rng, offense: &Lineup, defense: &Lineup) -> ResolvedPlay {
+    let rb = offense.ratings_at(Positions::RB);
+    let line = offensive_line(offense);
+    let d_line = defensive_line(defense);
+    let lb = defense.ratings_at(Positions::LB);
+    let cd = defense.ratings_at(Positions::CD);
+    let s = defense.ratings_at(Positions::S);
+
+    let blocking = avg(&line.iter().map(|r| r.strength).collect::<Vec<i32>>());
+    let attack = avg(&[rb.speed, rb.strength, rb.agility]) + blocking / 2;
+    let stop = avg(&[lb.strength, cd.strength, d_line[0].strength, d_line[1].strength]) +
+        avg(&[lb.football_iq, s.speed]) / 2;
+
+    let mut yards = rng.range(-3, 8) + (attack - stop) / RATING_PER_YARD;
+    //breakaway runs come from pure speed
+    if rng.chance(clamp(30 + (rb.speed - s.speed) / 20, 10, 120)) {
+        yards += rng.range(10, 40);
+    }
+    let tackler =
+        match rng.below(2) {
+            0 => defense.address_at(Positions::LB),
+            _ => defense.address_at(Positions::CD)
+        };
+    //sure hands and a calm temperament hold on to the ball
+    let fumbled = rng.chance(clamp(35 - avg(&[rb.hands, rb.temperament]) / 40, 5, 60));
+    ResolvedPlay {
+        play_type: PlayType::Run,
+        ball_carrier: offense.address_at(Positions::RB),
+        defender: tackler,
+        yards,
+        outcome: match fumbled { true => PlayOutcome::Fumble, false => PlayOutcome::Gain }
+    }
+}
+
+
+fn pass_play(rng: &mut Rng, offense: &Lineup, defense: &Lineup) -> ResolvedPlay {
+    let qb = offense.ratings_at(Positions::QB);
+    let line = offensive_line(offense);
+    let d_line = defensive_line(defense);
+    let lb = defense.ratings_at(Positions::LB);
+    let s = defense.ratings_at(Positions::S);
+
+    //pass rush against protection and the quarterback's composure
+    let rush = avg(&[d_line[0].speed, d_line[1].speed, lb.speed]);
+    let protection = avg(&line.iter().map(|r| r.strength).collect::<Vec<i32>>());
+    if rng.chance(clamp(70 + (rush - protection) / 20 - qb.pressure_threshold / 40, 15, 220)) {
+        let sacker =
+            match rng.below(2) {
+                0 => defense.address_at(Positions::TL),
+                _ => defense.address_at(Positions::TR)
+            };
+        return ResolvedPlay {
+            play_type: PlayType::Pass,
+            ball_carrier: offense.address_at(Positions::QB),
+            defender: sacker,
+            yards: -rng.range(2, 9),
+            outcome: PlayOutcome::Sack
+        }
+    }
+
+    let targets = [(Positions::WR1, Positions::CB1), (Positions::WR2, Positions::CB2)];
+    let separation = |(wr, cb): &(Positions, Positions)| -> i32 {
+        let (wr, cb) = (offense.ratings_at(wr.clone()), defense.ratings_at(cb.clone()));
+        avg(&[wr.speed, wr.agility]) - avg(&[cb.speed, cb.agility])
+    };
+    //a wider field of view finds the open receiver more often
+    let (receiver, corner) =
+        match rng.chance(clamp(qb.angle_of_view * 1000 / 180, 0, 1000)) {
+            true => match separation(&targets[0]) >= separation(&targets[1]) {
+                true => targets[0].clone(),
+                false => targets[1].clone()
+            },
+            false => targets[rng.below(2) as usize].clone()
+        };
+    let wr = offense.ratings_at(receiver.clone());
+    let cb = defense.ratings_at(corner.clone());
+
+    let completion = clamp(550 + (avg(&[qb.accuracy, wr.hands]) - avg(&[cb.agility, cb.football_iq])) / 10 +
+                               qb.leader / 50, 200, 850);
+    match rng.chance(completion) {
+        true => {
+            let mut yards = rng.range(3, 15) + (wr.speed - cb.speed) / RATING_PER_YARD;
+            if rng.chance(clamp(50 + (wr.agility - cb.agility) / 20, 10, 150)) {
+                yards += rng.range(10, 45);
+            }
+            ResolvedPlay {
+                play_type: PlayType::Pass,
+                ball_carrier: offense.address_at(receiver),
+                defender: defense.address_at(corner),
+                yards,
+                outcome: PlayOutcome::Gain
+            }
+        }
+        false => {
+            let intercepted = rng.chance(clamp(120 + (s.football_iq - qb.football_iq) / 20, 30, 300));
+            ResolvedPlay {
+                play_type: PlayType::Pass,
+                ball_carrier: offense.address_at(receiver),
+                defender: match intercepted {
+                    true => defense.address_at(Positions::S),
+                    false => defense.address_at(corner)
+                },
+                yards: 0,
+                outcome: match intercepted { true => PlayOutcome::Interception, false => PlayOutcome::Incomplete }
+            }
+        }
+    }
+}
+
+
+fn fourth_down_play(rng: &mut Rng, drive: &Drive) -> ResolvedPlay {
+    let distance = FIELD_LENGTH - drive.yard_line + FIELD_GOAL_EXTRA_YARDS;
+    match distance <= MAX_FIELD_GOAL_DISTANCE {
+        true => {
+            let made = rng.chance(clamp(980 - (distance - 20) * 18, 150, 980));
+            ResolvedPlay {
+                play_type: PlayType::FieldGoal,
+                ball_carrier: None,
+                defender: None,
+                yards: 0,
+                outcome: PlayOutcome::FieldGoal { made, distance }
+            }
+        }
+        false => ResolvedPlay {
+            play_type: PlayType::Punt,
+            ball_carrier: None,
+            defender: None,
+            yards: rng.range(35, 50),
+            outcome: PlayOutcome::Punt
+        }
+    }
+}
+
+
+fn strategy_bias(strategy: &PlayCallingStrategy) -> i32 {
+    match strategy {
+        PlayCallingStrategy::Balanced => 0,
+        PlayCallingStrategy::RunHeavy => -200,
+        PlayCallingStrategy::PassHeavy => 200,
+    }
+}
+
+
+fn choose_play(rng: &mut Rng, offense: &Lineup, drive: &Drive) -> PlayType {
+    let qb = offense.ratings_at(Positions::QB);
+    let rb = offense.ratings_at(Positions::RB);
+    //lean on the better of the two and throw more on long yardage
+    let pass_bias = clamp(500 + strategy_bias(&offense.strategy) + (qb.accuracy - rb.strength) / 20 +
+                              (drive.to_go - 5) * 15, 200, 850);
+    match rng.chance(pass_bias) {
+        true => PlayType::Pass,
+        false => PlayType::Run
+    }
+}
+
+
+// Plays a fixed number of snaps per quarter. The home team receives the
+// opening kickoff and the away team the second half kickoff. The same
+// lineups and seed always produce the same result.
+pub fn simulate_game(home: &Lineup, away: &Lineup, seed: u64) -> GameResult {
+    let mut rng = Rng::new(seed);
+    let mut plays: Vec<Play> = vec![];
+    let (mut home_score, mut away_score) = (0u32, 0u32);
+    let mut drive = Drive::start(true, DRIVE_START);
+
+    for number in 1..=QUARTERS * PLAYS_PER_QUARTER {
+        let quarter = (number - 1) / PLAYS_PER_QUARTER + 1;
+        if number == (QUARTERS / 2) * PLAYS_PER_QUARTER + 1 {
+            drive = Drive::start(false, DRIVE_START);
+        }
+        let (offense, defense) =
+            match drive.home_has_ball {
+                true => (home, away),
+                false => (away, home)
+            };
+
+        let resolved =
+            match drive.down >= 4 {
+                true => fourth_down_play(&mut rng, &drive),
+                false => match choose_play(&mut rng, offense, &drive) {
+                    PlayType::Pass => pass_play(&mut rng, offense, defense),
+                    _ => run_play(&mut rng, offense, defense)
+                }
+            };
+
+        let mut outcome = resolved.outcome.clone();
+        let mut yards = resolved.yards;
+        let (mut offense_points, mut defense_points) = (0u32, 0u32);
+        let next_drive =
+            match &resolved.outcome {
+                PlayOutcome::FieldGoal { made: true, .. } => {
+                    offense_points = FIELD_GOAL_POINTS;
+                    Drive::start(!drive.home_has_ball, DRIVE_START)
+                }
+                PlayOutcome::FieldGoal { made: false, .. } => drive.turnover(drive.yard_line),
+                PlayOutcome::Punt => {
+                    match drive.yard_line + yards >= FIELD_LENGTH {
+                        true => {
+                            yards = FIELD_LENGTH - drive.yard_line;
+                            Drive::start(!drive.home_has_ball, TOUCHBACK)
+                        }
+                        false => drive.turnover(drive.yard_line + yards)
+                    }
+                }
+                PlayOutcome::Interception => drive.turnover(drive.yard_line),
+                _ => {
+                    let yard_line = drive.yard_line + yards;
+                    match (yard_line >= FIELD_LENGTH, yard_line <= 0, &resolved.outcome) {
+                        (true, _, _) if resolved.outcome != PlayOutcome::Fumble => {
+                            yards = FIELD_LENGTH - drive.yard_line;
+                            offense_points = TOUCHDOWN_POINTS;
+                            outcome = PlayOutcome::Touchdown;
+                            Drive::start(!drive.home_has_ball, DRIVE_START)
+                        }
+                        (_, true, _) => {
+                            yards = -drive.yard_line;
+                            defense_points = SAFETY_POINTS;
+                            outcome = PlayOutcome::Safety;
+                            Drive::start(!drive.home_has_ball, DRIVE_START)
+                        }
+                        (_, _, PlayOutcome::Fumble) => drive.turnover(yard_line.min(FIELD_LENGTH - 1)),
+                        _ => {
+                            match yards >= drive.to_go {
+                                true => {
+                                    outcome = match outcome {
+                                        PlayOutcome::Gain => PlayOutcome::FirstDown,
+                                        o => o
+                                    };
+                                    Drive::start(drive.home_has_ball, yard_line)
+                                }
+                                false => Drive {
+                                    home_has_ball: drive.home_has_ball,
+                                    yard_line,
+                                    down: drive.down + 1,
+                                    to_go: drive.to_go - yards,
+                                }
+                            }
+                        }
+                    }
+                }
+            };
+
+        match drive.home_has_ball {
+            true => {
+                home_score += offense_points;
+                away_score += defense_points;
+            }
+            false => {
+                away_score += offense_points;
+                home_score += defense_points;
+            }
+        }
+        plays.push(Play {
+            number,
+            quarter,
+            offense: offense.team.clone(),
+            down: drive.down,
+            yards_to_go: drive.to_go,
+            yard_line: drive.yard_line,
+            play_type: resolved.play_type,
+            ball_carrier: resolved.ball_carrier,
+            defender: resolved.defender,
+            yards,
+            outcome,
+            home_score,
+            away_score,
+        });
+        drive = next_drive;
+    }
+
+    GameResult {
+        home: home.team.clone(),
+        away: away.team.clone(),
+        home_score,
+        away_score,
+        plays,
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use shared::player_attributes::{AttrItem, PlayerAttributes};
+
+    use crate::lineup::LineupPlayer;
+
+    use super::*;
+
+    const ALL_POSITIONS: [Positions; 14] = [
+        Positions::RB, Positions::QB, Positions::WR1, Positions::WR2, Positions::CO, Positions::GL, Positions::GR,
+        Positions::S, Positions::CB1, Positions::CB2, Positions::LB, Positions::CD, Positions::TR, Positions::TL];
+
+    fn lineup(team: &str, rating: &str) -> Lineup {
+        let attr = || AttrItem { value: rating.to_string() };
+        Lineup {
+            team: Addr::unchecked(team),
+            players: ALL_POSITIONS.iter().enumerate().map(|(i, position)| LineupPlayer {
+                player: Addr::unchecked(format!("{}{}", team, i)),
+                position: position.clone(),
+                attributes: PlayerAttributes {
+                    hands: attr(),
+                    accuracy: attr(),
+                    speed: attr(),
+                    strength: attr(),
+                    leader: attr(),
+                    pressure_threshold: attr(),
+                    agility: attr(),
+                    football_iq: attr(),
+                    temperament: attr(),
+                    angle_of_view: 90,
+                }
+            }).collect(),
+            strategy: PlayCallingStrategy::Balanced
+        }
+    }
+
+    #[test]
+    fn same_seed_same_game() {
+        let (home, away) = (lineup("home", "5.0"), lineup("away", "5.0"));
+        let game = simulate_game(&home, &away, 42);
+        assert_eq!(game, simulate_game(&home, &away, 42));
+        assert_eq!((QUARTERS * PLAYS_PER_QUARTER) as usize, game.plays.len());
+        let last = game.plays.last().unwrap();
+        assert_eq!((game.home_score, game.away_score), (last.home_score, last.away_score));
+        assert!((1..=1000u64).any(|seed| simulate_game(&home, &away, seed) != game));
+    }
+
+    #[test]
+    fn better_attributes_win_more() {
+        let (strong, weak) = (lineup("strong", "9.0"), lineup("weak", "2.0"));
+        let strong_wins = (1..=50u64).
+            filter(|seed| simulate_game(&strong, &weak, *seed).winner() == Some(&strong.team)).
+            count();
+        assert!(strong_wins > 40);
+
+        //an empty roster still plays a full game
+        let empty = Lineup { team: Addr::unchecked("empty"), players: vec![], strategy: PlayCallingStrategy::Balanced };
+        let game = simulate_game(&strong, &empty, 7);
+        assert_eq!(Some(&strong.team), game.winner());
+    }
+
+    #[test]
+    fn play_calling_strategy_shapes_the_play_mix() {
+        let passes = |strategy: PlayCallingStrategy| -> usize {
+            let home = Lineup { strategy, ..lineup("home", "5.0") };
+            let away = lineup("away", "5.0");
+            (1..=20u64).
+                map(|seed| simulate_game(&home, &away, seed).plays.into_iter().
+                    filter(|p| p.offense == home.team && p.play_type == PlayType::Pass).
+                    count()).
+                sum()
+        };
+        assert!(passes(PlayCallingStrategy::PassHeavy) > passes(PlayCallingStrategy::Balanced));
+        assert!(passes(PlayCallingStrategy::Balanced) > passes(PlayCallingStrategy::RunHeavy));
+    }
+}
@@ -13,7 +13,7 @@ use cosmwasm_std::testing::mock_env;
 use cw2::set_contract_version;
 
 
-use cw_storage_plus::{Item, Map, MultiIndex, PrefixBound};
+use cw_storage_plus::{Bound, Item, Map, MultiIndex, PrefixBound};
 use StdError::NotFound;
 use group_admin::execute::execute_group_admin_message;
 use group_admin::service::initialize_members_and_admin;
@@ -27,33 +27,89 @@ use shared::goi_manager::{GoiMangerContractModel, GoiManagerQueryMsg, ExecuteMsg
 use shared::GoiError;
 use shared::league::LeagueTeamAssignment;
 use shared::manage::{Management, ManagementFee, ManagedStatus, ManagedContract, ManagedStatusChangedHookMsg};
-use shared::messaging::{DeliveryInfo, DeliveryPacket, JoinSeasonRequestInfo, Message, MessageTypes};
-use shared::player::{PlayerInfo, PlayerInfoPacket};
+use shared::messaging::{DeliveryInfo, DeliveryPacket, InboxContent, InboxMessageInfo, JoinSeasonRequestInfo, Message, MessageTypes, SeasonRescheduleInfo, MAX_INBOX_BODY_LENGTH};
+use shared::player::{self as player, CareerSummary, FreeAgent, LineupCheckResponse, normalized_player_name, PlayerInfo, PlayerStatus,
+                     remove_retired_player_msg, update_player_status_msg};
+use shared::player_attributes::{Positions, SideOfBall};
 
 
 use shared::rewards::{Reward, RewardTypes};
-use shared::season::{Season, SeasonAccessTypes, SeasonLedger, SeasonModelData, SeasonStatus};
+use shared::season::{LeagueSeasonTemplate, Season, SeasonAccessTypes, SeasonCapacityPolicy, SeasonLedger, SeasonModelData, SeasonStatus, SeasonTemplate};
+use shared::division::{Division, DivisionModelData, DivisionTeamAssignment, TeamStanding, TeamStandingItem};
+use shared::playoffs::{PlayoffFormat, Playoffs};
+use shared::fixture::{Fixture, FixtureLineup, FixtureResult, GameDayLineup, LineupSlot, PlayCallingStrategy, SeedCommitment, seed_commitment};
+use game_engine::{box_score, Lineup, LineupPlayer, simulate_game};
+use shared::stats::{PlayerStats, TeamStats};
+use shared::leaderboard::{HallOfFameEntry, PlayerLeaderboardItem, StatCategory, TeamRecord};
+use shared::league::{LeagueInfo, LeagueTypes};
+use shared::team::{deposit_to_team_treasury_msg, TeamCoaching};
+use shared::display::{DisplayDetails, DisplayInfo, DisplayStatusResponse, Heartbeat};
+use shared::broadcast::{BroadcastBooking, BroadcastStatus, MAX_BROADCAST_SLOT};
+use shared::advertising::{AdContent, AdPlacement, AdSlot, AdStatus, AssetInfoQueryMsg, AssetOwnersResponse};
+use shared::trade::{apply_trade_msg, TradeProposal, TradeStatus};
+use shared::player_contract::{pay_salaries_msg, PlayerContract, PlayerSigning, SalaryPayment};
+use shared::draft::{add_drafted_players_msg, Draft, DraftLottery, DraftOrderType, DraftPickRef, RosterResponse, TeamRosterQueryMsg};
 pub use shared::team::{TeamInfo};
 use shared::utils::{Fee, FeeType, FName, MangedItem, PlayerAddr, TeamAddr, PlayerTeamAddr, BlockTime,
                     ManagedItemResponse, AssetSaleItems, AssetSaleItem, AssetSaleItemAddUpdateModel,
-                    OwnershipHistory, SeasonId, MessageId, PRIOR_TO_SEASON_START_PADDING};
-use shared::utils::general::{AssetTypes, GameItemTypes, generate_id_from_strings, index_string, merge_strings};
+                    OwnershipHistory, SeasonId, MessageId, DivisionId, LeagueAddr, PRIOR_TO_SEASON_START_PADDING, FIFTEEN_MINUTES, DEFAULT_SEASON_EPISODES};
+use shared::utils::general::{AssetTypes, GameItemTypes, generate_id_from_strings, index_string, merge_strings, normalize_coins, payment_distribution};
 use shared::utils::general::GameItemTypes::Player;
 
 use crate::error::ContractError;
-use crate::msg::{ InstantiateMsg};
-use crate::queries::{query_player_name, query_rewards_by_type_and_address,
+use crate::msg::{InstantiateMsg, MigrateMsg};
+use crate::queries::{query_rewards_by_type_and_address,
                      query_get_all_seasons_by_league, query_check_for_season_date_range_conflicts,
                      query_get_upcoming_seasons_by_league, query_get_past_seasons_by_league,
                      query_get_active_seasons_by_league, query_get_upcoming_seasons,
                      query_get_seasons_by_season_id,
-                     query_get_messages_to_item, query_get_messages_from_item, query_get_league_teams};
-use crate::state::{ADMIN, HOOKS, MANAGEMENT, teams, TeamIndexes, managed_assets, PLAYER_NAMES, seasons, next_index_counter, join_season_requests, Config, season_deposits_ledger};
+                     query_get_messages_to_item, query_get_messages_from_item, query_get_league_teams,
+                     query_get_season_divisions, query_get_season_teams_by_division, query_get_season_standings_by_division, query_get_season_schedule_by_division,
+                     query_get_season_reschedule_notices};
+use crate::state::{ADMIN, HOOKS, MANAGEMENT, teams, TeamIndexes, managed_assets, players, load_player_by_name,
+                   assign_player_team, is_player_name_taken, migrate_player_names, rebuild_join_season_request_indexes, UNASSIGNED_PLAYER_TEAM, seasons, next_index_counter, join_season_requests, Config, season_deposits_ledger,
+                   SEASON_CAPACITY_POLICY, get_season_capacity_policy, divisions, division_assignments, SEASON_STANDINGS, PLAYOFFS,
+                   season_reschedule_notices, SEASON_TEMPLATES, trade_proposals, free_agents,
+                   DRAFTS, DRAFT_POOL_PLAYERS, DRAFT_POOL_NAMES, DRAFT_OPT_INS, SALARY_CAPS, player_contracts, PLAYER_STATUSES, CAREER_SUMMARIES,
+                   get_player_status, fixtures, PLAYER_STATS, TEAM_STATS, TEAM_RECORDS, TEAM_WIN_PCT_BOARD,
+                   TEAM_STREAK_BOARD, PLAYER_CAREER_STATS, PLAYER_STAT_BOARD, win_pct_board_score, player_stat_board,
+                   hall_of_fame, leagues, LINEUPS, LINEUP_LOCKS, DISPLAYS, broadcasts, ads, inbox_messages};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:goi-manager";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+// settings for free agent pagination
+const MAX_FREE_AGENT_LIMIT: u32 = 30;
+const DEFAULT_FREE_AGENT_LIMIT: u32 = 10;
+
+// settings for player pagination
+const MAX_PLAYER_LIMIT: u32 = 30;
+const DEFAULT_PLAYER_LIMIT: u32 = 10;
+
+// settings for leaderboard pagination
+const MAX_LEADERBOARD_LIMIT: u32 = 30;
+const DEFAULT_LEADERBOARD_LIMIT: u32 = 10;
+
+// settings for display pagination
+const MAX_DISPLAY_LIMIT: u32 = 30;
+const DEFAULT_DISPLAY_LIMIT: u32 = 10;
+
+// settings for broadcast pagination
+const MAX_BROADCAST_LIMIT: u32 = 30;
+const DEFAULT_BROADCAST_LIMIT: u32 = 10;
+
+// settings for ad pagination
+const MAX_AD_LIMIT: u32 = 30;
+const DEFAULT_AD_LIMIT: u32 = 10;
+
+// settings for inbox pagination
+const MAX_INBOX_LIMIT: u32 = 30;
+const DEFAULT_INBOX_LIMIT: u32 = 10;
+
+// lineups lock this long before kickoff unless the league sets its own period
+const DEFAULT_LINEUP_LOCK: u64 = FIFTEEN_MINUTES;
+
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -79,7 +135,7 @@ pub fn instantiate(
             set_contract_version(deps.storage,
                                  CONTRACT_NAME, CONTRACT_VERSION)?;
 
-            PLAYER_NAMES.save(deps.storage, &PlayerInfoPacket { items: vec![] })?;
+            SEASON_CAPACITY_POLICY.save(deps.storage, &SeasonCapacityPolicy::default())?;
 
             let save_res =
                     MANAGEMENT.save(deps.storage, &Management { fees: vec![ManagementFee{
@@ -116,6 +172,21 @@ pub fn instantiate(
 
 
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    let (migrated, renamed) = migrate_player_names(deps.storage)?;
+    let reindexed = rebuild_join_season_request_indexes(deps.storage)?;
+    let free_agents_added = backfill_free_agents(&mut deps, &_env.block)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("players_migrated", migrated.to_string())
+        .add_attribute("join_season_requests_reindexed", reindexed.to_string())
+        .add_attribute("free_agents_backfilled", free_agents_added.to_string())
+        .add_attributes(renamed.into_iter().map(|p| ("renamed_duplicate_name", p))))
+}
+
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     mut deps: DepsMut,
@@ -142,13 +213,132 @@ pub fn execute(
                             time: _env.block.time,
                             chain_id: _env.block.chain_id
                         },
-                        owner: asset_owner
+                        owner: asset_owner,
+                        coaching: TeamCoaching::NotSet,
+                        ai_confirmed: false
                     })?;
                 }
+                AssetTypes::League => {
+                    leagues().save(deps.storage, &info.sender.clone(),
+                                   &LeagueInfo::new(asset_name.unwrap_or_default(), info.sender,
+                                                    asset_owner, BlockTime {
+                                                        height: _env.block.height,
+                                                        time: _env.block.time,
+                                                        chain_id: _env.block.chain_id
+                                                    }))?;
+                }
                 _ => {}
             }
             Ok(Response::new())
         },
+        ExecuteMsg::DeclareLeagueType { league_type } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()) {
+                Some(mc) if mc.asset_type == AssetTypes::League => {
+                    declare_league_type(deps, info.sender, league_type)
+                },
+                _ => {
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::DeclareTeamCoaching { coaching } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()) {
+                Some(mc) if mc.asset_type == AssetTypes::Team => {
+                    declare_team_coaching(deps, info.sender, coaching)
+                },
+                _ => {
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::ConfirmAiCoaching { team } => {
+            confirm_ai_coaching(deps, info.sender, team)
+        },
+        ExecuteMsg::UpdateDisplayDetails { details } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()) {
+                Some(mc) if mc.asset_type == AssetTypes::Display => {
+                    update_display_details(deps, _env, mc, details)
+                },
+                _ => {
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::DisplayHeartbeat { online } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()) {
+                Some(mc) if mc.asset_type == AssetTypes::Display => {
+                    display_heartbeat(deps, _env, info.sender, online)
+                },
+                _ => {
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::RequestBroadcast { display, fixture_id, start, end, payer } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()) {
+                Some(mc) if mc.asset_type == AssetTypes::League => {
+                    request_broadcast(deps, _env, info.sender, payer, display, fixture_id, start, end, info.funds)
+                },
+                _ => {
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::RespondToBroadcast { booking_id, accept } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()) {
+                Some(mc) if mc.asset_type == AssetTypes::Display => {
+                    respond_to_broadcast(deps, _env, info.sender, booking_id, accept)
+                },
+                _ => {
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::CancelBroadcast { booking_id } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()) {
+                Some(mc) if mc.asset_type == AssetTypes::League || mc.asset_type == AssetTypes::Display => {
+                    cancel_broadcast(deps, _env, info.sender, booking_id)
+                },
+                _ => {
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::SettleBroadcast { booking_id } => {
+            settle_broadcast(deps, _env, booking_id)
+        },
+        ExecuteMsg::RequestAd { placement, content } => {
+            request_ad(deps, _env, info.sender, placement, content, info.funds)
+        },
+        ExecuteMsg::RespondToAd { ad_id, accept, attestor } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()) {
+                Some(mc) if mc.asset_type == AssetTypes::League || mc.asset_type == AssetTypes::Display => {
+                    respond_to_ad(deps, _env, info.sender, ad_id, accept, attestor)
+                },
+                _ => {
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::AttestAdAired { ad_id } => {
+            attest_ad_aired(deps, _env, info.sender, ad_id)
+        },
+        ExecuteMsg::WithdrawAd { ad_id } => {
+            withdraw_ad(deps, _env, info.sender, ad_id)
+        },
+        ExecuteMsg::SendInboxMessage { to, content, body, reply_to, expires } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()) {
+                Some(mc) => {
+                    send_inbox_message(deps, _env, mc, to, content, body, reply_to, expires)
+                },
+                _ => {
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::MarkInboxMessageRead { message_id, read } => {
+            mark_inbox_message_read(deps, _env, info.sender, message_id, read)
+        },
         ExecuteMsg::ManagedStatusChangedHook(ManagedStatusChangedHookMsg{ change }) => {
                 match is_contract_under_management(deps.storage, info.sender.clone()){
                     None => {
@@ -201,6 +391,10 @@ pub fn execute(
                                         new_owner.clone())
 
         }
+        ExecuteMsg::ManagedAssetSharesTransferredHook { from, to, weight } => {
+            managed_asset_shares_transferred(deps, _env.block.clone(),
+                                             info.sender.clone(), from, to, weight)
+        }
         ExecuteMsg::Withdraw { recipient, amount } => {
             let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
             match is_admin_res {
@@ -304,187 +498,537 @@ pub fn execute(
                 },
             }
 
-        }
-
-    }
-}
-
-
-
-
-
-fn process_adding_players(deps: DepsMut,  _env: Env, sender: Addr, players: Vec<PlayerInfo>)
-    -> Result<Response, ContractError> {
-    let mut player_errors: Vec<PlayerInfo> = Vec::default();
-    let mut dup_name_errors = 0;
-    let mut unauthorized_request = false;
-    match is_contract_under_management(deps.storage, sender.clone()) {
-        None => {
-            unauthorized_request = true;
-        }
-        Some(mc) => {
-            match has_unique_player_names(players.clone()) {
-                true => {
-
-                    let mut current_state = PLAYER_NAMES.load(deps.storage)?;
-                    for a_player in players.clone() {
-                        let res =
-                            query_player_name
-                                (  match current_state.items.len() > 0
-                                   { true => Some(current_state.items.clone()), false => None },
-                                   a_player.first_name.clone(),
-                                   a_player.last_name.clone())?;
-
-                        match res {
-                            None => {
-                                current_state.items.insert( current_state.items.len(),
-                                                            PlayerInfo
-                                { first_name: a_player.first_name.clone(),
-                                    last_name: a_player.last_name.clone(),
-                                    address: a_player.address.clone(),
-                                    assigned_team_address: Some(sender.clone()),
-                                    position: a_player.position.clone(),
-                                });
-                                PLAYER_NAMES.save(deps.storage,
-                                                  &current_state).expect("Failed to add player.");
-
-                            }
-                            Some(p) => {
-                                match p.assigned_team_address.clone() {
-                                    None => {
-                                        //Do nothing. This is an unnecessary call, but Ok
-                                    }
-                                    Some(addr) => {
-                                        match addr == sender.clone() {
-                                            true => {
-                                                //Do nothing. This is an unnecessary call, but Ok
-                                            }
-                                            false => {
-                                                player_errors.push(p);
-
-                                            }
-                                        }
-                                    }
-                                }
-                            }
+        },
+        ExecuteMsg::UpdateSeasonCapacity { season_id, max_teams_allowed } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(_) => {
+                    update_season_capacity(deps, _env.block, info.sender, season_id, max_teams_allowed)
+                },
+                None =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::UpdateSeasonCapacityPolicy { policy } => {
+            match ADMIN.assert_admin(deps.as_ref(), &info.sender.clone()) {
+                Ok(_) => {
+                    match policy.is_valid() {
+                        true => {
+                            SEASON_CAPACITY_POLICY.save(deps.storage, &policy)?;
+                            Ok(Response::new()
+                                .add_attribute("action", "update_season_capacity_policy")
+                                .add_attribute("min_teams_allowed", policy.min_teams_allowed.to_string())
+                                .add_attribute("max_teams_allowed", policy.max_teams_allowed.to_string()))
                         }
+                        false => Err(ContractError::InvalidSeasonCapacityPolicy {})
                     }
-                }
-                false => {
-                    dup_name_errors += 1;
-                }
+                },
+                Err(_) => {
+                    Err(ContractError::Unauthorized { sender: info.sender})
+                },
             }
-        }
-    }
-    match (player_errors.len() > 0) | (dup_name_errors > 0) | (unauthorized_request) {
-        true => {
-            Err(ContractError::AddPlayerErrors { players_assigned_to_another_team: player_errors,
-                source_dupe_name_count: dup_name_errors,
-                unauthorized_request
-            })
-        }
-        false => {
-            Ok(Response::new())
-        }
-    }
-
-
-}
-
-fn managed_asset_owner_changed(deps: DepsMut, block: BlockInfo,
-                               sender_contract_addr: Addr, new_owner: Addr)  -> Result<Response, ContractError> {
-    match is_contract_under_management(deps.storage, sender_contract_addr.clone()) {
-        Some(_) => {
-            let update_fn = |d: Option<MangedItem>|
-                -> Result<MangedItem, ContractError>{
-                let mut item = d.unwrap();
-                let histor_item_count = item.ownership_history.len();
-                match item.ownership_history.len() > 0 {
-                    true => {
-                        item.ownership_history[histor_item_count - 1].sold = Some(block.clone())
-                    },
-                    false => {
-                        ()
-                    }
-                }
-
-                Ok(
-                    MangedItem {
-                        asset_addr: item.asset_addr,
-                        asset_name: item.asset_name,
-                        asset_owner: new_owner.clone(),
-                        asset_type: item.asset_type,
-                        managed_status: item.managed_status,
-                        created: item.created,
-                        updated: block.time,
-                        for_sale: 0u8,
-                        for_sale_price: item.for_sale_price,
-                        for_sale_price_version: item.for_sale_price_version,
-                        for_sale_last_updated: item.for_sale_last_updated,
-                        ownership_history: [item.ownership_history, vec![OwnershipHistory {
-                            owners: new_owner,
-                            purchased: block.clone(),
-                            sold: None
-                        }]].concat()
-                    }
-                )
-
-            };
-
-            managed_assets().update(deps.storage, &sender_contract_addr.clone(), update_fn)?;
-            update_asset_for_sale_status(deps, block, sender_contract_addr, false, None )?;
-            Ok(Response::new())
         },
-        None => {
-            Err(ContractError::Unauthorized { sender: sender_contract_addr.clone() })
-        }
-
-    }
-}
-fn update_managed_status(mut deps: DepsMut, block: BlockInfo, sender_contract_addr: Addr,
-                         managed_status: ManagedStatus, asset_name: Option<String>,
-                         asset_owner: Addr, asset_type: AssetTypes, init_creation_request: bool )
-    -> Result<Response, ContractError> {
-
-    match ( init_creation_request, managed_assets().may_load(deps.storage,
-                                                             &sender_contract_addr.clone())?) {
-
-        (false, None ) => {
-            Err(ContractError::Unauthorized { sender: sender_contract_addr.clone() })
+        ExecuteMsg::AddDivisionToSeason { season_id, division } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(_) => {
+                    add_division_to_season(deps, _env.block, info.sender, season_id, division)
+                },
+                None =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
         },
-        (false, Some(mc))  => {
-            let update_fn = |d: Option<MangedItem>|
-                -> StdResult<MangedItem>{
-                match d {
-                    None => {
-                        Ok(MangedItem {
-                            asset_addr: sender_contract_addr.clone(),
-                            asset_name,
-                            asset_owner: asset_owner.clone(),
-                            asset_type: asset_type.clone(),
-                            managed_status,
-                            created: block.time,
-                            updated: block.time,
-                            for_sale: 0u8,
-                            for_sale_price: None,
-                            for_sale_price_version: 0,
-                            for_sale_last_updated: Default::default(),
-                            ownership_history: vec![OwnershipHistory{
-                                owners: asset_owner,
-                                purchased: block,
-                                sold: None
-                            }]
-                        })
-                    }
-                    Some(item) => {
-                        Ok(
-                            MangedItem {
-                                asset_addr: item.asset_addr,
-                                asset_name: item.asset_name,
-                                asset_owner: item.asset_owner,
-                                asset_type: item.asset_type,
-                                managed_status,
-                                created: item.created,
+        ExecuteMsg::UpdateDivision { division_id, division } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(_) => {
+                    update_division(deps, _env.block, info.sender, division_id, division)
+                },
+                None =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::StartPlayoffs { season_id, format, playoff_teams } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(_) => {
+                    start_playoffs(deps, _env.block, info.sender, season_id, format, playoff_teams)
+                },
+                None =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::SubmitPlayoffResult { season_id, match_id, winner } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(_) => {
+                    submit_playoff_result(deps, _env.block, info.sender, season_id, match_id, winner)
+                },
+                None =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::RescheduleSeason { season_id, new_start, new_end, reason } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(_) => {
+                    reschedule_season(deps, _env.block, info.sender, season_id, new_start, new_end, reason)
+                },
+                None =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::WithdrawFromRescheduledSeason { season_id } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(_) => {
+                    withdraw_from_rescheduled_season(deps, _env.block, info.sender, season_id)
+                },
+                None =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::SetSeasonTemplate { template } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(mc) if mc.asset_type == AssetTypes::League => {
+                    set_season_template(deps, info.sender, template)
+                },
+                _ =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::SpawnNextSeason { league } => {
+            spawn_next_season(deps, _env.block, league)
+        },
+        ExecuteMsg::ProposeTrade { counterparty_team, offered_players, requested_players, offered_funds,
+            offered_picks, requested_picks } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(mc) if mc.asset_type == AssetTypes::Team => {
+                    propose_trade(deps, _env.block, info.sender, counterparty_team,
+                                  offered_players, requested_players, offered_funds,
+                                  offered_picks, requested_picks, info.funds)
+                },
+                _ =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::RespondToTrade { trade_id, accept } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(_) => {
+                    respond_to_trade(deps, _env.block, info.sender, trade_id, accept)
+                },
+                None =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::CancelTrade { trade_id } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(_) => {
+                    cancel_trade(deps, _env.block, info.sender, trade_id)
+                },
+                None =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::UpdateFreeAgentTerms { player, signing_fee, approved_team } => {
+            update_free_agent_terms(deps, _env.block, info.sender, player, signing_fee, approved_team)
+        },
+        ExecuteMsg::SignFreeAgent { player } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(mc) if mc.asset_type == AssetTypes::Team => {
+                    sign_free_agent(deps, info.sender, player, info.funds)
+                },
+                _ =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::ReleasePlayers { players } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(mc) if mc.asset_type == AssetTypes::Team => {
+                    release_players(deps, _env.block, info.sender, players)
+                },
+                _ =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::CreateDraft { season_id, pool, rounds, order_type, pick_duration, start } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(mc) if mc.asset_type == AssetTypes::League => {
+                    create_draft(deps, _env.block, info.sender, season_id, pool, rounds,
+                                 order_type, pick_duration, start)
+                },
+                _ =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::MakeDraftPick { season_id, player } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(mc) if mc.asset_type == AssetTypes::Team => {
+                    make_draft_pick(deps, _env.block, info.sender, season_id, player)
+                },
+                _ =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::CommitDraftSeed { season_id, commitment } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(mc) if mc.asset_type == AssetTypes::Team => {
+                    commit_draft_seed(deps, _env.block, info.sender, season_id, commitment)
+                },
+                _ =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::RevealDraftSeed { season_id, secret } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(mc) if mc.asset_type == AssetTypes::Team => {
+                    reveal_draft_seed(deps, _env.block, info.sender, season_id, secret)
+                },
+                _ =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::SetDraftOptIn { player, season_id } => {
+            set_draft_opt_in(deps, info.sender, player, season_id)
+        },
+        ExecuteMsg::AdvanceDraft { season_id } => {
+            advance_draft(deps, _env.block, season_id)
+        },
+        ExecuteMsg::AddPlayersToTeamWithContracts { signings } => {
+            add_players_with_contracts(deps, _env, info.sender, signings)
+        },
+        ExecuteMsg::SetSalaryCap { cap } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(mc) if mc.asset_type == AssetTypes::League => {
+                    set_salary_cap(deps, info.sender, cap)
+                },
+                _ =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::AdvanceEpisode { season_id } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(mc) if mc.asset_type == AssetTypes::League => {
+                    advance_episode(deps, _env.block, info.sender, season_id)
+                },
+                _ =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::SetPlayerStatus { player, status } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(mc) if mc.asset_type == AssetTypes::League => {
+                    set_player_status(deps, info.sender, player, status)
+                },
+                _ =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::RetirePlayer { player } => {
+            retire_player(deps, _env.block, info.sender, player)
+        },
+        ExecuteMsg::ScheduleFixture { season_id, episode, home, away, league_commits, commit_deadline, reveal_deadline } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(mc) if mc.asset_type == AssetTypes::League => {
+                    schedule_fixture(deps, _env.block, info.sender, season_id, episode, home, away, league_commits,
+                                     commit_deadline, reveal_deadline)
+                },
+                _ =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::CommitFixtureSeed { fixture_id, commitment } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(_) => {
+                    commit_fixture_seed(deps, _env.block, info.sender, fixture_id, commitment)
+                },
+                None =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::RevealFixtureSeed { fixture_id, secret } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(_) => {
+                    reveal_fixture_seed(deps, _env.block, info.sender, fixture_id, secret)
+                },
+                None =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::ResolveFixture { fixture_id } => {
+            resolve_fixture(deps, _env.block, fixture_id)
+        }
+        ExecuteMsg::SubmitLineup { season_id, episode, offense, defense, play_calling_strategy } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(mc) if mc.asset_type == AssetTypes::Team => {
+                    submit_lineup(deps, _env.block, info.sender, season_id, episode, offense, defense,
+                                  play_calling_strategy)
+                },
+                _ =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::SetLineupLock { seconds } => {
+            match is_contract_under_management(deps.storage, info.sender.clone()){
+                Some(mc) if mc.asset_type == AssetTypes::League => {
+                    LINEUP_LOCKS.save(deps.storage, &info.sender, &seconds)?;
+                    Ok(Response::new()
+                        .add_attribute("action", "set_lineup_lock")
+                        .add_attribute("seconds", seconds.to_string()))
+                },
+                _ =>{
+                    Err(ContractError::Unauthorized{ sender: info.sender })
+                },
+            }
+        },
+
+    }
+}
+
+
+
+
+
+fn process_adding_players(deps: DepsMut,  _env: Env, sender: Addr, to_add: Vec<PlayerInfo>)
+    -> Result<Response, ContractError> {
+    let mut player_errors: Vec<PlayerInfo> = Vec::default();
+    let mut dup_name_errors = 0;
+    let mut unauthorized_request = false;
+    match is_contract_under_management(deps.storage, sender.clone()) {
+        None => {
+            unauthorized_request = true;
+        }
+        Some(mc) => {
+            match has_unique_player_names(to_add.clone()) {
+                true => {
+
+                    for a_player in to_add.clone() {
+                        //draft pool players can only join a roster by being drafted
+                        if DRAFT_POOL_PLAYERS.has(deps.storage, &a_player.address) {
+                            player_errors.push(a_player);
+                            continue;
+                        }
+                        let res =
+                            load_player_by_name(deps.storage, &a_player.first_name, &a_player.last_name)?;
+
+                        match res {
+                            None if is_player_name_taken(deps.storage, &a_player.address,
+                                                         &a_player.first_name, &a_player.last_name)? => {
+                                //held by a player in a draft pool
+                                dup_name_errors += 1;
+                            }
+                            None => {
+                                players().save(deps.storage, a_player.address.clone(),
+                                               &PlayerInfo
+                                { first_name: a_player.first_name.clone(),
+                                    last_name: a_player.last_name.clone(),
+                                    address: a_player.address.clone(),
+                                    assigned_team_address: Some(sender.clone()),
+                                    position: a_player.position.clone(),
+                                })?;
+
+                            }
+                            Some(p) if p.address != a_player.address => {
+                                //another player already has this name
+                                dup_name_errors += 1;
+                            }
+                            Some(p) if get_player_status(deps.storage, &p.address)? == PlayerStatus::Retired => {
+                                player_errors.push(p);
+                            }
+                            Some(p) => {
+                                match p.assigned_team_address.clone() {
+                                    None => {
+                                        //free agents join a roster through SignFreeAgent
+                                        match free_agents().has(deps.storage, p.address.clone()) {
+                                            true => player_errors.push(p),
+                                            false => {
+                                                //Do nothing. This is an unnecessary call, but Ok
+                                            }
+                                        }
+                                    }
+                                    Some(addr) => {
+                                        match addr == sender.clone() {
+                                            true => {
+                                                //Do nothing. This is an unnecessary call, but Ok
+                                            }
+                                            false => {
+                                                player_errors.push(p);
+
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                false => {
+                    dup_name_errors += 1;
+                }
+            }
+        }
+    }
+    match (player_errors.len() > 0) | (dup_name_errors > 0) | (unauthorized_request) {
+        true => {
+            Err(ContractError::AddPlayerErrors { players_assigned_to_another_team: player_errors,
+                source_dupe_name_count: dup_name_errors,
+                unauthorized_request
+            })
+        }
+        false => {
+            Ok(Response::new())
+        }
+    }
+
+
+}
+
+fn managed_asset_owner_changed(deps: DepsMut, block: BlockInfo,
+                               sender_contract_addr: Addr, new_owner: Addr)  -> Result<Response, ContractError> {
+    match is_contract_under_management(deps.storage, sender_contract_addr.clone()) {
+        Some(_) => {
+            let update_fn = |d: Option<MangedItem>|
+                -> Result<MangedItem, ContractError>{
+                let mut item = d.unwrap();
+                let histor_item_count = item.ownership_history.len();
+                match item.ownership_history.len() > 0 {
+                    true => {
+                        item.ownership_history[histor_item_count - 1].sold = Some(block.clone())
+                    },
+                    false => {
+                        ()
+                    }
+                }
+
+                Ok(
+                    MangedItem {
+                        asset_addr: item.asset_addr,
+                        asset_name: item.asset_name,
+                        asset_owner: new_owner.clone(),
+                        asset_type: item.asset_type,
+                        managed_status: item.managed_status,
+                        created: item.created,
+                        updated: block.time,
+                        for_sale: 0u8,
+                        for_sale_price: item.for_sale_price,
+                        for_sale_price_version: item.for_sale_price_version,
+                        for_sale_last_updated: item.for_sale_last_updated,
+                        ownership_history: [item.ownership_history, vec![OwnershipHistory {
+                            owners: new_owner,
+                            purchased: block.clone(),
+                            sold: None,
+                            weight: None,
+                            transferred_from: None
+                        }]].concat()
+                    }
+                )
+
+            };
+
+            managed_assets().update(deps.storage, &sender_contract_addr.clone(), update_fn)?;
+            update_asset_for_sale_status(deps, block, sender_contract_addr, false, None )?;
+            Ok(Response::new())
+        },
+        None => {
+            Err(ContractError::Unauthorized { sender: sender_contract_addr.clone() })
+        }
+
+    }
+}
+//Partial ownership changes are appended to the history without
+//touching the asset owner, which only changes on a full sale
+fn managed_asset_shares_transferred(deps: DepsMut, block: BlockInfo, sender_contract_addr: Addr,
+                                    from: Addr, to: Addr, weight: u64) -> Result<Response, ContractError> {
+    match is_contract_under_management(deps.storage, sender_contract_addr.clone()) {
+        Some(_) => {
+            managed_assets().update(deps.storage, &sender_contract_addr, |d: Option<MangedItem>|
+                -> Result<MangedItem, ContractError> {
+                let mut item = d.unwrap();
+                item.updated = block.time;
+                item.ownership_history.push(OwnershipHistory {
+                    owners: to.clone(),
+                    purchased: block.clone(),
+                    sold: None,
+                    weight: Some(weight),
+                    transferred_from: Some(from.clone())
+                });
+                Ok(item)
+            })?;
+            Ok(Response::new()
+                .add_attribute("action", "managed_asset_shares_transferred")
+                .add_attribute("from", from)
+                .add_attribute("to", to)
+                .add_attribute("weight", weight.to_string()))
+        },
+        None => {
+            Err(ContractError::Unauthorized { sender: sender_contract_addr.clone() })
+        }
+    }
+}
+
+fn update_managed_status(mut deps: DepsMut, block: BlockInfo, sender_contract_addr: Addr,
+                         managed_status: ManagedStatus, asset_name: Option<String>,
+                         asset_owner: Addr, asset_type: AssetTypes, init_creation_request: bool )
+    -> Result<Response, ContractError> {
+
+    match ( init_creation_request, managed_assets().may_load(deps.storage,
+                                                             &sender_contract_addr.clone())?) {
+
+        (false, None ) => {
+            Err(ContractError::Unauthorized { sender: sender_contract_addr.clone() })
+        },
+        (false, Some(mc))  => {
+            let update_fn = |d: Option<MangedItem>|
+                -> StdResult<MangedItem>{
+                match d {
+                    None => {
+                        Ok(MangedItem {
+                            asset_addr: sender_contract_addr.clone(),
+                            asset_name,
+                            asset_owner: asset_owner.clone(),
+                            asset_type: asset_type.clone(),
+                            managed_status,
+                            created: block.time,
+                            updated: block.time,
+                            for_sale: 0u8,
+                            for_sale_price: None,
+                            for_sale_price_version: 0,
+                            for_sale_last_updated: Default::default(),
+                            ownership_history: vec![OwnershipHistory{
+                                owners: asset_owner,
+                                purchased: block,
+                                sold: None,
+                                weight: None,
+                                transferred_from: None
+                            }]
+                        })
+                    }
+                    Some(item) => {
+                        Ok(
+                            MangedItem {
+                                asset_addr: item.asset_addr,
+                                asset_name: item.asset_name,
+                                asset_owner: item.asset_owner,
+                                asset_type: item.asset_type,
+                                managed_status,
+                                created: item.created,
                                 updated: block.time,
                                 for_sale: item.for_sale,
                                 for_sale_price: item.for_sale_price,
@@ -492,1016 +1036,3774 @@ fn update_managed_status(mut deps: DepsMut, block: BlockInfo, sender_contract_ad
                                 for_sale_last_updated: item.for_sale_last_updated,
                                 ownership_history: item.ownership_history
                             }
-                        )
+                        )
+                    }
+                }
+            };
+
+            managed_assets().update(deps.storage, &sender_contract_addr,
+                                    update_fn)?;
+            Ok(Response::new())
+        },
+        (true, _ ) => {
+            let item =
+                 MangedItem {
+                    asset_addr: sender_contract_addr.clone(),
+                    asset_name,
+                    asset_owner,
+                    asset_type: asset_type.clone(),
+                    managed_status,
+                    created: block.time,
+                    updated: block.time,
+                     for_sale: 0u8,
+                     for_sale_price: None,
+                     for_sale_price_version: 0,
+                     for_sale_last_updated: Default::default(),
+                     ownership_history: vec![]
+                 };
+            managed_assets().save(deps.storage, &sender_contract_addr, &item)?;
+            Ok(Response::new())
+        }
+        _ => {
+                Err(ContractError::Unauthorized { sender: sender_contract_addr.clone() })
+        }
+    }
+}
+
+fn add_update_forsale_item(store: &mut dyn Storage, block: BlockInfo, sender: Addr,
+                           update_item: AssetSaleItemAddUpdateModel) -> StdResult<()>{
+
+    let managed_item_res = managed_assets().may_load(store, &sender.clone());
+    match managed_item_res {
+        Ok(r) => {
+            match r {
+                None => {
+                    Err(StdError::not_found(sender.clone()))
+                }
+                Some(mut m) => {
+                    m.for_sale = match update_item.for_sale { true => 1u8, false => 0u8};
+                    m.for_sale_price = update_item.for_sale_price;
+                    m.for_sale_price_version = update_item.for_sale_price_version;
+                    m.for_sale_last_updated = block.time;
+                    managed_assets().save(store, &sender.clone(), &m)?;
+                    Ok(())
+                }
+            }
+        }
+        Err(e) => {
+            Err(e)
+        }
+    }
+
+
+
+}
+
+fn get_managed_items_for_sale(store: &dyn Storage) -> Vec<StdResult<(Addr, MangedItem)>> {
+    let res = //: Result<Vec<_>, _>
+        managed_assets().idx.
+            for_sale.
+            sub_prefix(1u8).
+            range(store, None, None, Order::Ascending).
+            collect();
+    res
+}
+
+fn get_forsale_items_by_asset_type(store: &dyn Storage, asset_type: AssetTypes )
+    -> Option<Vec<(Addr, MangedItem)>>{
+    let items_for_sale_res = get_managed_items_for_sale(store);
+    match items_for_sale_res.len() > 0 {
+        true => {
+
+                    let res: Vec<(Addr, MangedItem)> =
+                        items_for_sale_res.
+                            into_iter().
+                            filter(|mc|
+                                         match mc.clone() {
+                                             Ok(r) => r.1.asset_type == asset_type,
+                                             Err(_) => false
+                                         } ).
+                            map(|i |  i.unwrap()).
+                            collect();
+                    Some(res)
+        }
+        false => {
+            None
+        }
+    }
+}
+
+fn get_forsale_item_by_addr(store: & dyn Storage,  addr:Addr )
+    -> Option<MangedItem>{
+        //let res = managed_assets().may_load(store, &addr)?;
+        let items_for_sale_res = get_managed_items_for_sale(store);
+        match items_for_sale_res.len() > 0 {
+            true => {
+
+                let res: Vec<MangedItem> =
+                    items_for_sale_res.
+                        into_iter().
+                        filter(|mc|
+                            match mc.clone() {
+                                Ok(r) => r.1.asset_addr == addr,
+                                Err(_) => false
+                            } ).
+                        map(|i |  i.unwrap().1).
+                        collect();
+                Some(res[0].clone())
+            }
+            false => {
+                None
+            }
+        }
+
+
+
+}
+
+fn is_validate_season(season: &Season, block: BlockInfo, capacity_policy: &SeasonCapacityPolicy) -> Result<Response,
+    ContractError>
+{
+    match season.max_teams_allowed {
+        Some(capacity) if capacity_policy.is_capacity_allowed(capacity) => {
+            //Ok, allow to continue
+        }
+        _ => {
+            return Err(ContractError::SeasonCapacityOutOfRange {
+                min_teams_allowed: capacity_policy.min_teams_allowed,
+                max_teams_allowed: capacity_policy.max_teams_allowed
+            })
+        }
+    }
+    match season.validate(block, capacity_policy) {
+        true => {
+            Ok(Response::new())
+        }
+        false => {
+            Err(ContractError::InvalidSeason {})
+        }
+    }
+}
+
+
+fn verify_address_owns_assets(deps: &DepsMut, owning_address: Addr, addresses: Vec<Addr>) -> Result<bool, ContractError> {
+    for item_addr in addresses {
+        let res = managed_assets().may_load(deps.storage, &item_addr);
+        match res {
+            Ok(mi) => {
+                    match mi {
+                        None => {
+                            return Err(ContractError::ItemNotFound { item_address: item_addr })
+                        }
+                        Some(m) => {
+                            match m.asset_owner == owning_address {
+                                true => continue,
+                                false => return Ok(false)
+                            }
+                        }
+                    }
+            },
+            Err(e) => {
+              return  Err(ContractError::Std(e))
+            }
+        }       
+    }
+    Ok(true)
+}
+
+fn check_teams_managed_status_and_verify_asset_type(mut deps: &DepsMut, teams: Vec<TeamAddr>) -> Result<u32, ContractError>{
+    let mut item: Option<MangedItem> = None;
+    let mut fail_count:u32 = 0;
+    for team in teams.clone() {
+        item = managed_assets().may_load(deps.storage, &team)?;
+        match item {
+            None => {
+                fail_count = fail_count + 1;
+                break;
+            }
+            Some(a_team) => {
+                match a_team.clone().asset_type == AssetTypes::Team
+                    && a_team.clone().managed_status == ManagedStatus::Enabled {
+                    true =>  continue,
+                    false => {
+                        fail_count = fail_count + 1;
+                        break;
+                    }
+
+                }
+
+            }
+        }
+
+    }
+    Ok( fail_count)
+}
+
+
+fn verify_teams_are_available(mut deps: &DepsMut, block: BlockInfo, sending_user: Addr, sending_league_contract_addr: Addr,  team_items: Vec<TeamAddr>) -> Result<Response, ContractError> {
+    let mut res:  Result<Response, ContractError> ;
+    for a_team in team_items {
+        let team_res = teams().may_load(deps.storage, a_team.clone())?;
+        match team_res {
+            None => {
+                return  Err(ContractError::ItemNotFound { item_address: a_team.clone() });
+            }
+            Some(t) => {
+                match t.league_assigned {
+                    None => {
+                        //None found is good, so let's
+                        //continue looping through for loop
+                    }
+                    Some(l) => {
+                        return Err(ContractError::TeamAlreadyMemberOfALeague { team_addr: a_team, league_assigned_to: l.league });
+                    }
+                }
+            }
+        }
+    }
+    return Ok(Response::new())
+}
+
+
+// Add teams to leagues
+// Validation checks:
+//   --check if all teams are managed
+//    --make sure sending user owns teams being added
+//    --check to make sure team(s) are not already assigned to a league
+//    --add teams to league
+///
+fn add_teams_to_league(mut deps: DepsMut, block: BlockInfo, sending_user: Addr,
+                       sending_league_contract_addr:
+                       Addr, teams_to_assign: Vec<TeamAddr>) -> Result<Response, ContractError> {
+    match check_teams_managed_status_and_verify_asset_type(deps.borrow(), teams_to_assign.clone()) {
+        Ok(fv) => {
+            match fv > 0 {
+                true =>  Err(ContractError::InvalidTeamSubmissions{}),
+                false => {
+                    match verify_address_owns_assets(deps.borrow(),
+                                                     sending_user.clone(),
+                                                     teams_to_assign.clone()) {
+                        Ok(r) => {
+                            match r {
+                                true => {
+                                   match verify_teams_are_available(deps.borrow(), block.clone(), sending_user,
+                                                                    sending_league_contract_addr.clone(),
+                                                                    teams_to_assign.clone()) {
+                                       Ok(_) => {
+                                           for team_addr in teams_to_assign.iter() {
+                                               assert_league_admits_team(deps.storage, &sending_league_contract_addr, team_addr)?;
+                                           }
+                                           for team_addr in teams_to_assign {
+                                               let mut a_team  =
+                                                   teams().may_load(deps.storage, team_addr.clone()).unwrap();
+                                               match a_team {
+                                                   None => {}
+                                                   Some(mut _team) => {
+                                                       _team.league_assigned =
+                                                           Some(LeagueTeamAssignment{ league: sending_league_contract_addr.clone(),
+                                                               assigned_date:block.time });
+                                                       teams().save(deps.storage, team_addr, &_team)?;
+                                                   }
+                                               }
+                                           }
+                                           Ok(Response::new())
+                                       }
+                                       Err(e) => {
+                                           Err(e)
+                                       }
+                                   }
+
+                                },
+                                false => {
+                                    Err(ContractError::InvalidTeamSubmissions{})
+                                }
+                            }
+
+                        }
+                        Err(e) => {
+                            Err(e)
+                        }
+                    }
+
+                },
+            }
+
+        },
+        Err(e) => {
+            Err(ContractError::InvalidTeamSubmissions{})
+       }
+    }
+
+
+}
+fn get_team_season_conflicts(store: &mut dyn Storage , asset_type: AssetTypes,
+                             asset_addr: Addr, start_date: Timestamp,
+                             end_date: Timestamp) -> Option<Vec<Season>>{
+    let sender_res: Result<Vec<_>, _> =
+        join_season_requests().idx
+            .sender
+            .prefix((asset_type.to_u8(), asset_addr.clone()))
+            .range(store, None, None, Order::Ascending)
+            .collect();
+    let recipient_res: Result<Vec<_>, _> =
+        join_season_requests().idx
+            .recipient
+            .prefix((asset_type.to_u8(), asset_addr.clone()))
+            .range(store, None, None, Order::Ascending)
+            .collect();
+
+    let res_items = [sender_res.unwrap(),recipient_res.unwrap() ].concat();
+    let seasons: Option<Vec<Season>> =
+            res_items.
+            into_iter().
+            filter(|i|  {
+                (i.1.delivery.to.address == asset_addr || i.1.delivery.from.address == asset_addr)
+                    && i.1.data.status_type == MessageTypes::Accepted {}
+            }).
+            map(|i|   seasons().may_load(store, i.1.data.season_id).unwrap()  ).
+            filter(|season| {
+                match season {
+                    None => false,
+                    Some(s) => {
+                        (s.start_date.seconds() < start_date.seconds() && start_date.seconds() < s.end_date.seconds()) ||
+                            (s.start_date.seconds() < end_date.seconds() &&  end_date.seconds() < s.end_date.seconds())
+                    }
+                }
+            }).
+            collect();
+
+   match seasons {
+       None => {
+           None
+       }
+       Some(items) => {
+           match items.len() > 0 {
+               true => Some(items),
+               false => None
+           }
+       }
+   }
+
+}
+
+
+fn get_existing_join_request(store: &mut dyn Storage , season_id: SeasonId, team_addr: Addr, league_addr: Addr  ) -> Option<Message<JoinSeasonRequestInfo>>{
+    let res_1: Result<Vec<_>, _> =
+        join_season_requests().idx
+            .season_from_to
+            .prefix((season_id, team_addr.clone(), league_addr.clone()))
+            .range(store, None, None, Order::Ascending)
+            .collect();
+
+    return match res_1 {
+        Ok(r) => {
+            match r.len() > 0 {
+                true => Some(r[0].1.clone()),
+                false => {
+                    None
+                }
+            }
+        }
+        Err(_) => {
+            None
+        }
+    }
+
+
+}
+
+
+fn get_winner_take_all_deposits_by_season(store: &dyn Storage , season_id: SeasonId) -> Vec<(u64, SeasonLedger)> {
+    let res: Result<Vec<_>, _> =
+        season_deposits_ledger().idx
+            .season
+            .prefix(season_id)
+            .range(store, None, None, Order::Ascending)
+            .collect();
+    res.unwrap()
+}
+
+
+//checks if team has made a deposit for season
+//if so, then distribute refund accordingly
+fn distribute_individual_refund(mut deps: DepsMut, block: BlockInfo,
+                                season_id: SeasonId, team_addr: TeamAddr) -> Result<Response, ContractError> {
+    let a_season = seasons().may_load(deps.storage.borrow(), season_id).unwrap();
+
+    return match a_season {
+        None => {
+            Err(ContractError::SeasonNotFound {})
+        }
+        Some(s) => {
+            match s.access_type.clone() {
+                None => {
+                    Ok(Response::new())
+                }
+                Some(at) => {
+                    match at.clone() {
+                        SeasonAccessTypes::WinnerTakeAll { .. } => {
+                            let sub_msg =
+                                get_team_unpaid_refund_deposit_sub_message(deps.storage, s.clone(), team_addr.clone());
+
+                            match sub_msg {
+                                Some(sb) => {
+                                    let refund_deposit = get_team_unpaid_deposit_by_season(deps.storage, s.clone(), team_addr.clone());
+
+                                    match refund_deposit {
+                                        None => Err(ContractError::SeasonDepositAlreadyClaimed {}),
+                                        Some(rd) => {
+                                            mark_deposit_withdrawls_paid(deps.branch(), block, vec![rd]);
+                                            Ok(Response::new().add_submessage(sb))
+                                        }
+                                    }
+                                },
+                                None => Err(ContractError::SeasonDepositAlreadyClaimed {}),
+                            }
+                        },
+                        _ => {
+                            Ok(Response::new())
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn distribute_refunds(mut deps: DepsMut, block: BlockInfo, season_id: SeasonId) -> Result<Response, ContractError> {
+    let a_season = seasons().may_load(deps.storage.borrow(), season_id).unwrap();
+
+    return match a_season {
+        None => {
+            Err(ContractError::SeasonNotFound {})
+        }
+        Some(s) => {
+            match s.access_type.clone() {
+                None => {
+                    Ok(Response::new())
+                }
+                Some(at) => {
+                    match at.clone() {
+                        SeasonAccessTypes::WinnerTakeAll { .. } => {
+                            let sub_msgs =
+                                get_unpaid_refund_deposits_submessages(deps.storage, s.clone());
+
+                            match sub_msgs.len() > 0 {
+                                true => {
+                                    let refund_deposits = get_unpaid_deposits_by_season(deps.storage, s.clone());
+                                    mark_deposit_withdrawls_paid(deps.branch(), block, refund_deposits);
+                                    Ok(Response::new().add_submessages(sub_msgs))
+                                },
+                                false => Ok(Response::new())
+                            }
+                        },
+                        _ => {
+                            Ok(Response::new())
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn mark_deposit_withdrawls_paid(mut deps: DepsMut, block: BlockInfo, items: Vec<(u64, SeasonLedger)>) {
+    for mut item in items {
+        item.1.withdrawal_distribution_date = Some(block.time);
+        season_deposits_ledger().save(deps.storage, item.0, &item.1).expect("Problem marking deposits as paid.");
+    }
+}
+
+
+fn get_team_unpaid_refund_deposit_sub_message(store: &mut dyn Storage ,
+                                           s: Season, team_addr: TeamAddr) ->  Option<SubMsg>{
+    let unpaid_deposit =
+        get_team_unpaid_deposits_by_season(store, s.clone(), team_addr.clone());
+            
+
+    match unpaid_deposit {
+        None => {
+            None
+        }
+        Some(i) => {
+            let res: CosmosMsg =
+                deposit_to_team_treasury_msg(i.1.team, vec![i.1.team_deposit_amount]);
+            let res_sub_msg =
+                SubMsg {
+                    id: 0,
+                    msg: res.clone(),
+                    gas_limit: None,
+                    reply_on: ReplyOn::Never
+                };
+
+            Some(res_sub_msg)
+        }
+    }
+
+}
+
+
+fn get_unpaid_refund_deposits_submessages(store: &dyn Storage , s: Season) ->  Vec<SubMsg>{
+    let unpaid_deposits = get_unpaid_deposits_by_season(store, s.clone());
+    let mut count: u64 = 0;
+    let res: Vec<SubMsg> =
+        unpaid_deposits.into_iter()
+            .map(|i| {
+                let res: CosmosMsg =
+                    deposit_to_team_treasury_msg(i.1.team, vec![i.1.team_deposit_amount]);
+                let res_sub_msg =
+                    SubMsg {
+                        id: count,
+                        msg: res,
+                        gas_limit: None,
+                        reply_on: ReplyOn::Never
+                    };
+                count += 1;
+                res_sub_msg
+            })
+            .collect();
+    res
+}
+
+
+fn get_team_unpaid_deposit_by_season(store: &dyn Storage , s: Season, team_addr: TeamAddr) -> Option<(u64, SeasonLedger)> {
+    let unpaid_deposit: Vec<(u64, SeasonLedger)> =
+        get_winner_take_all_deposits_by_season(store, s.id).
+            into_iter().
+            filter(|item| item.1.withdrawal_distribution_date.is_none() && item.1.team == team_addr).
+            collect();
+    match unpaid_deposit.len() > 0 {
+        true => {
+            Some(unpaid_deposit[0].clone())
+        },
+        false => {
+            None
+
+        }
+    }
+}
+
+fn get_team_unpaid_deposits_by_season(store: &dyn Storage , s: Season, team_addr: TeamAddr) -> Option<(u64, SeasonLedger)> {
+    let unpaid_deposit: Vec<(u64, SeasonLedger)> =
+        get_winner_take_all_deposits_by_season(store, s.id).
+            into_iter().
+            filter(|item| item.1.withdrawal_distribution_date.is_none() && item.1.team == team_addr).
+            collect();
+    match unpaid_deposit.len() > 0 {
+        true => {
+            Some(unpaid_deposit[0].clone())
+        },
+        false => {
+            None
+        }
+    }
+}
+
+fn get_unpaid_deposits_by_season(store: &dyn Storage , s: Season) -> Vec<(u64, SeasonLedger)> {
+    let unpaid_deposits: Vec<(u64, SeasonLedger)> =
+        get_winner_take_all_deposits_by_season(store, s.id).
+            into_iter().
+            filter(|item| item.1.withdrawal_distribution_date.is_none()).
+            collect();
+    unpaid_deposits
+}
+
+fn update_message_status(mut deps: DepsMut, sending_league: Addr, block: BlockInfo,
+     season_id: SeasonId, update_status_type: MessageTypes) -> Result<Response, ContractError> {
+    let a_season = seasons().may_load(deps.storage.borrow(), season_id).unwrap();
+
+    //let invite_res = join_season_requests().may_load(deps.storage, season_id)?;
+    match a_season {
+        None => {
+            Err(ContractError::SeasonNotFound{  })
+        }
+        Some(mut se) => {
+            return match update_status_type {
+                MessageTypes::CancelSeason {} => {
+                    //make sure league sending update own season being updated
+                    match se.league == sending_league {
+                        true => {
+                            match (se.start_date < block.time, (get_season_teams_accepted_count(deps.storage, se.id.clone())) > 1) {
+                                (true, true) => {
+                                    Err(ContractError::TooLateToCancelSeason {})
+                                },
+                                (true, false)/* not enough teams accepted to start season, so allow cancel */ |
+                                (false, _) /* season hasn't started, so allow cancel */ => {
+                                    se.status = Some(SeasonStatus::Cancelled { date_cancelled: block.clone() });
+                                    seasons().save(deps.storage, se.id, &se)?;
+                                    let res = distribute_refunds(deps, block.clone(), se.id);
+                                    match res {
+                                        Ok(r) => {
+                                            //response from distribute_refunds contains sub_messages
+                                            //needed for payment transfers
+                                            Ok(r)
+                                        }
+                                        Err(e) => {
+                                            Err(e)
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        false => {
+                            Err(ContractError::Unauthorized { sender: sending_league })
+                        }
+                    }
+                }
+                _ => {
+                    Err(ContractError::Unauthorized { sender: sending_league })
+                }
+            }
+
+
+        }
+    }
+}
+
+
+
+fn process_season_join_request(mut deps: DepsMut, block: BlockInfo, season: Season,
+                               team_addr: Addr) -> StdResult<()> {
+
+
+            let id = next_index_counter(deps.storage)?;
+            join_season_requests().save(deps.storage, id, &Message {
+                id,
+                updated: block.time.clone(),
+                created: block.time.clone(),
+                delivery:  DeliveryInfo {
+                        to: DeliveryPacket {
+                            asset_type: AssetTypes::League,
+                            address: season.league.clone()
+                        },
+                        from: DeliveryPacket {
+                            asset_type: AssetTypes::Team,
+                            address: team_addr.clone()
+                        }
+
+                },
+                data: JoinSeasonRequestInfo {
+                    status_type: MessageTypes::Accepted {},
+                    season_id: season.id
+                },
+                notes: vec![]
+            })?;
+            SEASON_STANDINGS.save(deps.storage, (season.id, team_addr), &TeamStanding::default())
+
+}
+
+
+
+
+fn join_winner_take_all_season(mut deps: DepsMut, block: BlockInfo, team_addr: Addr,
+                               season: Season, funds: Vec<Coin>) -> Result<Response, ContractError> {
+   let res =  join_season(deps.branch(), block.clone(), team_addr.clone(), season.id.clone(), Some(funds.clone()));
+    match res {
+        Ok(r) => {
+            //TODO: add sanity check on funds balance vs what's
+            //been sent in for kitties and payouts all balances out
+
+            let id = next_index_counter( deps.storage)?;
+            season_deposits_ledger().save(deps.storage, id, &SeasonLedger {
+                id,
+                season_id: season.id.clone(),
+                league: season.league,
+                team: team_addr,
+                team_deposit_amount: funds[0].clone(),
+                deposit_date: block.time.clone(),
+                withdrawal_distribution_date: None
+            }).expect("Problem saving season deposit.");
+            Ok(r)
+        }
+        Err(e) => {
+            Err(e)
+        }
+    }
+}
+
+
+fn join_open_season(mut deps: DepsMut, block: BlockInfo, team_addr: Addr,
+                    season_id: SeasonId) -> Result<Response, ContractError> {
+    join_season(deps, block, team_addr, season_id, None)
+
+}
+
+fn get_season_league_entry_for_team(store: &dyn Storage, team_addr: Addr,
+                                    season_id: SeasonId, league_addr: Addr) -> Option<(u64, Message<JoinSeasonRequestInfo>)> {
+    let res: Result<Vec<_>, _> =
+        join_season_requests().idx
+            .season_from_to
+            .prefix((season_id, team_addr,league_addr))
+            .range(store, None, None, Order::Ascending)
+            .collect();
+
+    match res {
+        Ok(r ) => {
+            match r.len() > 0 {
+                true =>{
+                    Some(r[0].clone())
+                },
+                false => None
+            }
+
+        }
+        Err(_) => {
+            None
+        }
+    }
+
+}
+
+fn cancel_team_season_spot(deps: DepsMut, block: BlockInfo, team_addr: Addr,
+                           season_id: SeasonId) -> Result<Response, ContractError> {
+    let season = seasons().may_load(deps.storage,season_id.clone()).unwrap();
+    return match season {
+        None => {
+            Err(ContractError::SeasonNotFound {})
+        },
+        Some(se) => {
+            match (se.start_date < block.time, (get_season_teams_accepted_count(deps.storage, se.id.clone())) > 1) {
+                (true, true) => {
+                    Err(ContractError::TooLateToCancelSeason {})
+                },
+                (true, false)/* not enough teams accepted to start season, so allow cancel */ |
+                (false, _) /* season hasn't started, so allow cancel */ => {
+                    remove_team_from_season(deps, block, se, team_addr)
+                }
+            }
+        }
+    }
+
+}
+
+//Cancels the team's accepted entry and refunds any season deposit
+fn remove_team_from_season(deps: DepsMut, block: BlockInfo, se: Season,
+                           team_addr: Addr) -> Result<Response, ContractError> {
+    let team_entry = get_season_league_entry_for_team(deps.storage, team_addr.clone(), se.id, se.league);
+    if let Some(te) = team_entry {
+        let mut item = te.clone().1;
+        item.data.status_type = MessageTypes::CancelSeason {};
+        join_season_requests().save(deps.storage, te.0, &item).expect("Problem processing cancel request.");
+        remove_team_from_division(deps.storage, se.id, team_addr.clone())?;
+        SEASON_STANDINGS.remove(deps.storage, (se.id, team_addr.clone()));
+
+
+        let res = distribute_individual_refund(deps, block.clone(), se.id, team_addr);
+        match res {
+            Ok(r) => {
+                //response from distribute_refund contains sub_message
+                //needed for payment transfers
+                Ok(r)
+            }
+            Err(e) => {
+                Err(e)
+            }
+        }
+    } else {
+        Err(ContractError::TeamNotMemberOfSeason {})
+    }
+}
+
+fn join_season(mut deps: DepsMut, block: BlockInfo, team_addr: Addr,
+               season_id: SeasonId, funds: Option<Vec<Coin>>) -> Result<Response, ContractError>  {
+    let season = seasons().may_load(deps.storage,season_id.clone()).unwrap();
+    match season {
+        None => {
+            Err(ContractError::SeasonNotFound {})
+        }
+        Some(s) => {
+            let validation_res =
+                validate_season_join_request(deps.storage, block.clone(), team_addr.clone(), s.league.clone(), funds, s.clone() );
+
+            match validation_res {
+                Ok(r) => {
+                    assign_team_to_division(deps.storage, block.clone(), s.clone(), team_addr.clone())?;
+                    let process_res = process_season_join_request(deps.branch(), block, s.clone(),
+                                                                  team_addr.clone());
+                    match process_res {
+                        Ok(_) => {
+                            Ok(r)
+                        },
+                        Err(e) => {
+                            Err(ContractError::Std(e))
+                        }
+                    }
+                },
+                Err(e) => Err(e)
+            }
+        }
+    }
+
+
+}
+
+
+fn get_season_teams_accepted_count (store: & dyn Storage , season_id: SeasonId) -> u32 {
+    let res: Result<Vec<_>, _> =
+        join_season_requests().idx
+            .season_id
+            .prefix(season_id)
+            .range(store, None, None, Order::Ascending)
+            .filter(|s|
+                {
+                    match s {
+                        Ok(item) => {
+                            item.1.data.status_type == MessageTypes::Accepted {}
+                        },
+                        _ => false,
+                    }
+
+                })
+            .collect();
+
+    match res {
+        Ok(r) => {
+            
+            r.len() as u32
+        },
+        _ => panic!("Problem calculating teams accepted count!")
+
+    }
+}
+
+
+fn is_season_at_capacity (store: &mut dyn Storage , season_id: SeasonId) -> bool {
+    let res_1: Result<Vec<_>, _> =
+        join_season_requests().idx
+            .season_id
+            .prefix(season_id)
+            .range(store, None, None, Order::Ascending)
+            .filter(|s|
+                {
+                    match s {
+                        Ok(item) => {
+                            item.1.data.status_type == MessageTypes::Accepted {}
+                        },
+                        _ => false,
+                    }
+                 
+                })
+            .collect();
+
+    match res_1 {
+        Ok(r) => {
+            let season = seasons().may_load(store ,season_id.clone()).unwrap().unwrap();
+            !((r.len() as u32) < season.max_teams_allowed.unwrap())
+        },
+        _ => true
+
+    }
+}
+
+fn validate_season_join_request(store: &mut dyn Storage , block: BlockInfo, team_addr: Addr,
+                                league_addr: Addr, funds: Option<Vec<Coin>>, season: Season)
+    -> Result<Response, ContractError> {
+
+
+            //(15 mins) padding time to give
+            //some time for teams response
+            //PRIOR_TO_SEASON_START_PADDING = 900
+            return match season.start_date.seconds() - block.time.seconds() >= PRIOR_TO_SEASON_START_PADDING {
+                true => {
+                    match season.status {
+                        None => {
+                            return Err(ContractError::SeasonStatusNotSet {})
+                        },
+                        Some(cs) => {
+                            match cs {
+                                SeasonStatus::Active => {
+
+                                    match is_season_at_capacity(store, season.id.clone()) {
+                                        true => {
+                                            return Err(ContractError::SeasonHasReachedCapacity {})
+                                        }
+                                        false => {
+                                            //Ok, allow to continue
+                                        }
+                                    }
+                                },
+                                SeasonStatus::Cancelled { date_cancelled } => {
+                                    return Err(ContractError::SeasonStatusCancelled { date_cancelled})
+                                }
+                                SeasonStatus::Private => {
+                                    return Err(ContractError::SeasonStatusPrivate {})
+                                }
+                            }
+                        }
+                    }
+
+                    assert_league_admits_team(store, &league_addr, &team_addr)?;
+
+                    let existing_request_res =
+                            get_existing_join_request(store, season.id.clone()
+                                                      , team_addr.clone(), league_addr);
+                    match existing_request_res {
+                        None => {
+                            //Ok, allow to continue
+                        }
+                        Some(_) => {
+                            return Err(ContractError::TeamAlreadyMemberOfSeason {})
+                        }
+                    }
+                    match season.access_type.clone() {
+                        Some(st) => {
+                            match st {
+                                SeasonAccessTypes::Open => {
+                                    //Ok, allow to continue
+                                }
+                                SeasonAccessTypes::WinnerTakeAll { coin } => {
+
+                                    match funds {
+                                        None => {
+                                            return Err(ContractError::IncorrectFundingSent {})
+                                        }
+                                        Some(f) => {
+                                            match coin.amount == f[0].amount &&
+                                                coin.denom == f[0].denom {
+                                                true => {
+                                                    //Ok, allow to continue
+                                                }
+                                                false => {
+                                                    return Err(ContractError::IncorrectFundingSent {})
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        None => {
+                            return Err(ContractError::SeasonTypeNotSet {})
+                        }
+                    }
+
+                    let conflicting_seasons =
+                        get_team_season_conflicts(store, AssetTypes::Team,
+                                                  team_addr.clone(),
+                                                  season.start_date.clone(),
+                                                  season.end_date.clone());
+                    match conflicting_seasons {
+                        None => {
+                            Ok(Response::new())
+                        }
+                        Some(items) => {
+                            let season_ids: Vec<SeasonId> =
+                                items.
+                                    into_iter().
+                                    map(|s| s.id).
+                                    collect();
+                            Err(ContractError::SeasonScheduleConflict { conflicting_season: season_ids })
+                        }
+                    }
+                }
+                false => {
+                    Err(ContractError::TooLateToRequestToJoinLeagueSeason {})
+                }
+            }
+
+}
+
+fn add_season_to_league(deps: DepsMut, block: BlockInfo,
+                        sender_contract_addr: Addr, season: Season) -> Result<Response,
+    ContractError>{
+    let season_id = create_league_season(deps, block, sender_contract_addr, season)?;
+    Ok(Response::new()
+        .add_attribute("action", "add_season_to_league")
+        .add_attribute("season_id", season_id.to_string()))
+}
+
+fn create_league_season(mut deps: DepsMut, block: BlockInfo,
+                        sender_contract_addr: Addr, mut season: Season) -> Result<SeasonId,
+    ContractError>{
+    
+    let capacity_policy = get_season_capacity_policy(deps.storage)?;
+    match is_validate_season(season.borrow(), block.clone(), &capacity_policy) {
+        Ok(r) => {
+
+            match managed_assets().may_load(deps.storage, &sender_contract_addr.clone())? {
+                None => {
+                    Err(ContractError::Unauthorized { sender: sender_contract_addr.clone() })
+                },
+                Some(mut mc) => {
+                    match mc.clone().asset_type == AssetTypes::League {
+                        true => {
+                            match query_check_for_season_date_range_conflicts(deps.storage,
+                                                                              season.clone().start_date.seconds(),
+                                                                  season.clone().end_date.seconds(),
+                                                                  sender_contract_addr.clone()){
+                                Ok(res) => {
+                                    match res {
+                                        None => {
+
+                                            let id = next_index_counter( deps.storage)?;
+                                            season.id = id.clone();
+                                            //let's make sure correct league aggress is assigned to
+                                            //new season
+                                            season.league = mc.asset_addr;
+                                            seasons().save(deps.storage, id , &season)?;
+                                            Ok(id)
+                                        }
+                                        Some(items) => {
+                                            let seasons: Vec<SeasonId> =
+                                                    items.
+                                                        into_iter().
+                                                        map( |s| s.id).
+                                                        collect();
+                                            Err(ContractError::SeasonScheduleConflict{ conflicting_season: seasons })
+                                        }
+                                    }
+                                },
+                                Err(e) => {
+                                    Err(ContractError::Std(e))
+                                }
+                            }
+
+                        }
+                        false => {
+                            Err(ContractError::Unauthorized { sender: sender_contract_addr })
+                        }
+                    }
+
+                }
+            }
+        },
+        Err(e) => {
+            Err(e)
+        }
+    }
+
+}
+
+// Update a season's capacity (max_teams_allowed)
+// Validation checks:
+//   --sending league must own the season
+//   --season must not have started or been cancelled
+//   --new capacity must fall within the manager's capacity policy
+//   --new capacity can't be lower than the teams already accepted
+///
+fn update_season_capacity(deps: DepsMut, block: BlockInfo, sending_league: Addr,
+                          season_id: SeasonId, max_teams_allowed: u32) -> Result<Response, ContractError> {
+    let a_season = seasons().may_load(deps.storage, season_id)?;
+    match a_season {
+        None => {
+            Err(ContractError::SeasonNotFound {})
+        }
+        Some(mut se) => {
+            match se.league == sending_league {
+                true => {
+                    match se.status.clone() {
+                        Some(SeasonStatus::Cancelled { date_cancelled }) => {
+                            return Err(ContractError::SeasonStatusCancelled { date_cancelled })
+                        }
+                        _ => {
+                            //Ok, allow to continue
+                        }
+                    }
+                    match se.start_date < block.time {
+                        true => return Err(ContractError::TooLateToUpdateSeason {}),
+                        false => {
+                            //Ok, allow to continue
+                        }
+                    }
+
+                    let capacity_policy = get_season_capacity_policy(deps.storage)?;
+                    match capacity_policy.is_capacity_allowed(max_teams_allowed) {
+                        true => {
+                            let accepted_teams = get_season_teams_accepted_count(deps.storage, se.id);
+                            match max_teams_allowed < accepted_teams {
+                                true => {
+                                    Err(ContractError::SeasonCapacityBelowAcceptedTeams { accepted_teams })
+                                }
+                                false => {
+                                    se.max_teams_allowed = Some(max_teams_allowed);
+                                    seasons().save(deps.storage, se.id, &se)?;
+                                    Ok(Response::new()
+                                        .add_attribute("action", "update_season_capacity")
+                                        .add_attribute("season_id", se.id.to_string())
+                                        .add_attribute("max_teams_allowed", max_teams_allowed.to_string()))
+                                }
+                            }
+                        }
+                        false => {
+                            Err(ContractError::SeasonCapacityOutOfRange {
+                                min_teams_allowed: capacity_policy.min_teams_allowed,
+                                max_teams_allowed: capacity_policy.max_teams_allowed
+                            })
+                        }
+                    }
+                }
+                false => {
+                    Err(ContractError::Unauthorized { sender: sending_league })
+                }
+            }
+        }
+    }
+}
+
+// Add a division to a season that hasn't started yet
+// Validation checks:
+//   --sending league must own the season
+//   --season must not have started or been cancelled
+//   --division must have a name and room for at least one team
+//   --teams that joined before the season had divisions must fit in them
+///
+fn add_division_to_season(deps: DepsMut, block: BlockInfo, sending_league: Addr,
+                          season_id: SeasonId, model: DivisionModelData) -> Result<Response, ContractError> {
+    let season = get_season_open_for_updates(deps.storage, block.clone(), sending_league, season_id)?;
+    let id = next_index_counter(deps.storage)?;
+    let division = Division {
+        id,
+        league: season.league.clone(),
+        season_id: season.id,
+        name: model.name.data.unwrap_or_default(),
+        conference: model.conference.data,
+        max_teams: model.max_teams.data.unwrap_or_default(),
+        created: block.time,
+    };
+    match division.validate() {
+        true => {
+            divisions().save(deps.storage, id, &division)?;
+            //teams only get a division when they join, so place the ones already in the season
+            let unassigned_teams: Vec<TeamAddr> =
+                query_get_season_teams_by_division(deps.storage, season_id)?.
+                    into_iter().
+                    filter(|g| g.division.is_none()).
+                    flat_map(|g| g.items).
+                    collect();
+            for team in unassigned_teams {
+                assign_team_to_division(deps.storage, block.clone(), season.clone(), team)?;
+            }
+            Ok(Response::new()
+                .add_attribute("action", "add_division_to_season")
+                .add_attribute("season_id", season_id.to_string())
+                .add_attribute("division_id", id.to_string()))
+        }
+        false => {
+            Err(ContractError::InvalidDivision {})
+        }
+    }
+}
+
+
+fn update_division(deps: DepsMut, block: BlockInfo, sending_league: Addr,
+                   division_id: DivisionId, model: DivisionModelData) -> Result<Response, ContractError> {
+    match divisions().may_load(deps.storage, division_id)? {
+        None => {
+            Err(ContractError::DivisionNotFound {})
+        }
+        Some(current) => {
+            get_season_open_for_updates(deps.storage, block, sending_league, current.season_id)?;
+            let division = current.merge_updates(model);
+            match division.validate() {
+                true => {
+                    let assigned_teams = get_division_teams_assigned_count(deps.storage, division_id);
+                    match division.max_teams < assigned_teams {
+                        true => {
+                            Err(ContractError::DivisionCapacityBelowAssignedTeams { assigned_teams })
+                        }
+                        false => {
+                            divisions().save(deps.storage, division_id, &division)?;
+                            Ok(Response::new()
+                                .add_attribute("action", "update_division")
+                                .add_attribute("division_id", division_id.to_string()))
+                        }
+                    }
+                }
+                false => {
+                    Err(ContractError::InvalidDivision {})
+                }
+            }
+        }
+    }
+}
+
+
+//Season owned by sending league, not cancelled and not yet started
+fn get_season_open_for_updates(store: &dyn Storage, block: BlockInfo, sending_league: Addr,
+                               season_id: SeasonId) -> Result<Season, ContractError> {
+    match seasons().may_load(store, season_id)? {
+        None => {
+            Err(ContractError::SeasonNotFound {})
+        }
+        Some(se) => {
+            match se.league == sending_league {
+                true => {
+                    match (se.status.clone(), se.start_date < block.time) {
+                        (Some(SeasonStatus::Cancelled { date_cancelled }), _) => {
+                            Err(ContractError::SeasonStatusCancelled { date_cancelled })
+                        }
+                        (_, true) => {
+                            Err(ContractError::TooLateToUpdateSeason {})
+                        }
+                        _ => {
+                            Ok(se)
+                        }
+                    }
+                }
+                false => {
+                    Err(ContractError::Unauthorized { sender: sending_league })
+                }
+            }
+        }
+    }
+}
+
+
+fn get_division_teams_assigned_count(store: &dyn Storage, division_id: DivisionId) -> u32 {
+    division_assignments().idx
+        .division
+        .prefix(division_id)
+        .keys(store, None, None, Order::Ascending)
+        .count() as u32
+}
+
+
+//Seasons without divisions don't assign teams; otherwise the
+//team joins the division with the most room left (lowest id on ties)
+fn assign_team_to_division(store: &mut dyn Storage, block: BlockInfo, season: Season,
+                           team_addr: Addr) -> Result<(), ContractError> {
+    let season_divisions = query_get_season_divisions(store, season.id);
+    match season_divisions {
+        None => {
+            Ok(())
+        }
+        Some(items) => {
+            let mut selected: Option<(DivisionId, u32)> = None;
+            for division in items {
+                let assigned_teams = get_division_teams_assigned_count(store, division.id);
+                let open_spots = division.max_teams.saturating_sub(assigned_teams);
+                match (open_spots > 0, selected) {
+                    (true, None) => selected = Some((division.id, open_spots)),
+                    (true, Some((_, most_open_spots))) if open_spots > most_open_spots => {
+                        selected = Some((division.id, open_spots))
+                    }
+                    _ => {}
+                }
+            }
+            match selected {
+                None => {
+                    Err(ContractError::SeasonDivisionsAtCapacity {})
+                }
+                Some((division_id, _)) => {
+                    let id = next_index_counter(store)?;
+                    division_assignments().save(store, id, &DivisionTeamAssignment {
+                        id,
+                        season_id: season.id,
+                        division_id,
+                        team: team_addr,
+                        assigned_date: block.time,
+                    })?;
+                    Ok(())
+                }
+            }
+        }
+    }
+}
+
+
+fn remove_team_from_division(store: &mut dyn Storage, season_id: SeasonId, team_addr: Addr) -> StdResult<()> {
+    let assignment = division_assignments().idx
+        .season_team
+        .item(store, (season_id, team_addr))?;
+    match assignment {
+        Some((_, item)) => {
+            division_assignments().remove(store, item.id)
+        }
+        None => Ok(())
+    }
+}
+
+
+// Start a season's playoffs
+// Validation checks:
+//   --sending league must own the season
+//   --season can't be cancelled and its regular season must have ended
+//   --playoffs can only be started once per season
+//   --at least two teams must make the playoffs
+///
+fn start_playoffs(deps: DepsMut, block: BlockInfo, sending_league: Addr, season_id: SeasonId,
+                  format: PlayoffFormat, playoff_teams: Option<u32>) -> Result<Response, ContractError> {
+    let season = match seasons().may_load(deps.storage, season_id)? {
+        None => return Err(ContractError::SeasonNotFound {}),
+        Some(se) => se
+    };
+    match season.league == sending_league {
+        true => {
+            //Ok, allow to continue
+        }
+        false => return Err(ContractError::Unauthorized { sender: sending_league })
+    }
+    match (season.status.clone(), season.end_date > block.time) {
+        (Some(SeasonStatus::Cancelled { date_cancelled }), _) => {
+            return Err(ContractError::SeasonStatusCancelled { date_cancelled })
+        }
+        (_, true) => return Err(ContractError::RegularSeasonNotComplete {}),
+        _ => {
+            //Ok, allow to continue
+        }
+    }
+    match PLAYOFFS.has(deps.storage, season_id) {
+        true => return Err(ContractError::PlayoffsAlreadyStarted {}),
+        false => {
+            //Ok, allow to continue
+        }
+    }
+
+    let mut seeded_teams = get_season_seeding(deps.storage, season_id)?;
+    if let Some(count) = playoff_teams {
+        seeded_teams.truncate(count as usize);
+    }
+    match seeded_teams.len() < 2 {
+        true => {
+            Err(ContractError::NotEnoughPlayoffTeams {})
+        }
+        false => {
+            let playoffs = Playoffs::new(season_id, season.league, format, seeded_teams, block.time);
+            PLAYOFFS.save(deps.storage, season_id, &playoffs)?;
+            Ok(Response::new()
+                .add_attribute("action", "start_playoffs")
+                .add_attribute("season_id", season_id.to_string()))
+        }
+    }
+}
+
+
+fn submit_playoff_result(mut deps: DepsMut, block: BlockInfo, sending_league: Addr, season_id: SeasonId,
+                         match_id: u32, winner: TeamAddr) -> Result<Response, ContractError> {
+    let mut playoffs = match PLAYOFFS.may_load(deps.storage, season_id)? {
+        None => return Err(ContractError::PlayoffsNotFound {}),
+        Some(p) => p
+    };
+    match (playoffs.league == sending_league, playoffs.champion.clone()) {
+        (false, _) => Err(ContractError::Unauthorized { sender: sending_league }),
+        (true, Some(champion)) => Err(ContractError::PlayoffsCompleted { champion }),
+        (true, None) => {
+            match playoffs.record_result(match_id, winner, block.time) {
+                true => {
+                    PLAYOFFS.save(deps.storage, season_id, &playoffs)?;
+                    let res = Response::new()
+                        .add_attribute("action", "submit_playoff_result")
+                        .add_attribute("season_id", season_id.to_string())
+                        .add_attribute("match_id", match_id.to_string());
+                    match playoffs.champion {
+                        Some(champion) => {
+                            let league_type = league_type_of(deps.storage, &playoffs.league)?;
+                            hall_of_fame().save(deps.storage, season_id, &HallOfFameEntry {
+                                season_id,
+                                league: playoffs.league.clone(),
+                                league_type,
+                                champion: champion.clone(),
+                                crowned: block.time
+                            })?;
+                            distribute_winner_take_all_payout(deps.branch(), block, season_id,
+                                                              champion.clone(),
+                                                              res.add_attribute("champion", champion.to_string()))
+                        }
+                        None => Ok(res)
                     }
                 }
-            };
+                false => {
+                    Err(ContractError::InvalidPlayoffResult { match_id })
+                }
+            }
+        }
+    }
+}
 
-            managed_assets().update(deps.storage, &sender_contract_addr,
-                                    update_fn)?;
-            Ok(Response::new())
-        },
-        (true, _ ) => {
-            let item =
-                 MangedItem {
-                    asset_addr: sender_contract_addr.clone(),
-                    asset_name,
-                    asset_owner,
-                    asset_type: asset_type.clone(),
-                    managed_status,
-                    created: block.time,
-                    updated: block.time,
-                     for_sale: 0u8,
-                     for_sale_price: None,
-                     for_sale_price_version: 0,
-                     for_sale_last_updated: Default::default(),
-                     ownership_history: vec![]
-                 };
-            managed_assets().save(deps.storage, &sender_contract_addr, &item)?;
-            Ok(Response::new())
+
+//Season teams ordered by wins, then fewest losses. Ties keep division order.
+fn get_season_seeding(store: &dyn Storage, season_id: SeasonId) -> StdResult<Vec<TeamAddr>> {
+    let mut standings: Vec<TeamStandingItem> =
+        query_get_season_standings_by_division(store, season_id)?.
+            into_iter().
+            flat_map(|g| g.items).
+            collect();
+    standings.sort_by(|a, b|
+        b.standing.wins.cmp(&a.standing.wins).
+            then(a.standing.losses.cmp(&b.standing.losses)));
+    Ok(standings.into_iter().map(|s| s.team).collect())
+}
+
+
+//Pays every unpaid winner take all deposit for the season to the champion
+fn distribute_winner_take_all_payout(deps: DepsMut, block: BlockInfo, season_id: SeasonId,
+                                     champion: TeamAddr, response: Response) -> Result<Response, ContractError> {
+    let season = match seasons().may_load(deps.storage, season_id)? {
+        None => return Err(ContractError::SeasonNotFound {}),
+        Some(se) => se
+    };
+    match season.access_type.clone() {
+        Some(SeasonAccessTypes::WinnerTakeAll { .. }) => {
+            let unpaid_deposits = get_unpaid_deposits_by_season(deps.storage, season);
+            let mut payout: Vec<Coin> = vec![];
+            for deposit in unpaid_deposits.iter() {
+                let amount = deposit.1.team_deposit_amount.clone();
+                match payout.iter_mut().find(|c| c.denom == amount.denom) {
+                    Some(c) => c.amount += amount.amount,
+                    None => payout.push(amount)
+                }
+            }
+            match payout.len() > 0 {
+                true => {
+                    mark_deposit_withdrawls_paid(deps, block, unpaid_deposits);
+                    let msg: CosmosMsg = deposit_to_team_treasury_msg(champion, payout);
+                    Ok(response.add_submessage(SubMsg {
+                        id: 0,
+                        msg,
+                        gas_limit: None,
+                        reply_on: ReplyOn::Never
+                    }))
+                }
+                false => Ok(response)
+            }
+        }
+        _ => Ok(response)
+    }
+}
+
+
+// Reschedule (or postpone) a season
+// Validation checks:
+//   --sending league must own the season
+//   --season can't be cancelled, finished or in its playoffs
+//   --new dates must leave the usual join padding and not overlap
+//     the league's other seasons
+// Every accepted team is sent a notice listing any of its other seasons
+// that now overlap, and may withdraw with a full refund until the new start.
+///
+fn reschedule_season(deps: DepsMut, block: BlockInfo, sending_league: Addr, season_id: SeasonId,
+                     new_start: Timestamp, new_end: Timestamp, reason: String) -> Result<Response, ContractError> {
+    let mut season = match seasons().may_load(deps.storage, season_id)? {
+        None => return Err(ContractError::SeasonNotFound {}),
+        Some(se) => se
+    };
+    match season.league == sending_league {
+        true => {
+            //Ok, allow to continue
+        }
+        false => return Err(ContractError::Unauthorized { sender: sending_league })
+    }
+    match (season.status.clone(), season.end_date < block.time || PLAYOFFS.has(deps.storage, season_id)) {
+        (Some(SeasonStatus::Cancelled { date_cancelled }), _) => {
+            return Err(ContractError::SeasonStatusCancelled { date_cancelled })
         }
+        (_, true) => return Err(ContractError::TooLateToUpdateSeason {}),
         _ => {
-                Err(ContractError::Unauthorized { sender: sender_contract_addr.clone() })
+            //Ok, allow to continue
+        }
+    }
+    match new_start >= block.time.plus_seconds(PRIOR_TO_SEASON_START_PADDING) && new_end > new_start {
+        true => {
+            //Ok, allow to continue
+        }
+        false => return Err(ContractError::InvalidSeason {})
+    }
+
+    let league_conflicts: Vec<SeasonId> =
+        query_check_for_season_date_range_conflicts(deps.storage, new_start.seconds(),
+                                                    new_end.seconds(), season.league.clone())?.
+            unwrap_or_default().
+            into_iter().
+            map(|s| s.id).
+            filter(|id| *id != season_id).
+            collect();
+    match league_conflicts.len() > 0 {
+        true => return Err(ContractError::SeasonScheduleConflict { conflicting_season: league_conflicts }),
+        false => {
+            //Ok, allow to continue
+        }
+    }
+
+    let previous_start = season.start_date;
+    let previous_end = season.end_date;
+    season.start_date = new_start;
+    season.end_date = new_end;
+    seasons().save(deps.storage, season_id, &season)?;
+
+    let accepted_teams: Vec<TeamAddr> =
+        join_season_requests().idx
+            .season_id
+            .prefix(season_id)
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?
+            .into_iter()
+            .filter(|i| i.1.data.status_type == MessageTypes::Accepted {})
+            .map(|i| i.1.delivery.from.address)
+            .collect();
+
+    for team_addr in accepted_teams.iter() {
+        let conflicting_seasons: Vec<SeasonId> =
+            get_team_season_conflicts(deps.storage, AssetTypes::Team, team_addr.clone(), new_start, new_end).
+                unwrap_or_default().
+                into_iter().
+                map(|s| s.id).
+                filter(|id| *id != season_id).
+                collect();
+        let id = next_index_counter(deps.storage)?;
+        season_reschedule_notices().save(deps.storage, id, &Message {
+            id,
+            updated: block.time,
+            created: block.time,
+            delivery: DeliveryInfo {
+                to: DeliveryPacket {
+                    asset_type: AssetTypes::Team,
+                    address: team_addr.clone()
+                },
+                from: DeliveryPacket {
+                    asset_type: AssetTypes::League,
+                    address: season.league.clone()
+                }
+            },
+            data: SeasonRescheduleInfo {
+                season_id,
+                previous_start,
+                previous_end,
+                new_start,
+                new_end,
+                reason: reason.clone(),
+                conflicting_seasons,
+                withdrawn: false
+            },
+            notes: vec![]
+        })?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "reschedule_season")
+        .add_attribute("season_id", season_id.to_string())
+        .add_attribute("notified_teams", accepted_teams.len().to_string()))
+}
+
+
+fn withdraw_from_rescheduled_season(deps: DepsMut, block: BlockInfo, team_addr: Addr,
+                                    season_id: SeasonId) -> Result<Response, ContractError> {
+    let season = match seasons().may_load(deps.storage, season_id)? {
+        None => return Err(ContractError::SeasonNotFound {}),
+        Some(se) => se
+    };
+    match season.start_date < block.time {
+        true => return Err(ContractError::TooLateToCancelSeason {}),
+        false => {
+            //Ok, allow to continue
+        }
+    }
+    let pending_notices: Vec<(MessageId, Message<SeasonRescheduleInfo>)> =
+        season_reschedule_notices().idx
+            .season_recipient
+            .prefix((season_id, team_addr.clone()))
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?
+            .into_iter()
+            .filter(|i| !i.1.data.withdrawn)
+            .collect();
+    match pending_notices.len() > 0 {
+        true => {
+            for (id, mut notice) in pending_notices {
+                notice.data.withdrawn = true;
+                notice.updated = block.time;
+                season_reschedule_notices().save(deps.storage, id, &notice)?;
+            }
+            remove_team_from_season(deps, block, season, team_addr)
+        }
+        false => {
+            Err(ContractError::NoPendingRescheduleNotice {})
         }
     }
 }
 
-fn add_update_forsale_item(store: &mut dyn Storage, block: BlockInfo, sender: Addr,
-                           update_item: AssetSaleItemAddUpdateModel) -> StdResult<()>{
 
-    let managed_item_res = managed_assets().may_load(store, &sender.clone());
-    match managed_item_res {
-        Ok(r) => {
-            match r {
-                None => {
-                    Err(StdError::not_found(sender.clone()))
+fn set_season_template(deps: DepsMut, league: Addr, template: Option<SeasonTemplate>) -> Result<Response, ContractError> {
+    match template {
+        None => {
+            SEASON_TEMPLATES.remove(deps.storage, &league);
+            Ok(Response::new()
+                .add_attribute("action", "remove_season_template")
+                .add_attribute("league", league.to_string()))
+        }
+        Some(t) => {
+            let capacity_policy = get_season_capacity_policy(deps.storage)?;
+            match t.validate(&capacity_policy) {
+                true => {
+                    //replacing a template keeps the recurrence count going
+                    let current = SEASON_TEMPLATES.may_load(deps.storage, &league)?;
+                    let (spawned_count, last_spawned_season) = match current {
+                        None => (0, None),
+                        Some(c) => (c.spawned_count, c.last_spawned_season)
+                    };
+                    SEASON_TEMPLATES.save(deps.storage, &league, &LeagueSeasonTemplate {
+                        league: league.clone(),
+                        template: t,
+                        spawned_count,
+                        last_spawned_season,
+                    })?;
+                    Ok(Response::new()
+                        .add_attribute("action", "set_season_template")
+                        .add_attribute("league", league.to_string()))
                 }
-                Some(mut m) => {
-                    m.for_sale = match update_item.for_sale { true => 1u8, false => 0u8};
-                    m.for_sale_price = update_item.for_sale_price;
-                    m.for_sale_price_version = update_item.for_sale_price_version;
-                    m.for_sale_last_updated = block.time;
-                    managed_assets().save(store, &sender.clone(), &m)?;
-                    Ok(())
+                false => {
+                    Err(ContractError::InvalidSeasonTemplate {})
                 }
             }
         }
-        Err(e) => {
-            Err(e)
+    }
+}
+
+
+// Create a league's next season from its template
+// Validation checks:
+//   --league must have a template whose recurrence hasn't run out
+//   --league's latest season must have ended
+// The new season starts `gap` seconds after the previous one ends, but never
+// sooner than PRIOR_TO_SEASON_START_PADDING from now so teams can still join.
+// Date conflicts and capacity are checked as for any other new season.
+///
+fn spawn_next_season(mut deps: DepsMut, block: BlockInfo, league: Addr) -> Result<Response, ContractError> {
+    let mut league_template = match SEASON_TEMPLATES.may_load(deps.storage, &league)? {
+        None => return Err(ContractError::SeasonTemplateNotFound {}),
+        Some(t) => t
+    };
+
+    let previous_season =
+        query_get_all_seasons_by_league(deps.storage, league.clone()).
+            unwrap_or_default().
+            into_iter().
+            max_by_key(|s| s.end_date);
+    let earliest_start = block.time.plus_seconds(PRIOR_TO_SEASON_START_PADDING);
+    let start_date = match previous_season {
+        None => earliest_start,
+        Some(ps) => {
+            match ps.end_date > block.time {
+                true => return Err(ContractError::PreviousSeasonNotFinished { season_id: ps.id }),
+                false => std::cmp::max(ps.end_date.plus_seconds(league_template.template.gap), earliest_start)
+            }
+        }
+    };
+
+    let template = league_template.template.clone();
+    match template.allows_next(league_template.spawned_count, start_date) {
+        true => {
+            //Ok, allow to continue
         }
+        false => return Err(ContractError::SeasonRecurrenceComplete {})
     }
 
+    let season = Season {
+        id: 0, //assigned by create_league_season
+        league: league.clone(),
+        name: format!("{} {}", template.name, league_template.spawned_count + 1),
+        current_episode: 0,
+        description: template.description,
+        start_date,
+        end_date: start_date.plus_seconds(template.duration),
+        access_type: Some(template.access_type),
+        status: Some(SeasonStatus::Active),
+        max_teams_allowed: Some(template.max_teams_allowed),
+        episodes: DEFAULT_SEASON_EPISODES,
+    };
+    let season_id = create_league_season(deps.branch(), block, league.clone(), season)?;
+
+    league_template.spawned_count += 1;
+    league_template.last_spawned_season = Some(season_id);
+    SEASON_TEMPLATES.save(deps.storage, &league, &league_template)?;
+    Ok(Response::new()
+        .add_attribute("action", "spawn_next_season")
+        .add_attribute("league", league.to_string())
+        .add_attribute("season_id", season_id.to_string()))
+}
 
 
+// Player trade proposal from one team to another
+// Validation checks:
+//   --counterparty must be a different team under management
+//   --at least one player or draft pick has to change hands
+//   --each offered/requested player must currently be assigned to its team
+//   --each offered/requested pick must be unmade and owned by its team
+//   --neither team may be inside a season lock window
+//   --the funds sent are exactly the offered funds; they are escrowed here
+fn propose_trade(deps: DepsMut, block: BlockInfo, proposing_team: TeamAddr, counterparty_team: TeamAddr,
+                 offered_players: Vec<PlayerInfo>, requested_players: Vec<PlayerInfo>,
+                 offered_funds: Vec<Coin>, offered_picks: Vec<DraftPickRef>, requested_picks: Vec<DraftPickRef>,
+                 funds: Vec<Coin>) -> Result<Response, ContractError> {
+    let offered_funds = normalize_coins(offered_funds);
+    match normalize_coins(funds) == offered_funds {
+        true => (),
+        false => return Err(ContractError::TradeFundsMismatch {})
+    }
+    match is_contract_under_management(deps.storage, counterparty_team.clone()) {
+        Some(mc) if mc.asset_type == AssetTypes::Team && counterparty_team != proposing_team => (),
+        _ => return Err(ContractError::InvalidTrade {})
+    }
+    match offered_players.len() + requested_players.len() + offered_picks.len() + requested_picks.len() > 0 {
+        true => (),
+        false => return Err(ContractError::InvalidTrade {})
+    }
+    validate_trade(deps.storage, block.clone(), &proposing_team, &counterparty_team,
+                   &offered_players, &requested_players)?;
+    validate_trade_picks(deps.storage, &proposing_team, &counterparty_team, &offered_picks, &requested_picks)?;
+
+    let id = next_index_counter(deps.storage)?;
+    trade_proposals().save(deps.storage, id, &TradeProposal {
+        id,
+        proposing_team: proposing_team.clone(),
+        counterparty_team: counterparty_team.clone(),
+        offered_players,
+        requested_players,
+        offered_funds,
+        offered_picks,
+        requested_picks,
+        status: TradeStatus::Proposed,
+        created: block.time,
+        updated: block.time
+    })?;
+    Ok(Response::new()
+        .add_attribute("action", "propose_trade")
+        .add_attribute("trade_id", id.to_string())
+        .add_attribute("proposing_team", proposing_team)
+        .add_attribute("counterparty_team", counterparty_team))
 }
 
-fn get_managed_items_for_sale(store: &dyn Storage) -> Vec<StdResult<(Addr, MangedItem)>> {
-    let res = //: Result<Vec<_>, _>
-        managed_assets().idx.
-            for_sale.
-            sub_prefix(1u8).
-            range(store, None, None, Order::Ascending).
-            collect();
-    res
+
+fn validate_trade(store: &mut dyn Storage, block: BlockInfo, proposing_team: &TeamAddr, counterparty_team: &TeamAddr,
+                  offered_players: &[PlayerInfo], requested_players: &[PlayerInfo]) -> Result<(), ContractError> {
+    let assignments =
+        offered_players.iter().map(|p| (p, proposing_team)).
+            chain(requested_players.iter().map(|p| (p, counterparty_team)));
+    for (player, team) in assignments {
+        match players().may_load(store, player.address.clone())? {
+            Some(t) if t.assigned_team_address.as_ref() == Some(team) => (),
+            _ => return Err(ContractError::TradePlayerNotOnTeam { player: player.address.clone(), team: team.clone() })
+        }
+    }
+
+    //rosters are locked from shortly before a season starts until it ends
+    for team in [proposing_team, counterparty_team] {
+        match get_team_season_conflicts(store, AssetTypes::Team, team.clone(), block.time,
+                                        block.time.plus_seconds(PRIOR_TO_SEASON_START_PADDING)) {
+            Some(seasons) => {
+                return Err(ContractError::TradeLockedDuringSeason { team: team.clone(), season_id: seasons[0].id })
+            }
+            None => ()
+        }
+    }
+    Ok(())
 }
 
-fn get_forsale_items_by_asset_type(store: &dyn Storage, asset_type: AssetTypes )
-    -> Option<Vec<(Addr, MangedItem)>>{
-    let items_for_sale_res = get_managed_items_for_sale(store);
-    match items_for_sale_res.len() > 0 {
-        true => {
 
-                    let res: Vec<(Addr, MangedItem)> =
-                        items_for_sale_res.
-                            into_iter().
-                            filter(|mc|
-                                         match mc.clone() {
-                                             Ok(r) => r.1.asset_type == asset_type,
-                                             Err(_) => false
-                                         } ).
-                            map(|i |  i.unwrap()).
-                            collect();
-                    Some(res)
+//Picks can only go to a team taking part in the same draft
+fn validate_trade_picks(store: &dyn Storage, proposing_team: &TeamAddr, counterparty_team: &TeamAddr,
+                        offered_picks: &[DraftPickRef], requested_picks: &[DraftPickRef]) -> Result<(), ContractError> {
+    let owners =
+        offered_picks.iter().map(|p| (p, proposing_team, counterparty_team)).
+            chain(requested_picks.iter().map(|p| (p, counterparty_team, proposing_team)));
+    for (pick_ref, team, to_team) in owners {
+        let draft = match DRAFTS.may_load(store, pick_ref.season_id)? {
+            None => return Err(ContractError::DraftNotFound {}),
+            Some(d) => d
+        };
+        //picks only belong to a slot once the lottery has set the order
+        match draft.lottery_pending() {
+            true => return Err(ContractError::DraftLotteryNotDrawn {}),
+            false => ()
         }
-        false => {
-            None
+        match draft.picks.iter().any(|p| &p.original_team == to_team) {
+            true => (),
+            false => return Err(ContractError::InvalidTrade {})
+        }
+        match draft.picks.iter().find(|p| p.pick_number == pick_ref.pick_number) {
+            None => return Err(ContractError::DraftPickNotFound {}),
+            Some(p) if p.made.is_some() => return Err(ContractError::DraftPickAlreadyMade {}),
+            Some(p) if &p.team != team => return Err(ContractError::NotDraftPickOwner { sender: team.clone() }),
+            Some(_) => ()
         }
     }
+    Ok(())
 }
 
-fn get_forsale_item_by_addr(store: & dyn Storage,  addr:Addr )
-    -> Option<MangedItem>{
-        //let res = managed_assets().may_load(store, &addr)?;
-        let items_for_sale_res = get_managed_items_for_sale(store);
-        match items_for_sale_res.len() > 0 {
-            true => {
 
-                let res: Vec<MangedItem> =
-                    items_for_sale_res.
-                        into_iter().
-                        filter(|mc|
-                            match mc.clone() {
-                                Ok(r) => r.1.asset_addr == addr,
-                                Err(_) => false
-                            } ).
-                        map(|i |  i.unwrap().1).
-                        collect();
-                Some(res[0].clone())
+fn move_draft_picks(store: &mut dyn Storage, picks: &[DraftPickRef], to_team: &TeamAddr) -> StdResult<()> {
+    for pick_ref in picks {
+        let mut draft = DRAFTS.load(store, pick_ref.season_id)?;
+        if let Some(pick) = draft.picks.iter_mut().find(|p| p.pick_number == pick_ref.pick_number) {
+            pick.team = to_team.clone();
+        }
+        DRAFTS.save(store, pick_ref.season_id, &draft)?;
+    }
+    Ok(())
+}
+
+
+fn get_open_trade(store: &dyn Storage, trade_id: u64) -> Result<TradeProposal, ContractError> {
+    match trade_proposals().may_load(store, trade_id)? {
+        None => Err(ContractError::TradeNotFound {}),
+        Some(t) => {
+            match t.status == TradeStatus::Proposed {
+                true => Ok(t),
+                false => Err(ContractError::TradeNotOpen { status: t.status })
+            }
+        }
+    }
+}
+
+
+fn close_trade_with_refund(store: &mut dyn Storage, block: BlockInfo, mut trade: TradeProposal,
+                           status: TradeStatus, response: Response) -> Result<Response, ContractError> {
+    trade.status = status;
+    trade.updated = block.time;
+    trade_proposals().save(store, trade.id, &trade)?;
+    match trade.offered_funds.len() > 0 {
+        true => Ok(response.add_message(deposit_to_team_treasury_msg(trade.proposing_team, trade.offered_funds))),
+        false => Ok(response)
+    }
+}
+
+
+// Counterparty accepts or rejects. On acceptance both rosters and the player
+// tracking update in this transaction; if either team rejects its roster
+// change the whole trade is reverted.
+fn respond_to_trade(deps: DepsMut, block: BlockInfo, sending_team: TeamAddr, trade_id: u64,
+                    accept: bool) -> Result<Response, ContractError> {
+    let mut trade = get_open_trade(deps.storage, trade_id)?;
+    match trade.counterparty_team == sending_team {
+        true => (),
+        false => return Err(ContractError::Unauthorized { sender: sending_team })
+    }
+    let res = Response::new()
+        .add_attribute("action", "respond_to_trade")
+        .add_attribute("trade_id", trade_id.to_string());
+    match accept {
+        false => close_trade_with_refund(deps.storage, block, trade, TradeStatus::Rejected, res),
+        true => {
+            validate_trade(deps.storage, block.clone(), &trade.proposing_team, &trade.counterparty_team,
+                           &trade.offered_players, &trade.requested_players)?;
+            validate_trade_picks(deps.storage, &trade.proposing_team, &trade.counterparty_team,
+                                 &trade.offered_picks, &trade.requested_picks)?;
+            move_draft_picks(deps.storage, &trade.offered_picks, &trade.counterparty_team)?;
+            move_draft_picks(deps.storage, &trade.requested_picks, &trade.proposing_team)?;
+
+            for p in trade.offered_players.iter() {
+                assign_player_team(deps.storage, &p.address, Some(trade.counterparty_team.clone()))?;
+            }
+            for p in trade.requested_players.iter() {
+                assign_player_team(deps.storage, &p.address, Some(trade.proposing_team.clone()))?;
+            }
+
+            //player contracts move with the players; both payrolls must stay under the cap
+            for (players, to_team) in [(&trade.offered_players, &trade.counterparty_team),
+                                       (&trade.requested_players, &trade.proposing_team)] {
+                for p in players.iter() {
+                    if let Some(mut contract) = player_contracts().may_load(deps.storage, p.address.clone())? {
+                        contract.team = to_team.clone();
+                        player_contracts().save(deps.storage, p.address.clone(), &contract)?;
+                    }
+                }
+            }
+            check_salary_cap(deps.storage, &trade.proposing_team)?;
+            check_salary_cap(deps.storage, &trade.counterparty_team)?;
+
+            trade.status = TradeStatus::Accepted;
+            trade.updated = block.time;
+            trade_proposals().save(deps.storage, trade_id, &trade)?;
+
+            let mut res = res
+                .add_message(apply_trade_msg(trade.proposing_team.clone(), trade_id,
+                                             trade.offered_players.clone(), trade.requested_players.clone()))
+                .add_message(apply_trade_msg(trade.counterparty_team.clone(), trade_id,
+                                             trade.requested_players.clone(), trade.offered_players.clone()));
+            match trade.offered_funds.len() > 0 {
+                true => res = res.add_message(deposit_to_team_treasury_msg(trade.counterparty_team, trade.offered_funds)),
+                false => ()
+            }
+            Ok(res)
+        }
+    }
+}
+
+
+fn cancel_trade(deps: DepsMut, block: BlockInfo, sending_team: TeamAddr, trade_id: u64) -> Result<Response, ContractError> {
+    let trade = get_open_trade(deps.storage, trade_id)?;
+    match trade.proposing_team == sending_team {
+        true => close_trade_with_refund(deps.storage, block, trade, TradeStatus::Cancelled,
+                                        Response::new()
+                                            .add_attribute("action", "cancel_trade")
+                                            .add_attribute("trade_id", trade_id.to_string())),
+        false => Err(ContractError::Unauthorized { sender: sending_team })
+    }
+}
+
+
+fn get_team_trade_proposals(store: &dyn Storage, team_addr: TeamAddr) -> StdResult<Vec<TradeProposal>> {
+    let proposed: Vec<(u64, TradeProposal)> =
+        trade_proposals().idx.proposing_team
+            .prefix(team_addr.clone())
+            .range(store, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+    let received: Vec<(u64, TradeProposal)> =
+        trade_proposals().idx.counterparty_team
+            .prefix(team_addr)
+            .range(store, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+    let mut res: Vec<TradeProposal> = [proposed, received].concat().into_iter().map(|t| t.1).collect();
+    res.sort_by_key(|t| t.id);
+    Ok(res)
+}
+
+
+fn query_player_contract(deps: &DepsMut, player: &PlayerAddr) -> StdResult<player::Player> {
+    let res: player::InfoResponse =
+        deps.querier.query_wasm_smart(player.clone(), &player::QueryMsg::GetInfo {})?;
+    Ok(res.player)
+}
+
+
+// Player owner sets the signing terms for an unassigned player.
+// Players that were never on a roster join the pool here.
+fn update_free_agent_terms(deps: DepsMut, block: BlockInfo, sender: Addr, player: PlayerAddr,
+                           signing_fee: Option<Coin>, approved_team: Option<TeamAddr>) -> Result<Response, ContractError> {
+    let player_info = query_player_contract(&deps, &player)?;
+    match player_info.owner == sender {
+        true => (),
+        false => return Err(ContractError::NotPlayerOwner { sender })
+    }
+    match player_info.status {
+        PlayerStatus::Retired => return Err(ContractError::PlayerRetired { player }),
+        _ => ()
+    }
+
+    let tracked_player =
+        match players().may_load(deps.storage, player.clone())? {
+            Some(p) => {
+                match p.assigned_team_address.clone() {
+                    Some(team) => return Err(ContractError::PlayerAlreadyAssigned { player, team }),
+                    None => p
+                }
+            }
+            None => {
+                match is_player_name_taken(deps.storage, &player, &player_info.first_name, &player_info.last_name)? {
+                    true => return Err(ContractError::PlayerNameAlreadyInUse { first_name: player_info.first_name,
+                        last_name: player_info.last_name }),
+                    false => ()
+                }
+                let p = PlayerInfo {
+                    address: player.clone(),
+                    first_name: player_info.first_name,
+                    last_name: player_info.last_name,
+                    position: player_info.position,
+                    assigned_team_address: None
+                };
+                players().save(deps.storage, player.clone(), &p)?;
+                p
             }
-            false => {
-                None
+        };
+
+    let listed =
+        match free_agents().may_load(deps.storage, player.clone())? {
+            Some(fa) => fa.listed,
+            None => block.time
+        };
+    free_agents().save(deps.storage, player.clone(), &FreeAgent {
+        player: tracked_player,
+        owner: sender,
+        signing_fee,
+        approved_team,
+        listed
+    })?;
+    Ok(Response::new()
+        .add_attribute("action", "update_free_agent_terms")
+        .add_attribute("player", player))
+}
+
+
+// Team signs a free agent either with the owner's approval or by
+// paying exactly the listed signing fee, which goes to the owner
+fn sign_free_agent(deps: DepsMut, team: TeamAddr, player: PlayerAddr,
+                   funds: Vec<Coin>) -> Result<Response, ContractError> {
+    let free_agent =
+        match free_agents().may_load(deps.storage, player.clone())? {
+            None => return Err(ContractError::FreeAgentNotFound { player }),
+            Some(fa) => fa
+        };
+    //terms set by a previous owner don't bind the player's current owner
+    let owner = query_player_contract(&deps, &player)?.owner;
+    let (approved_team, signing_fee) =
+        match free_agent.owner == owner {
+            true => (free_agent.approved_team, free_agent.signing_fee),
+            false => (None, None)
+        };
+    match (approved_team.as_ref() == Some(&team), signing_fee) {
+        (true, _) => (),
+        (false, Some(fee)) => {
+            match funds == vec![fee.clone()] {
+                true => (),
+                false => return Err(ContractError::IncorrectSigningFee { expected: fee })
             }
         }
+        (false, None) => return Err(ContractError::FreeAgentSigningNotApproved { player })
+    }
 
+    assign_player_team(deps.storage, &player, Some(team.clone()))?;
+    free_agents().remove(deps.storage, player.clone())?;
 
-
+    let res = Response::new()
+        .add_attribute("action", "sign_free_agent")
+        .add_attribute("player", player)
+        .add_attribute("team", team);
+    match funds.len() > 0 {
+        true => Ok(res.add_message(BankMsg::Send { to_address: owner.to_string(), amount: funds })),
+        false => Ok(res)
+    }
 }
 
-fn is_validate_season(season: &Season, block: BlockInfo) -> Result<Response,
-    ContractError>
-{
-    match season.validate(block) {
-        true => {
-            Ok(Response::new())
-        }
-        false => {
-            Err(ContractError::InvalidSeason {})
+
+// Unassigned players tracked before the free agent pool existed never
+// got an entry; they're listed with no signing terms. Retired players,
+// draft pool players and players whose contract can't be queried are skipped.
+fn backfill_free_agents(deps: &mut DepsMut, block: &BlockInfo) -> StdResult<u32> {
+    let unassigned: Vec<PlayerInfo> =
+        players().idx.team
+            .prefix(Addr::unchecked(UNASSIGNED_PLAYER_TEAM))
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, p)| p))
+            .collect::<StdResult<_>>()?;
+    let mut added = 0u32;
+    for p in unassigned {
+        match free_agents().has(deps.storage, p.address.clone()) ||
+            DRAFT_POOL_PLAYERS.has(deps.storage, &p.address) ||
+            get_player_status(deps.storage, &p.address)? == PlayerStatus::Retired {
+            true => continue,
+            false => ()
         }
+        let owner =
+            match query_player_contract(deps, &p.address) {
+                Ok(info) => info.owner,
+                Err(_) => continue
+            };
+        free_agents().save(deps.storage, p.address.clone(), &FreeAgent {
+            player: p,
+            owner,
+            signing_fee: None,
+            approved_team: None,
+            listed: block.time
+        })?;
+        added += 1;
     }
+    Ok(added)
 }
 
 
-fn verify_address_owns_assets(deps: &DepsMut, owning_address: Addr, addresses: Vec<Addr>) -> Result<bool, ContractError> {
-    for item_addr in addresses {
-        let res = managed_assets().may_load(deps.storage, &item_addr);
-        match res {
-            Ok(mi) => {
-                    match mi {
-                        None => {
-                            return Err(ContractError::ItemNotFound { item_address: item_addr })
-                        }
-                        Some(m) => {
-                            match m.asset_owner == owning_address {
-                                true => continue,
-                                false => return Ok(false)
-                            }
-                        }
-                    }
-            },
-            Err(e) => {
-              return  Err(ContractError::Std(e))
+// Players a team dropped from its roster become free agents with no
+// signing terms until their owner sets some. Players tracked against
+// another team are ignored.
+fn release_players(deps: DepsMut, block: BlockInfo, team: TeamAddr,
+                   to_release: Vec<PlayerInfo>) -> Result<Response, ContractError> {
+    let mut released: Vec<PlayerInfo> = vec![];
+    for r in to_release.iter() {
+        match players().may_load(deps.storage, r.address.clone())? {
+            Some(p) if p.assigned_team_address.as_ref() == Some(&team) => {
+                let p = PlayerInfo { assigned_team_address: None, ..p };
+                players().save(deps.storage, p.address.clone(), &p)?;
+                released.push(p);
             }
-        }       
+            _ => ()
+        }
     }
-    Ok(true)
+
+    for p in released.iter() {
+        //a released player's contract ends with the release
+        player_contracts().remove(deps.storage, p.address.clone())?;
+        let owner = query_player_contract(&deps, &p.address)?.owner;
+        free_agents().save(deps.storage, p.address.clone(), &FreeAgent {
+            player: p.clone(),
+            owner,
+            signing_fee: None,
+            approved_team: None,
+            listed: block.time
+        })?;
+    }
+    Ok(Response::new()
+        .add_attribute("action", "release_players")
+        .add_attribute("team", team)
+        .add_attribute("released_count", released.len().to_string()))
 }
 
-fn check_teams_managed_status_and_verify_asset_type(mut deps: &DepsMut, teams: Vec<TeamAddr>) -> Result<u32, ContractError>{
-    let mut item: Option<MangedItem> = None;
-    let mut fail_count:u32 = 0;
-    for team in teams.clone() {
-        item = managed_assets().may_load(deps.storage, &team)?;
-        match item {
+
+fn query_free_agents(store: &dyn Storage, position: Option<Positions>, start_after: Option<PlayerAddr>,
+                     limit: Option<u32>) -> StdResult<Vec<FreeAgent>> {
+    let limit = limit.unwrap_or(DEFAULT_FREE_AGENT_LIMIT).min(MAX_FREE_AGENT_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let items: StdResult<Vec<(PlayerAddr, FreeAgent)>> =
+        match position {
+            Some(pos) => {
+                free_agents().idx.position
+                    .prefix(pos.to_u8())
+                    .range(store, start, None, Order::Ascending)
+                    .take(limit)
+                    .collect()
+            }
             None => {
-                fail_count = fail_count + 1;
-                break;
+                free_agents()
+                    .range(store, start, None, Order::Ascending)
+                    .take(limit)
+                    .collect()
             }
-            Some(a_team) => {
-                match a_team.clone().asset_type == AssetTypes::Team
-                    && a_team.clone().managed_status == ManagedStatus::Enabled {
-                    true =>  continue,
-                    false => {
-                        fail_count = fail_count + 1;
-                        break;
-                    }
+        };
+    Ok(items?.into_iter().map(|i| i.1).collect())
+}
 
-                }
 
+// League sets injuries and suspensions for players on its teams.
+// Retirement is only done by the owner through RetirePlayer.
+fn set_player_status(deps: DepsMut, league: Addr, player: PlayerAddr,
+                     status: PlayerStatus) -> Result<Response, ContractError> {
+    match (status.clone(), get_player_status(deps.storage, &player)?) {
+        (PlayerStatus::Retired, _) => return Err(ContractError::InvalidPlayerStatus {}),
+        (_, PlayerStatus::Retired) => return Err(ContractError::PlayerRetired { player }),
+        _ => ()
+    }
+    let team = players().may_load(deps.storage, player.clone())?.and_then(|p| p.assigned_team_address);
+    let team_league =
+        match team {
+            Some(t) => teams().may_load(deps.storage, t)?.and_then(|t| t.league_assigned).map(|l| l.league),
+            None => None
+        };
+    match team_league == Some(league) {
+        true => (),
+        false => return Err(ContractError::PlayerNotInLeague { player })
+    }
+    PLAYER_STATUSES.save(deps.storage, &player, &status)?;
+    Ok(Response::new()
+        .add_message(update_player_status_msg(player.clone(), status))
+        .add_attribute("action", "set_player_status")
+        .add_attribute("player", player))
+}
+
+
+// Owner retires a player for good. The player leaves their roster, free
+// agency and contract, keeps their name reserved and gets a career summary.
+fn retire_player(deps: DepsMut, block: BlockInfo, sender: Addr,
+                 player: PlayerAddr) -> Result<Response, ContractError> {
+    let player_info = query_player_contract(&deps, &player)?;
+    match player_info.owner == sender {
+        true => (),
+        false => return Err(ContractError::NotPlayerOwner { sender })
+    }
+    match (get_player_status(deps.storage, &player)?, DRAFT_POOL_PLAYERS.has(deps.storage, &player)) {
+        (PlayerStatus::Retired, _) => return Err(ContractError::PlayerRetired { player }),
+        (_, true) => return Err(ContractError::DraftPlayerUnavailable { player }),
+        _ => ()
+    }
+
+    let tracked =
+        match players().may_load(deps.storage, player.clone())? {
+            Some(p) => p,
+            None => PlayerInfo {
+                address: player.clone(),
+                first_name: player_info.first_name,
+                last_name: player_info.last_name,
+                position: player_info.position,
+                assigned_team_address: None
             }
-        }
+        };
+    let last_team = tracked.assigned_team_address.clone();
+    players().save(deps.storage, player.clone(), &PlayerInfo { assigned_team_address: None, ..tracked.clone() })?;
+    free_agents().remove(deps.storage, player.clone())?;
+    let contract = player_contracts().may_load(deps.storage, player.clone())?;
+    player_contracts().remove(deps.storage, player.clone())?;
+    PLAYER_STATUSES.save(deps.storage, &player, &PlayerStatus::Retired)?;
+    CAREER_SUMMARIES.save(deps.storage, &player, &CareerSummary {
+        player: player.clone(),
+        first_name: tracked.first_name.clone(),
+        last_name: tracked.last_name.clone(),
+        position: tracked.position.clone(),
+        last_team: last_team.clone(),
+        seasons: contract.as_ref().map(|c| c.seasons.clone()).unwrap_or_default(),
+        episodes_paid: contract.map(|c| c.episodes_paid).unwrap_or_default(),
+        retired: block.time
+    })?;
+
+    let mut res = Response::new()
+        .add_message(update_player_status_msg(player.clone(), PlayerStatus::Retired))
+        .add_attribute("action", "retire_player")
+        .add_attribute("player", player);
+    if let Some(team) = last_team {
+        res = res.add_message(remove_retired_player_msg(team, tracked));
+    }
+    Ok(res)
+}
+
+
+//Players in `lineup` that aren't on the team or can't play the season's current episode
+fn unavailable_lineup_players(store: &dyn Storage, team: &TeamAddr, season_id: SeasonId,
+                              lineup: &[PlayerAddr]) -> Result<Vec<PlayerAddr>, ContractError> {
+    let season = match seasons().may_load(store, season_id)? {
+        None => return Err(ContractError::SeasonNotFound {}),
+        Some(se) => se
+    };
+    Ok(players_unavailable_for_episode(store, team, season.current_episode, lineup)?)
+}
+
 
+fn players_unavailable_for_episode(store: &dyn Storage, team: &TeamAddr, episode: u32,
+                                   lineup: &[PlayerAddr]) -> StdResult<Vec<PlayerAddr>> {
+    let mut unavailable: Vec<PlayerAddr> = vec![];
+    for player in lineup {
+        let on_team =
+            players().may_load(store, player.clone())?.
+                and_then(|p| p.assigned_team_address).
+                map_or(false, |t| &t == team);
+        match on_team && get_player_status(store, player)?.is_available(episode) {
+            true => (),
+            false => unavailable.push(player.clone())
+        }
     }
-    Ok( fail_count)
+    Ok(unavailable)
 }
 
 
-fn verify_teams_are_available(mut deps: &DepsMut, block: BlockInfo, sending_user: Addr, sending_league_contract_addr: Addr,  team_items: Vec<TeamAddr>) -> Result<Response, ContractError> {
-    let mut res:  Result<Response, ContractError> ;
-    for a_team in team_items {
-        let team_res = teams().may_load(deps.storage, a_team.clone())?;
-        match team_res {
-            None => {
-                return  Err(ContractError::ItemNotFound { item_address: a_team.clone() });
+fn query_team_players(store: &dyn Storage, team: TeamAddr) -> StdResult<Vec<PlayerInfo>> {
+    players().idx.team
+        .prefix(team)
+        .range(store, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, p)| p))
+        .collect()
+}
+
+
+fn query_players_by_position(store: &dyn Storage, position: Positions, start_after: Option<PlayerAddr>,
+                             limit: Option<u32>) -> StdResult<Vec<PlayerInfo>> {
+    let limit = limit.unwrap_or(DEFAULT_PLAYER_LIMIT).min(MAX_PLAYER_LIMIT) as usize;
+    players().idx.position
+        .prefix(position.to_u8())
+        .range(store, start_after.map(Bound::exclusive), None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, p)| p))
+        .collect()
+}
+
+
+// League sets up a draft for one of its seasons before it starts.
+// Validation checks:
+//   --season belongs to the league and hasn't started; one draft per season
+//   --at least one accepted team, one round and a non-zero pick clock
+//   --the draft starts now or later, but before the season does
+//   --pool players are new: not tracked by this contract or in another draft
+//   --pool player names aren't used by a tracked player or another pool player
+//   --each pool player's owner opted it in for this season
+fn create_draft(deps: DepsMut, block: BlockInfo, sending_league: Addr, season_id: SeasonId,
+                pool: Vec<PlayerAddr>, rounds: u32, order_type: DraftOrderType, pick_duration: u64,
+                start: Timestamp) -> Result<Response, ContractError> {
+    let season = match seasons().may_load(deps.storage, season_id)? {
+        None => return Err(ContractError::SeasonNotFound {}),
+        Some(se) => se
+    };
+    match season.league == sending_league {
+        true => (),
+        false => return Err(ContractError::Unauthorized { sender: sending_league })
+    }
+    match DRAFTS.has(deps.storage, season_id) {
+        true => return Err(ContractError::DraftAlreadyExists {}),
+        false => ()
+    }
+    let teams: Vec<TeamAddr> =
+        query_get_season_teams_by_division(deps.storage, season_id)?.
+            into_iter().
+            flat_map(|g| g.items).
+            collect();
+    match block.time <= start && start < season.start_date &&
+        teams.len() > 0 && pool.len() > 0 && rounds > 0 && pick_duration > 0 {
+        true => (),
+        false => return Err(ContractError::InvalidDraft {})
+    }
+
+    let mut lottery = None;
+    let order =
+        match order_type {
+            DraftOrderType::ReverseStandings { previous_season_id } => {
+                match seasons().may_load(deps.storage, previous_season_id)? {
+                    Some(se) if se.league == sending_league => (),
+                    _ => return Err(ContractError::InvalidDraft {})
+                }
+                //teams new to the league pick ahead of everyone with a record
+                let mut seeding = get_season_seeding(deps.storage, previous_season_id)?;
+                seeding.reverse();
+                let mut order: Vec<TeamAddr> =
+                    teams.iter().filter(|t| !seeding.contains(t)).cloned().collect();
+                order.extend(seeding.into_iter().filter(|t| teams.contains(t)));
+                order
             }
-            Some(t) => {
-                match t.league_assigned {
-                    None => {
-                        //None found is good, so let's
-                        //continue looping through for loop
-                    }
-                    Some(l) => {
-                        return Err(ContractError::TeamAlreadyMemberOfALeague { team_addr: a_team, league_assigned_to: l.league });
-                    }
+            //kept in season order until the teams' seeds draw the lottery
+            DraftOrderType::Lottery { commit_deadline, reveal_deadline } => {
+                match block.time < commit_deadline && commit_deadline < reveal_deadline && reveal_deadline <= start {
+                    true => (),
+                    false => return Err(ContractError::InvalidDraft {})
                 }
+                lottery = Some(DraftLottery { commit_deadline, reveal_deadline, commitments: vec![], drawn: None });
+                teams
             }
+        };
+
+    let mut pool_players: Vec<PlayerInfo> = vec![];
+    for player in pool {
+        let tracked = players().has(deps.storage, player.clone());
+        match tracked || DRAFT_POOL_PLAYERS.has(deps.storage, &player) ||
+            pool_players.iter().any(|p| p.address == player) {
+            true => return Err(ContractError::DraftPlayerUnavailable { player }),
+            false => ()
+        }
+        match DRAFT_OPT_INS.may_load(deps.storage, &player)? {
+            Some(id) if id == season_id => DRAFT_OPT_INS.remove(deps.storage, &player),
+            _ => return Err(ContractError::DraftPlayerNotOptedIn { player })
         }
+        let player_info = query_player_contract(&deps, &player)?;
+        //a drafted player is tracked by name, so the name has to stay free until then
+        match is_player_name_taken(deps.storage, &player, &player_info.first_name, &player_info.last_name)? {
+            true => return Err(ContractError::PlayerNameAlreadyInUse { first_name: player_info.first_name,
+                last_name: player_info.last_name }),
+            false => ()
+        }
+        DRAFT_POOL_NAMES.save(deps.storage, normalized_player_name(&player_info.first_name, &player_info.last_name), &player)?;
+        DRAFT_POOL_PLAYERS.save(deps.storage, &player, &season_id)?;
+        pool_players.push(PlayerInfo {
+            address: player,
+            first_name: player_info.first_name,
+            last_name: player_info.last_name,
+            position: player_info.position,
+            assigned_team_address: None
+        });
     }
-    return Ok(Response::new())
+
+    let mut draft = Draft::new(season_id, sending_league, pool_players, order, rounds, pick_duration, start);
+    draft.lottery = lottery;
+    DRAFTS.save(deps.storage, season_id, &draft)?;
+    Ok(Response::new()
+        .add_attribute("action", "create_draft")
+        .add_attribute("season_id", season_id.to_string())
+        .add_attribute("pick_count", draft.picks.len().to_string()))
 }
 
 
-// Add teams to leagues
-// Validation checks:
-//   --check if all teams are managed
-//    --make sure sending user owns teams being added
-//    --check to make sure team(s) are not already assigned to a league
-//    --add teams to league
-///
-fn add_teams_to_league(mut deps: DepsMut, block: BlockInfo, sending_user: Addr,
-                       sending_league_contract_addr:
-                       Addr, teams_to_assign: Vec<TeamAddr>) -> Result<Response, ContractError> {
-    match check_teams_managed_status_and_verify_asset_type(deps.borrow(), teams_to_assign.clone()) {
-        Ok(fv) => {
-            match fv > 0 {
-                true =>  Err(ContractError::InvalidTeamSubmissions{}),
-                false => {
-                    match verify_address_owns_assets(deps.borrow(),
-                                                     sending_user.clone(),
-                                                     teams_to_assign.clone()) {
-                        Ok(r) => {
-                            match r {
-                                true => {
-                                   match verify_teams_are_available(deps.borrow(), block.clone(), sending_user,
-                                                                    sending_league_contract_addr.clone(),
-                                                                    teams_to_assign.clone()) {
-                                       Ok(_) => {
-                                           for team_addr in teams_to_assign {
-                                               let mut a_team  =
-                                                   teams().may_load(deps.storage, team_addr.clone()).unwrap();
-                                               match a_team {
-                                                   None => {}
-                                                   Some(mut _team) => {
-                                                       _team.league_assigned =
-                                                           Some(LeagueTeamAssignment{ league: sending_league_contract_addr.clone(),
-                                                               assigned_date:block.time });
-                                                       teams().save(deps.storage, team_addr, &_team)?;
-                                                   }
-                                               }
-                                           }
-                                           Ok(Response::new())
-                                       }
-                                       Err(e) => {
-                                           Err(e)
-                                       }
-                                   }
+fn query_filled_positions(deps: Deps, team: &TeamAddr) -> StdResult<Vec<Positions>> {
+    let roster: RosterResponse = deps.querier.query_wasm_smart(team.clone(), &TeamRosterQueryMsg::GetAllPlayers {})?;
+    Ok(roster.players.into_iter().filter(|s| s.player.is_some()).map(|s| s.position).collect())
+}
 
-                                },
-                                false => {
-                                    Err(ContractError::InvalidTeamSubmissions{})
-                                }
-                            }
 
-                        }
-                        Err(e) => {
-                            Err(e)
-                        }
-                    }
+//Tracks the drafted player the same way AddPlayersToTeam does and
+//returns the roster update for the team
+fn assign_drafted_player(store: &mut dyn Storage, season_id: SeasonId, team: TeamAddr,
+                         player: PlayerInfo) -> StdResult<CosmosMsg> {
+    let drafted = PlayerInfo { assigned_team_address: Some(team.clone()), ..player };
+    DRAFT_POOL_PLAYERS.remove(store, &drafted.address);
+    DRAFT_POOL_NAMES.remove(store, normalized_player_name(&drafted.first_name, &drafted.last_name));
+    players().save(store, drafted.address.clone(), &drafted)?;
+    Ok(add_drafted_players_msg(team, season_id, vec![drafted]))
+}
 
-                },
-            }
 
-        },
-        Err(e) => {
-            Err(ContractError::InvalidTeamSubmissions{})
-       }
+//Auto-picks for every pick whose clock ran out. Each expired pick's
+//clock starts where the previous one ended.
+fn process_expired_picks(deps: &mut DepsMut, block: &BlockInfo, draft: &mut Draft) -> StdResult<Vec<CosmosMsg>> {
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    //positions filled by picks in this batch that rosters don't show yet
+    let mut pending: Vec<(TeamAddr, Positions)> = vec![];
+    while let Some(pick) = draft.on_the_clock().cloned() {
+        if block.time < draft.current_pick_deadline() {
+            break;
+        }
+        let mut filled = query_filled_positions(deps.as_ref(), &pick.team)?;
+        filled.extend(pending.iter().filter(|p| p.0 == pick.team).map(|p| p.1.clone()));
+        let player = draft.best_available(&filled);
+        let deadline = draft.current_pick_deadline();
+        draft.record_pick(player.clone(), true, block.time, deadline);
+        if let Some(p) = player {
+            pending.push((pick.team.clone(), p.position.clone()));
+            msgs.push(assign_drafted_player(deps.storage, draft.season_id, pick.team, p)?);
+        }
     }
+    Ok(msgs)
+}
 
 
+//Players left undrafted become free agents owned by whoever minted them
+fn close_completed_draft(deps: &mut DepsMut, block: &BlockInfo, draft: &mut Draft) -> StdResult<()> {
+    match draft.completed {
+        None => return Ok(()),
+        Some(_) => ()
+    }
+    for p in draft.pool.drain(..).collect::<Vec<PlayerInfo>>() {
+        DRAFT_POOL_PLAYERS.remove(deps.storage, &p.address);
+        DRAFT_POOL_NAMES.remove(deps.storage, normalized_player_name(&p.first_name, &p.last_name));
+        let owner = query_player_contract(deps, &p.address)?.owner;
+        players().save(deps.storage, p.address.clone(), &p)?;
+        free_agents().save(deps.storage, p.address.clone(), &FreeAgent {
+            player: p,
+            owner,
+            signing_fee: None,
+            approved_team: None,
+            listed: block.time
+        })?;
+    }
+    Ok(())
 }
-fn get_team_season_conflicts(store: &mut dyn Storage , asset_type: AssetTypes,
-                             asset_addr: Addr, start_date: Timestamp,
-                             end_date: Timestamp) -> Option<Vec<Season>>{
-    let sender_res: Result<Vec<_>, _> =
-        join_season_requests().idx
-            .sender
-            .prefix((asset_type.to_u8(), asset_addr.clone()))
-            .range(store, None, None, Order::Ascending)
-            .collect();
-    let recipient_res: Result<Vec<_>, _> =
-        join_season_requests().idx
-            .recipient
-            .prefix((asset_type.to_u8(), asset_addr.clone()))
-            .range(store, None, None, Order::Ascending)
-            .collect();
-
-    let res_items = [sender_res.unwrap(),recipient_res.unwrap() ].concat();
-    let seasons: Option<Vec<Season>> =
-            res_items.
-            into_iter().
-            filter(|i|  {
-                (i.1.delivery.to.address == asset_addr || i.1.delivery.from.address == asset_addr)
-                    && i.1.data.status_type == MessageTypes::Accepted {}
-            }).
-            map(|i|   seasons().may_load(store, i.1.data.season_id).unwrap()  ).
-            filter(|season| {
-                match season {
-                    None => false,
-                    Some(s) => {
-                        (s.start_date.seconds() < start_date.seconds() && start_date.seconds() < s.end_date.seconds()) ||
-                            (s.start_date.seconds() < end_date.seconds() &&  end_date.seconds() < s.end_date.seconds())
-                    }
-                }
-            }).
-            collect();
 
-   match seasons {
-       None => {
-           None
-       }
-       Some(items) => {
-           match items.len() > 0 {
-               true => Some(items),
-               false => None
-           }
-       }
-   }
 
+fn make_draft_pick(mut deps: DepsMut, block: BlockInfo, team: TeamAddr, season_id: SeasonId,
+                   player: PlayerAddr) -> Result<Response, ContractError> {
+    let mut draft = match DRAFTS.may_load(deps.storage, season_id)? {
+        None => return Err(ContractError::DraftNotFound {}),
+        Some(d) => d
+    };
+    match block.time >= draft.start {
+        true => (),
+        false => return Err(ContractError::DraftNotStarted { start: draft.start })
+    }
+    if draft.lottery_pending() {
+        draft.draw_lottery(block.time);
+    }
+    let mut msgs = process_expired_picks(&mut deps, &block, &mut draft)?;
+    let pick = match draft.on_the_clock() {
+        None => return Err(ContractError::DraftComplete {}),
+        Some(p) => p.clone()
+    };
+    match pick.team == team {
+        true => (),
+        false => return Err(ContractError::NotDraftPickOwner { sender: team })
+    }
+    let player_info = match draft.pool.iter().find(|p| p.address == player) {
+        None => return Err(ContractError::DraftPlayerUnavailable { player }),
+        Some(p) => p.clone()
+    };
+    draft.record_pick(Some(player_info.clone()), false, block.time, block.time);
+    msgs.push(assign_drafted_player(deps.storage, season_id, team, player_info)?);
+    close_completed_draft(&mut deps, &block, &mut draft)?;
+    DRAFTS.save(deps.storage, season_id, &draft)?;
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("action", "make_draft_pick")
+        .add_attribute("season_id", season_id.to_string())
+        .add_attribute("pick_number", pick.pick_number.to_string())
+        .add_attribute("player", player))
 }
 
 
-fn get_existing_join_request(store: &mut dyn Storage , season_id: SeasonId, team_addr: Addr, league_addr: Addr  ) -> Option<Message<JoinSeasonRequestInfo>>{
-    let res_1: Result<Vec<_>, _> =
-        join_season_requests().idx
-            .season_from_to
-            .prefix((season_id, team_addr.clone(), league_addr.clone()))
-            .range(store, None, None, Order::Ascending)
-            .collect();
+fn advance_draft(mut deps: DepsMut, block: BlockInfo, season_id: SeasonId) -> Result<Response, ContractError> {
+    let mut draft = match DRAFTS.may_load(deps.storage, season_id)? {
+        None => return Err(ContractError::DraftNotFound {}),
+        Some(d) => d
+    };
+    if draft.lottery_due(block.time) {
+        draft.draw_lottery(block.time);
+    }
+    let msgs = process_expired_picks(&mut deps, &block, &mut draft)?;
+    close_completed_draft(&mut deps, &block, &mut draft)?;
+    DRAFTS.save(deps.storage, season_id, &draft)?;
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("action", "advance_draft")
+        .add_attribute("season_id", season_id.to_string())
+        .add_attribute("current_pick", draft.current_pick.to_string()))
+}
 
-    return match res_1 {
-        Ok(r) => {
-            match r.len() > 0 {
-                true => Some(r[0].1.clone()),
-                false => {
-                    None
-                }
-            }
-        }
-        Err(_) => {
-            None
-        }
+
+fn commit_draft_seed(deps: DepsMut, block: BlockInfo, team: TeamAddr, season_id: SeasonId,
+                     commitment: String) -> Result<Response, ContractError> {
+    let mut draft = match DRAFTS.may_load(deps.storage, season_id)? {
+        None => return Err(ContractError::DraftNotFound {}),
+        Some(d) => d
+    };
+    match draft.teams().contains(&team) {
+        true => (),
+        false => return Err(ContractError::NotDraftTeam { sender: team })
+    }
+    let lottery = match draft.lottery.as_mut() {
+        Some(l) if l.drawn.is_none() => l,
+        _ => return Err(ContractError::SeedCommitPeriodEnded {})
+    };
+    match (block.time < lottery.commit_deadline, lottery.commitment(&team).is_some()) {
+        (false, _) => return Err(ContractError::SeedCommitPeriodEnded {}),
+        (_, true) => return Err(ContractError::SeedAlreadyCommitted {}),
+        _ => ()
     }
+    lottery.commitments.push(SeedCommitment { party: team.clone(), commitment, secret: None });
+    DRAFTS.save(deps.storage, season_id, &draft)?;
+    Ok(Response::new()
+        .add_attribute("action", "commit_draft_seed")
+        .add_attribute("season_id", season_id.to_string())
+        .add_attribute("team", team))
+}
 
 
+// The last reveal draws the lottery right away
+fn reveal_draft_seed(deps: DepsMut, block: BlockInfo, team: TeamAddr, season_id: SeasonId,
+                     secret: Binary) -> Result<Response, ContractError> {
+    let mut draft = match DRAFTS.may_load(deps.storage, season_id)? {
+        None => return Err(ContractError::DraftNotFound {}),
+        Some(d) => d
+    };
+    match draft.teams().contains(&team) {
+        true => (),
+        false => return Err(ContractError::NotDraftTeam { sender: team })
+    }
+    let lottery = match draft.lottery.as_mut() {
+        Some(l) if l.drawn.is_none() && l.reveals_open(block.time) => l,
+        _ => return Err(ContractError::SeedRevealNotOpen {})
+    };
+    let expected = seed_commitment(&team, &secret);
+    match lottery.commitments.iter_mut().find(|c| c.party == team) {
+        Some(c) if c.secret.is_none() && c.commitment == expected => c.secret = Some(secret),
+        _ => return Err(ContractError::InvalidSeedReveal {})
+    }
+    if draft.lottery_due(block.time) {
+        draft.draw_lottery(block.time);
+    }
+    DRAFTS.save(deps.storage, season_id, &draft)?;
+    Ok(Response::new()
+        .add_attribute("action", "reveal_draft_seed")
+        .add_attribute("season_id", season_id.to_string())
+        .add_attribute("team", team))
 }
 
 
-fn get_winner_take_all_deposits_by_season(store: &dyn Storage , season_id: SeasonId) -> Vec<(u64, SeasonLedger)> {
-    let res: Result<Vec<_>, _> =
-        season_deposits_ledger().idx
-            .season
-            .prefix(season_id)
-            .range(store, None, None, Order::Ascending)
-            .collect();
-    res.unwrap()
+// Player owner makes an untracked player available to one season's draft.
+// The league can only put opted-in players in its pool.
+fn set_draft_opt_in(deps: DepsMut, sender: Addr, player: PlayerAddr,
+                    season_id: Option<SeasonId>) -> Result<Response, ContractError> {
+    let player_info = query_player_contract(&deps, &player)?;
+    match player_info.owner == sender {
+        true => (),
+        false => return Err(ContractError::NotPlayerOwner { sender })
+    }
+    match players().has(deps.storage, player.clone()) || DRAFT_POOL_PLAYERS.has(deps.storage, &player) {
+        true => return Err(ContractError::DraftPlayerUnavailable { player }),
+        false => ()
+    }
+    match season_id {
+        Some(id) => DRAFT_OPT_INS.save(deps.storage, &player, &id)?,
+        None => DRAFT_OPT_INS.remove(deps.storage, &player)
+    }
+    Ok(Response::new()
+        .add_attribute("action", "set_draft_opt_in")
+        .add_attribute("player", player)
+        .add_attribute("season_id", season_id.map(|id| id.to_string()).unwrap_or_default()))
 }
 
 
-//checks if team has made a deposit for season
-//if so, then distribute refund accordingly
-fn distribute_individual_refund(mut deps: DepsMut, block: BlockInfo,
-                                season_id: SeasonId, team_addr: TeamAddr) -> Result<Response, ContractError> {
-    let a_season = seasons().may_load(deps.storage.borrow(), season_id).unwrap();
+fn get_team_salary_cap(store: &dyn Storage, team: &TeamAddr) -> StdResult<Option<Coin>> {
+    match teams().may_load(store, team.clone())?.and_then(|t| t.league_assigned) {
+        None => Ok(None),
+        Some(assignment) => SALARY_CAPS.may_load(store, &assignment.league)
+    }
+}
 
-    return match a_season {
-        None => {
-            Err(ContractError::SeasonNotFound {})
-        }
-        Some(s) => {
-            match s.access_type.clone() {
-                None => {
-                    Ok(Response::new())
-                }
-                Some(at) => {
-                    match at.clone() {
-                        SeasonAccessTypes::WinnerTakeAll { .. } => {
-                            let sub_msg =
-                                get_team_unpaid_refund_deposit_sub_message(deps.storage, s.clone(), team_addr.clone());
 
-                            match sub_msg {
-                                Some(sb) => {
-                                    let refund_deposit = get_team_unpaid_deposit_by_season(deps.storage, s.clone(), team_addr.clone());
+fn get_team_player_contracts(store: &dyn Storage, team: &TeamAddr) -> StdResult<Vec<PlayerContract>> {
+    player_contracts().idx.team
+        .prefix(team.clone())
+        .range(store, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, contract)| contract))
+        .collect()
+}
 
-                                    match refund_deposit {
-                                        None => Err(ContractError::SeasonDepositAlreadyClaimed {}),
-                                        Some(rd) => {
-                                            mark_deposit_withdrawls_paid(deps.branch(), block, vec![rd]);
-                                            Ok(Response::new().add_submessage(sb))
-                                        }
-                                    }
-                                },
-                                None => Err(ContractError::SeasonDepositAlreadyClaimed {}),
-                            }
-                        },
-                        _ => {
-                            Ok(Response::new())
-                        }
-                    }
-                }
+
+//Per-episode payroll in the cap's denom must not exceed the cap of the team's league
+fn check_salary_cap(store: &dyn Storage, team: &TeamAddr) -> Result<(), ContractError> {
+    match get_team_salary_cap(store, team)? {
+        None => Ok(()),
+        Some(cap) => {
+            let payroll: Uint128 =
+                get_team_player_contracts(store, team)?.
+                    iter().
+                    filter(|c| c.terms.salary_per_episode.denom == cap.denom).
+                    map(|c| c.terms.salary_per_episode.amount).
+                    sum();
+            match payroll <= cap.amount {
+                true => Ok(()),
+                false => Err(ContractError::SalaryCapExceeded { team: team.clone(), cap, payroll })
             }
         }
     }
 }
 
-fn distribute_refunds(mut deps: DepsMut, block: BlockInfo, season_id: SeasonId) -> Result<Response, ContractError> {
-    let a_season = seasons().may_load(deps.storage.borrow(), season_id).unwrap();
-
-    return match a_season {
-        None => {
-            Err(ContractError::SeasonNotFound {})
-        }
-        Some(s) => {
-            match s.access_type.clone() {
-                None => {
-                    Ok(Response::new())
-                }
-                Some(at) => {
-                    match at.clone() {
-                        SeasonAccessTypes::WinnerTakeAll { .. } => {
-                            let sub_msgs =
-                                get_unpaid_refund_deposits_submessages(deps.storage, s.clone());
 
-                            match sub_msgs.len() > 0 {
-                                true => {
-                                    let refund_deposits = get_unpaid_deposits_by_season(deps.storage, s.clone());
-                                    mark_deposit_withdrawls_paid(deps.branch(), block, refund_deposits);
-                                    Ok(Response::new().add_submessages(sub_msgs))
-                                },
-                                false => Ok(Response::new())
-                            }
-                        },
-                        _ => {
-                            Ok(Response::new())
-                        }
-                    }
-                }
-            }
+// AddPlayersToTeam plus a contract for each player.
+// Salaries have to be in the league cap's denom when the league has a cap.
+fn add_players_with_contracts(mut deps: DepsMut, env: Env, sender: Addr,
+                              signings: Vec<PlayerSigning>) -> Result<Response, ContractError> {
+    let cap = get_team_salary_cap(deps.storage, &sender)?;
+    for signing in signings.iter() {
+        let denom_allowed = match &cap {
+            Some(c) => signing.terms.salary_per_episode.denom == c.denom,
+            None => true
+        };
+        match signing.terms.is_valid() && denom_allowed {
+            true => (),
+            false => return Err(ContractError::InvalidPlayerContract {})
         }
     }
+    let block_time = env.block.time;
+    let res = process_adding_players(deps.branch(), env,
+                                     sender.clone(), signings.iter().map(|s| s.player.clone()).collect())?;
+
+    for signing in signings {
+        let owner = query_player_contract(&deps, &signing.player.address)?.owner;
+        player_contracts().save(deps.storage, signing.player.address.clone(), &PlayerContract {
+            player: signing.player.address,
+            team: sender.clone(),
+            owner,
+            terms: signing.terms,
+            signed: block_time,
+            seasons: vec![],
+            episodes_paid: 0
+        })?;
+    }
+    check_salary_cap(deps.storage, &sender)?;
+    Ok(res.add_attribute("action", "add_players_with_contracts"))
 }
 
-fn mark_deposit_withdrawls_paid(mut deps: DepsMut, block: BlockInfo, items: Vec<(u64, SeasonLedger)>) {
-    for mut item in items {
-        item.1.withdrawal_distribution_date = Some(block.time);
-        season_deposits_ledger().save(deps.storage, item.0, &item.1).expect("Problem marking deposits as paid.");
+
+fn set_salary_cap(deps: DepsMut, league: Addr, cap: Option<Coin>) -> Result<Response, ContractError> {
+    match cap {
+        Some(c) => SALARY_CAPS.save(deps.storage, &league, &c)?,
+        None => SALARY_CAPS.remove(deps.storage, &league)
     }
+    Ok(Response::new()
+        .add_attribute("action", "set_salary_cap")
+        .add_attribute("league", league))
 }
 
 
-fn get_team_unpaid_refund_deposit_sub_message(store: &mut dyn Storage ,
-                                           s: Season, team_addr: TeamAddr) ->  Option<SubMsg>{
-    let unpaid_deposit =
-        get_team_unpaid_deposits_by_season(store, s.clone(), team_addr.clone());
-            
+// Moves a running season to its next episode and sends each team in it
+// the salaries due. Contracts whose term is used up end here.
+fn advance_episode(deps: DepsMut, block: BlockInfo, league: Addr,
+                   season_id: SeasonId) -> Result<Response, ContractError> {
+    let mut season = match seasons().may_load(deps.storage, season_id)? {
+        None => return Err(ContractError::SeasonNotFound {}),
+        Some(se) => se
+    };
+    match season.league == league {
+        true => (),
+        false => return Err(ContractError::Unauthorized { sender: league })
+    }
+    match (season.status.clone(), block.time >= season.start_date && block.time < season.end_date) {
+        (Some(SeasonStatus::Cancelled { date_cancelled }), _) => {
+            return Err(ContractError::SeasonStatusCancelled { date_cancelled })
+        }
+        (_, false) => return Err(ContractError::SeasonNotInProgress {}),
+        _ => ()
+    }
+    //one episode per interval of the schedule, never more than the season has
+    match season.current_episode < season.episodes {
+        true => (),
+        false => return Err(ContractError::SeasonEpisodesComplete { episodes: season.episodes })
+    }
+    let next_start = season.episode_start(season.current_episode + 1);
+    match block.time >= next_start {
+        true => (),
+        false => return Err(ContractError::EpisodeNotDue { start: next_start })
+    }
+    season.current_episode += 1;
+    seasons().save(deps.storage, season_id, &season)?;
 
-    match unpaid_deposit {
-        None => {
-            None
+    let season_teams: Vec<TeamAddr> =
+        query_get_season_teams_by_division(deps.storage, season_id)?.
+            into_iter().
+            flat_map(|g| g.items).
+            collect();
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    for team in season_teams {
+        let mut payments: Vec<SalaryPayment> = vec![];
+        for mut contract in get_team_player_contracts(deps.storage, &team)? {
+            match contract.covers_season(season_id) {
+                true => {
+                    //the player may have been sold since signing; pay whoever owns it now
+                    if let Ok(player_info) = query_player_contract(&deps, &contract.player) {
+                        contract.owner = player_info.owner;
+                    }
+                    contract.episodes_paid += 1;
+                    payments.push(SalaryPayment {
+                        player: contract.player.clone(),
+                        owner: contract.owner.clone(),
+                        amount: contract.terms.salary_per_episode.clone()
+                    });
+                    player_contracts().save(deps.storage, contract.player.clone(), &contract)?;
+                }
+                false => player_contracts().remove(deps.storage, contract.player.clone())?
+            }
         }
-        Some(i) => {
-            let res: CosmosMsg =
-                cosmwasm_std::BankMsg::Send
-                {
-                    to_address: i.1.team.to_string(),
-                    amount: vec![i.1.team_deposit_amount]
-                }.into();
-            let res_sub_msg =
-                SubMsg {
-                    id: 0,
-                    msg: res.clone(),
-                    gas_limit: None,
-                    reply_on: ReplyOn::Never
-                };
+        if payments.len() > 0 {
+            msgs.push(pay_salaries_msg(team, season_id, season.current_episode, payments));
+        }
+    }
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("action", "advance_episode")
+        .add_attribute("season_id", season_id.to_string())
+        .add_attribute("episode", season.current_episode.to_string()))
+}
 
-            Some(res_sub_msg)
+
+///
+//  League schedules a fixture between two teams in its season.
+//   --season can't be cancelled
+//   --both teams must have been accepted into the season
+//   --the commit deadline must be in the future and before the reveal deadline
+///
+fn schedule_fixture(deps: DepsMut, block: BlockInfo, league: Addr, season_id: SeasonId, episode: u32,
+                    home: TeamAddr, away: TeamAddr, league_commits: bool, commit_deadline: Timestamp,
+                    reveal_deadline: Timestamp) -> Result<Response, ContractError> {
+    let season = match seasons().may_load(deps.storage, season_id)? {
+        None => return Err(ContractError::SeasonNotFound {}),
+        Some(se) => se
+    };
+    match season.league == league {
+        true => (),
+        false => return Err(ContractError::Unauthorized { sender: league })
+    }
+    match season.status.clone() {
+        Some(SeasonStatus::Cancelled { date_cancelled }) => {
+            return Err(ContractError::SeasonStatusCancelled { date_cancelled })
+        }
+        _ => ()
+    }
+    match home != away && block.time < commit_deadline && commit_deadline < reveal_deadline {
+        true => (),
+        false => return Err(ContractError::InvalidFixture {})
+    }
+    for team in [&home, &away] {
+        match SEASON_STANDINGS.has(deps.storage, (season_id, team.clone())) {
+            true => (),
+            false => return Err(ContractError::TeamNotMemberOfSeason {})
         }
     }
 
+    let id = next_index_counter(deps.storage)?;
+    fixtures().save(deps.storage, id, &Fixture {
+        id,
+        season_id,
+        episode,
+        league,
+        home,
+        away,
+        league_commits,
+        commit_deadline,
+        reveal_deadline,
+        commitments: vec![],
+        lineups: vec![],
+        result: None,
+        completed: None
+    })?;
+    Ok(Response::new()
+        .add_attribute("action", "schedule_fixture")
+        .add_attribute("fixture_id", id.to_string()))
 }
 
 
-fn get_unpaid_refund_deposits_submessages(store: &dyn Storage , s: Season) ->  Vec<SubMsg>{
-    let unpaid_deposits = get_unpaid_deposits_by_season(store, s.clone());
-    let mut count: u64 = 0;
-    let res: Vec<SubMsg> =
-        unpaid_deposits.into_iter()
-            .map(|i| {
-                let res: CosmosMsg =
-                    cosmwasm_std::BankMsg::Send
-                    {
-                        to_address: i.1.team.to_string(),
-                        amount: vec![i.1.team_deposit_amount]
-                    }.into();
-                let res_sub_msg =
-                    SubMsg {
-                        id: count,
-                        msg: res,
-                        gas_limit: None,
-                        reply_on: ReplyOn::Never
-                    };
-                count += 1;
-                res_sub_msg
-            })
-            .collect();
-    res
+fn load_open_fixture(store: &dyn Storage, fixture_id: u64) -> Result<Fixture, ContractError> {
+    match fixtures().may_load(store, fixture_id)? {
+        None => Err(ContractError::FixtureNotFound {}),
+        Some(f) if f.completed.is_some() => Err(ContractError::FixtureCompleted {}),
+        Some(f) => Ok(f)
+    }
 }
 
 
-fn get_team_unpaid_deposit_by_season(store: &dyn Storage , s: Season, team_addr: TeamAddr) -> Option<(u64, SeasonLedger)> {
-    let unpaid_deposit: Vec<(u64, SeasonLedger)> =
-        get_winner_take_all_deposits_by_season(store, s.id).
-            into_iter().
-            filter(|item| item.1.withdrawal_distribution_date.is_none() && item.1.team == team_addr).
-            collect();
-    match unpaid_deposit.len() > 0 {
-        true => {
-            Some(unpaid_deposit[0].clone())
-        },
-        false => {
-            None
-
-        }
+fn commit_fixture_seed(deps: DepsMut, block: BlockInfo, sender: Addr, fixture_id: u64,
+                       commitment: String) -> Result<Response, ContractError> {
+    let mut fixture = load_open_fixture(deps.storage, fixture_id)?;
+    match fixture.parties().contains(&sender) {
+        true => (),
+        false => return Err(ContractError::NotFixtureParty { sender })
     }
+    match (block.time < fixture.commit_deadline, fixture.commitment(&sender).is_some()) {
+        (false, _) => return Err(ContractError::SeedCommitPeriodEnded {}),
+        (_, true) => return Err(ContractError::SeedAlreadyCommitted {}),
+        _ => ()
+    }
+    fixture.commitments.push(SeedCommitment { party: sender.clone(), commitment, secret: None });
+    fixtures().save(deps.storage, fixture_id, &fixture)?;
+    Ok(Response::new()
+        .add_attribute("action", "commit_fixture_seed")
+        .add_attribute("fixture_id", fixture_id.to_string())
+        .add_attribute("party", sender))
 }
 
-fn get_team_unpaid_deposits_by_season(store: &dyn Storage , s: Season, team_addr: TeamAddr) -> Option<(u64, SeasonLedger)> {
-    let unpaid_deposit: Vec<(u64, SeasonLedger)> =
-        get_winner_take_all_deposits_by_season(store, s.id).
-            into_iter().
-            filter(|item| item.1.withdrawal_distribution_date.is_none() && item.1.team == team_addr).
-            collect();
-    match unpaid_deposit.len() > 0 {
-        true => {
-            Some(unpaid_deposit[0].clone())
-        },
+
+// The last reveal plays the fixture right away
+fn reveal_fixture_seed(deps: DepsMut, block: BlockInfo, sender: Addr, fixture_id: u64,
+                       secret: Binary) -> Result<Response, ContractError> {
+    let mut fixture = load_open_fixture(deps.storage, fixture_id)?;
+    match fixture.parties().contains(&sender) {
+        true => (),
+        false => return Err(ContractError::NotFixtureParty { sender })
+    }
+    match fixture.reveals_open(block.time) {
+        true => (),
+        false => return Err(ContractError::SeedRevealNotOpen {})
+    }
+    let expected = seed_commitment(&sender, &secret);
+    match fixture.commitments.iter_mut().find(|c| c.party == sender) {
+        Some(c) if c.secret.is_none() && c.commitment == expected => c.secret = Some(secret),
+        _ => return Err(ContractError::InvalidSeedReveal {})
+    }
+    if fixture.lineups.is_empty() {
+        fixture.lineups = vec![
+            fixture_lineup_slots(&deps, &fixture.home, fixture.season_id, fixture.episode)?,
+            fixture_lineup_slots(&deps, &fixture.away, fixture.season_id, fixture.episode)?
+        ];
+    }
+    let res = Response::new()
+        .add_attribute("action", "reveal_fixture_seed")
+        .add_attribute("fixture_id", fixture_id.to_string())
+        .add_attribute("party", sender);
+    match fixture.all_revealed() {
+        true => complete_fixture(deps, block, fixture, res),
         false => {
-            None
+            fixtures().save(deps.storage, fixture_id, &fixture)?;
+            Ok(res)
         }
     }
 }
 
-fn get_unpaid_deposits_by_season(store: &dyn Storage , s: Season) -> Vec<(u64, SeasonLedger)> {
-    let unpaid_deposits: Vec<(u64, SeasonLedger)> =
-        get_winner_take_all_deposits_by_season(store, s.id).
-            into_iter().
-            filter(|item| item.1.withdrawal_distribution_date.is_none()).
-            collect();
-    unpaid_deposits
+
+fn resolve_fixture(deps: DepsMut, block: BlockInfo, fixture_id: u64) -> Result<Response, ContractError> {
+    let fixture = load_open_fixture(deps.storage, fixture_id)?;
+    match fixture.all_revealed() || block.time >= fixture.reveal_deadline {
+        true => complete_fixture(deps, block, fixture, Response::new()
+            .add_attribute("action", "resolve_fixture")
+            .add_attribute("fixture_id", fixture_id.to_string())),
+        false => Err(ContractError::FixtureNotReady {})
+    }
 }
 
-fn update_message_status(mut deps: DepsMut, sending_league: Addr, block: BlockInfo,
-     season_id: SeasonId, update_status_type: MessageTypes) -> Result<Response, ContractError> {
-    let a_season = seasons().may_load(deps.storage.borrow(), season_id).unwrap();
 
-    //let invite_res = join_season_requests().may_load(deps.storage, season_id)?;
-    match a_season {
-        None => {
-            Err(ContractError::SeasonNotFound{  })
-        }
-        Some(mut se) => {
-            return match update_status_type {
-                MessageTypes::CancelSeason {} => {
-                    //make sure league sending update own season being updated
-                    match se.league == sending_league {
-                        true => {
-                            match (se.start_date < block.time, (get_season_teams_accepted_count(deps.storage, se.id.clone())) > 1) {
-                                (true, true) => {
-                                    Err(ContractError::TooLateToCancelSeason {})
-                                },
-                                (true, false)/* not enough teams accepted to start season, so allow cancel */ |
-                                (false, _) /* season hasn't started, so allow cancel */ => {
-                                    se.status = Some(SeasonStatus::Cancelled { date_cancelled: block.clone() });
-                                    seasons().save(deps.storage, se.id, &se)?;
-                                    let res = distribute_refunds(deps, block.clone(), se.id);
-                                    match res {
-                                        Ok(r) => {
-                                            //response from distribute_refunds contains sub_messages
-                                            //needed for payment transfers
-                                            Ok(r)
-                                        }
-                                        Err(e) => {
-                                            Err(e)
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                        false => {
-                            Err(ContractError::Unauthorized { sender: sending_league })
-                        }
-                    }
-                }
-                _ => {
-                    Err(ContractError::Unauthorized { sender: sending_league })
+// Plays the fixture from the revealed seed, or forfeits the teams that didn't
+// reveal, and records the result in the season standings. If both teams
+// revealed but the league withheld its secret the fixture is rescheduled instead.
+fn complete_fixture(deps: DepsMut, block: BlockInfo, mut fixture: Fixture,
+                    res: Response) -> Result<Response, ContractError> {
+    let forfeited = fixture.unrevealed_teams();
+    if forfeited.is_empty() && !fixture.withheld_reveals().is_empty() {
+        return reschedule_withheld_fixture(deps, block, fixture, res)
+    }
+    let league_type = league_type_of(deps.storage, &fixture.league)?;
+    let res =
+        match forfeited.is_empty() {
+            true => {
+                let home_lineup = fixture_lineup(&deps, &fixture, &fixture.home)?;
+                let away_lineup = fixture_lineup(&deps, &fixture, &fixture.away)?;
+                let seed = fixture.combined_seed();
+                let game = simulate_game(&home_lineup, &away_lineup, seed);
+                let home_result = game.home_score.cmp(&game.away_score);
+                record_fixture_standing(deps.storage, fixture.season_id, &fixture.home, home_result)?;
+                record_fixture_standing(deps.storage, fixture.season_id, &fixture.away, home_result.reverse())?;
+                record_team_result(deps.storage, &league_type, &fixture.home, home_result)?;
+                record_team_result(deps.storage, &league_type, &fixture.away, home_result.reverse())?;
+                record_fixture_stats(deps.storage, &fixture, &league_type, &game, &home_lineup, &away_lineup)?;
+                let slots = |lineup: Lineup| -> Vec<LineupSlot> {
+                    lineup.players.into_iter().map(|p| LineupSlot { player: p.player, position: p.position }).collect()
+                };
+                fixture.result = Some(FixtureResult::Played {
+                    seed,
+                    home_score: game.home_score,
+                    away_score: game.away_score,
+                    home_strategy: home_lineup.strategy.clone(),
+                    away_strategy: away_lineup.strategy.clone(),
+                    home_lineup: slots(home_lineup),
+                    away_lineup: slots(away_lineup)
+                });
+                res.add_attribute("seed", seed.to_string())
+                    .add_attribute("score", format!("{}-{}", game.home_score, game.away_score))
+            }
+            false => {
+                for team in [fixture.home.clone(), fixture.away.clone()] {
+                    let result =
+                        match forfeited.contains(&team) {
+                            true => std::cmp::Ordering::Less,
+                            false => std::cmp::Ordering::Greater
+                        };
+                    record_fixture_standing(deps.storage, fixture.season_id, &team, result)?;
+                    record_team_result(deps.storage, &league_type, &team, result)?;
                 }
+                fixture.result = Some(FixtureResult::Forfeit { forfeited: forfeited.clone() });
+                res.add_attributes(forfeited.into_iter().map(|t| ("forfeited", t)))
             }
+        };
+    fixture.completed = Some(block.time);
+    fixtures().save(deps.storage, fixture.id, &fixture)?;
+    Ok(res)
+}
+
+
+// Both team secrets are public, so the fixture restarts with fresh commitments
+// from the teams only; the league loses its part in the seed. The commit and
+// reveal periods each get the length of the original reveal period.
+fn reschedule_withheld_fixture(deps: DepsMut, block: BlockInfo, mut fixture: Fixture,
+                               res: Response) -> Result<Response, ContractError> {
+    let withheld = fixture.withheld_reveals();
+    let period = fixture.reveal_deadline.seconds() - fixture.commit_deadline.seconds();
+    fixture.league_commits = false;
+    fixture.commitments = vec![];
+    fixture.commit_deadline = block.time.plus_seconds(period);
+    fixture.reveal_deadline = fixture.commit_deadline.plus_seconds(period);
+    fixtures().save(deps.storage, fixture.id, &fixture)?;
+    Ok(res
+        .add_attribute("rescheduled_commit_deadline", fixture.commit_deadline.to_string())
+        .add_attributes(withheld.into_iter().map(|p| ("withheld_reveal", p))))
+}
+
+
+// The team's game-day lineup for the episode, less anyone who has since left
+// the team or can't play. Teams that never submitted one field every
+// available rostered player in their roster position.
+fn fixture_lineup_slots(deps: &DepsMut, team: &TeamAddr, season_id: SeasonId, episode: u32) -> StdResult<FixtureLineup> {
+    let (slots, strategy) =
+        match effective_lineup(deps.storage, team, season_id, episode)? {
+            Some(l) => (l.slots(), l.play_calling_strategy),
+            None => {
+                let roster =
+                    query_team_players(deps.storage, team.clone())?.
+                        into_iter().
+                        map(|p| LineupSlot { player: p.address, position: p.position }).
+                        collect();
+                (roster, PlayCallingStrategy::default())
+            }
+        };
+    let addresses: Vec<PlayerAddr> = slots.iter().map(|s| s.player.clone()).collect();
+    let unavailable = players_unavailable_for_episode(deps.storage, team, episode, &addresses)?;
+    Ok(FixtureLineup {
+        team: team.clone(),
+        slots: slots.into_iter().filter(|s| !unavailable.contains(&s.player)).collect(),
+        strategy
+    })
+}
+
+
+// The lineup the fixture was revealed with, with each player's attributes
+fn fixture_lineup(deps: &DepsMut, fixture: &Fixture, team: &TeamAddr) -> StdResult<Lineup> {
+    let snapshot =
+        match fixture.lineup(team) {
+            Some(l) => l.clone(),
+            None => fixture_lineup_slots(deps, team, fixture.season_id, fixture.episode)?
+        };
+    let mut lineup_players: Vec<LineupPlayer> = vec![];
+    for slot in snapshot.slots {
+        lineup_players.push(LineupPlayer {
+            attributes: query_player_contract(deps, &slot.player)?.attributes,
+            player: slot.player,
+            position: slot.position
+        });
+    }
+    Ok(Lineup { team: team.clone(), players: lineup_players, strategy: snapshot.strategy })
+}
+
 
+//The lineup submitted for the episode, else the team's latest earlier one
+fn effective_lineup(store: &dyn Storage, team: &TeamAddr, season_id: SeasonId,
+                    episode: u32) -> StdResult<Option<GameDayLineup>> {
+    LINEUPS
+        .sub_prefix(team)
+        .range(store, None, Some(Bound::inclusive((season_id, episode))), Order::Descending)
+        .next()
+        .map(|item| item.map(|(_, l)| l))
+        .transpose()
+}
+
+
+fn lineup_lock_period(store: &dyn Storage, league: &LeagueAddr) -> StdResult<u64> {
+    Ok(LINEUP_LOCKS.may_load(store, league)?.unwrap_or(DEFAULT_LINEUP_LOCK))
+}
+
+
+// A team sets who plays where, and how to call plays, for one episode of a
+// season. The lineup can be changed until the lock period before the team's
+// first fixture of the episode.
+fn submit_lineup(deps: DepsMut, block: BlockInfo, team: TeamAddr, season_id: SeasonId, episode: u32,
+                 offense: Vec<LineupSlot>, defense: Vec<LineupSlot>,
+                 play_calling_strategy: PlayCallingStrategy) -> Result<Response, ContractError> {
+    let season = match seasons().may_load(deps.storage, season_id)? {
+        None => return Err(ContractError::SeasonNotFound {}),
+        Some(se) => se
+    };
+    match SEASON_STANDINGS.has(deps.storage, (season_id, team.clone())) {
+        true => (),
+        false => return Err(ContractError::TeamNotMemberOfSeason {})
+    }
+    let lock_period = lineup_lock_period(deps.storage, &season.league)?;
+    let locked =
+        episode < season.current_episode ||
+            query_season_fixtures(deps.storage, season_id)?.
+                iter().
+                filter(|f| f.episode == episode && (f.home == team || f.away == team)).
+                any(|f| block.time >= f.lineup_lock(lock_period));
+    match locked {
+        true => return Err(ContractError::LineupLocked {}),
+        false => ()
+    }
+
+    let sides_match =
+        offense.iter().all(|s| s.position.side_of_ball() == SideOfBall::Offense) &&
+            defense.iter().all(|s| s.position.side_of_ball() == SideOfBall::Defense);
+    let lineup = GameDayLineup { team: team.clone(), season_id, episode, offense, defense,
+        play_calling_strategy, submitted: block.time };
+    let slots = lineup.slots();
+    let mut positions: Vec<u8> = slots.iter().map(|s| s.position.to_u8()).collect();
+    let mut addresses: Vec<PlayerAddr> = slots.iter().map(|s| s.player.clone()).collect();
+    positions.sort();
+    positions.dedup();
+    addresses.sort();
+    addresses.dedup();
+    match sides_match && positions.len() == slots.len() && addresses.len() == slots.len() {
+        true => (),
+        false => return Err(ContractError::InvalidLineup {})
+    }
+    let unavailable = players_unavailable_for_episode(deps.storage, &team, episode, &addresses)?;
+    match unavailable.is_empty() {
+        true => (),
+        false => return Err(ContractError::LineupPlayersUnavailable { players: unavailable })
+    }
 
+    LINEUPS.save(deps.storage, (&team, season_id, episode), &lineup)?;
+    Ok(Response::new()
+        .add_attribute("action", "submit_lineup")
+        .add_attribute("team", team)
+        .add_attribute("season_id", season_id.to_string())
+        .add_attribute("episode", episode.to_string()))
+}
+
+
+//Teams that have since left the season have no standing to update
+fn record_fixture_standing(store: &mut dyn Storage, season_id: SeasonId, team: &TeamAddr,
+                           result: std::cmp::Ordering) -> StdResult<()> {
+    match SEASON_STANDINGS.may_load(store, (season_id, team.clone()))? {
+        Some(mut standing) => {
+            match result {
+                std::cmp::Ordering::Greater => standing.wins += 1,
+                std::cmp::Ordering::Less => standing.losses += 1,
+                std::cmp::Ordering::Equal => standing.ties += 1
+            }
+            SEASON_STANDINGS.save(store, (season_id, team.clone()), &standing)
         }
+        None => Ok(())
     }
 }
 
 
+fn record_fixture_stats(store: &mut dyn Storage, fixture: &Fixture, league_type: &LeagueTypes,
+                        game: &game_engine::GameResult, home_lineup: &Lineup, away_lineup: &Lineup) -> StdResult<()> {
+    let score = box_score(game, home_lineup, away_lineup);
+    for (team, stats) in [(&fixture.home, score.home), (&fixture.away, score.away)] {
+        TEAM_STATS.update(store, (team, fixture.season_id), |s| -> StdResult<_> {
+            let mut total = s.unwrap_or_default();
+            total.add(&stats);
+            Ok(total)
+        })?;
+    }
+    for (player, stats) in score.players {
+        PLAYER_STATS.update(store, (&player, fixture.season_id), |s| -> StdResult<_> {
+            let mut total = s.unwrap_or_default();
+            total.add(&stats);
+            Ok(total)
+        })?;
+        record_player_career_stats(store, league_type, &player, &stats)?;
+    }
+    Ok(())
+}
 
-fn process_season_join_request(mut deps: DepsMut, block: BlockInfo, season: Season,
-                               team_addr: Addr) -> StdResult<()> {
 
+fn league_type_of(store: &dyn Storage, league: &Addr) -> StdResult<LeagueTypes> {
+    Ok(leagues().may_load(store, league)?.map(|l| l.league_type).unwrap_or_default())
+}
 
-            let id = next_index_counter(deps.storage)?;
-            join_season_requests().save(deps.storage, id, &Message {
-                id,
-                updated: block.time.clone(),
-                created: block.time.clone(),
-                delivery:  DeliveryInfo {
-                        to: DeliveryPacket {
-                            asset_type: AssetTypes::League,
-                            address: season.league.clone()
-                        },
-                        from: DeliveryPacket {
-                            asset_type: AssetTypes::Team,
-                            address: team_addr.clone()
-                        }
 
-                },
-                data: JoinSeasonRequestInfo {
-                    status_type: MessageTypes::Accepted {},
-                    season_id: season.id
-                },
-                notes: vec![]
+fn declare_league_type(deps: DepsMut, league: Addr, league_type: LeagueTypes) -> Result<Response, ContractError> {
+    let mut info = leagues().load(deps.storage, &league)?;
+    if info.league_type != LeagueTypes::NotSet {
+        return Err(ContractError::TypeAlreadyDeclared {});
+    }
+    info.league_type = league_type;
+    leagues().save(deps.storage, &league, &info)?;
+    Ok(Response::new().add_attribute("league_type", format!("{:?}", info.league_type)))
+}
+
+
+fn declare_team_coaching(deps: DepsMut, team: TeamAddr, coaching: TeamCoaching) -> Result<Response, ContractError> {
+    let mut info = teams().load(deps.storage, team.clone())?;
+    if info.coaching != TeamCoaching::NotSet {
+        return Err(ContractError::TypeAlreadyDeclared {});
+    }
+    info.coaching = coaching;
+    teams().save(deps.storage, team, &info)?;
+    Ok(Response::new())
+}
+
+
+fn confirm_ai_coaching(deps: DepsMut, sender: Addr, team: TeamAddr) -> Result<Response, ContractError> {
+    let mut info = match teams().may_load(deps.storage, team.clone())? {
+        None => return Err(ContractError::ItemNotFound { item_address: team }),
+        Some(t) => t
+    };
+    match &info.coaching {
+        TeamCoaching::AI { attestation } if *attestation == sender => (),
+        _ => return Err(ContractError::NotCoachingAttestor { sender })
+    }
+    info.ai_confirmed = true;
+    teams().save(deps.storage, team.clone(), &info)?;
+    Ok(Response::new()
+        .add_attribute("action", "confirm_ai_coaching")
+        .add_attribute("team", team))
+}
+
+
+// Creates the display's entry the first time its details arrive
+fn update_display_details(deps: DepsMut, env: Env, mc: MangedItem, details: DisplayDetails) -> Result<Response, ContractError> {
+    if !details.is_valid() {
+        return Err(ContractError::InvalidDisplayDetails {});
+    }
+    let display =
+        match DISPLAYS.may_load(deps.storage, &mc.asset_addr)? {
+            Some(mut d) => {
+                d.set_details(details);
+                d
+            }
+            None => DisplayInfo::new(mc.asset_addr.clone(), mc.asset_name.unwrap_or_default(), details, BlockTime {
+                height: env.block.height,
+                time: env.block.time,
+                chain_id: env.block.chain_id
             })
+        };
+    DISPLAYS.save(deps.storage, &mc.asset_addr, &display)?;
+    Ok(Response::new())
+}
 
+
+fn display_heartbeat(deps: DepsMut, env: Env, display: Addr, online: bool) -> Result<Response, ContractError> {
+    let mut info = DISPLAYS.may_load(deps.storage, &display)?.ok_or(ContractError::DisplayNotFound {})?;
+    info.last_heartbeat = Some(Heartbeat { online, reported: env.block.time });
+    DISPLAYS.save(deps.storage, &display, &info)?;
+    Ok(Response::new())
 }
 
 
+fn display_status(display: DisplayInfo, now: Timestamp) -> DisplayStatusResponse {
+    DisplayStatusResponse { online: display.is_online(now), open: display.is_open(now), display }
+}
 
 
-fn join_winner_take_all_season(mut deps: DepsMut, block: BlockInfo, team_addr: Addr,
-                               season: Season, funds: Vec<Coin>) -> Result<Response, ContractError> {
-   let res =  join_season(deps.branch(), block.clone(), team_addr.clone(), season.id.clone(), Some(funds.clone()));
-    match res {
-        Ok(r) => {
-            //TODO: add sanity check on funds balance vs what's
-            //been sent in for kitties and payouts all balances out
+fn query_displays(store: &dyn Storage, now: Timestamp, start_after: Option<Addr>,
+                  limit: Option<u32>) -> StdResult<Vec<DisplayStatusResponse>> {
+    let limit = limit.unwrap_or(DEFAULT_DISPLAY_LIMIT).min(MAX_DISPLAY_LIMIT) as usize;
+    DISPLAYS
+        .range(store, start_after.as_ref().map(Bound::exclusive), None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, d)| display_status(d, now)))
+        .collect()
+}
 
-            let id = next_index_counter( deps.storage)?;
-            season_deposits_ledger().save(deps.storage, id, &SeasonLedger {
-                id,
-                season_id: season.id.clone(),
-                league: season.league,
-                team: team_addr,
-                team_deposit_amount: funds[0].clone(),
-                deposit_date: block.time.clone(),
-                withdrawal_distribution_date: None
-            }).expect("Problem saving season deposit.");
-            Ok(r)
-        }
-        Err(e) => {
-            Err(e)
+
+// Escrows the payment sent through the league and holds the slot on the
+// display's calendar until the display answers.
+fn request_broadcast(deps: DepsMut, env: Env, league: LeagueAddr, payer: Addr, display: Addr, fixture_id: u64,
+                     start: Timestamp, end: Timestamp, payment: Vec<Coin>) -> Result<Response, ContractError> {
+    if payment.is_empty() || payment.iter().any(|c| c.amount.is_zero()) {
+        return Err(ContractError::BroadcastPaymentRequired {});
+    }
+    if start < env.block.time || end <= start || end.seconds() - start.seconds() > MAX_BROADCAST_SLOT {
+        return Err(ContractError::InvalidBroadcastSlot {});
+    }
+    if !DISPLAYS.has(deps.storage, &display) {
+        return Err(ContractError::DisplayNotFound {});
+    }
+    match fixtures().may_load(deps.storage, fixture_id)? {
+        Some(f) if f.league == league => {
+            if f.result.is_some() {
+                return Err(ContractError::FixtureCompleted {});
+            }
         }
+        _ => return Err(ContractError::FixtureNotFound {})
+    }
+    let conflicts = display_broadcast_conflicts(deps.storage, &display, start, end)?;
+    if conflicts.len() > 0 {
+        return Err(ContractError::BroadcastSlotConflict { bookings: conflicts.into_iter().map(|b| b.id).collect() });
     }
+
+    let id = next_index_counter(deps.storage)?;
+    broadcasts().save(deps.storage, id, &BroadcastBooking {
+        id,
+        display,
+        league,
+        payer,
+        fixture_id,
+        start,
+        end,
+        payment,
+        status: BroadcastStatus::Requested,
+        requested: env.block.time,
+        updated: env.block.time,
+    })?;
+    Ok(Response::new()
+        .add_attribute("action", "request_broadcast")
+        .add_attribute("booking_id", id.to_string()))
 }
 
 
-fn join_open_season(mut deps: DepsMut, block: BlockInfo, team_addr: Addr,
-                    season_id: SeasonId) -> Result<Response, ContractError> {
-    join_season(deps, block, team_addr, season_id, None)
+// A rejected request is refunded to the payer right away
+fn respond_to_broadcast(deps: DepsMut, env: Env, display: Addr, booking_id: u64,
+                        accept: bool) -> Result<Response, ContractError> {
+    let mut booking = broadcasts().may_load(deps.storage, booking_id)?.ok_or(ContractError::BroadcastNotFound {})?;
+    if booking.display != display {
+        return Err(ContractError::Unauthorized { sender: display });
+    }
+    if booking.status != BroadcastStatus::Requested {
+        return Err(ContractError::InvalidBroadcastStatus {});
+    }
+    if env.block.time >= booking.start {
+        return Err(ContractError::BroadcastStarted {});
+    }
+    booking.status = match accept {
+        true => BroadcastStatus::Accepted,
+        false => BroadcastStatus::Rejected
+    };
+    booking.updated = env.block.time;
+    broadcasts().save(deps.storage, booking_id, &booking)?;
+
+    let res = Response::new()
+        .add_attribute("action", "respond_to_broadcast")
+        .add_attribute("booking_id", booking_id.to_string());
+    match accept {
+        true => Ok(res),
+        false => Ok(res.add_message(BankMsg::Send { to_address: booking.payer.to_string(), amount: booking.payment }))
+    }
+}
 
+
+// Either party can cancel before the slot starts. See `BroadcastBooking::refunds_on_cancel`
+// for who keeps the payment.
+fn cancel_broadcast(deps: DepsMut, env: Env, sender: Addr, booking_id: u64) -> Result<Response, ContractError> {
+    let mut booking = broadcasts().may_load(deps.storage, booking_id)?.ok_or(ContractError::BroadcastNotFound {})?;
+    if sender != booking.league && sender != booking.display {
+        return Err(ContractError::Unauthorized { sender });
+    }
+    if !booking.holds_slot() {
+        return Err(ContractError::InvalidBroadcastStatus {});
+    }
+    if env.block.time >= booking.start {
+        return Err(ContractError::BroadcastStarted {});
+    }
+    let payee =
+        match booking.refunds_on_cancel(sender == booking.league, env.block.time) {
+            true => booking.payer.clone(),
+            false => display_owner(deps.storage, &booking.display)?
+        };
+    booking.status = BroadcastStatus::Cancelled;
+    booking.updated = env.block.time;
+    broadcasts().save(deps.storage, booking_id, &booking)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_broadcast")
+        .add_attribute("booking_id", booking_id.to_string())
+        .add_message(BankMsg::Send { to_address: payee.to_string(), amount: booking.payment }))
 }
 
-fn get_season_league_entry_for_team(store: &dyn Storage, team_addr: Addr,
-                                    season_id: SeasonId, league_addr: Addr) -> Option<(u64, Message<JoinSeasonRequestInfo>)> {
-    let res: Result<Vec<_>, _> =
-        join_season_requests().idx
-            .season_from_to
-            .prefix((season_id, team_addr,league_addr))
-            .range(store, None, None, Order::Ascending)
-            .collect();
 
-    match res {
-        Ok(r ) => {
-            match r.len() > 0 {
-                true =>{
-                    Some(r[0].clone())
-                },
-                false => None
-            }
+// Pays the display owner once an accepted slot has ended, or refunds a
+// request the display never answered once its slot has started
+fn settle_broadcast(deps: DepsMut, env: Env, booking_id: u64) -> Result<Response, ContractError> {
+    let mut booking = broadcasts().may_load(deps.storage, booking_id)?.ok_or(ContractError::BroadcastNotFound {})?;
+    let (status, payee) =
+        match booking.status {
+            BroadcastStatus::Accepted if env.block.time >= booking.end =>
+                (BroadcastStatus::Settled, display_owner(deps.storage, &booking.display)?),
+            BroadcastStatus::Requested if env.block.time >= booking.start =>
+                (BroadcastStatus::Expired, booking.payer.clone()),
+            BroadcastStatus::Accepted | BroadcastStatus::Requested => return Err(ContractError::BroadcastNotOver {}),
+            _ => return Err(ContractError::InvalidBroadcastStatus {})
+        };
+    booking.status = status;
+    booking.updated = env.block.time;
+    broadcasts().save(deps.storage, booking_id, &booking)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "settle_broadcast")
+        .add_attribute("booking_id", booking_id.to_string())
+        .add_message(BankMsg::Send { to_address: payee.to_string(), amount: booking.payment }))
+}
+
 
+fn display_owner(store: &dyn Storage, display: &Addr) -> StdResult<Addr> {
+    Ok(managed_assets().load(store, display)?.asset_owner)
+}
+
+
+// Bookings still holding a slot on the display that overlaps [start, end).
+// No slot is longer than MAX_BROADCAST_SLOT, so earlier bookings can't overlap.
+fn display_broadcast_conflicts(store: &dyn Storage, display: &Addr, start: Timestamp,
+                               end: Timestamp) -> StdResult<Vec<BroadcastBooking>> {
+    let mut res = vec![];
+    let from = Bound::inclusive((start.seconds().saturating_sub(MAX_BROADCAST_SLOT), 0u64));
+    for item in broadcasts().idx.display.sub_prefix(display.clone()).range(store, Some(from), None, Order::Ascending) {
+        let (_, booking) = item?;
+        if booking.start >= end {
+            break;
         }
-        Err(_) => {
-            None
+        if booking.holds_slot() && booking.overlaps(start, end) {
+            res.push(booking);
         }
     }
+    Ok(res)
+}
 
+
+fn query_display_broadcasts(store: &dyn Storage, display: Addr, from: Option<Timestamp>,
+                            limit: Option<u32>) -> StdResult<Vec<BroadcastBooking>> {
+    let limit = limit.unwrap_or(DEFAULT_BROADCAST_LIMIT).min(MAX_BROADCAST_LIMIT) as usize;
+    let from = from.unwrap_or_default();
+    //no booking starting earlier than the longest slot can still be running at `from`
+    let start = Bound::inclusive((from.seconds().saturating_sub(MAX_BROADCAST_SLOT), 0u64));
+    broadcasts().idx.display
+        .sub_prefix(display)
+        .range(store, Some(start), None, Order::Ascending)
+        .filter(|item| item.as_ref().map_or(true, |(_, b)| b.end > from))
+        .take(limit)
+        .map(|item| item.map(|(_, b)| b))
+        .collect()
 }
 
-fn cancel_team_season_spot(deps: DepsMut, block: BlockInfo, team_addr: Addr,
-                           season_id: SeasonId) -> Result<Response, ContractError> {
-    let season = seasons().may_load(deps.storage,season_id.clone()).unwrap();
-    return match season {
-        None => {
-            Err(ContractError::SeasonNotFound {})
-        },
-        Some(se) => {
-            match (se.start_date < block.time, (get_season_teams_accepted_count(deps.storage, se.id.clone())) > 1) {
-                (true, true) => {
-                    Err(ContractError::TooLateToCancelSeason {})
-                },
-                (true, false)/* not enough teams accepted to start season, so allow cancel */ |
-                (false, _) /* season hasn't started, so allow cancel */ => {
-                    let team_entry = get_season_league_entry_for_team(deps.storage, team_addr.clone(), se.id, se.league);
-                    if let Some(mut te) = team_entry {
-                        let mut item = te.clone().1;
-                        item.data.status_type = MessageTypes::CancelSeason {};
-                        join_season_requests().save(deps.storage, te.0, &item).expect("Problem processing cancel request.");
 
+fn query_league_broadcasts(store: &dyn Storage, league: LeagueAddr, start_after: Option<u64>,
+                           limit: Option<u32>) -> StdResult<Vec<BroadcastBooking>> {
+    let limit = limit.unwrap_or(DEFAULT_BROADCAST_LIMIT).min(MAX_BROADCAST_LIMIT) as usize;
+    broadcasts().idx.league
+        .prefix(league)
+        .range(store, start_after.map(Bound::exclusive), None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, b)| b))
+        .collect()
+}
 
-                        let res = distribute_individual_refund(deps, block.clone(), se.id, team_addr);
-                        match res {
-                            Ok(r) => {
-                                //response from distribute_refund contains sub_message
-                                //needed for payment transfers
-                                Ok(r)
-                            }
-                            Err(e) => {
-                                Err(e)
-                            }
-                        }
-                    } else {
-                        Err(ContractError::TeamNotMemberOfSeason {})
-                    }
-                }
-            }
+// Escrows a sponsor's payment for an ad on a broadcast booking or a league
+// season until the display or league answers.
+fn request_ad(deps: DepsMut, env: Env, sponsor: Addr, placement: AdPlacement, content: AdContent,
+              payment: Vec<Coin>) -> Result<Response, ContractError> {
+    if payment.is_empty() || payment.iter().any(|c| c.amount.is_zero()) {
+        return Err(ContractError::AdPaymentRequired {});
+    }
+    if !content.is_valid() || placement.slot_name().trim().is_empty() {
+        return Err(ContractError::InvalidAdContent {});
+    }
+    let approver = ad_placement_approver(deps.storage, env.block.time, &placement)?;
+    if let Some(holder) = ad_placement_holder(deps.storage, &approver, &placement)? {
+        return Err(ContractError::AdSlotTaken { ad_id: holder });
+    }
+
+    let id = next_index_counter(deps.storage)?;
+    ads().save(deps.storage, id, &AdSlot {
+        id,
+        sponsor,
+        placement,
+        content,
+        payment,
+        approver,
+        attestor: None,
+        status: AdStatus::Requested,
+        requested: env.block.time,
+        updated: env.block.time,
+        aired: None,
+    })?;
+    Ok(Response::new()
+        .add_attribute("action", "request_ad")
+        .add_attribute("ad_id", id.to_string()))
+}
+
+
+// The display or league approving an ad can name an xnode task to attest
+// it aired. A rejected ad is refunded to the sponsor right away.
+fn respond_to_ad(deps: DepsMut, env: Env, approver: Addr, ad_id: u64, accept: bool,
+                 attestor: Option<Addr>) -> Result<Response, ContractError> {
+    let mut ad = ads().may_load(deps.storage, ad_id)?.ok_or(ContractError::AdNotFound {})?;
+    if ad.approver != approver {
+        return Err(ContractError::Unauthorized { sender: approver });
+    }
+    if ad.status != AdStatus::Requested {
+        return Err(ContractError::InvalidAdStatus {});
+    }
+    if accept {
+        ad_placement_approver(deps.storage, env.block.time, &ad.placement)?;
+        if let Some(holder) = ad_placement_holder(deps.storage, &approver, &ad.placement)? {
+            return Err(ContractError::AdSlotTaken { ad_id: holder });
+        }
+    }
+    ad.status = match accept {
+        true => AdStatus::Approved,
+        false => AdStatus::Rejected
+    };
+    ad.attestor = attestor;
+    ad.updated = env.block.time;
+    ads().save(deps.storage, ad_id, &ad)?;
+
+    let res = Response::new()
+        .add_attribute("action", "respond_to_ad")
+        .add_attribute("ad_id", ad_id.to_string());
+    match accept {
+        true => Ok(res),
+        false => Ok(res.add_message(BankMsg::Send { to_address: ad.sponsor.to_string(), amount: ad.payment }))
+    }
+}
+
+
+// The sponsor can confirm an ad aired at any time. The ad's attestor or the
+// approver can once its placement is airing, see `placement_airing`; the approver
+// names the attestor, so it gets no earlier say than the approver. The payment
+// is distributed like a sale: management fees first, then the display or
+// league owners by weight.
+fn attest_ad_aired(deps: DepsMut, env: Env, sender: Addr, ad_id: u64) -> Result<Response, ContractError> {
+    let mut ad = ads().may_load(deps.storage, ad_id)?.ok_or(ContractError::AdNotFound {})?;
+    if ad.status != AdStatus::Approved {
+        return Err(ContractError::InvalidAdStatus {});
+    }
+    if sender != ad.sponsor {
+        if sender != ad.approver && Some(&sender) != ad.attestor.as_ref() {
+            return Err(ContractError::Unauthorized { sender });
+        }
+        if !placement_airing(deps.storage, env.block.time, &ad)? {
+            return Err(ContractError::AdNotAttestable {});
+        }
+    }
+
+    let fees: Vec<Fee> = MANAGEMENT.get(deps.as_ref())?.fees.into_iter().filter(|f| f.active).map(|f| f.fees).collect();
+    let owners: AssetOwnersResponse = deps.querier.query_wasm_smart(ad.approver.clone(), &AssetInfoQueryMsg::GetInfo {})?;
+    let mut distributions = vec![];
+    for coin in ad.payment.iter() {
+        if let Some(msgs) = payment_distribution(owners.owners.clone(), Some(fees.clone()), coin.clone())? {
+            distributions.extend(msgs);
         }
     }
 
+    ad.status = AdStatus::Paid;
+    ad.aired = Some(env.block.time);
+    ad.updated = env.block.time;
+    ads().save(deps.storage, ad_id, &ad)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "attest_ad_aired")
+        .add_attribute("ad_id", ad_id.to_string())
+        .add_submessages(distributions))
 }
 
-fn join_season(mut deps: DepsMut, block: BlockInfo, team_addr: Addr,
-               season_id: SeasonId, funds: Option<Vec<Coin>>) -> Result<Response, ContractError>  {
-    let season = seasons().may_load(deps.storage,season_id.clone()).unwrap();
-    match season {
-        None => {
-            Err(ContractError::SeasonNotFound {})
+
+// A sponsor can take back an ad that was never approved, or an approved one
+// nobody attested once its broadcast or season is over
+fn withdraw_ad(deps: DepsMut, env: Env, sender: Addr, ad_id: u64) -> Result<Response, ContractError> {
+    let mut ad = ads().may_load(deps.storage, ad_id)?.ok_or(ContractError::AdNotFound {})?;
+    if sender != ad.sponsor {
+        return Err(ContractError::Unauthorized { sender });
+    }
+    match ad.status {
+        AdStatus::Requested => (),
+        AdStatus::Approved => {
+            if !ad_placement_over(deps.storage, env.block.time, &ad.placement)? {
+                return Err(ContractError::AdPlacementNotOver {});
+            }
         }
-        Some(s) => {
-            let validation_res =
-                validate_season_join_request(deps.storage, block.clone(), team_addr.clone(), s.league.clone(), funds, s.clone() );
+        _ => return Err(ContractError::InvalidAdStatus {})
+    }
+    ad.status = AdStatus::Withdrawn;
+    ad.updated = env.block.time;
+    ads().save(deps.storage, ad_id, &ad)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "withdraw_ad")
+        .add_attribute("ad_id", ad_id.to_string())
+        .add_message(BankMsg::Send { to_address: ad.sponsor.to_string(), amount: ad.payment }))
+}
 
-            match validation_res {
-                Ok(r) => {
-                    let process_res = process_season_join_request(deps.branch(), block, s.clone(),
-                                                                  team_addr.clone());
-                    match process_res {
-                        Ok(_) => {
-                            Ok(r)
-                        },
-                        Err(e) => {
-                            Err(ContractError::Std(e))
-                        }
-                    }
-                },
-                Err(e) => Err(e)
+
+// The display or league that approves ads on the placement, as long as its
+// broadcast hasn't started or its season hasn't ended
+fn ad_placement_approver(store: &dyn Storage, now: Timestamp, placement: &AdPlacement) -> Result<Addr, ContractError> {
+    match placement {
+        AdPlacement::Broadcast { booking_id, .. } => {
+            let booking = broadcasts().may_load(store, *booking_id)?.ok_or(ContractError::BroadcastNotFound {})?;
+            match booking.holds_slot() && now < booking.start {
+                true => Ok(booking.display),
+                false => Err(ContractError::InvalidBroadcastStatus {})
+            }
+        }
+        AdPlacement::Season { season_id, .. } => {
+            let season = seasons().may_load(store, *season_id)?.ok_or(ContractError::SeasonNotFound {})?;
+            match now < season.end_date {
+                true => Ok(season.league),
+                false => Err(ContractError::InvalidSeason {})
             }
         }
     }
+}
 
 
+fn ad_placement_over(store: &dyn Storage, now: Timestamp, placement: &AdPlacement) -> StdResult<bool> {
+    match placement {
+        AdPlacement::Broadcast { booking_id, .. } => {
+            Ok(broadcasts().may_load(store, *booking_id)?.map_or(true, |b| !b.holds_slot() || now >= b.end))
+        }
+        AdPlacement::Season { season_id, .. } => {
+            Ok(seasons().may_load(store, *season_id)?.map_or(true, |s| now >= s.end_date))
+        }
+    }
 }
 
 
-fn get_season_teams_accepted_count (store: & dyn Storage , season_id: SeasonId) -> u32 {
-    let res: Result<Vec<_>, _> =
-        join_season_requests().idx
-            .season_id
-            .prefix(season_id)
-            .range(store, None, None, Order::Ascending)
-            .filter(|s|
-                {
-                    match s {
-                        Ok(item) => {
-                            item.1.data.status_type == MessageTypes::Accepted {}
-                        },
-                        _ => false,
-                    }
+// The approved or paid ad already holding the placement, if any
+fn ad_placement_holder(store: &dyn Storage, approver: &Addr, placement: &AdPlacement) -> StdResult<Option<u64>> {
+    for item in ads().idx.approver.prefix(approver.clone()).range(store, None, None, Order::Ascending) {
+        let (_, ad) = item?;
+        if ad.placement == *placement && ad.holds_placement() {
+            return Ok(Some(ad.id));
+        }
+    }
+    Ok(None)
+}
 
-                })
-            .collect();
 
-    match res {
-        Ok(r) => {
-            
-            r.len() as u32
+// The ad's broadcast has started on an accepted booking and the display is
+// online, or its season has started
+fn placement_airing(store: &dyn Storage, now: Timestamp, ad: &AdSlot) -> StdResult<bool> {
+    match &ad.placement {
+        AdPlacement::Broadcast { booking_id, .. } => {
+            match broadcasts().may_load(store, *booking_id)? {
+                Some(b) if b.status == BroadcastStatus::Accepted || b.status == BroadcastStatus::Settled => {
+                    let online = DISPLAYS.may_load(store, &b.display)?.map_or(false, |d| d.is_online(now));
+                    Ok(now >= b.start && online)
+                }
+                _ => Ok(false)
+            }
+        }
+        AdPlacement::Season { season_id, .. } => {
+            Ok(seasons().may_load(store, *season_id)?.map_or(false, |s| now >= s.start_date))
+        }
+    }
+}
+
+// Any managed asset can message another. A reply stays in its parent's
+// thread and has to go to the parent's other party.
+fn send_inbox_message(deps: DepsMut, env: Env, sender: MangedItem, to: Addr, content: InboxContent, body: String,
+                      reply_to: Option<MessageId>, expires: Option<Timestamp>) -> Result<Response, ContractError> {
+    let recipient = managed_assets().may_load(deps.storage, &to)?.ok_or(ContractError::InboxRecipientNotFound {})?;
+    if to == sender.asset_addr || body.len() > MAX_INBOX_BODY_LENGTH || expires.map_or(false, |e| e <= env.block.time) {
+        return Err(ContractError::InvalidInboxMessage {});
+    }
+    let id = next_index_counter(deps.storage)?;
+    let thread =
+        match reply_to {
+            None => id,
+            Some(parent_id) => {
+                let parent = inbox_messages().may_load(deps.storage, parent_id)?.ok_or(ContractError::InboxMessageNotFound {})?;
+                if parent.data.is_expired(env.block.time) {
+                    return Err(ContractError::InboxMessageExpired {});
+                }
+                let parties = (&parent.delivery.from.address, &parent.delivery.to.address);
+                if parties != (&sender.asset_addr, &to) && parties != (&to, &sender.asset_addr) {
+                    return Err(ContractError::Unauthorized { sender: sender.asset_addr });
+                }
+                parent.data.thread
+            }
+        };
+
+    inbox_messages().save(deps.storage, id, &Message {
+        id,
+        updated: env.block.time,
+        created: env.block.time,
+        delivery: DeliveryInfo {
+            to: DeliveryPacket { asset_type: recipient.asset_type, address: to.clone() },
+            from: DeliveryPacket { asset_type: sender.asset_type, address: sender.asset_addr.clone() }
         },
-        _ => panic!("Problem calculating teams accepted count!")
+        data: InboxMessageInfo { content, body, reply_to, thread, read: false, expires },
+        notes: vec![]
+    })?;
+    Ok(Response::new()
+        .add_attribute("action", "send_inbox_message")
+        .add_attribute("message_id", id.to_string())
+        .add_attribute("to", to))
+}
+
 
+fn mark_inbox_message_read(deps: DepsMut, env: Env, sender: Addr, message_id: MessageId,
+                           read: bool) -> Result<Response, ContractError> {
+    let mut message = inbox_messages().may_load(deps.storage, message_id)?.ok_or(ContractError::InboxMessageNotFound {})?;
+    if message.delivery.to.address != sender {
+        return Err(ContractError::Unauthorized { sender });
     }
+    message.data.read = read;
+    message.updated = env.block.time;
+    inbox_messages().save(deps.storage, message_id, &message)?;
+    Ok(Response::new())
 }
 
 
-fn is_season_at_capacity (store: &mut dyn Storage , season_id: SeasonId) -> bool {
-    let res_1: Result<Vec<_>, _> =
-        join_season_requests().idx
-            .season_id
-            .prefix(season_id)
-            .range(store, None, None, Order::Ascending)
-            .filter(|s|
-                {
-                    match s {
-                        Ok(item) => {
-                            item.1.data.status_type == MessageTypes::Accepted {}
-                        },
-                        _ => false,
-                    }
-                 
-                })
-            .collect();
+fn query_inbox(store: &dyn Storage, now: Timestamp, addr: Addr, unread_only: bool, start_after: Option<MessageId>,
+               limit: Option<u32>) -> StdResult<Vec<Message<InboxMessageInfo>>> {
+    let limit = limit.unwrap_or(DEFAULT_INBOX_LIMIT).min(MAX_INBOX_LIMIT) as usize;
+    inbox_messages().idx.recipient
+        .prefix(addr)
+        .range(store, None, start_after.map(Bound::exclusive), Order::Descending)
+        .filter(|item| item.as_ref().map_or(true, |(_, m)| !m.data.is_expired(now) && !(unread_only && m.data.read)))
+        .take(limit)
+        .map(|item| item.map(|(_, m)| m))
+        .collect()
+}
 
-    match res_1 {
-        Ok(r) => {
-            let season = seasons().may_load(store ,season_id.clone()).unwrap().unwrap();
-            !((r.len() as u32) < season.max_teams_allowed.unwrap())
-        },
-        _ => true
+// Checks the team's declared coaching against the league's declared type
+fn assert_league_admits_team(store: &dyn Storage, league: &Addr, team: &TeamAddr) -> Result<(), ContractError> {
+    let league_type = league_type_of(store, league)?;
+    let coaching = teams().may_load(store, team.clone())?.map(|t| t.admitted_coaching()).unwrap_or_default();
+    match league_type.admits(&coaching) {
+        true => Ok(()),
+        false => Err(ContractError::LeagueTypeMismatch { league_type, team: team.clone() })
+    }
+}
 
+
+// Updates the team's all-time record and moves it on the win percentage
+// and win streak boards for its league type.
+fn record_team_result(store: &mut dyn Storage, league_type: &LeagueTypes, team: &TeamAddr,
+                      result: std::cmp::Ordering) -> StdResult<()> {
+    let board = league_type.get_u8();
+    let mut record =
+        match TEAM_RECORDS.may_load(store, (team, board))? {
+            Some(r) => {
+                TEAM_WIN_PCT_BOARD.remove(store, (board, win_pct_board_score(&r), team));
+                TEAM_STREAK_BOARD.remove(store, (board, r.longest_streak, team));
+                r
+            }
+            None => TeamRecord::new(team.clone(), league_type.clone())
+        };
+    record.record(result);
+    TEAM_RECORDS.save(store, (team, board), &record)?;
+    TEAM_WIN_PCT_BOARD.save(store, (board, win_pct_board_score(&record), team), &())?;
+    match record.longest_streak > 0 {
+        true => TEAM_STREAK_BOARD.save(store, (board, record.longest_streak, team), &()),
+        false => Ok(())
     }
 }
 
-fn validate_season_join_request(store: &mut dyn Storage , block: BlockInfo, team_addr: Addr,
-                                league_addr: Addr, funds: Option<Vec<Coin>>, season: Season)
-    -> Result<Response, ContractError> {
 
+fn record_player_career_stats(store: &mut dyn Storage, league_type: &LeagueTypes, player: &PlayerAddr,
+                              stats: &PlayerStats) -> StdResult<()> {
+    let league_board = league_type.get_u8();
+    let mut career = PLAYER_CAREER_STATS.may_load(store, (player, league_board))?.unwrap_or_default();
+    for category in StatCategory::all() {
+        let board = player_stat_board(league_board, category.get_u8());
+        let (old, new) = (category.value(&career), category.value(&career) + category.value(stats));
+        if old != 0 {
+            PLAYER_STAT_BOARD.remove(store, (board, old, player));
+        }
+        if new != 0 {
+            PLAYER_STAT_BOARD.save(store, (board, new, player), &())?;
+        }
+    }
+    career.add(stats);
+    PLAYER_CAREER_STATS.save(store, (player, league_board), &career)
+}
 
-            //(15 mins) padding time to give
-            //some time for teams response
-            //PRIOR_TO_SEASON_START_PADDING = 900
-            return match season.start_date.seconds() - block.time.seconds() >= PRIOR_TO_SEASON_START_PADDING {
-                true => {
-                    match season.status {
-                        None => {
-                            return Err(ContractError::SeasonStatusNotSet {})
-                        },
-                        Some(cs) => {
-                            match cs {
-                                SeasonStatus::Active => {
 
-                                    match is_season_at_capacity(store, season.id.clone()) {
-                                        true => {
-                                            return Err(ContractError::SeasonHasReachedCapacity {})
-                                        }
-                                        false => {
-                                            //Ok, allow to continue
-                                        }
-                                    }
-                                },
-                                SeasonStatus::Cancelled { date_cancelled } => {
-                                    return Err(ContractError::SeasonStatusCancelled { date_cancelled})
-                                }
-                                SeasonStatus::Private => {
-                                    return Err(ContractError::SeasonStatusPrivate {})
-                                }
-                            }
-                        }
-                    }
+fn query_team_leaderboard(store: &dyn Storage, league_type: LeagueTypes, start_after: Option<TeamAddr>,
+                          limit: Option<u32>) -> StdResult<Vec<TeamRecord>> {
+    let board = league_type.get_u8();
+    let limit = limit.unwrap_or(DEFAULT_LEADERBOARD_LIMIT).min(MAX_LEADERBOARD_LIMIT) as usize;
+    let after = match start_after {
+        Some(team) => TEAM_RECORDS.may_load(store, (&team, board))?.map(|r| (win_pct_board_score(&r), team)),
+        None => None
+    };
+    let max = after.as_ref().map(|(score, team)| Bound::exclusive((*score, team)));
+    TEAM_WIN_PCT_BOARD.sub_prefix(board)
+        .keys(store, None, max, Order::Descending)
+        .take(limit)
+        .map(|k| TEAM_RECORDS.load(store, (&k?.1, board)))
+        .collect()
+}
 
-                    let existing_request_res =
-                            get_existing_join_request(store, season.id.clone()
-                                                      , team_addr.clone(), league_addr);
-                    match existing_request_res {
-                        None => {
-                            //Ok, allow to continue
-                        }
-                        Some(_) => {
-                            return Err(ContractError::TeamAlreadyMemberOfSeason {})
-                        }
-                    }
-                    match season.access_type.clone() {
-                        Some(st) => {
-                            match st {
-                                SeasonAccessTypes::Open => {
-                                    //Ok, allow to continue
-                                }
-                                SeasonAccessTypes::WinnerTakeAll { coin } => {
 
-                                    match funds {
-                                        None => {
-                                            return Err(ContractError::IncorrectFundingSent {})
-                                        }
-                                        Some(f) => {
-                                            match coin.amount == f[0].amount &&
-                                                coin.denom == f[0].denom {
-                                                true => {
-                                                    //Ok, allow to continue
-                                                }
-                                                false => {
-                                                    return Err(ContractError::IncorrectFundingSent {})
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        },
-                        None => {
-                            return Err(ContractError::SeasonTypeNotSet {})
-                        }
-                    }
+fn query_win_streak_leaderboard(store: &dyn Storage, league_type: LeagueTypes, start_after: Option<TeamAddr>,
+                                limit: Option<u32>) -> StdResult<Vec<TeamRecord>> {
+    let board = league_type.get_u8();
+    let limit = limit.unwrap_or(DEFAULT_LEADERBOARD_LIMIT).min(MAX_LEADERBOARD_LIMIT) as usize;
+    let after = match start_after {
+        Some(team) => TEAM_RECORDS.may_load(store, (&team, board))?.map(|r| (r.longest_streak, team)),
+        None => None
+    };
+    let max = after.as_ref().map(|(streak, team)| Bound::exclusive((*streak, team)));
+    TEAM_STREAK_BOARD.sub_prefix(board)
+        .keys(store, None, max, Order::Descending)
+        .take(limit)
+        .map(|k| TEAM_RECORDS.load(store, (&k?.1, board)))
+        .collect()
+}
 
-                    let conflicting_seasons =
-                        get_team_season_conflicts(store, AssetTypes::Team,
-                                                  team_addr.clone(),
-                                                  season.start_date.clone(),
-                                                  season.end_date.clone());
-                    match conflicting_seasons {
-                        None => {
-                            Ok(Response::new())
-                        }
-                        Some(items) => {
-                            let season_ids: Vec<SeasonId> =
-                                items.
-                                    into_iter().
-                                    map(|s| s.id).
-                                    collect();
-                            Err(ContractError::SeasonScheduleConflict { conflicting_season: season_ids })
-                        }
-                    }
-                }
-                false => {
-                    Err(ContractError::TooLateToRequestToJoinLeagueSeason {})
-                }
-            }
 
+fn query_player_leaderboard(store: &dyn Storage, league_type: LeagueTypes, category: StatCategory,
+                            start_after: Option<PlayerAddr>, limit: Option<u32>) -> StdResult<Vec<PlayerLeaderboardItem>> {
+    let league_board = league_type.get_u8();
+    let limit = limit.unwrap_or(DEFAULT_LEADERBOARD_LIMIT).min(MAX_LEADERBOARD_LIMIT) as usize;
+    let after = match start_after {
+        Some(player) => PLAYER_CAREER_STATS.may_load(store, (&player, league_board))?.
+            map(|s| (category.value(&s), player)),
+        None => None
+    };
+    let max = after.as_ref().map(|(value, player)| Bound::exclusive((*value, player)));
+    PLAYER_STAT_BOARD.sub_prefix(player_stat_board(league_board, category.get_u8()))
+        .keys(store, None, max, Order::Descending)
+        .take(limit)
+        .map(|k| k.map(|(value, player)| PlayerLeaderboardItem { player, value }))
+        .collect()
 }
 
-fn add_season_to_league(mut deps: DepsMut, block: BlockInfo,
-                        sender_contract_addr: Addr, mut season: Season) -> Result<Response,
-    ContractError>{
-    
-    match is_validate_season(season.borrow(), block.clone()) {
-        Ok(r) => {
 
-            match managed_assets().may_load(deps.storage, &sender_contract_addr.clone())? {
-                None => {
-                    Err(ContractError::Unauthorized { sender: sender_contract_addr.clone() })
-                },
-                Some(mut mc) => {
-                    match mc.clone().asset_type == AssetTypes::League {
-                        true => {
-                            match query_check_for_season_date_range_conflicts(deps.storage,
-                                                                              season.clone().start_date.seconds(),
-                                                                  season.clone().end_date.seconds(),
-                                                                  sender_contract_addr.clone()){
-                                Ok(res) => {
-                                    match res {
-                                        None => {
+fn query_hall_of_fame(store: &dyn Storage, league_type: LeagueTypes, start_after: Option<SeasonId>,
+                      limit: Option<u32>) -> StdResult<Vec<HallOfFameEntry>> {
+    let limit = limit.unwrap_or(DEFAULT_LEADERBOARD_LIMIT).min(MAX_LEADERBOARD_LIMIT) as usize;
+    hall_of_fame().idx.league_type
+        .prefix(league_type.get_u8())
+        .range(store, None, start_after.map(Bound::exclusive), Order::Descending)
+        .take(limit)
+        .map(|item| item.map(|(_, e)| e))
+        .collect()
+}
 
-                                            let id = next_index_counter( deps.storage)?;
-                                            season.id = id.clone();
-                                            //let's make sure correct league aggress is assigned to
-                                            //new season
-                                            season.league = mc.asset_addr;
-                                            seasons().save(deps.storage, id , &season)?;
-                                            Ok(Response::new())
-                                        }
-                                        Some(items) => {
-                                            let seasons: Vec<SeasonId> =
-                                                    items.
-                                                        into_iter().
-                                                        map( |s| s.id).
-                                                        collect();
-                                            Err(ContractError::SeasonScheduleConflict{ conflicting_season: seasons })
-                                        }
-                                    }
-                                },
-                                Err(e) => {
-                                    Err(ContractError::Std(e))
-                                }
-                            }
 
-                        }
-                        false => {
-                            Err(ContractError::Unauthorized { sender: sender_contract_addr })
-                        }
-                    }
+fn query_player_stats(store: &dyn Storage, player: PlayerAddr, season_id: Option<SeasonId>) -> StdResult<PlayerStats> {
+    match season_id {
+        Some(id) => Ok(PLAYER_STATS.may_load(store, (&player, id))?.unwrap_or_default()),
+        None => {
+            let mut total = PlayerStats::default();
+            for item in PLAYER_STATS.prefix(&player).range(store, None, None, Order::Ascending) {
+                total.add(&item?.1);
+            }
+            Ok(total)
+        }
+    }
+}
 
-                }
+
+fn query_team_stats(store: &dyn Storage, team: TeamAddr, season_id: Option<SeasonId>) -> StdResult<TeamStats> {
+    match season_id {
+        Some(id) => Ok(TEAM_STATS.may_load(store, (&team, id))?.unwrap_or_default()),
+        None => {
+            let mut total = TeamStats::default();
+            for item in TEAM_STATS.prefix(&team).range(store, None, None, Order::Ascending) {
+                total.add(&item?.1);
             }
-        },
-        Err(e) => {
-            Err(e)
+            Ok(total)
         }
     }
+}
 
+
+fn query_season_fixtures(store: &dyn Storage, season_id: SeasonId) -> StdResult<Vec<Fixture>> {
+    fixtures().idx.season
+        .prefix(season_id)
+        .range(store, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, f)| f))
+        .collect()
 }
 
+
 fn update_asset_for_sale_status(deps: DepsMut, block: BlockInfo, sender_contract_addr: Addr,
                                 for_sale_status: bool, price: Option<Coin>) -> Result<Response,
     ContractError> {
@@ -1532,10 +4834,14 @@ fn add_team_player_to_team(deps: DepsMut, block: BlockInfo, team_sender_contract
                         None => "".to_string(),
                         Some(n) => n
                     };
+                    let coaching =
+                        teams().may_load(deps.storage, team_sender_contract_addr.clone())?.
+                            map(|t| t.coaching).
+                            unwrap_or_default();
                     teams().save(deps.storage, team_sender_contract_addr.clone(),
                                  &TeamInfo::new(None,team_sender_contract_addr.clone(),
                                                 team_name.clone(), player_info,
-                                                ma.asset_owner.clone(), BlockTime {
+                                                ma.asset_owner.clone(), coaching, BlockTime {
                         height:block.height,
                         time: block.time,
                         chain_id: block.chain_id
@@ -1639,20 +4945,8 @@ fn validate_player_names(store: &mut dyn Storage , players: Vec<PlayerInfo>) ->
 
 
 fn is_name_in_use(store: &mut dyn Storage, first_name: String, last_name: String) -> bool{
-    let current_state_res = PLAYER_NAMES.load(store);
-    match current_state_res {
-        Ok(p) => {
-            let res:  Vec<PlayerInfo> =
-                p.items
-                    .into_iter()
-                    .filter(| i | i.last_name.to_lowercase().trim() == last_name.to_lowercase().trim() &&
-                                             i.first_name.to_lowercase().trim() == first_name.to_lowercase().trim())
-                    .collect();
-            match res.len() > 0 {
-                true => true,
-                false => false
-            }
-        }
+    match load_player_by_name(store, &first_name, &last_name) {
+        Ok(p) => p.is_some() || DRAFT_POOL_NAMES.has(store, normalized_player_name(&first_name, &last_name)),
         Err(_) => false
     }
 }
@@ -1737,17 +5031,13 @@ pub fn query(deps: Deps, _env: Env, msg: GoiManagerQueryMsg) -> StdResult<Binary
             to_binary(&res)
         },
         GoiManagerQueryMsg::GetPlayerByName { first_name: f_name, last_name: l_name } => {
-            let current_state = PLAYER_NAMES.load(deps.storage)?;
-            match current_state.items.len() > 0 {
-                false => {
-                    to_binary(&no_player().unwrap())
-                },
-                true => {
-
-                    to_binary( &query_player_name
-                        ( Some(current_state.items), f_name, l_name)?)
-                }
-            }
+            to_binary(&load_player_by_name(deps.storage, &f_name, &l_name)?)
+        },
+        GoiManagerQueryMsg::GetTeamPlayers { team_addr } => {
+            to_binary(&query_team_players(deps.storage, team_addr)?)
+        },
+        GoiManagerQueryMsg::GetPlayersByPosition { position, start_after, limit } => {
+            to_binary(&query_players_by_position(deps.storage, position, start_after, limit)?)
         },
         GoiManagerQueryMsg::GetOwnerAssets { owner_address } => {
             let res: Result<Vec<_>, _> =
@@ -1812,6 +5102,168 @@ pub fn query(deps: Deps, _env: Env, msg: GoiManagerQueryMsg) -> StdResult<Binary
         GoiManagerQueryMsg::GetLeagueTeams { league_addr } => {
             let res = query_get_league_teams(deps.storage, league_addr);
             to_binary(&res)
+        },
+        GoiManagerQueryMsg::GetSeasonCapacityPolicy {} => {
+            to_binary(&get_season_capacity_policy(deps.storage)?)
+        },
+        GoiManagerQueryMsg::GetSeasonDivisions { season_id } => {
+            let res = query_get_season_divisions(deps.storage, season_id);
+            to_binary(&res)
+        },
+        GoiManagerQueryMsg::GetSeasonTeamsByDivision { season_id } => {
+            to_binary(&query_get_season_teams_by_division(deps.storage, season_id)?)
+        },
+        GoiManagerQueryMsg::GetSeasonStandingsByDivision { season_id } => {
+            to_binary(&query_get_season_standings_by_division(deps.storage, season_id)?)
+        },
+        GoiManagerQueryMsg::GetSeasonScheduleByDivision { season_id } => {
+            to_binary(&query_get_season_schedule_by_division(deps.storage, season_id)?)
+        },
+        GoiManagerQueryMsg::GetPlayoffs { season_id } => {
+            to_binary(&PLAYOFFS.may_load(deps.storage, season_id)?)
+        },
+        GoiManagerQueryMsg::GetSeasonChampion { season_id } => {
+            let res = PLAYOFFS.may_load(deps.storage, season_id)?.and_then(|p| p.champion);
+            to_binary(&res)
+        },
+        GoiManagerQueryMsg::GetSeasonRescheduleNotices { item_addr, asset_type } => {
+            let res = query_get_season_reschedule_notices(deps.storage, item_addr, asset_type);
+            to_binary(&res)
+        },
+        GoiManagerQueryMsg::GetSeasonTemplate { league_addr } => {
+            to_binary(&SEASON_TEMPLATES.may_load(deps.storage, &league_addr)?)
+        },
+        GoiManagerQueryMsg::GetTradeProposal { trade_id } => {
+            to_binary(&trade_proposals().may_load(deps.storage, trade_id)?)
+        },
+        GoiManagerQueryMsg::GetFreeAgents { position, start_after, limit } => {
+            to_binary(&query_free_agents(deps.storage, position, start_after, limit)?)
+        },
+        GoiManagerQueryMsg::GetDraft { season_id } => {
+            to_binary(&DRAFTS.may_load(deps.storage, season_id)?)
+        },
+        GoiManagerQueryMsg::GetSalaryCap { league_addr } => {
+            to_binary(&SALARY_CAPS.may_load(deps.storage, &league_addr)?)
+        },
+        GoiManagerQueryMsg::GetPlayerContract { player } => {
+            to_binary(&player_contracts().may_load(deps.storage, player)?)
+        },
+        GoiManagerQueryMsg::GetTeamPlayerContracts { team_addr } => {
+            to_binary(&get_team_player_contracts(deps.storage, &team_addr)?)
+        },
+        GoiManagerQueryMsg::GetPlayerStatus { player } => {
+            to_binary(&get_player_status(deps.storage, &player)?)
+        },
+        GoiManagerQueryMsg::GetCareerSummary { player } => {
+            to_binary(&CAREER_SUMMARIES.may_load(deps.storage, &player)?)
+        },
+        GoiManagerQueryMsg::GetFixture { fixture_id } => {
+            to_binary(&fixtures().may_load(deps.storage, fixture_id)?)
+        },
+        GoiManagerQueryMsg::GetSeasonFixtures { season_id } => {
+            to_binary(&query_season_fixtures(deps.storage, season_id)?)
+        },
+        GoiManagerQueryMsg::GetLineup { team_addr, season_id, episode } => {
+            to_binary(&effective_lineup(deps.storage, &team_addr, season_id, episode)?)
+        },
+        GoiManagerQueryMsg::GetLineupLock { league_addr } => {
+            to_binary(&lineup_lock_period(deps.storage, &league_addr)?)
+        },
+        GoiManagerQueryMsg::GetDisplay { display_addr } => {
+            to_binary(&DISPLAYS.may_load(deps.storage, &display_addr)?.map(|d| display_status(d, _env.block.time)))
+        },
+        GoiManagerQueryMsg::GetDisplays { start_after, limit } => {
+            to_binary(&query_displays(deps.storage, _env.block.time, start_after, limit)?)
+        },
+        GoiManagerQueryMsg::GetBroadcast { booking_id } => {
+            to_binary(&broadcasts().may_load(deps.storage, booking_id)?)
+        },
+        GoiManagerQueryMsg::GetAd { ad_id } => {
+            to_binary(&ads().may_load(deps.storage, ad_id)?)
+        },
+        GoiManagerQueryMsg::GetInbox { addr, unread_only, start_after, limit } => {
+            to_binary(&query_inbox(deps.storage, _env.block.time, addr, unread_only, start_after, limit)?)
+        },
+        GoiManagerQueryMsg::GetOutbox { addr, start_after, limit } => {
+            let limit = limit.unwrap_or(DEFAULT_INBOX_LIMIT).min(MAX_INBOX_LIMIT) as usize;
+            let res: StdResult<Vec<Message<InboxMessageInfo>>> = inbox_messages().idx.sender
+                .prefix(addr)
+                .range(deps.storage, None, start_after.map(Bound::exclusive), Order::Descending)
+                .take(limit)
+                .map(|item| item.map(|(_, m)| m))
+                .collect();
+            to_binary(&res?)
+        },
+        GoiManagerQueryMsg::GetInboxThread { thread } => {
+            let res: StdResult<Vec<Message<InboxMessageInfo>>> = inbox_messages().idx.thread
+                .prefix(thread)
+                .range(deps.storage, None, None, Order::Ascending)
+                .map(|item| item.map(|(_, m)| m))
+                .collect();
+            to_binary(&res?)
+        },
+        GoiManagerQueryMsg::GetAdsByApprover { approver, start_after, limit } => {
+            let limit = limit.unwrap_or(DEFAULT_AD_LIMIT).min(MAX_AD_LIMIT) as usize;
+            let res: StdResult<Vec<AdSlot>> = ads().idx.approver
+                .prefix(approver)
+                .range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+                .take(limit)
+                .map(|item| item.map(|(_, a)| a))
+                .collect();
+            to_binary(&res?)
+        },
+        GoiManagerQueryMsg::GetAdsBySponsor { sponsor, start_after, limit } => {
+            let limit = limit.unwrap_or(DEFAULT_AD_LIMIT).min(MAX_AD_LIMIT) as usize;
+            let res: StdResult<Vec<AdSlot>> = ads().idx.sponsor
+                .prefix(sponsor)
+                .range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+                .take(limit)
+                .map(|item| item.map(|(_, a)| a))
+                .collect();
+            to_binary(&res?)
+        },
+        GoiManagerQueryMsg::GetDisplayBroadcasts { display_addr, from, limit } => {
+            to_binary(&query_display_broadcasts(deps.storage, display_addr, from, limit)?)
+        },
+        GoiManagerQueryMsg::GetLeagueBroadcasts { league_addr, start_after, limit } => {
+            to_binary(&query_league_broadcasts(deps.storage, league_addr, start_after, limit)?)
+        },
+        GoiManagerQueryMsg::CheckBroadcastConflicts { display_addr, start, end } => {
+            let conflicts = display_broadcast_conflicts(deps.storage, &display_addr, start, end)?;
+            to_binary(&match conflicts.len() > 0 {
+                true => Some(conflicts),
+                false => None
+            })
+        },
+        GoiManagerQueryMsg::GetPlayerStats { player, season_id } => {
+            to_binary(&query_player_stats(deps.storage, player, season_id)?)
+        },
+        GoiManagerQueryMsg::GetTeamStats { team_addr, season_id } => {
+            to_binary(&query_team_stats(deps.storage, team_addr, season_id)?)
+        },
+        GoiManagerQueryMsg::GetTeamLeaderboard { league_type, start_after, limit } => {
+            to_binary(&query_team_leaderboard(deps.storage, league_type, start_after, limit)?)
+        },
+        GoiManagerQueryMsg::GetWinStreakLeaderboard { league_type, start_after, limit } => {
+            to_binary(&query_win_streak_leaderboard(deps.storage, league_type, start_after, limit)?)
+        },
+        GoiManagerQueryMsg::GetPlayerLeaderboard { league_type, category, start_after, limit } => {
+            to_binary(&query_player_leaderboard(deps.storage, league_type, category, start_after, limit)?)
+        },
+        GoiManagerQueryMsg::GetHallOfFame { league_type, start_after, limit } => {
+            to_binary(&query_hall_of_fame(deps.storage, league_type, start_after, limit)?)
+        },
+        GoiManagerQueryMsg::CheckLineup { team_addr, season_id, players } => {
+            let unavailable = unavailable_lineup_players(deps.storage, &team_addr, season_id, &players).
+                map_err(|e| StdError::generic_err(e.to_string()))?;
+            to_binary(&LineupCheckResponse { unavailable })
+        },
+        GoiManagerQueryMsg::GetTeamTradeProposals { team_addr } => {
+            let res = get_team_trade_proposals(deps.storage, team_addr)?;
+            match res.len() > 0 {
+                true => to_binary(&Some(res)),
+                false => to_binary(&None::<Vec<TradeProposal>>)
+            }
         }
 
     }
@@ -1,10 +1,13 @@
-use cosmwasm_std::{Addr, StdError, Timestamp, BlockInfo};
+use cosmwasm_std::{Addr, Coin, StdError, Timestamp, BlockInfo, Uint128};
 use thiserror::Error;
 
 use group_admin::GroupAdminError;
 use manager::error::ManagementError;
+use shared::GoiError;
 use shared::player::PlayerInfo;
-use shared::utils::{MessageId, LeagueAddr, SeasonId, TeamAddr};
+use shared::league::LeagueTypes;
+use shared::trade::TradeStatus;
+use shared::utils::{MessageId, LeagueAddr, PlayerAddr, SeasonId, TeamAddr};
 
 #[derive(Error, Debug, PartialEq)]
 pub enum ContractError {
@@ -15,6 +18,9 @@ pub enum ContractError {
     #[error("{0}")]
     GroupAdminHooksError(#[from] GroupAdminError),
 
+    #[error("{0}")]
+    GoiError(#[from] GoiError),
+
     #[error("ManagementExecError")]
     ManagementExecError {management_error: ManagementError},
 
@@ -123,6 +129,346 @@ pub enum ContractError {
     InvitationNotFoundOrIncorrectSeason {  invite_message_id: MessageId, leave_league_at_end_of_season_id: SeasonId },
 
 
+    #[error("SeasonCapacityOutOfRange")]
+    SeasonCapacityOutOfRange { min_teams_allowed: u32, max_teams_allowed: u32 },
+
+
+    #[error("SeasonCapacityBelowAcceptedTeams")]
+    SeasonCapacityBelowAcceptedTeams { accepted_teams: u32 },
+
+
+    #[error("InvalidSeasonCapacityPolicy")]
+    InvalidSeasonCapacityPolicy { },
+
+
+    #[error("TooLateToUpdateSeason")]
+    TooLateToUpdateSeason { },
+
+
+    #[error("DivisionNotFound")]
+    DivisionNotFound { },
+
+
+    #[error("InvalidDivision")]
+    InvalidDivision { },
+
+
+    #[error("DivisionCapacityBelowAssignedTeams")]
+    DivisionCapacityBelowAssignedTeams { assigned_teams: u32 },
+
+
+    #[error("SeasonDivisionsAtCapacity")]
+    SeasonDivisionsAtCapacity { },
+
+
+    #[error("RegularSeasonNotComplete")]
+    RegularSeasonNotComplete { },
+
+
+    #[error("PlayoffsAlreadyStarted")]
+    PlayoffsAlreadyStarted { },
+
+
+    #[error("PlayoffsNotFound")]
+    PlayoffsNotFound { },
+
+
+    #[error("PlayoffsCompleted")]
+    PlayoffsCompleted { champion: Addr },
+
+
+    #[error("NotEnoughPlayoffTeams")]
+    NotEnoughPlayoffTeams { },
+
+
+    #[error("InvalidPlayoffResult")]
+    InvalidPlayoffResult { match_id: u32 },
+
+
+    #[error("NoPendingRescheduleNotice")]
+    NoPendingRescheduleNotice { },
+
+
+    #[error("InvalidSeasonTemplate")]
+    InvalidSeasonTemplate { },
+
+
+    #[error("SeasonTemplateNotFound")]
+    SeasonTemplateNotFound { },
+
+
+    #[error("SeasonRecurrenceComplete")]
+    SeasonRecurrenceComplete { },
+
+
+    #[error("PreviousSeasonNotFinished")]
+    PreviousSeasonNotFinished { season_id: SeasonId },
+
+
+    #[error("InvalidTrade")]
+    InvalidTrade { },
+
+
+    #[error("TradeFundsMismatch")]
+    TradeFundsMismatch {},
+
+
+    #[error("TradePlayerNotOnTeam")]
+    TradePlayerNotOnTeam { player: Addr, team: Addr },
+
+
+    #[error("TradeLockedDuringSeason")]
+    TradeLockedDuringSeason { team: Addr, season_id: SeasonId },
+
+
+    #[error("TradeNotFound")]
+    TradeNotFound { },
+
+
+    #[error("TradeNotOpen")]
+    TradeNotOpen { status: TradeStatus },
+
+
+    #[error("FreeAgentNotFound")]
+    FreeAgentNotFound { player: Addr },
+
+
+    #[error("NotPlayerOwner")]
+    NotPlayerOwner { sender: Addr },
+
+
+    #[error("PlayerAlreadyAssigned")]
+    PlayerAlreadyAssigned { player: Addr, team: Addr },
+
+
+    #[error("FreeAgentSigningNotApproved")]
+    FreeAgentSigningNotApproved { player: Addr },
+
+
+    #[error("IncorrectSigningFee")]
+    IncorrectSigningFee { expected: Coin },
+
+
+    #[error("InvalidDraft")]
+    InvalidDraft { },
+
+
+    #[error("DraftAlreadyExists")]
+    DraftAlreadyExists { },
+
+
+    #[error("DraftNotFound")]
+    DraftNotFound { },
+
+
+    #[error("DraftNotStarted")]
+    DraftNotStarted { start: Timestamp },
+
+
+    #[error("DraftComplete")]
+    DraftComplete { },
+
+
+    #[error("DraftPlayerUnavailable")]
+    DraftPlayerUnavailable { player: Addr },
+
+
+    #[error("DraftPlayerNotOptedIn")]
+    DraftPlayerNotOptedIn { player: Addr },
+
+
+    #[error("NotDraftPickOwner")]
+    NotDraftPickOwner { sender: Addr },
+
+
+    #[error("DraftPickNotFound")]
+    DraftPickNotFound { },
+
+
+    #[error("DraftPickAlreadyMade")]
+    DraftPickAlreadyMade { },
+
+
+    #[error("DraftLotteryNotDrawn")]
+    DraftLotteryNotDrawn { },
+
+
+    #[error("NotDraftTeam")]
+    NotDraftTeam { sender: Addr },
+
+
+    #[error("InvalidPlayerContract")]
+    InvalidPlayerContract { },
+
+
+    #[error("SalaryCapExceeded")]
+    SalaryCapExceeded { team: TeamAddr, cap: Coin, payroll: Uint128 },
+
+
+    #[error("SeasonNotInProgress")]
+    SeasonNotInProgress { },
+
+
+    #[error("SeasonEpisodesComplete")]
+    SeasonEpisodesComplete { episodes: u32 },
+
+
+    #[error("EpisodeNotDue")]
+    EpisodeNotDue { start: Timestamp },
+
+
+    #[error("PlayerRetired")]
+    PlayerRetired { player: Addr },
+
+
+    #[error("InvalidPlayerStatus")]
+    InvalidPlayerStatus { },
+
+
+    #[error("PlayerNotInLeague")]
+    PlayerNotInLeague { player: Addr },
+
+
+    #[error("FixtureNotFound")]
+    FixtureNotFound { },
+
+
+    #[error("InvalidFixture")]
+    InvalidFixture { },
+
+
+    #[error("FixtureCompleted")]
+    FixtureCompleted { },
+
+
+    #[error("NotFixtureParty")]
+    NotFixtureParty { sender: Addr },
+
+
+    #[error("SeedCommitPeriodEnded")]
+    SeedCommitPeriodEnded { },
+
+
+    #[error("SeedAlreadyCommitted")]
+    SeedAlreadyCommitted { },
+
+
+    #[error("SeedRevealNotOpen")]
+    SeedRevealNotOpen { },
+
+
+    #[error("InvalidSeedReveal")]
+    InvalidSeedReveal { },
+
+
+    #[error("FixtureNotReady")]
+    FixtureNotReady { },
+
+
+    #[error("LeagueTypeMismatch")]
+    LeagueTypeMismatch { league_type: LeagueTypes, team: Addr },
+
+
+    #[error("TypeAlreadyDeclared")]
+    TypeAlreadyDeclared {},
+
+
+    #[error("NotCoachingAttestor")]
+    NotCoachingAttestor { sender: Addr },
+
+
+    #[error("InvalidDisplayDetails")]
+    InvalidDisplayDetails {},
+
+
+    #[error("DisplayNotFound")]
+    DisplayNotFound {},
+
+
+    #[error("BroadcastNotFound")]
+    BroadcastNotFound {},
+
+
+    #[error("InvalidBroadcastSlot")]
+    InvalidBroadcastSlot {},
+
+
+    #[error("BroadcastSlotConflict")]
+    BroadcastSlotConflict { bookings: Vec<u64> },
+
+
+    #[error("BroadcastPaymentRequired")]
+    BroadcastPaymentRequired {},
+
+
+    #[error("InvalidBroadcastStatus")]
+    InvalidBroadcastStatus {},
+
+
+    #[error("BroadcastStarted")]
+    BroadcastStarted {},
+
+
+    #[error("BroadcastNotOver")]
+    BroadcastNotOver {},
+
+
+    #[error("AdNotFound")]
+    AdNotFound {},
+
+
+    #[error("InvalidAdContent")]
+    InvalidAdContent {},
+
+
+    #[error("AdPaymentRequired")]
+    AdPaymentRequired {},
+
+
+    #[error("AdSlotTaken")]
+    AdSlotTaken { ad_id: u64 },
+
+
+    #[error("InvalidAdStatus")]
+    InvalidAdStatus {},
+
+
+    #[error("AdNotAttestable")]
+    AdNotAttestable {},
+
+
+    #[error("AdPlacementNotOver")]
+    AdPlacementNotOver {},
+
+
+    #[error("InboxRecipientNotFound")]
+    InboxRecipientNotFound {},
+
+
+    #[error("InvalidInboxMessage")]
+    InvalidInboxMessage {},
+
+
+    #[error("InboxMessageNotFound")]
+    InboxMessageNotFound {},
+
+
+    #[error("InboxMessageExpired")]
+    InboxMessageExpired {},
+
+
+    #[error("InvalidLineup")]
+    InvalidLineup {},
+
+
+    #[error("LineupLocked")]
+    LineupLocked {},
+
+
+    #[error("LineupPlayersUnavailable")]
+    LineupPlayersUnavailable { players: Vec<PlayerAddr> },
+
+
     #[error("ErrorProcessingRequest")]
     ErrorProcessingRequest {  request: String},
 
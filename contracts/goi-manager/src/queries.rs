@@ -2,42 +2,19 @@ use std::collections::HashMap;
 
 use cosmwasm_std::{Addr, BlockInfo, Order, StdError, StdResult, Storage};
 use cosmwasm_std::Order::Ascending;
-use shared::messaging::{JoinSeasonRequestInfo, Message};
+use shared::division::{Division, DivisionGroup, TeamStandingItem};
+use shared::fixture::Fixture;
+use shared::messaging::{JoinSeasonRequestInfo, Message, MessageTypes, SeasonRescheduleInfo};
 
 
 use shared::player::PlayerInfo;
 use shared::rewards::{Reward, RewardTypes};
 use shared::season::Season;
 use shared::team::TeamInfo;
-use shared::utils::{AsseTypes_u8, LeagueAddr};
+use shared::utils::{AsseTypes_u8, LeagueAddr, SeasonId, TeamAddr};
 use shared::utils::general::AssetTypes;
 
-use crate::state::{join_season_requests, seasons, teams};
-
-pub fn query_player_name
-(player_items: Option<Vec<PlayerInfo>>, first_name: String,
- last_name: String) -> StdResult<Option<PlayerInfo>> {
-
-    match player_items {
-        None => Ok(None),
-        Some(pls) => {
-            let p_res: Vec<PlayerInfo> =
-                pls.into_iter().filter(|p|
-                    p.first_name == first_name &&
-                    p.last_name == last_name)
-                    .collect();
-            match p_res.len() > 0 {
-                false => { Ok(None)}
-                true => {
-                    Ok(Some(p_res[0].clone()))
-                }
-            }
-        }
-    }
-}
-
-
-
+use crate::state::{division_assignments, divisions, fixtures, join_season_requests, season_reschedule_notices, seasons, teams, SEASON_STANDINGS};
 
 pub fn query_rewards_by_type_and_address<'a>(state_rewards: &Option<HashMap<Addr, Vec<Reward>>>,
                                              search_address: Addr, reward_type: RewardTypes)
@@ -129,6 +106,25 @@ pub fn query_get_messages_from_item(store: &dyn Storage, item_addr: Addr, item_a
 }
 
 
+pub fn query_get_season_reschedule_notices(store: &dyn Storage, item_addr: Addr, item_asset_type: AssetTypes) -> Option<Vec<Message<SeasonRescheduleInfo>>>  {
+    let res: StdResult<Vec<_>> =
+        season_reschedule_notices().idx.
+            recipient.
+            prefix((item_asset_type.to_u8(), item_addr)).
+            range(store, None, None, Ascending).
+            collect();
+    match res {
+        Ok(items) => {
+            match items.len() > 0 {
+                true => Some(items.into_iter().map(|i| i.1).collect()),
+                false => None
+            }
+        }
+        Err(_) => None
+    }
+}
+
+
 pub fn query_get_league_teams(store: &dyn Storage, league_addr: LeagueAddr) -> Option<Vec<TeamInfo>>  {
     let res: Result<Vec<_>, _> =
         teams().idx.
@@ -303,3 +299,110 @@ pub fn query_check_for_season_date_range_conflicts(store: &dyn Storage, start_da
 }
 
 
+
+
+
+pub fn query_get_season_divisions(store: &dyn Storage, season_id: SeasonId) -> Option<Vec<Division>>  {
+    let res =
+        divisions().idx.
+            season.
+            prefix(season_id).
+            range(store, None, None, Ascending).
+            collect::<StdResult<Vec<_>>>();
+    match res {
+        Ok(items) => {
+            match items.len() > 0 {
+                true => Some(items.into_iter().map(|i| i.1).collect()),
+                false => None
+            }
+        }
+        Err(_) => None
+    }
+}
+
+
+//Teams accepted into a season, grouped by their assigned division.
+//Accepted teams without an assignment are returned under `division: None`.
+pub fn query_get_season_teams_by_division(store: &dyn Storage, season_id: SeasonId) -> StdResult<Vec<DivisionGroup<TeamAddr>>>  {
+    let accepted_teams: Vec<TeamAddr> =
+        join_season_requests().idx.
+            season_id.
+            prefix(season_id).
+            range(store, None, None, Ascending).
+            collect::<StdResult<Vec<_>>>()?.
+            into_iter().
+            filter(|i| i.1.data.status_type == MessageTypes::Accepted {}).
+            map(|i| i.1.delivery.from.address).
+            collect();
+
+    let mut assigned_teams: Vec<TeamAddr> = vec![];
+    let mut groups: Vec<DivisionGroup<TeamAddr>> = vec![];
+    for division in query_get_season_divisions(store, season_id).unwrap_or_default() {
+        let items: Vec<TeamAddr> =
+            division_assignments().idx.
+                division.
+                prefix(division.id).
+                range(store, None, None, Ascending).
+                collect::<StdResult<Vec<_>>>()?.
+                into_iter().
+                map(|i| i.1.team).
+                filter(|t| accepted_teams.contains(t)).
+                collect();
+        assigned_teams.extend(items.clone());
+        groups.push(DivisionGroup { division: Some(division), items });
+    }
+
+    let unassigned_teams: Vec<TeamAddr> =
+        accepted_teams.
+            into_iter().
+            filter(|t| !assigned_teams.contains(t)).
+            collect();
+    if unassigned_teams.len() > 0 {
+        groups.push(DivisionGroup { division: None, items: unassigned_teams });
+    }
+    Ok(groups)
+}
+
+
+//Standings sorted within each division by wins, then fewest losses
+pub fn query_get_season_standings_by_division(store: &dyn Storage, season_id: SeasonId) -> StdResult<Vec<DivisionGroup<TeamStandingItem>>>  {
+    let groups = query_get_season_teams_by_division(store, season_id)?;
+    let mut res: Vec<DivisionGroup<TeamStandingItem>> = vec![];
+    for group in groups {
+        let mut items: Vec<TeamStandingItem> = vec![];
+        for team in group.items {
+            let standing = SEASON_STANDINGS.may_load(store, (season_id, team.clone()))?.unwrap_or_default();
+            items.push(TeamStandingItem { team, standing });
+        }
+        items.sort_by(|a, b|
+            b.standing.wins.cmp(&a.standing.wins).
+                then(a.standing.losses.cmp(&b.standing.losses)));
+        res.push(DivisionGroup { division: group.division, items });
+    }
+    Ok(res)
+}
+
+
+//Cross-division fixtures show up under both teams' divisions.
+pub fn query_get_season_schedule_by_division(store: &dyn Storage, season_id: SeasonId) -> StdResult<Vec<DivisionGroup<Fixture>>>  {
+    let season_fixtures: Vec<Fixture> =
+        fixtures().idx.
+            season.
+            prefix(season_id).
+            range(store, None, None, Ascending).
+            map(|item| item.map(|(_, f)| f)).
+            collect::<StdResult<Vec<_>>>()?;
+
+    let groups = query_get_season_teams_by_division(store, season_id)?;
+    let mut res: Vec<DivisionGroup<Fixture>> = vec![];
+    for group in groups {
+        let items: Vec<Fixture> =
+            season_fixtures.
+                iter().
+                filter(|f| group.items.contains(&f.home) || group.items.contains(&f.away)).
+                cloned().
+                collect();
+        res.push(DivisionGroup { division: group.division, items });
+    }
+    Ok(res)
+}
@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, Coin, StdResult, Storage, Timestamp, Uint128};
+use cosmwasm_std::{Addr, Coin, Empty, Order, StdResult, Storage, Timestamp, Uint128};
 use cw4::TOTAL_KEY;
 use cw_controllers::{Admin, Hooks};
 use cw_storage_plus::{Item, SnapshotMap, Strategy, Map, UniqueIndex, MultiIndex, IndexList, Index, IndexedMap, PrimaryKey, Key};
@@ -12,20 +12,38 @@ use shared::league::{LeagueInfo, SeasonActiveStatus};
 use shared::team::{ TeamInfo};
 
 use shared::utils::general::{AssetTypes, merge_strings};
-use shared::utils::{FName, PlayerAddr, TeamAddr, PlayerTeamAddr, TeamPlayerAddr, OwnerAddr, LNameFNameString, LName, ForSaleStatus, AssetSaleItems, LeagueAddr, SeasonId, SeasonActiveStatusValue, BlockChainTimeValue, StartDate, EndDate, MessageId, AsseTypes_u8, InviteAccepted, SeasonDepositId};
+use shared::utils::{FName, PlayerAddr, TeamAddr, PlayerTeamAddr, TeamPlayerAddr, OwnerAddr, LNameFNameString, LName, ForSaleStatus, AssetSaleItems, LeagueAddr, SeasonId, SeasonActiveStatusValue, BlockChainTimeValue, StartDate, EndDate, MessageId, AsseTypes_u8, InviteAccepted, SeasonDepositId, DivisionId};
 
 use shared::link_team_player::LinkTeamPlayer;
 use shared::manage::ManagedStatus;
-use shared::messaging::{JoinSeasonRequestInfo, Message};
-use shared::player::PlayerInfoPacket;
-use shared::season::{Season, SeasonLedger};
+use shared::messaging::{InboxMessageInfo, JoinSeasonRequestInfo, Message, SeasonRescheduleInfo};
+use shared::player::{CareerSummary, FreeAgent, normalized_player_name, PlayerInfoPacket, PlayerStatus};
+use shared::season::{LeagueSeasonTemplate, Season, SeasonCapacityPolicy, SeasonLedger};
+use shared::division::{Division, DivisionTeamAssignment, TeamStanding};
+use shared::playoffs::Playoffs;
+use shared::draft::Draft;
+use shared::player_contract::PlayerContract;
+use shared::trade::TradeProposal;
+use shared::fixture::{Fixture, GameDayLineup};
+use shared::display::DisplayInfo;
+use shared::broadcast::BroadcastBooking;
+use shared::advertising::AdSlot;
+use shared::stats::{PlayerStats, TeamStats};
+use shared::leaderboard::{HallOfFameEntry, TeamRecord};
 
 
 
-pub const PLAYER_NAMES: Item<PlayerInfoPacket> = Item::new("teams_players_names");
 //pub const ASSETS_FOR_SALE: Item<AssetSaleItems> = Item::new("TEAMS_FOR_SALE");
 pub const INDEX_COUNTER: Item<u64> = Item::new("index_counter");
 
+pub const SEASON_CAPACITY_POLICY: Item<SeasonCapacityPolicy> = Item::new("season_capacity_policy");
+
+//contracts instantiated before the policy existed
+//fall back to the default (MIN_TEAMS_ALLOWED..=MAX_TEAMS_ALLOWED)
+pub fn get_season_capacity_policy(store: &dyn Storage) -> StdResult<SeasonCapacityPolicy> {
+    Ok(SEASON_CAPACITY_POLICY.may_load(store)?.unwrap_or_default())
+}
+
 pub fn next_index_counter(store: &mut dyn Storage) -> StdResult<u64> {
     let id: u64 = INDEX_COUNTER.may_load(store)?.unwrap_or_default() + 1;
     INDEX_COUNTER.save(store, &id)?;
@@ -169,6 +187,71 @@ pub fn seasons<'a>()-> IndexedMap<'a, SeasonId, Season, SeasonIndexes<'a>> {
 
 
 
+//   DIVISIONS
+pub struct DivisionIndexes<'a>{
+    pub identifier: UniqueIndex<'a, DivisionId, Division>,
+    pub season: MultiIndex<'a, SeasonId, Division, DivisionId>,
+    pub league: MultiIndex<'a, LeagueAddr, Division, DivisionId>,
+}
+
+impl<'a> IndexList<Division> for DivisionIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item=&'_ dyn Index<Division>> + '_> {
+        let v: Vec<&dyn Index<Division>> = vec![&self.identifier, &self.season, &self.league];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn divisions<'a>()-> IndexedMap<'a, DivisionId, Division, DivisionIndexes<'a>> {
+    let indexes = DivisionIndexes {
+        identifier: UniqueIndex::new(|d| (d.clone().id), "DIVISION"),
+        season: MultiIndex::new(|t, key| key.clone().season_id, "DIVISION", "DIVISION_SEASON"),
+        league: MultiIndex::new(|t, key| key.clone().league, "DIVISION", "DIVISION_LEAGUE"),
+    };
+    IndexedMap::new("DIVISION", indexes)
+}
+
+
+pub struct DivisionAssignmentIndexes<'a>{
+    pub season_team: UniqueIndex<'a, (SeasonId, TeamAddr), DivisionTeamAssignment>,
+    pub season: MultiIndex<'a, SeasonId, DivisionTeamAssignment, u64>,
+    pub division: MultiIndex<'a, DivisionId, DivisionTeamAssignment, u64>,
+}
+
+impl<'a> IndexList<DivisionTeamAssignment> for DivisionAssignmentIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item=&'_ dyn Index<DivisionTeamAssignment>> + '_> {
+        let v: Vec<&dyn Index<DivisionTeamAssignment>> = vec![&self.season_team, &self.season, &self.division];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn division_assignments<'a>()-> IndexedMap<'a, u64, DivisionTeamAssignment, DivisionAssignmentIndexes<'a>> {
+    let indexes = DivisionAssignmentIndexes {
+        season_team: UniqueIndex::new(|d| (d.season_id, d.team.clone()), "DIVISION_ASSIGNMENT_SEASON_TEAM"),
+        season: MultiIndex::new(|t, key| key.clone().season_id, "DIVISION_ASSIGNMENT", "DIVISION_ASSIGNMENT_SEASON"),
+        division: MultiIndex::new(|t, key| key.clone().division_id, "DIVISION_ASSIGNMENT", "DIVISION_ASSIGNMENT_DIVISION"),
+    };
+    IndexedMap::new("DIVISION_ASSIGNMENT", indexes)
+}
+
+
+//Win/loss record of each team accepted into a season
+pub const SEASON_STANDINGS: Map<(SeasonId, TeamAddr), TeamStanding> = Map::new("season_standings");
+
+pub const PLAYOFFS: Map<SeasonId, Playoffs> = Map::new("playoffs");
+
+pub const DRAFTS: Map<SeasonId, Draft> = Map::new("drafts");
+//players waiting in a draft pool; they can't be added to a roster any other way
+pub const DRAFT_POOL_PLAYERS: Map<&PlayerAddr, SeasonId> = Map::new("draft_pool_players");
+//normalized names of draft pool players; nobody else can take them until the player leaves the pool
+pub const DRAFT_POOL_NAMES: Map<String, PlayerAddr> = Map::new("draft_pool_names");
+//season each player's owner has made it available to; consumed when the draft is created
+pub const DRAFT_OPT_INS: Map<&PlayerAddr, SeasonId> = Map::new("draft_opt_ins");
+
+pub const SEASON_TEMPLATES: Map<&Addr, LeagueSeasonTemplate> = Map::new("season_templates");
+
+
+
+
 // SEASON DEPOSITS
 
 
@@ -290,7 +373,7 @@ pub fn join_season_requests<'a>() -> IndexedMap<'a, MessageId, Message<JoinSeaso
                                             (key.clone().data.season_id,
                                              key.clone().delivery.from.address,
                                              key.clone().delivery.to.address),
-                                        "INVITE", "INVITE_SEASON_ID"),
+                                        "INVITE", "INVITE_SEASON_FROM_TO"),
         from_to: MultiIndex::new(|t, key|
                                      {
                                          (key.clone().delivery.from.address, key.clone().delivery.to.address)
@@ -301,6 +384,380 @@ pub fn join_season_requests<'a>() -> IndexedMap<'a, MessageId, Message<JoinSeaso
 }
 
 
+//`season_from_to` used to share the INVITE_SEASON_ID namespace with `season_id`,
+//so that namespace holds both key shapes. Clears both index namespaces and
+//re-indexes every request; safe to run more than once.
+pub fn rebuild_join_season_request_indexes(store: &mut dyn Storage) -> StdResult<u32> {
+    for namespace in ["INVITE_SEASON_ID", "INVITE_SEASON_FROM_TO"] {
+        let index: Map<Vec<u8>, Empty> = Map::new(namespace);
+        let keys: Vec<Vec<u8>> = index.keys_raw(store, None, None, Order::Ascending).collect();
+        for key in keys {
+            index.remove(store, key);
+        }
+    }
+    let requests: Vec<Message<JoinSeasonRequestInfo>> = join_season_requests()
+        .range(store, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, m)| m))
+        .collect::<StdResult<_>>()?;
+    for request in &requests {
+        join_season_requests().save(store, request.id, request)?;
+    }
+    Ok(requests.len() as u32)
+}
+
+
+//  SEASON RESCHEDULE NOTICES
+
+pub struct SeasonNoticeIndexes<'a>{
+    pub recipient: MultiIndex<'a, (AsseTypes_u8, Addr), Message<SeasonRescheduleInfo>, MessageId>,
+    pub season_recipient: MultiIndex<'a, (SeasonId, Addr), Message<SeasonRescheduleInfo>, MessageId>,
+}
+
+impl<'a> IndexList<Message<SeasonRescheduleInfo>> for SeasonNoticeIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item=&'_ dyn Index<Message<SeasonRescheduleInfo>>> + '_> {
+        let v: Vec<&dyn Index<Message<SeasonRescheduleInfo>>> = vec![&self.recipient, &self.season_recipient];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn season_reschedule_notices<'a>() -> IndexedMap<'a, MessageId, Message<SeasonRescheduleInfo>, SeasonNoticeIndexes<'a>> {
+    let indexes = SeasonNoticeIndexes {
+        recipient: MultiIndex::new(|t, key| {
+            (key.clone().delivery.to.asset_type.to_u8(), key.clone().delivery.to.address)
+        }, "SEASON_NOTICE", "SEASON_NOTICE_RECIPIENT"),
+        season_recipient: MultiIndex::new(|t, key|
+                                              (key.clone().data.season_id, key.clone().delivery.to.address),
+                                          "SEASON_NOTICE", "SEASON_NOTICE_SEASON_RECIPIENT"),
+    };
+    IndexedMap::new("SEASON_NOTICE", indexes)
+}
+
+
+//  INBOX
+//  Messages between managed assets
+
+pub struct InboxIndexes<'a>{
+    pub recipient: MultiIndex<'a, Addr, Message<InboxMessageInfo>, MessageId>,
+    pub sender: MultiIndex<'a, Addr, Message<InboxMessageInfo>, MessageId>,
+    pub thread: MultiIndex<'a, MessageId, Message<InboxMessageInfo>, MessageId>,
+}
+
+impl<'a> IndexList<Message<InboxMessageInfo>> for InboxIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item=&'_ dyn Index<Message<InboxMessageInfo>>> + '_> {
+        let v: Vec<&dyn Index<Message<InboxMessageInfo>>> = vec![&self.recipient, &self.sender, &self.thread];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn inbox_messages<'a>() -> IndexedMap<'a, MessageId, Message<InboxMessageInfo>, InboxIndexes<'a>> {
+    let indexes = InboxIndexes {
+        recipient: MultiIndex::new(|t, key| key.delivery.to.address.clone(),
+                                   "INBOX", "INBOX_RECIPIENT"),
+        sender: MultiIndex::new(|t, key| key.delivery.from.address.clone(),
+                                "INBOX", "INBOX_SENDER"),
+        thread: MultiIndex::new(|t, key| key.data.thread,
+                                "INBOX", "INBOX_THREAD"),
+    };
+    IndexedMap::new("INBOX", indexes)
+}
+
+
+//  PLAYER TRADES
+
+pub struct TradeIndexes<'a>{
+    pub proposing_team: MultiIndex<'a, TeamAddr, TradeProposal, u64>,
+    pub counterparty_team: MultiIndex<'a, TeamAddr, TradeProposal, u64>,
+}
+
+impl<'a> IndexList<TradeProposal> for TradeIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item=&'_ dyn Index<TradeProposal>> + '_> {
+        let v: Vec<&dyn Index<TradeProposal>> = vec![&self.proposing_team, &self.counterparty_team];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn trade_proposals<'a>() -> IndexedMap<'a, u64, TradeProposal, TradeIndexes<'a>> {
+    let indexes = TradeIndexes {
+        proposing_team: MultiIndex::new(|t, key| key.proposing_team.clone(),
+                                        "TRADE_PROPOSALS", "TRADE_PROPOSALS_PROPOSING_TEAM"),
+        counterparty_team: MultiIndex::new(|t, key| key.counterparty_team.clone(),
+                                           "TRADE_PROPOSALS", "TRADE_PROPOSALS_COUNTERPARTY_TEAM"),
+    };
+    IndexedMap::new("TRADE_PROPOSALS", indexes)
+}
+
+
+//  PLAYERS
+
+//replaced by `players()`; only read by `migrate_player_names`
+pub const PLAYER_NAMES: Item<PlayerInfoPacket> = Item::new("teams_players_names");
+
+//team index key for players that aren't on a roster
+pub const UNASSIGNED_PLAYER_TEAM: &str = "[-|---0]";
+
+pub struct PlayerIndexes<'a>{
+    pub name: UniqueIndex<'a, String, PlayerInfo, PlayerAddr>,
+    pub team: MultiIndex<'a, TeamAddr, PlayerInfo, PlayerAddr>,
+    pub position: MultiIndex<'a, u8, PlayerInfo, PlayerAddr>,
+}
+
+impl<'a> IndexList<PlayerInfo> for PlayerIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item=&'_ dyn Index<PlayerInfo>> + '_> {
+        let v: Vec<&dyn Index<PlayerInfo>> = vec![&self.name, &self.team, &self.position];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn players<'a>() -> IndexedMap<'a, PlayerAddr, PlayerInfo, PlayerIndexes<'a>> {
+    let indexes = PlayerIndexes {
+        name: UniqueIndex::new(|d| normalized_player_name(&d.first_name, &d.last_name), "PLAYERS_NAME"),
+        team: MultiIndex::new(|t, key|
+                                  {
+                                      match key.assigned_team_address.clone() {
+                                          Some(team) => team,
+                                          None => Addr::unchecked(UNASSIGNED_PLAYER_TEAM)
+                                      }
+                                  }, "PLAYERS", "PLAYERS_TEAM"),
+        position: MultiIndex::new(|t, key| key.position.to_u8(),
+                                  "PLAYERS", "PLAYERS_POSITION"),
+    };
+    IndexedMap::new("PLAYERS", indexes)
+}
+
+pub fn load_player_by_name(store: &dyn Storage, first_name: &str, last_name: &str) -> StdResult<Option<PlayerInfo>> {
+    Ok(players().idx.name
+        .item(store, normalized_player_name(first_name, last_name))?
+        .map(|(_, p)| p))
+}
+
+//Moves a tracked player to `team` (or off every roster); untracked players are ignored
+pub fn assign_player_team(store: &mut dyn Storage, player: &PlayerAddr, team: Option<TeamAddr>) -> StdResult<()> {
+    match players().may_load(store, player.clone())? {
+        Some(p) => players().save(store, player.clone(), &PlayerInfo { assigned_team_address: team, ..p }),
+        None => Ok(())
+    }
+}
+
+//One-time move of the PLAYER_NAMES packet into `players()`. The packet never
+//enforced case-folded names, so a later entry whose name is already taken is
+//kept under a numbered last name ("Rivers (2)") with its team assignment and
+//returned for the admin to sort out.
+pub fn migrate_player_names(store: &mut dyn Storage) -> StdResult<(u32, Vec<PlayerAddr>)> {
+    let packet = match PLAYER_NAMES.may_load(store)? {
+        None => return Ok((0, vec![])),
+        Some(p) => p
+    };
+    let mut migrated = 0u32;
+    let mut renamed: Vec<PlayerAddr> = vec![];
+    for mut p in packet.items {
+        match load_player_by_name(store, &p.first_name, &p.last_name)? {
+            Some(existing) if existing.address != p.address => {
+                let base = p.last_name.trim().to_string();
+                let mut suffix = 2u32;
+                p.last_name = format!("{} ({})", base, suffix);
+                while load_player_by_name(store, &p.first_name, &p.last_name)?.is_some() {
+                    suffix += 1;
+                    p.last_name = format!("{} ({})", base, suffix);
+                }
+                renamed.push(p.address.clone());
+            }
+            _ => ()
+        }
+        players().save(store, p.address.clone(), &p)?;
+        migrated += 1;
+    }
+    PLAYER_NAMES.remove(store);
+    Ok((migrated, renamed))
+}
+
+//True when the name belongs to another tracked player or is held
+//by a player waiting in a draft pool
+pub fn is_player_name_taken(store: &dyn Storage, player: &PlayerAddr, first_name: &str, last_name: &str) -> StdResult<bool> {
+    let name = normalized_player_name(first_name, last_name);
+    let tracked = players().idx.name.item(store, name.clone())?.map(|(_, p)| p.address);
+    let reserved = DRAFT_POOL_NAMES.may_load(store, name)?;
+    Ok([tracked, reserved].iter().flatten().any(|a| a != player))
+}
+
+
+//mirrors each player contract's status; players without an entry are active
+pub const PLAYER_STATUSES: Map<&PlayerAddr, PlayerStatus> = Map::new("player_statuses");
+pub const CAREER_SUMMARIES: Map<&PlayerAddr, CareerSummary> = Map::new("career_summaries");
+
+pub fn get_player_status(store: &dyn Storage, player: &PlayerAddr) -> StdResult<PlayerStatus> {
+    Ok(PLAYER_STATUSES.may_load(store, player)?.unwrap_or_default())
+}
+
+
+//  PLAYER CONTRACTS
+
+pub const SALARY_CAPS: Map<&LeagueAddr, Coin> = Map::new("salary_caps");
+
+pub struct PlayerContractIndexes<'a>{
+    pub team: MultiIndex<'a, TeamAddr, PlayerContract, PlayerAddr>,
+}
+
+impl<'a> IndexList<PlayerContract> for PlayerContractIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item=&'_ dyn Index<PlayerContract>> + '_> {
+        let v: Vec<&dyn Index<PlayerContract>> = vec![&self.team];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn player_contracts<'a>() -> IndexedMap<'a, PlayerAddr, PlayerContract, PlayerContractIndexes<'a>> {
+    let indexes = PlayerContractIndexes {
+        team: MultiIndex::new(|t, key| key.team.clone(),
+                              "PLAYER_CONTRACTS", "PLAYER_CONTRACTS_TEAM"),
+    };
+    IndexedMap::new("PLAYER_CONTRACTS", indexes)
+}
+
+
+//  FREE AGENTS
+
+pub struct FreeAgentIndexes<'a>{
+    pub position: MultiIndex<'a, u8, FreeAgent, PlayerAddr>,
+}
+
+impl<'a> IndexList<FreeAgent> for FreeAgentIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item=&'_ dyn Index<FreeAgent>> + '_> {
+        let v: Vec<&dyn Index<FreeAgent>> = vec![&self.position];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn free_agents<'a>() -> IndexedMap<'a, PlayerAddr, FreeAgent, FreeAgentIndexes<'a>> {
+    let indexes = FreeAgentIndexes {
+        position: MultiIndex::new(|t, key| key.player.position.to_u8(),
+                                  "FREE_AGENTS", "FREE_AGENTS_POSITION"),
+    };
+    IndexedMap::new("FREE_AGENTS", indexes)
+}
+
+
+//  FIXTURES
+
+pub struct FixtureIndexes<'a>{
+    pub season: MultiIndex<'a, SeasonId, Fixture, u64>,
+}
+
+impl<'a> IndexList<Fixture> for FixtureIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item=&'_ dyn Index<Fixture>> + '_> {
+        let v: Vec<&dyn Index<Fixture>> = vec![&self.season];
+        Box::new(v.into_iter())
+    }
+}
+
+//accumulated from played fixtures
+pub const PLAYER_STATS: Map<(&PlayerAddr, SeasonId), PlayerStats> = Map::new("player_stats");
+pub const TEAM_STATS: Map<(&TeamAddr, SeasonId), TeamStats> = Map::new("team_stats");
+
+//submitted game-day lineups by (team, season, episode); a team without a lineup
+//for an episode plays its latest earlier one
+pub const LINEUPS: Map<(&TeamAddr, SeasonId, u32), GameDayLineup> = Map::new("lineups");
+//seconds before a fixture's commit deadline that lineups lock, per league
+pub const LINEUP_LOCKS: Map<&LeagueAddr, u64> = Map::new("lineup_locks");
+
+//managed displays by contract address, with their latest reported heartbeat
+pub const DISPLAYS: Map<&Addr, DisplayInfo> = Map::new("displays");
+
+
+pub struct BroadcastIndexes<'a>{
+    //a display's calendar, ordered by slot start
+    pub display: MultiIndex<'a, (Addr, u64), BroadcastBooking, u64>,
+    pub league: MultiIndex<'a, LeagueAddr, BroadcastBooking, u64>,
+}
+
+impl<'a> IndexList<BroadcastBooking> for BroadcastIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item=&'_ dyn Index<BroadcastBooking>> + '_> {
+        let v: Vec<&dyn Index<BroadcastBooking>> = vec![&self.display, &self.league];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn broadcasts<'a>() -> IndexedMap<'a, u64, BroadcastBooking, BroadcastIndexes<'a>> {
+    let indexes = BroadcastIndexes {
+        display: MultiIndex::new(|t, key| (key.display.clone(), key.start.seconds()),
+                                 "BROADCASTS", "BROADCASTS_DISPLAY"),
+        league: MultiIndex::new(|t, key| key.league.clone(),
+                                "BROADCASTS", "BROADCASTS_LEAGUE"),
+    };
+    IndexedMap::new("BROADCASTS", indexes)
+}
+
+
+pub struct AdIndexes<'a>{
+    pub approver: MultiIndex<'a, Addr, AdSlot, u64>,
+    pub sponsor: MultiIndex<'a, Addr, AdSlot, u64>,
+}
+
+impl<'a> IndexList<AdSlot> for AdIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item=&'_ dyn Index<AdSlot>> + '_> {
+        let v: Vec<&dyn Index<AdSlot>> = vec![&self.approver, &self.sponsor];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn ads<'a>() -> IndexedMap<'a, u64, AdSlot, AdIndexes<'a>> {
+    let indexes = AdIndexes {
+        approver: MultiIndex::new(|t, key| key.approver.clone(),
+                                  "ADS", "ADS_APPROVER"),
+        sponsor: MultiIndex::new(|t, key| key.sponsor.clone(),
+                                 "ADS", "ADS_SPONSOR"),
+    };
+    IndexedMap::new("ADS", indexes)
+}
+
+pub fn fixtures<'a>() -> IndexedMap<'a, u64, Fixture, FixtureIndexes<'a>> {
+    let indexes = FixtureIndexes {
+        season: MultiIndex::new(|t, key| key.season_id,
+                                "FIXTURES", "FIXTURES_SEASON"),
+    };
+    IndexedMap::new("FIXTURES", indexes)
+}
+
+
+//  LEADERBOARDS
+//  All-time records are kept per league type (`LeagueTypes::get_u8`). Each board
+//  is a key-only map sorted by (board, score, address) that's rewritten whenever
+//  a record changes, so a page is read straight off the index.
+
+pub const TEAM_RECORDS: Map<(&TeamAddr, u8), TeamRecord> = Map::new("team_records");
+pub const TEAM_WIN_PCT_BOARD: Map<(u8, u64, &TeamAddr), ()> = Map::new("team_win_pct_board");
+pub const TEAM_STREAK_BOARD: Map<(u8, u32, &TeamAddr), ()> = Map::new("team_streak_board");
+pub const PLAYER_CAREER_STATS: Map<(&PlayerAddr, u8), PlayerStats> = Map::new("player_career_stats");
+//only non-zero values are on a board, see `player_stat_board`
+pub const PLAYER_STAT_BOARD: Map<(u16, i64, &PlayerAddr), ()> = Map::new("player_stat_board");
+
+//win percentage first, then games played
+pub fn win_pct_board_score(record: &TeamRecord) -> u64 {
+    (record.win_percentage() as u64) << 32 | record.games() as u64
+}
+
+pub fn player_stat_board(league_type: u8, category: u8) -> u16 {
+    (league_type as u16) << 8 | category as u16
+}
+
+pub struct HallOfFameIndexes<'a>{
+    pub league_type: MultiIndex<'a, u8, HallOfFameEntry, SeasonId>,
+}
+
+impl<'a> IndexList<HallOfFameEntry> for HallOfFameIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item=&'_ dyn Index<HallOfFameEntry>> + '_> {
+        let v: Vec<&dyn Index<HallOfFameEntry>> = vec![&self.league_type];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn hall_of_fame<'a>() -> IndexedMap<'a, SeasonId, HallOfFameEntry, HallOfFameIndexes<'a>> {
+    let indexes = HallOfFameIndexes {
+        league_type: MultiIndex::new(|t, key| key.league_type.get_u8(),
+                                     "HALL_OF_FAME", "HALL_OF_FAME_LEAGUE_TYPE"),
+    };
+    IndexedMap::new("HALL_OF_FAME", indexes)
+}
+
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Config {
     pub native_denom: String,
@@ -321,3 +778,94 @@ pub const MEMBERS: SnapshotMap<&Addr, u64> = SnapshotMap::new(
     cw4::MEMBERS_CHANGELOG,
     Strategy::EveryBlock,
 );
+
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::Order;
+    use cosmwasm_std::testing::MockStorage;
+    use shared::messaging::{DeliveryInfo, DeliveryPacket, MessageTypes};
+    use shared::player_attributes::Positions;
+
+    use super::*;
+
+    fn player(addr: &str, first_name: &str, team: Option<&str>) -> PlayerInfo {
+        PlayerInfo {
+            address: Addr::unchecked(addr),
+            first_name: first_name.to_string(),
+            last_name: "Rivers".to_string(),
+            position: Positions::QB,
+            assigned_team_address: team.map(Addr::unchecked)
+        }
+    }
+
+    #[test]
+    fn migrates_player_names_packet() {
+        let mut store = MockStorage::new();
+        PLAYER_NAMES.save(&mut store, &PlayerInfoPacket { items: vec![
+            player("p1", "Sam", Some("team")),
+            player("p2", "Alex", None),
+            //only differs by case from p1
+            player("p3", "SAM", Some("team"))
+        ] }).unwrap();
+
+        assert_eq!((3, vec![Addr::unchecked("p3")]), migrate_player_names(&mut store).unwrap());
+        assert_eq!(None, PLAYER_NAMES.may_load(&store).unwrap());
+        assert_eq!(Some(player("p1", "Sam", Some("team"))), load_player_by_name(&store, " sam", "RIVERS").unwrap());
+        //the duplicate keeps its team under a numbered last name
+        assert_eq!(Some(PlayerInfo { last_name: "Rivers (2)".to_string(), ..player("p3", "SAM", Some("team")) }),
+                   load_player_by_name(&store, "sam", "rivers (2)").unwrap());
+        let team_players: Vec<PlayerAddr> =
+            players().idx.team.prefix(Addr::unchecked("team"))
+                .keys(&store, None, None, Order::Ascending)
+                .collect::<StdResult<_>>().unwrap();
+        assert_eq!(vec![Addr::unchecked("p1"), Addr::unchecked("p3")], team_players);
+
+        //running it again is a no-op
+        assert_eq!((0, vec![]), migrate_player_names(&mut store).unwrap());
+    }
+
+
+    fn join_request(id: MessageId, season_id: SeasonId, team: &str) -> Message<JoinSeasonRequestInfo> {
+        Message {
+            id,
+            updated: Timestamp::from_seconds(0),
+            created: Timestamp::from_seconds(0),
+            delivery: DeliveryInfo {
+                to: DeliveryPacket { asset_type: AssetTypes::League, address: Addr::unchecked("league") },
+                from: DeliveryPacket { asset_type: AssetTypes::Team, address: Addr::unchecked(team) }
+            },
+            data: JoinSeasonRequestInfo { status_type: MessageTypes::Accepted {}, season_id },
+            notes: vec![]
+        }
+    }
+
+    #[test]
+    fn rebuilds_shared_season_index_namespace() {
+        let mut store = MockStorage::new();
+        //the old layout: `season_from_to` entries under the season id namespace
+        let old_season_from_to: MultiIndex<(SeasonId, Addr, Addr), Message<JoinSeasonRequestInfo>, MessageId> =
+            MultiIndex::new(|_, m| (m.data.season_id, m.delivery.from.address.clone(), m.delivery.to.address.clone()),
+                            "INVITE", "INVITE_SEASON_ID");
+        for request in [join_request(1, 7, "team1"), join_request(2, 7, "team2"), join_request(3, 8, "team1")] {
+            join_season_requests().save(&mut store, request.id, &request).unwrap();
+            old_season_from_to.save(&mut store, &request.id.joined_key(), &request).unwrap();
+        }
+        let season_from_to = |store: &MockStorage| join_season_requests().idx.season_from_to
+            .prefix((7, Addr::unchecked("team1"), Addr::unchecked("league")))
+            .keys(store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<MessageId>>>().unwrap();
+        assert_eq!(vec![1u64], season_from_to(&store));
+
+        assert_eq!(3, rebuild_join_season_request_indexes(&mut store).unwrap());
+        assert_eq!(3, rebuild_join_season_request_indexes(&mut store).unwrap());
+
+        let season_requests: Vec<MessageId> = join_season_requests().idx.season_id.prefix(7)
+            .keys(&store, None, None, Order::Ascending)
+            .collect::<StdResult<_>>().unwrap();
+        assert_eq!(vec![1u64, 2], season_requests);
+        assert_eq!(vec![1u64], season_from_to(&store));
+        let leftover: Map<Vec<u8>, Empty> = Map::new("INVITE_SEASON_ID");
+        assert_eq!(3, leftover.keys_raw(&store, None, None, Order::Ascending).count());
+    }
+}
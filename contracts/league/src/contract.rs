@@ -1,6 +1,6 @@
 
 use std::borrow::BorrowMut;
-use cosmwasm_std::{Addr, Api, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult, to_binary, WasmMsg, CosmosMsg, SubMsg, ReplyOn, Coin, Timestamp};
+use cosmwasm_std::{Addr, Api, Binary, from_binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult, to_binary, WasmMsg, CosmosMsg, SubMsg, ReplyOn, Coin, Timestamp, BankMsg, Order, Uint128};
 #[cfg(not
 (feature = "library"))]
 use cosmwasm_std::entry_point;
@@ -14,10 +14,14 @@ use group_admin::GroupAdminError;
 use group_admin::service::list_members;
 use managed::ManagedServiceError;
 use managed::queries::query_manageable_info;
+use managed::governance::{execute_propose, execute_update_governance_config, execute_vote, get_governance_config,
+                           query_list_proposals, query_proposal, query_vote, take_passed_proposal_msg};
+use managed::shares::query_share_listings;
 
 use saleable::queries::query_saleable_info;
 use shared::goi_manager::ExecuteMsg::AddPlayersToTeam;
-use shared::goi_manager::{get_minters, on_successful_buy, on_successful_forsale_update, on_successful_init_processing, send_add_season_msg_to_goi_manager, send_add_team_to_league_msg_to_goi_manager, update_messaging_item_msg_to_goi_manager};
+use shared::goi_manager::{GoiManagerQueryMsg, get_minters, send_inbox_message_msg_to_goi_manager, send_mark_inbox_message_read_msg_to_goi_manager, send_declare_league_type_msg_to_goi_manager, on_successful_buy, on_successful_forsale_update, on_successful_init_processing, send_add_season_msg_to_goi_manager, send_add_team_to_league_msg_to_goi_manager, send_update_season_capacity_msg_to_goi_manager, send_add_division_msg_to_goi_manager, send_update_division_msg_to_goi_manager, send_start_playoffs_msg_to_goi_manager, send_create_draft_msg_to_goi_manager, send_set_salary_cap_msg_to_goi_manager, send_set_lineup_lock_msg_to_goi_manager, send_advance_episode_msg_to_goi_manager, send_set_player_status_msg_to_goi_manager, send_schedule_fixture_msg_to_goi_manager, send_request_broadcast_msg_to_goi_manager, send_cancel_broadcast_msg_to_goi_manager, send_respond_to_ad_msg_to_goi_manager, send_attest_ad_aired_msg_to_goi_manager, send_commit_fixture_seed_msg_to_goi_manager, send_reveal_fixture_seed_msg_to_goi_manager, send_submit_playoff_result_msg_to_goi_manager, send_reschedule_season_msg_to_goi_manager, send_set_season_template_msg_to_goi_manager, update_messaging_item_msg_to_goi_manager};
+use shared::dividends::{dividend_payout_messages, split_by_weight, total_of_shares};
 use shared::league::{LeagueInfo, set_start_and_end_date};
 
 use shared::manage::Manageable;
@@ -26,14 +30,14 @@ use shared::player_attributes::{PlayerAttributes, SideOfBall};
 use shared::query_response_info::{InfoManagedResponse, NameResponse};
 use shared::saleable::Saleable;
 use shared::season::{SeasonModelData, Season};
-use shared::utils::BlockTime;
+use shared::utils::{BlockTime, DEFAULT_SEASON_EPISODES};
 use shared::utils::general::AssetTypes;
 
 use crate::error::LeagueError;
 use crate::LeagueError::Unauthorized;
 use crate::msg::{ExecuteMsg,
                  QueryMsg, InstantiateLeagueMsg};
-use crate::state::{ADMIN, HOOKS, MANAGEABLE_SERVICE, SALEABLE_SERVICE, STATE};
+use crate::state::{ADMIN, DIVIDEND_CLAIMS, HOOKS, MANAGEABLE_SERVICE, SALEABLE_SERVICE, SEASONS, STATE};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:team";
@@ -56,7 +60,7 @@ pub fn instantiate(
                 address: _env.clone().contract.address,
                 owner: info.sender.clone(),
                 name: msg.name.clone(),
-                league_type: Default::default(),
+                league_type: msg.league_type.clone(),
                 created:  BlockTime{
                     height: _env.block.height.clone(),
                     time: _env.block.time.clone(),
@@ -74,7 +78,10 @@ pub fn instantiate(
                 }, Some(SALEABLE_SERVICE), Some(msg.admin.clone()), msg.price, msg.for_sale, Some(msg.name.clone()),  Some(on_successful_init_processing));
             match init_res {
                 Ok(r) => {
-                    Ok(r)
+                    match msg.managing_contract {
+                        Some(mc) => Ok(send_declare_league_type_msg_to_goi_manager(msg.league_type, mc, r)),
+                        None => Ok(r)
+                    }
                 }
                 Err(e) => {
                     Err(LeagueError::ManagableServiceError(e))
@@ -89,7 +96,7 @@ pub fn instantiate(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
-    deps: DepsMut,
+    mut deps: DepsMut,
     _env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
@@ -137,7 +144,8 @@ pub fn execute(
                             end_date: match season_model.end_date.data { Some(t) => t, None => Timestamp::default()},
                             access_type: season_model.access_type.data,
                             status: season_model.status.data,
-                            max_teams_allowed: season_model.max_teams_allowed.data };
+                            max_teams_allowed: season_model.max_teams_allowed.data,
+                            episodes: season_model.episodes.data.unwrap_or(DEFAULT_SEASON_EPISODES) };
             let res =                 
                 match  manager_info.managing_contract {
                     Some(mc) => send_add_season_msg_to_goi_manager(season, mc, Response::new()),
@@ -191,17 +199,605 @@ pub fn execute(
 
 
 
+        },
+        ExecuteMsg::SendMessage { to, content, body, reply_to, expires } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    let to = deps.api.addr_validate(&to)?;
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_inbox_message_msg_to_goi_manager(to, content, body, reply_to, expires, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::MarkMessageRead { message_id, read } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_mark_inbox_message_read_msg_to_goi_manager(message_id, read, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::UpdateSeasonCapacity { season_id, max_teams_allowed } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_update_season_capacity_msg_to_goi_manager(season_id, max_teams_allowed, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::AddDivisionToSeason { season_id, division } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_add_division_msg_to_goi_manager(season_id, division, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::UpdateDivision { division_id, division } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_update_division_msg_to_goi_manager(division_id, division, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::StartPlayoffs { season_id, format, playoff_teams } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_start_playoffs_msg_to_goi_manager(season_id, format, playoff_teams, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::CreateDraft { season_id, pool, rounds, order_type, pick_duration, start } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            let pool = pool.iter().map(|p| deps.api.addr_validate(p)).collect::<StdResult<Vec<Addr>>>()?;
+                            res = send_create_draft_msg_to_goi_manager(season_id, pool, rounds, order_type,
+                                                                       pick_duration, start, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::DeclareLeagueType { league_type } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            STATE.update(deps.storage, |mut state| -> StdResult<_> {
+                                state.league_type = league_type.clone();
+                                Ok(state)
+                            })?;
+                            res = send_declare_league_type_msg_to_goi_manager(league_type, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::SetSalaryCap { cap } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_set_salary_cap_msg_to_goi_manager(cap, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::SetLineupLock { seconds } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_set_lineup_lock_msg_to_goi_manager(seconds, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::AdvanceEpisode { season_id } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_advance_episode_msg_to_goi_manager(season_id, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::SetPlayerStatus { player, status } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            let player = deps.api.addr_validate(&player)?;
+                            res = send_set_player_status_msg_to_goi_manager(player, status, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::ScheduleFixture { season_id, episode, home, away, league_commits, commit_deadline, reveal_deadline } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            let home = deps.api.addr_validate(&home)?;
+                            let away = deps.api.addr_validate(&away)?;
+                            res = send_schedule_fixture_msg_to_goi_manager(season_id, episode, home, away, league_commits,
+                                                                           commit_deadline, reveal_deadline, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::BookBroadcast { display, fixture_id, start, end } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    let display = deps.api.addr_validate(&display)?;
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_request_broadcast_msg_to_goi_manager(display, fixture_id, start, end, info.sender.clone(),
+                                                                            info.funds.clone(), mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::CancelBroadcast { booking_id } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_cancel_broadcast_msg_to_goi_manager(booking_id, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::RespondToAd { ad_id, accept, attestor } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    let attestor = match attestor {
+                        Some(a) => Some(deps.api.addr_validate(&a)?),
+                        None => None
+                    };
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_respond_to_ad_msg_to_goi_manager(ad_id, accept, attestor, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::AttestAdAired { ad_id } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_attest_ad_aired_msg_to_goi_manager(ad_id, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::CommitFixtureSeed { fixture_id, commitment } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_commit_fixture_seed_msg_to_goi_manager(fixture_id, commitment, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::RevealFixtureSeed { fixture_id, secret } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_reveal_fixture_seed_msg_to_goi_manager(fixture_id, secret, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::SubmitPlayoffResult { season_id, match_id, winner } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_submit_playoff_result_msg_to_goi_manager(season_id, match_id, winner, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::RescheduleSeason { season_id, new_start, new_end, reason } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            //goi-manager validates the new dates; if it rejects them this save is reverted too
+                            let season: Option<Season> = deps.querier.query_wasm_smart(mc.clone(),
+                                &GoiManagerQueryMsg::GetSeasonById { season_id })?;
+                            if let Some(season) = season {
+                                SEASONS.save(deps.storage, season_id,
+                                             &Season { start_date: new_start, end_date: new_end, ..season })?;
+                            }
+                            res = send_reschedule_season_msg_to_goi_manager(season_id, new_start, new_end, reason, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::SetSeasonTemplate { template } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("League is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_set_season_template_msg_to_goi_manager(template, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::DistributeDividends { denom } => {
+            match ADMIN.assert_admin(deps.as_ref(), &info.sender.clone()) {
+                Ok(_) => {
+                    let shares = get_owner_dividend_shares(deps.as_ref(), &_env, &denom)?;
+                    Ok(Response::new()
+                        .add_messages(dividend_payout_messages(&denom, &shares))
+                        .add_attribute("action", "distribute_dividends")
+                        .add_attribute("total", total_of_shares(&shares).to_string()))
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::AllocateDividends { denom } => {
+            match ADMIN.assert_admin(deps.as_ref(), &info.sender.clone()) {
+                Ok(_) => {
+                    let shares = get_owner_dividend_shares(deps.as_ref(), &_env, &denom)?;
+                    DIVIDEND_CLAIMS.allocate(deps.storage, &denom, &shares)?;
+                    Ok(Response::new()
+                        .add_attribute("action", "allocate_dividends")
+                        .add_attribute("total", total_of_shares(&shares).to_string()))
+                },
+                Err(_) => {
+                    Err(Unauthorized { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::ClaimDividends { denom } => {
+            match DIVIDEND_CLAIMS.claim(deps.storage, &info.sender, &denom)? {
+                Some(amount) => {
+                    let msg: CosmosMsg =
+                        BankMsg::Send {
+                            to_address: info.sender.to_string(),
+                            amount: vec![amount]
+                        }.into();
+                    Ok(Response::new()
+                        .add_message(msg)
+                        .add_attribute("action", "claim_dividends")
+                        .add_attribute("owner", info.sender))
+                },
+                None => {
+                    Err(LeagueError::NoDividendsToClaim { denom })
+                }
+            }
+        },
+        ExecuteMsg::Propose { title, description, msg } => {
+            match is_governable_msg(&msg) {
+                true => Ok(execute_propose(deps, _env, info, title, description, to_binary(&msg)?)?),
+                false => Err(ManagedServiceError::ProposalMsgNotAllowed {}.into())
+            }
+        },
+        ExecuteMsg::VoteOnProposal { proposal_id, vote } => {
+            Ok(execute_vote(deps, _env, info, proposal_id, vote)?)
+        },
+        ExecuteMsg::ExecuteProposal { proposal_id } => {
+            //passed proposals run with the admin's authority
+            let proposal_msg: ExecuteMsg = from_binary(&take_passed_proposal_msg(deps.branch(), &_env, proposal_id)?)?;
+            match ADMIN.get(deps.as_ref())? {
+                None => Err(LeagueError::Unauthorized { sender: info.sender }),
+                Some(admin) => {
+                    let res = execute(deps, _env, MessageInfo { sender: admin, funds: vec![] }, proposal_msg)?;
+                    Ok(res.add_attribute("proposal_id", proposal_id.to_string()))
+                }
+            }
+        },
+        ExecuteMsg::UpdateGovernanceConfig { config } => {
+            Ok(execute_update_governance_config(deps, info, config)?)
         }
     }
 }
 
 
+//Proposals can wrap any message except the governance ones themselves
+fn is_governable_msg(msg: &ExecuteMsg) -> bool {
+    match msg {
+        ExecuteMsg::Propose { .. } | ExecuteMsg::VoteOnProposal { .. } | ExecuteMsg::ExecuteProposal { .. } => false,
+        _ => true
+    }
+}
+
+//Splits the league's balance of `denom` among current owners by weight,
+//leaving out dividends already allocated but not yet claimed
+fn get_owner_dividend_shares(deps: Deps, env: &Env, denom: &str) -> Result<Vec<(Addr, Uint128)>, LeagueError> {
+    let owners: Vec<(Addr, u64)> =
+        MEMBERS.range(deps.storage, None, None, Order::Ascending).collect::<StdResult<_>>()?;
+    let balance = deps.querier.query_balance(env.contract.address.clone(), denom)?.amount;
+    let available = balance.saturating_sub(DIVIDEND_CLAIMS.outstanding(deps.storage, denom)?);
+    let shares = split_by_weight(available, &owners);
+    match shares.len() > 0 {
+        true => Ok(shares),
+        false => Err(LeagueError::NoDividendsToDistribute { denom: denom.to_string() })
+    }
+}
+
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         //QueryMsg::GetTeamCount {} => to_binary(&query_count(deps)?),
         QueryMsg::GetName {} => to_binary(&query_name(deps)?),
         QueryMsg::GetInfo {} => to_binary(&query_info(deps)?),
+        QueryMsg::GetDividendClaims { owner } => {
+            let owner = deps.api.addr_validate(&owner)?;
+            to_binary(&DIVIDEND_CLAIMS.query_claims(deps.storage, &owner)?)
+        },
+        QueryMsg::GetShareListings {} => to_binary(&query_share_listings(deps)?),
+        QueryMsg::GetProposal { proposal_id } => to_binary(&query_proposal(deps, _env, proposal_id)?),
+        QueryMsg::ListProposals { start_after, limit } => to_binary(&query_list_proposals(deps, _env, start_after, limit)?),
+        QueryMsg::GetVote { proposal_id, voter } => to_binary(&query_vote(deps, proposal_id, voter)?),
+        QueryMsg::GetGovernanceConfig {} => to_binary(&get_governance_config(deps)?),
+        QueryMsg::GetSeason { season_id } => to_binary(&SEASONS.may_load(deps.storage, season_id)?),
     }
 }
 
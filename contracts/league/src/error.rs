@@ -71,6 +71,13 @@ pub enum LeagueError {
     GenericErr { message: String },
 
 
+    #[error("NoDividendsToDistribute")]
+    NoDividendsToDistribute { denom: String },
+
+    #[error("NoDividendsToClaim")]
+    NoDividendsToClaim { denom: String },
+
+
 
 }
 
@@ -1,12 +1,18 @@
-use cosmwasm_std::{Addr, Coin, Timestamp};
+use cosmwasm_std::{Addr, Binary, Coin, Timestamp};
 use cw4::Member;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use managed::messages::ManagedExecuteMsg;
-use shared::messaging::MessageTypes;
-use shared::season::SeasonModelData;
-use shared::utils::{MessageId, SeasonId, TeamAddr};
+use shared::governance::{GovernanceConfig, Vote};
+use shared::messaging::{InboxContent, MessageTypes};
+use shared::division::DivisionModelData;
+use shared::league::LeagueTypes;
+use shared::draft::DraftOrderType;
+use shared::player::PlayerStatus;
+use shared::playoffs::PlayoffFormat;
+use shared::season::{SeasonModelData, SeasonTemplate};
+use shared::utils::{DivisionId, MessageId, SeasonId, TeamAddr};
 
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -18,6 +24,8 @@ pub struct InstantiateLeagueMsg {
     //pub managing_contract_active_status: bool,
     pub for_sale: bool,
     pub price: Option<Coin>,
+    #[serde(default)]
+    pub league_type: LeagueTypes,
 }
 
 
@@ -33,6 +41,43 @@ pub enum ExecuteMsg {
     UpdateMessageStatus {  message_id: MessageId,
         updated_message_status: MessageTypes
     },
+    //inbox messages to other managed assets, see `GoiManagerQueryMsg::GetInbox`
+    SendMessage { to: String, content: InboxContent, body: String, reply_to: Option<MessageId>, expires: Option<Timestamp> },
+    MarkMessageRead { message_id: MessageId, read: bool },
+    UpdateSeasonCapacity { season_id: SeasonId, max_teams_allowed: u32 },
+    AddDivisionToSeason { season_id: SeasonId, division: DivisionModelData },
+    UpdateDivision { division_id: DivisionId, division: DivisionModelData },
+    StartPlayoffs { season_id: SeasonId, format: PlayoffFormat, playoff_teams: Option<u32> },
+    CreateDraft { season_id: SeasonId, pool: Vec<String>, rounds: u32, order_type: DraftOrderType,
+        pick_duration: u64, start: Timestamp },
+    SetSalaryCap { cap: Option<Coin> },
+    //for leagues that didn't declare when instantiated; a declared type can't change
+    DeclareLeagueType { league_type: LeagueTypes },
+    //seconds before a fixture's commit deadline that team lineups lock
+    SetLineupLock { seconds: u64 },
+    AdvanceEpisode { season_id: SeasonId },
+    SetPlayerStatus { player: String, status: PlayerStatus },
+    ScheduleFixture { season_id: SeasonId, episode: u32, home: String, away: String, league_commits: bool,
+        commit_deadline: Timestamp, reveal_deadline: Timestamp },
+    CommitFixtureSeed { fixture_id: u64, commitment: String },
+    RevealFixtureSeed { fixture_id: u64, secret: Binary },
+    //books a display slot to show the fixture; the attached funds pay the display owner
+    BookBroadcast { display: String, fixture_id: u64, start: Timestamp, end: Timestamp },
+    CancelBroadcast { booking_id: u64 },
+    //approves or rejects a sponsor's ad on one of the league's seasons
+    RespondToAd { ad_id: u64, accept: bool, attestor: Option<String> },
+    //confirms an approved ad on one of the league's seasons aired
+    AttestAdAired { ad_id: u64 },
+    SubmitPlayoffResult { season_id: SeasonId, match_id: u32, winner: TeamAddr },
+    RescheduleSeason { season_id: SeasonId, new_start: Timestamp, new_end: Timestamp, reason: String },
+    SetSeasonTemplate { template: Option<SeasonTemplate> },
+    DistributeDividends { denom: String },
+    AllocateDividends { denom: String },
+    ClaimDividends { denom: String },
+    Propose { title: String, description: String, msg: Box<ExecuteMsg> },
+    VoteOnProposal { proposal_id: u64, vote: Vote },
+    ExecuteProposal { proposal_id: u64 },
+    UpdateGovernanceConfig { config: GovernanceConfig },
 }
 
 
@@ -42,4 +87,11 @@ pub enum ExecuteMsg {
 pub enum QueryMsg {
     GetName {},
     GetInfo {},
+    GetDividendClaims { owner: String },
+    GetShareListings {},
+    GetProposal { proposal_id: u64 },
+    ListProposals { start_after: Option<u64>, limit: Option<u32> },
+    GetVote { proposal_id: u64, voter: String },
+    GetGovernanceConfig {},
+    GetSeason { season_id: SeasonId },
 }
@@ -1,21 +1,28 @@
 use cosmwasm_std::Addr;
 use cw4::TOTAL_KEY;
 use cw_controllers::{Admin, Hooks};
-use cw_storage_plus::{Item, SnapshotMap, Strategy};
+use cw_storage_plus::{Item, Map, SnapshotMap, Strategy};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use managed::service::ManagedService;
 use saleable::service::SaleableService;
+use shared::dividends::DividendClaims;
 use shared::league::LeagueInfo;
+use shared::season::Season;
+use shared::utils::SeasonId;
 
 
 
 pub const STATE: Item<LeagueInfo> = Item::new("state");
 
+//league's copy of seasons it has rescheduled; goi-manager's `seasons()` is authoritative
+pub const SEASONS: Map<SeasonId, Season> = Map::new("seasons");
+
 pub const SALEABLE_SERVICE: SaleableService = SaleableService::new("saleable_service");
 pub const MANAGEABLE_SERVICE: ManagedService = ManagedService::new("manageable_service");
 pub const ADMIN: Admin = Admin::new("admin");
+pub const DIVIDEND_CLAIMS: DividendClaims = DividendClaims::new("dividend_claims", "dividends_outstanding");
 pub const HOOKS: Hooks = Hooks::new("cw4-hooks");
 pub const TOTAL: Item<u64> = Item::new(TOTAL_KEY);
 
@@ -1,9 +1,10 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Addr, Coin, StdError};
 use cw_controllers::AdminError;
 use thiserror::Error;
 
 use group_admin::GroupAdminError;
 use saleable::error::SaleableItemError;
+use shared::governance::ProposalStatus;
 
 #[derive(Error, Debug, PartialEq)]
 pub enum ManagedServiceError {
@@ -28,6 +29,39 @@ pub enum ManagedServiceError {
 
     #[error("NoManagerContractAddressProvided")]
     NoManagerContractAddressProvided {},
+
+    #[error("InsufficientShares")]
+    InsufficientShares { available: u64 },
+
+    #[error("InvalidShareTransfer")]
+    InvalidShareTransfer {},
+
+    #[error("ShareListingNotFound")]
+    ShareListingNotFound {},
+
+    #[error("IncorrectSharePayment")]
+    IncorrectSharePayment { expected: Coin },
+
+    #[error("NoVotingPower")]
+    NoVotingPower { voter: Addr },
+
+    #[error("ProposalNotFound")]
+    ProposalNotFound {},
+
+    #[error("ProposalNotOpen")]
+    ProposalNotOpen { status: ProposalStatus },
+
+    #[error("ProposalNotPassed")]
+    ProposalNotPassed { status: ProposalStatus },
+
+    #[error("AlreadyVoted")]
+    AlreadyVoted {},
+
+    #[error("InvalidGovernanceConfig")]
+    InvalidGovernanceConfig {},
+
+    #[error("ProposalMsgNotAllowed")]
+    ProposalMsgNotAllowed {},
 }
 
 
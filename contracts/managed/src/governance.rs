@@ -0,0 +1,153 @@
+use cosmwasm_std::{Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult};
+use cw_storage_plus::{Bound, Item, Map};
+
+use goi_manager::state::{ADMIN, MEMBERS, TOTAL};
+use shared::governance::{GovernanceConfig, Proposal, ProposalResponse, ProposalStatus, Vote};
+
+use crate::error::ManagedServiceError;
+
+// settings for pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+pub const GOVERNANCE_CONFIG: Item<GovernanceConfig> = Item::new("governance_config");
+pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
+pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
+pub const BALLOTS: Map<(u64, &Addr), Vote> = Map::new("ballots");
+
+
+pub fn get_governance_config(deps: Deps) -> StdResult<GovernanceConfig> {
+    Ok(GOVERNANCE_CONFIG.may_load(deps.storage)?.unwrap_or_default())
+}
+
+
+pub fn execute_update_governance_config(deps: DepsMut, info: MessageInfo,
+                                        config: GovernanceConfig) -> Result<Response, ManagedServiceError> {
+    ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+    match config.is_valid() {
+        true => {
+            GOVERNANCE_CONFIG.save(deps.storage, &config)?;
+            Ok(Response::new().add_attribute("action", "update_governance_config"))
+        }
+        false => Err(ManagedServiceError::InvalidGovernanceConfig {})
+    }
+}
+
+
+//Voting power as of the start of the proposal's block, so weight
+//moved around after a proposal is created can't vote on it
+fn get_voting_power(deps: Deps, voter: &Addr, height: u64) -> Result<u64, ManagedServiceError> {
+    match MEMBERS.may_load_at_height(deps.storage, voter, height)? {
+        Some(weight) if weight > 0 => Ok(weight),
+        _ => Err(ManagedServiceError::NoVotingPower { voter: voter.clone() })
+    }
+}
+
+
+// Any co-owner may propose; their weight is counted as a yes vote.
+// `msg` is the caller contract's ExecuteMsg already serialized.
+pub fn execute_propose(deps: DepsMut, env: Env, info: MessageInfo, title: String,
+                       description: String, msg: Binary) -> Result<Response, ManagedServiceError> {
+    //proposer must already have held weight before this block
+    let weight = get_voting_power(deps.as_ref(), &info.sender, env.block.height)?;
+    let config = get_governance_config(deps.as_ref())?;
+    let id = PROPOSAL_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+    PROPOSAL_COUNT.save(deps.storage, &id)?;
+
+    let mut proposal = Proposal {
+        id,
+        title,
+        description,
+        msg,
+        proposer: info.sender.clone(),
+        start_height: env.block.height,
+        expires: env.block.time.plus_seconds(config.voting_period),
+        config,
+        total_weight: TOTAL.load(deps.storage)?,
+        yes: 0,
+        no: 0,
+        abstain: 0,
+        executed: None
+    };
+    proposal.add_vote(Vote::Yes, weight);
+    PROPOSALS.save(deps.storage, id, &proposal)?;
+    BALLOTS.save(deps.storage, (id, &info.sender), &Vote::Yes)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose")
+        .add_attribute("proposer", info.sender)
+        .add_attribute("proposal_id", id.to_string())
+        .add_attribute("status", format!("{:?}", proposal.status(env.block.time))))
+}
+
+
+pub fn execute_vote(deps: DepsMut, env: Env, info: MessageInfo, proposal_id: u64,
+                    vote: Vote) -> Result<Response, ManagedServiceError> {
+    let mut proposal =
+        match PROPOSALS.may_load(deps.storage, proposal_id)? {
+            None => return Err(ManagedServiceError::ProposalNotFound {}),
+            Some(p) => p
+        };
+    //votes may still be added after passing early, until it's executed or expires
+    let status = proposal.status(env.block.time);
+    match status == ProposalStatus::Open || status == ProposalStatus::Passed && env.block.time < proposal.expires {
+        true => (),
+        false => return Err(ManagedServiceError::ProposalNotOpen { status })
+    }
+    match BALLOTS.may_load(deps.storage, (proposal_id, &info.sender))? {
+        Some(_) => return Err(ManagedServiceError::AlreadyVoted {}),
+        None => ()
+    }
+    let weight = get_voting_power(deps.as_ref(), &info.sender, proposal.start_height)?;
+    proposal.add_vote(vote.clone(), weight);
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+    BALLOTS.save(deps.storage, (proposal_id, &info.sender), &vote)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "vote")
+        .add_attribute("voter", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("status", format!("{:?}", proposal.status(env.block.time))))
+}
+
+
+//Marks a passed proposal executed and hands back its message; the caller
+//contract runs it with the admin's authority
+pub fn take_passed_proposal_msg(deps: DepsMut, env: &Env, proposal_id: u64) -> Result<Binary, ManagedServiceError> {
+    let mut proposal =
+        match PROPOSALS.may_load(deps.storage, proposal_id)? {
+            None => return Err(ManagedServiceError::ProposalNotFound {}),
+            Some(p) => p
+        };
+    match proposal.status(env.block.time) {
+        ProposalStatus::Passed => {
+            proposal.executed = Some(env.block.time);
+            PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+            Ok(proposal.msg)
+        }
+        status => Err(ManagedServiceError::ProposalNotPassed { status })
+    }
+}
+
+
+pub fn query_proposal(deps: Deps, env: Env, proposal_id: u64) -> StdResult<Option<ProposalResponse>> {
+    Ok(PROPOSALS.may_load(deps.storage, proposal_id)?
+        .map(|proposal| ProposalResponse { status: proposal.status(env.block.time), proposal }))
+}
+
+
+pub fn query_list_proposals(deps: Deps, env: Env, start_after: Option<u64>,
+                            limit: Option<u32>) -> StdResult<Vec<ProposalResponse>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    PROPOSALS
+        .range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, proposal)| ProposalResponse { status: proposal.status(env.block.time), proposal }))
+        .collect()
+}
+
+
+pub fn query_vote(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Option<Vote>> {
+    let voter = deps.api.addr_validate(&voter)?;
+    BALLOTS.may_load(deps.storage, (proposal_id, &voter))
+}
@@ -4,4 +4,6 @@ pub mod service;
 pub mod queries;
 pub mod error;
 pub mod messages;
+pub mod shares;
+pub mod governance;
 
@@ -1,4 +1,4 @@
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Coin};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -11,5 +11,9 @@ pub enum ManagedExecuteMsg {
     Saleable{ saleable_msg: SaleableExecuteMsg},
     UpdateManager{ manager_address: Addr},
     GroupAdminHooks {group_admin_hooks_msg: GroupAdminHooksMsg  },
+    TransferShares { to: String, weight: u64 },
+    ListSharesForSale { weight: u64, price_per_unit: Coin },
+    CancelSharesListing {},
+    BuyShares { seller: String, weight: u64 },
 
 }
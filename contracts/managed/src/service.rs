@@ -26,6 +26,7 @@ use shared::utils::general::AssetTypes;
 
 use crate::error::ManagedServiceError;
 use crate::messages::ManagedExecuteMsg;
+use crate::shares::{execute_buy_shares, execute_cancel_shares_listing, execute_list_shares_for_sale, execute_transfer_shares};
 
 
 
@@ -196,6 +197,18 @@ impl<'a> ManagedService<'a> {
                         Ok(r) => Ok(r),
                         Err(e) => Err(ManagedServiceError::GroupAdminHooksError(e))
                     }
+                },
+                ManagedExecuteMsg::TransferShares { to, weight } => {
+                    execute_transfer_shares(deps, env, info, to, weight, manageable)
+                },
+                ManagedExecuteMsg::ListSharesForSale { weight, price_per_unit } => {
+                    execute_list_shares_for_sale(deps, env, info, weight, price_per_unit)
+                },
+                ManagedExecuteMsg::CancelSharesListing {} => {
+                    execute_cancel_shares_listing(deps, info)
+                },
+                ManagedExecuteMsg::BuyShares { seller, weight } => {
+                    execute_buy_shares(deps, env, info, seller, weight, manageable)
                 }
             }
 
@@ -0,0 +1,179 @@
+use cosmwasm_std::{Addr, BankMsg, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Uint128};
+use cw4::Member;
+use cw_storage_plus::Map;
+
+use goi_manager::state::MEMBERS;
+use group_admin::service::validate_owner_count;
+use shared::goi_manager::on_shares_transferred;
+use shared::manage::Manageable;
+use shared::saleable::ShareListing;
+
+use crate::error::ManagedServiceError;
+
+pub const SHARE_LISTINGS: Map<&Addr, ShareListing> = Map::new("share_listings");
+
+
+// Moves `weight` of ownership from one co-owner to another address.
+// Total weight is unchanged, so owner-level splits (dividends, votes)
+// follow the new weights right away.
+// Validation checks:
+//   --weight must be positive and sender can't transfer to themselves
+//   --sender must hold at least `weight`
+//   --resulting owner count must stay within the group limits
+fn transfer_owner_weight(deps: DepsMut, height: u64, from: &Addr, to: &Addr,
+                         weight: u64) -> Result<(), ManagedServiceError> {
+    match weight > 0 && from != to {
+        true => (),
+        false => return Err(ManagedServiceError::InvalidShareTransfer {})
+    }
+    let from_weight = MEMBERS.may_load(deps.storage, from)?.unwrap_or_default();
+    match from_weight >= weight {
+        true => (),
+        false => return Err(ManagedServiceError::InsufficientShares { available: from_weight })
+    }
+    let to_weight = MEMBERS.may_load(deps.storage, to)?;
+
+    let remove = match from_weight == weight {
+        true => Some(vec![from.to_string()]),
+        false => None
+    };
+    let add = match to_weight {
+        None => Some(vec![Member { addr: to.to_string(), weight }]),
+        Some(_) => None
+    };
+    validate_owner_count(deps.as_ref(), remove, add)?;
+
+    match from_weight == weight {
+        true => MEMBERS.remove(deps.storage, from, height)?,
+        false => MEMBERS.save(deps.storage, from, &(from_weight - weight), height)?
+    }
+    MEMBERS.save(deps.storage, to, &(to_weight.unwrap_or_default() + weight), height)?;
+
+    //an open listing can never offer more than the seller still holds
+    match SHARE_LISTINGS.may_load(deps.storage, from)? {
+        Some(mut listing) if listing.weight > from_weight - weight => {
+            listing.weight = from_weight - weight;
+            match listing.weight > 0 {
+                true => SHARE_LISTINGS.save(deps.storage, from, &listing)?,
+                false => SHARE_LISTINGS.remove(deps.storage, from)
+            }
+        }
+        _ => ()
+    }
+    Ok(())
+}
+
+
+fn notify_manager(manageable: Manageable, from: Addr, to: Addr, weight: u64, response: Response) -> Response {
+    match manageable.managing_contract {
+        None => response,
+        Some(mc) => on_shares_transferred(from, to, weight, mc, response)
+    }
+}
+
+
+pub fn execute_transfer_shares(deps: DepsMut, env: Env, info: MessageInfo, to: String, weight: u64,
+                               manageable: Manageable) -> Result<Response, ManagedServiceError> {
+    let to = deps.api.addr_validate(&to)?;
+    transfer_owner_weight(deps, env.block.height, &info.sender, &to, weight)?;
+    let res = Response::new()
+        .add_attribute("action", "transfer_shares")
+        .add_attribute("from", info.sender.clone())
+        .add_attribute("to", to.clone())
+        .add_attribute("weight", weight.to_string());
+    Ok(notify_manager(manageable, info.sender, to, weight, res))
+}
+
+
+// A co-owner keeps at most one listing; listing again replaces it
+pub fn execute_list_shares_for_sale(deps: DepsMut, env: Env, info: MessageInfo, weight: u64,
+                                    price_per_unit: Coin) -> Result<Response, ManagedServiceError> {
+    let available = MEMBERS.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    match (weight > 0 && !price_per_unit.amount.is_zero(), available >= weight) {
+        (false, _) => Err(ManagedServiceError::InvalidShareTransfer {}),
+        (true, false) => Err(ManagedServiceError::InsufficientShares { available }),
+        (true, true) => {
+            SHARE_LISTINGS.save(deps.storage, &info.sender, &ShareListing {
+                seller: info.sender.clone(),
+                weight,
+                price_per_unit,
+                listed: env.block.time
+            })?;
+            Ok(Response::new()
+                .add_attribute("action", "list_shares_for_sale")
+                .add_attribute("seller", info.sender)
+                .add_attribute("weight", weight.to_string()))
+        }
+    }
+}
+
+
+pub fn execute_cancel_shares_listing(deps: DepsMut, info: MessageInfo) -> Result<Response, ManagedServiceError> {
+    match SHARE_LISTINGS.may_load(deps.storage, &info.sender)? {
+        None => Err(ManagedServiceError::ShareListingNotFound {}),
+        Some(_) => {
+            SHARE_LISTINGS.remove(deps.storage, &info.sender);
+            Ok(Response::new()
+                .add_attribute("action", "cancel_shares_listing")
+                .add_attribute("seller", info.sender))
+        }
+    }
+}
+
+
+// Buyer sends exactly price_per_unit * weight, which goes straight to the seller
+pub fn execute_buy_shares(mut deps: DepsMut, env: Env, info: MessageInfo, seller: String, weight: u64,
+                          manageable: Manageable) -> Result<Response, ManagedServiceError> {
+    let seller = deps.api.addr_validate(&seller)?;
+    let mut listing =
+        match SHARE_LISTINGS.may_load(deps.storage, &seller)? {
+            None => return Err(ManagedServiceError::ShareListingNotFound {}),
+            Some(l) => l
+        };
+    match listing.weight >= weight {
+        true => (),
+        false => return Err(ManagedServiceError::InsufficientShares { available: listing.weight })
+    }
+    let expected = Coin {
+        denom: listing.price_per_unit.denom.clone(),
+        amount: listing.price_per_unit.amount * Uint128::from(weight)
+    };
+    match info.funds == vec![expected.clone()] {
+        true => (),
+        false => return Err(ManagedServiceError::IncorrectSharePayment { expected })
+    }
+
+    transfer_owner_weight(deps.branch(), env.block.height, &seller, &info.sender, weight)?;
+    //the transfer may already have trimmed or removed the listing
+    match SHARE_LISTINGS.may_load(deps.storage, &seller)? {
+        Some(l) => {
+            listing.weight = l.weight.min(listing.weight - weight);
+            match listing.weight > 0 {
+                true => SHARE_LISTINGS.save(deps.storage, &seller, &listing)?,
+                false => SHARE_LISTINGS.remove(deps.storage, &seller)
+            }
+        }
+        None => ()
+    }
+
+    let payment: CosmosMsg =
+        BankMsg::Send {
+            to_address: seller.to_string(),
+            amount: vec![expected]
+        }.into();
+    let res = Response::new()
+        .add_message(payment)
+        .add_attribute("action", "buy_shares")
+        .add_attribute("seller", seller.clone())
+        .add_attribute("buyer", info.sender.clone())
+        .add_attribute("weight", weight.to_string());
+    Ok(notify_manager(manageable, seller, info.sender, weight, res))
+}
+
+
+pub fn query_share_listings(deps: Deps) -> StdResult<Vec<ShareListing>> {
+    SHARE_LISTINGS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, listing)| listing))
+        .collect()
+}
@@ -5,12 +5,12 @@ use cw2::set_contract_version;
 
 
 use shared::goi_manager::GoiManagerQueryMsg;
-use shared::player::{PlayerInfo, Player};
+use shared::player::{PlayerInfo, Player, PlayerStatus};
 pub use shared::player_attributes::Positions;
 
 use crate::error::ContractError;
-use shared::player::{InfoResponse, InstantiateMsg, QueryMsg};
-use crate::state::{STATE};
+use shared::player::{ExecuteMsg, InfoResponse, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::state::{MANAGING_CONTRACT, STATE};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:player";
@@ -26,7 +26,7 @@ pub fn instantiate(
     let name_valid = is_player_name_in_use(&deps.querier,
                                                             msg.first_name.clone(),
                                                             msg.last_name.clone(),
-                                           msg.managing_contract_address);
+                                           msg.managing_contract_address.clone());
 
     match name_valid {
         Ok(p) => {
@@ -38,9 +38,11 @@ pub fn instantiate(
                         owner: info.sender.clone(),
                         position: msg.position,
                         attributes: msg.attributes,
+                        status: PlayerStatus::Active,
                     };
                     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
                     STATE.save(deps.storage, &state)?;
+                    MANAGING_CONTRACT.save(deps.storage, &msg.managing_contract_address)?;
 
                     Ok(Response::new()
                         .add_attribute("method", "instantiate")
@@ -88,6 +90,40 @@ fn is_player_name_in_use(q_wrapper: &QuerierWrapper, first_name: String,
 
 
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    MANAGING_CONTRACT.save(deps.storage, &msg.managing_contract_address)?;
+    Ok(Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("managing_contract", msg.managing_contract_address))
+}
+
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::UpdateStatus { status } => {
+            match MANAGING_CONTRACT.may_load(deps.storage)? {
+                Some(mc) if mc == info.sender => {
+                    STATE.update(deps.storage, |mut state| -> StdResult<_> {
+                        state.status = status;
+                        Ok(state)
+                    })?;
+                    Ok(Response::new().add_attribute("method", "update_status"))
+                }
+                _ => Err(ContractError::Unauthorized {})
+            }
+        }
+    }
+}
+
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
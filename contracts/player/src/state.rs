@@ -1,4 +1,5 @@
 
+use cosmwasm_std::Addr;
 use cw_storage_plus::Item;
 
 
@@ -9,3 +10,5 @@ pub use shared::player_attributes::{PlayerAttributes, Positions};
 
 
 pub const STATE: Item<Player> = Item::new("state");
+//only this contract may change the player's status
+pub const MANAGING_CONTRACT: Item<Addr> = Item::new("managing_contract");
@@ -0,0 +1,105 @@
+use cosmwasm_std::{Addr, Coin, Timestamp};
+use cw4::Member;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::SeasonId;
+
+
+//Where an ad runs. `slot_name` tells slots on the same booking or season
+//apart, e.g. "halftime".
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum AdPlacement {
+    Broadcast { booking_id: u64, slot_name: String },
+    Season { season_id: SeasonId, slot_name: String },
+}
+
+impl AdPlacement {
+    pub fn slot_name(&self) -> &str {
+        match self {
+            AdPlacement::Broadcast { slot_name, .. } => slot_name,
+            AdPlacement::Season { slot_name, .. } => slot_name,
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AdContent {
+    //hex sha256 of the creative at `uri`
+    pub hash: String,
+    pub uri: String,
+}
+
+impl AdContent {
+    pub fn is_valid(&self) -> bool {
+        self.hash.len() == 64 && self.hash.chars().all(|c| c.is_ascii_hexdigit()) && !self.uri.trim().is_empty()
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum AdStatus {
+    Requested,
+    Approved,
+    Rejected,
+    //the sponsor took its payment back
+    Withdrawn,
+    //aired and paid out
+    Paid,
+}
+
+
+//A sponsor's ad slot. The payment is held by the goi manager until the
+//ad is attested as aired or refunded.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AdSlot {
+    pub id: u64,
+    pub sponsor: Addr,
+    pub placement: AdPlacement,
+    pub content: AdContent,
+    pub payment: Vec<Coin>,
+    //the display or league contract that approves the ad
+    pub approver: Addr,
+    //an xnode task the approver trusts to attest the ad aired
+    pub attestor: Option<Addr>,
+    pub status: AdStatus,
+    pub requested: Timestamp,
+    pub updated: Timestamp,
+    pub aired: Option<Timestamp>,
+}
+
+impl AdSlot {
+    //Approved and paid ads keep other sponsors out of their placement
+    pub fn holds_placement(&self) -> bool {
+        self.status == AdStatus::Approved || self.status == AdStatus::Paid
+    }
+}
+
+
+//Mirrors the GetInfo query every managed asset answers so the managing
+//contract can pay an ad's revenue to the display or league owners
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetInfoQueryMsg {
+    GetInfo {},
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AssetOwnersResponse {
+    pub owners: Vec<Member>,
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ad_content_needs_a_sha256_and_uri() {
+        assert!(!AdContent { hash: "abc".to_string(), uri: "ipfs://ad".to_string() }.is_valid());
+        assert!(!AdContent { hash: "a".repeat(64), uri: " ".to_string() }.is_valid());
+        assert!(AdContent { hash: "a".repeat(64), uri: "ipfs://ad".to_string() }.is_valid());
+    }
+}
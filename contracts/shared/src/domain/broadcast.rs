@@ -0,0 +1,96 @@
+use cosmwasm_std::{Addr, Coin, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::LeagueAddr;
+
+//a league cancelling an accepted booking with less notice than this pays the display anyway
+pub const BROADCAST_CANCELLATION_NOTICE: u64 = 86_400;
+//longest slot a league can book; conflict checks only look this far back on a calendar
+pub const MAX_BROADCAST_SLOT: u64 = 43_200;
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum BroadcastStatus {
+    Requested,
+    Accepted,
+    Rejected,
+    Cancelled,
+    //nobody answered the request before the slot started
+    Expired,
+    //the payment went to the display owner
+    Settled,
+}
+
+
+//A league's booking of a display time slot to show a fixture. The payment
+//is held by the goi manager until the booking is settled or refunded to
+//the league admin who paid it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BroadcastBooking {
+    pub id: u64,
+    pub display: Addr,
+    pub league: LeagueAddr,
+    pub payer: Addr,
+    pub fixture_id: u64,
+    pub start: Timestamp,
+    pub end: Timestamp,
+    pub payment: Vec<Coin>,
+    pub status: BroadcastStatus,
+    pub requested: Timestamp,
+    pub updated: Timestamp,
+}
+
+impl BroadcastBooking {
+    //Requested and accepted bookings hold their slot on the display's calendar
+    pub fn holds_slot(&self) -> bool {
+        self.status == BroadcastStatus::Requested || self.status == BroadcastStatus::Accepted
+    }
+
+    pub fn overlaps(&self, start: Timestamp, end: Timestamp) -> bool {
+        self.start < end && start < self.end
+    }
+
+    //Whether the payer gets the payment back when the booking is cancelled at `now`.
+    //Displays cancelling, and any cancellation before acceptance, refund in full.
+    pub fn refunds_on_cancel(&self, by_league: bool, now: Timestamp) -> bool {
+        match (&self.status, by_league) {
+            (BroadcastStatus::Accepted, true) => now.plus_seconds(BROADCAST_CANCELLATION_NOTICE) <= self.start,
+            _ => true
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn late_league_cancellations_are_not_refunded() {
+        let start = Timestamp::from_seconds(1_000_000);
+        let mut booking = BroadcastBooking {
+            id: 1,
+            display: Addr::unchecked("display"),
+            league: Addr::unchecked("league"),
+            payer: Addr::unchecked("admin"),
+            fixture_id: 1,
+            start,
+            end: start.plus_seconds(3_600),
+            payment: vec![],
+            status: BroadcastStatus::Requested,
+            requested: Timestamp::from_seconds(0),
+            updated: Timestamp::from_seconds(0),
+        };
+        let late = start.minus_seconds(60);
+        assert!(booking.refunds_on_cancel(true, late));
+
+        booking.status = BroadcastStatus::Accepted;
+        assert!(!booking.refunds_on_cancel(true, late));
+        assert!(booking.refunds_on_cancel(false, late));
+        assert!(booking.refunds_on_cancel(true, start.minus_seconds(BROADCAST_CANCELLATION_NOTICE)));
+
+        assert!(booking.overlaps(start.minus_seconds(60), start.plus_seconds(1)));
+        assert!(!booking.overlaps(start.plus_seconds(3_600), start.plus_seconds(7_200)));
+    }
+}
@@ -1,3 +1,4 @@
+use cosmwasm_std::{Addr, Timestamp};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -6,10 +7,79 @@ use crate::utils::{AppAddress, BlockTime, IManaged, TaskAddress};
 use crate::utils::general::AssetTypes;
 use crate::utils::xnodes::XNodeAddress;
 
+//a display that hasn't sent a heartbeat for this long is treated as offline
+pub const HEARTBEAT_TIMEOUT: u64 = 600;
+const SECONDS_PER_DAY: u32 = 86_400;
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum DisplayKind {
+    Physical,
+    Virtual,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Resolution {
+    pub width: u32,
+    pub height: u32,
+}
+
+
+//Seconds after midnight UTC. Hours that close before they open run past midnight.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OperatingHours {
+    pub opens: u32,
+    pub closes: u32,
+}
+
+impl OperatingHours {
+    pub fn is_open(&self, time: Timestamp) -> bool {
+        let now = (time.seconds() % SECONDS_PER_DAY as u64) as u32;
+        match self.opens <= self.closes {
+            true => now >= self.opens && now < self.closes,
+            false => now >= self.opens || now < self.closes
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DisplayDetails {
+    pub kind: DisplayKind,
+    //required for physical screens
+    pub geo_location: String,
+    pub resolution: Resolution,
+    //None runs around the clock
+    pub operating_hours: Option<OperatingHours>,
+}
+
+impl DisplayDetails {
+    pub fn is_valid(&self) -> bool {
+        let located = self.kind == DisplayKind::Virtual || !self.geo_location.trim().is_empty();
+        let hours_valid = self.operating_hours.as_ref().map_or(true, |h|
+            h.opens < SECONDS_PER_DAY && h.closes < SECONDS_PER_DAY && h.opens != h.closes);
+        located && hours_valid && self.resolution.width > 0 && self.resolution.height > 0
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Heartbeat {
+    pub online: bool,
+    pub reported: Timestamp,
+}
+
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct DisplayInfo {
+    pub address: Addr,
     pub name: String,
+    pub kind: DisplayKind,
     pub geo_location: String,
+    pub resolution: Resolution,
+    pub operating_hours: Option<OperatingHours>,
+    pub last_heartbeat: Option<Heartbeat>,
     pub created: BlockTime,
     managed_status: ManagedStatus,
     for_sale: bool,
@@ -19,10 +89,15 @@ pub struct DisplayInfo {
 impl DisplayInfo {
     // Constructs a new instance of [`Second`].
     // Note this is an associated function - no self.
-    pub fn new(block_time: BlockTime) -> Self {
+    pub fn new(address: Addr, name: String, details: DisplayDetails, block_time: BlockTime) -> Self {
         Self{
-            name: "".to_string(),
-            geo_location: "".to_string(),
+            address,
+            name,
+            kind: details.kind,
+            geo_location: details.geo_location,
+            resolution: details.resolution,
+            operating_hours: details.operating_hours,
+            last_heartbeat: None,
             created: BlockTime{
                 height: block_time.height,
                 time: block_time.time,
@@ -34,6 +109,31 @@ impl DisplayInfo {
         }
 
     }
+
+    pub fn set_details(&mut self, details: DisplayDetails) {
+        self.kind = details.kind;
+        self.geo_location = details.geo_location;
+        self.resolution = details.resolution;
+        self.operating_hours = details.operating_hours;
+    }
+
+    //Online while the last heartbeat said so and isn't older than `HEARTBEAT_TIMEOUT`
+    pub fn is_online(&self, now: Timestamp) -> bool {
+        self.last_heartbeat.as_ref().map_or(false, |h|
+            h.online && now.seconds().saturating_sub(h.reported.seconds()) <= HEARTBEAT_TIMEOUT)
+    }
+
+    pub fn is_open(&self, now: Timestamp) -> bool {
+        self.operating_hours.as_ref().map_or(true, |h| h.is_open(now))
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DisplayStatusResponse {
+    pub display: DisplayInfo,
+    pub online: bool,
+    pub open: bool,
 }
 
 
@@ -82,3 +182,17 @@ pub enum ApplicationQueryMsg {
     GetTask { task_address: TaskAddress, xnode_address: Option<XNodeAddress>},
 }
 
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn overnight_operating_hours() {
+        //20:00 to 02:00
+        let hours = OperatingHours { opens: 72_000, closes: 7_200 };
+        assert!(hours.is_open(Timestamp::from_seconds(86_400 * 3 + 80_000)));
+        assert!(hours.is_open(Timestamp::from_seconds(86_400 * 3 + 3_600)));
+        assert!(!hours.is_open(Timestamp::from_seconds(86_400 * 3 + 43_200)));
+    }
+}
@@ -0,0 +1,90 @@
+use cosmwasm_std::{Addr, BankMsg, Coin, CosmosMsg, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+
+//Splits `amount` among owners pro-rata by cw4 weight. Shares are rounded
+//down, so any dust stays with the contract for the next distribution.
+pub fn split_by_weight(amount: Uint128, owners: &[(Addr, u64)]) -> Vec<(Addr, Uint128)> {
+    let total_weight: u64 = owners.iter().map(|o| o.1).sum();
+    match total_weight > 0 {
+        true => {
+            owners.
+                iter().
+                map(|(owner, weight)| (owner.clone(), amount.multiply_ratio(*weight, total_weight))).
+                filter(|(_, share)| !share.is_zero()).
+                collect()
+        }
+        false => vec![]
+    }
+}
+
+
+pub fn total_of_shares(shares: &[(Addr, Uint128)]) -> Uint128 {
+    shares.iter().map(|s| s.1).sum()
+}
+
+
+pub fn dividend_payout_messages(denom: &str, shares: &[(Addr, Uint128)]) -> Vec<CosmosMsg> {
+    shares.
+        iter().
+        map(|(owner, amount)| BankMsg::Send {
+            to_address: owner.to_string(),
+            amount: vec![Coin { denom: denom.to_string(), amount: *amount }]
+        }.into()).
+        collect()
+}
+
+
+// state/logic for claim based dividends; owners pull their share
+// instead of the distribution sending one BankMsg per owner
+pub struct DividendClaims<'a> {
+    claims: Map<'a, (&'a Addr, &'a str), Uint128>,
+    outstanding: Map<'a, &'a str, Uint128>,
+}
+
+impl<'a> DividendClaims<'a> {
+    pub const fn new(claims_key: &'a str, outstanding_key: &'a str) -> Self {
+        DividendClaims {
+            claims: Map::new(claims_key),
+            outstanding: Map::new(outstanding_key),
+        }
+    }
+
+    pub fn allocate(&self, storage: &mut dyn Storage, denom: &str, shares: &[(Addr, Uint128)]) -> StdResult<()> {
+        for (owner, amount) in shares {
+            self.claims.update(storage, (owner, denom), |claim| -> StdResult<_> {
+                Ok(claim.unwrap_or_default() + *amount)
+            })?;
+        }
+        self.outstanding.update(storage, denom, |total| -> StdResult<_> {
+            Ok(total.unwrap_or_default() + total_of_shares(shares))
+        })?;
+        Ok(())
+    }
+
+    //Removes and returns the owner's unclaimed amount for the denom
+    pub fn claim(&self, storage: &mut dyn Storage, owner: &Addr, denom: &str) -> StdResult<Option<Coin>> {
+        match self.claims.may_load(storage, (owner, denom))? {
+            Some(amount) if !amount.is_zero() => {
+                self.claims.remove(storage, (owner, denom));
+                let outstanding = self.outstanding(storage, denom)?;
+                self.outstanding.save(storage, denom, &(outstanding - amount))?;
+                Ok(Some(Coin { denom: denom.to_string(), amount }))
+            }
+            _ => Ok(None)
+        }
+    }
+
+    //Allocated but not yet claimed
+    pub fn outstanding(&self, storage: &dyn Storage, denom: &str) -> StdResult<Uint128> {
+        Ok(self.outstanding.may_load(storage, denom)?.unwrap_or_default())
+    }
+
+    pub fn query_claims(&self, storage: &dyn Storage, owner: &Addr) -> StdResult<Vec<Coin>> {
+        self.claims.
+            prefix(owner).
+            range(storage, None, None, Order::Ascending).
+            map(|item| item.map(|(denom, amount)| Coin { denom, amount })).
+            collect()
+    }
+}
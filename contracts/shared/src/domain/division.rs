@@ -0,0 +1,81 @@
+use cosmwasm_std::Timestamp;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::data::ModelItem;
+use crate::utils::{DivisionId, LeagueAddr, SeasonId, TeamAddr};
+use crate::utils::general::merge_data;
+
+
+//A named group of teams (division) within a league's season.
+//Divisions sharing the same conference name make up that conference.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Division {
+    pub id: DivisionId,
+    pub league: LeagueAddr,
+    pub season_id: SeasonId,
+    pub name: String,
+    pub conference: Option<String>,
+    pub max_teams: u32,
+    pub created: Timestamp,
+}
+
+impl Division {
+    pub fn validate(&self) -> bool {
+        !self.name.trim().is_empty() && self.max_teams > 0
+    }
+
+    pub fn merge_updates(&self, model: DivisionModelData) -> Self {
+        Division {
+            id: self.id,
+            league: self.league.clone(),
+            season_id: self.season_id,
+            created: self.created,
+            name: merge_data(Some(self.name.clone()), model.name).unwrap_or_default(),
+            conference: merge_data(self.conference.clone(), model.conference),
+            max_teams: merge_data(Some(self.max_teams), model.max_teams).unwrap_or_default(),
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DivisionModelData {
+    pub name: ModelItem<Option<String>>,
+    pub conference: ModelItem<Option<String>>,
+    pub max_teams: ModelItem<Option<u32>>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DivisionTeamAssignment {
+    pub id: u64,
+    pub season_id: SeasonId,
+    pub division_id: DivisionId,
+    pub team: TeamAddr,
+    pub assigned_date: Timestamp,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct TeamStanding {
+    pub wins: u32,
+    pub losses: u32,
+    pub ties: u32,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TeamStandingItem {
+    pub team: TeamAddr,
+    pub standing: TeamStanding,
+}
+
+
+//Query response grouping season items by division.
+//Teams accepted before any division existed are grouped under `division: None`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DivisionGroup<T> {
+    pub division: Option<Division>,
+    pub items: Vec<T>,
+}
@@ -0,0 +1,334 @@
+use cosmwasm_std::{Addr, CosmosMsg, Timestamp, to_binary, WasmMsg};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::fixture::SeedCommitment;
+use crate::player::PlayerInfo;
+use crate::player_attributes::Positions;
+use crate::utils::{LeagueAddr, SeasonId, TeamAddr};
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum DraftOrderType {
+    //worst record in the previous season picks first
+    ReverseStandings { previous_season_id: SeasonId },
+    //drawn from secrets the season's teams commit and reveal, see `DraftLottery`
+    Lottery { commit_deadline: Timestamp, reveal_deadline: Timestamp },
+}
+
+
+//Commit-reveal for a lottery draft's order, the same scheme fixtures use for
+//game seeds. Teams commit before `commit_deadline` and reveal after it, until
+//`reveal_deadline`. The last team to reveal knows the other secrets and can
+//still withhold its own to redraw everyone else's order, but a team that
+//doesn't reveal picks after every team that did.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DraftLottery {
+    pub commit_deadline: Timestamp,
+    pub reveal_deadline: Timestamp,
+    pub commitments: Vec<SeedCommitment>,
+    pub drawn: Option<Timestamp>,
+}
+
+impl DraftLottery {
+    pub fn commitment(&self, team: &TeamAddr) -> Option<&SeedCommitment> {
+        self.commitments.iter().find(|c| c.party == *team)
+    }
+
+    pub fn reveals_open(&self, block_time: Timestamp) -> bool {
+        block_time >= self.commit_deadline && block_time < self.reveal_deadline
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DraftPick {
+    pub pick_number: u32,
+    pub round: u32,
+    pub original_team: TeamAddr,
+    //current owner of the pick; differs from `original_team` once traded
+    pub team: TeamAddr,
+    pub player: Option<PlayerInfo>,
+    pub auto_picked: bool,
+    pub made: Option<Timestamp>,
+}
+
+
+//Identifies a pick in a season's draft; picks change hands through trade proposals
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DraftPickRef {
+    pub season_id: SeasonId,
+    pub pick_number: u32,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Draft {
+    pub season_id: SeasonId,
+    pub league: LeagueAddr,
+    //players still available
+    pub pool: Vec<PlayerInfo>,
+    pub picks: Vec<DraftPick>,
+    //seconds each team has to make its pick
+    pub pick_duration: u64,
+    pub start: Timestamp,
+    pub current_pick: u32,
+    pub current_pick_started: Timestamp,
+    pub completed: Option<Timestamp>,
+    //lottery drafts keep the season's team order until the lottery is drawn
+    #[serde(default)]
+    pub lottery: Option<DraftLottery>,
+}
+
+impl Draft {
+    //`order` is the pick order for every round
+    pub fn new(season_id: SeasonId, league: LeagueAddr, pool: Vec<PlayerInfo>, order: Vec<TeamAddr>,
+               rounds: u32, pick_duration: u64, start: Timestamp) -> Self {
+        let mut picks: Vec<DraftPick> = vec![];
+        for round in 1..=rounds {
+            for team in order.iter() {
+                picks.push(DraftPick {
+                    pick_number: picks.len() as u32 + 1,
+                    round,
+                    original_team: team.clone(),
+                    team: team.clone(),
+                    player: None,
+                    auto_picked: false,
+                    made: None
+                });
+            }
+        }
+        Draft {
+            season_id,
+            league,
+            pool,
+            picks,
+            pick_duration,
+            start,
+            current_pick: 0,
+            current_pick_started: start,
+            completed: None,
+            lottery: None,
+        }
+    }
+
+    //Teams in the order they pick each round
+    pub fn teams(&self) -> Vec<TeamAddr> {
+        self.picks.iter().filter(|p| p.round == 1).map(|p| p.original_team.clone()).collect()
+    }
+
+    pub fn lottery_pending(&self) -> bool {
+        self.lottery.as_ref().map_or(false, |l| l.drawn.is_none())
+    }
+
+    //Once every team has revealed or the reveal deadline has passed
+    pub fn lottery_due(&self, block_time: Timestamp) -> bool {
+        match &self.lottery {
+            Some(l) if l.drawn.is_none() => {
+                block_time >= l.reveal_deadline ||
+                    self.teams().iter().all(|t| l.commitment(t).map_or(false, |c| c.secret.is_some()))
+            }
+            _ => false
+        }
+    }
+
+    //Orders the teams that revealed by a hash of their secrets, in season order,
+    //followed by the teams that didn't, and reassigns every round's picks
+    pub fn draw_lottery(&mut self, block_time: Timestamp) {
+        let teams = self.teams();
+        let lottery = match self.lottery.as_mut() {
+            Some(l) => l,
+            None => return
+        };
+        let mut hasher = Sha256::new();
+        let mut revealed: Vec<TeamAddr> = vec![];
+        let mut withheld: Vec<TeamAddr> = vec![];
+        for team in teams {
+            match lottery.commitment(&team).and_then(|c| c.secret.clone()) {
+                Some(secret) => {
+                    hasher.update(secret.as_slice());
+                    revealed.push(team);
+                }
+                None => withheld.push(team)
+            }
+        }
+        let digest = hasher.finalize();
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&digest[..8]);
+        let mut order = lottery_order(revealed, u64::from_be_bytes(bytes));
+        order.extend(withheld);
+        lottery.drawn = Some(block_time);
+        for (i, pick) in self.picks.iter_mut().enumerate() {
+            pick.original_team = order[i % order.len()].clone();
+            pick.team = pick.original_team.clone();
+        }
+    }
+
+    pub fn on_the_clock(&self) -> Option<&DraftPick> {
+        match self.completed {
+            Some(_) => None,
+            None => self.picks.get(self.current_pick as usize)
+        }
+    }
+
+    pub fn current_pick_deadline(&self) -> Timestamp {
+        self.current_pick_started.plus_seconds(self.pick_duration)
+    }
+
+    //Records the current pick (`None` when nobody could be picked) and puts the
+    //next team on the clock. `clock_start` is when the next pick's timer begins.
+    pub fn record_pick(&mut self, player: Option<PlayerInfo>, auto_picked: bool,
+                       block_time: Timestamp, clock_start: Timestamp) {
+        if let Some(p) = &player {
+            self.pool.retain(|i| i.address != p.address);
+        }
+        if let Some(pick) = self.picks.get_mut(self.current_pick as usize) {
+            pick.player = player;
+            pick.auto_picked = auto_picked;
+            pick.made = Some(block_time);
+        }
+        self.current_pick += 1;
+        self.current_pick_started = clock_start;
+        if self.current_pick as usize >= self.picks.len() || self.pool.is_empty() {
+            self.completed = Some(block_time);
+        }
+    }
+
+    //First player in the pool whose position isn't in `filled_positions`
+    pub fn best_available(&self, filled_positions: &[Positions]) -> Option<PlayerInfo> {
+        self.pool.
+            iter().
+            find(|p| !filled_positions.contains(&p.position)).
+            cloned()
+    }
+}
+
+
+//Shuffles teams with a seed; lottery drafts draw it from the teams' revealed secrets
+pub fn lottery_order(mut teams: Vec<TeamAddr>, seed: u64) -> Vec<TeamAddr> {
+    let mut state = seed | 1;
+    for i in (1..teams.len()).rev() {
+        //xorshift64
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let j = (state % (i as u64 + 1)) as usize;
+        teams.swap(i, j);
+    }
+    teams
+}
+
+
+//Mirrors the team contract's `ExecuteMsg::AddDraftedPlayers` so the managing
+//contract can update a roster without depending on the team crate
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamDraftExecuteMsg {
+    AddDraftedPlayers { season_id: SeasonId, players: Vec<PlayerInfo> },
+}
+
+
+pub fn add_drafted_players_msg(team_addr: TeamAddr, season_id: SeasonId, players: Vec<PlayerInfo>) -> CosmosMsg {
+    WasmMsg::Execute {
+        contract_addr: team_addr.to_string(),
+        msg: to_binary(&TeamDraftExecuteMsg::AddDraftedPlayers { season_id, players })
+            .expect("Expected known add_drafted_players msg"),
+        funds: vec![]
+    }.into()
+}
+
+
+//Mirrors the team contract's roster query so the managing contract
+//can see which positions a team still has open
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamRosterQueryMsg {
+    GetAllPlayers {},
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RosterSlot {
+    pub player: Option<Addr>,
+    pub position: Positions,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RosterResponse {
+    pub players: Vec<RosterSlot>,
+}
+
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::testing::mock_env;
+
+    use super::*;
+
+    fn player(name: &str, position: Positions) -> PlayerInfo {
+        PlayerInfo {
+            address: Addr::unchecked(name),
+            first_name: name.to_string(),
+            last_name: name.to_string(),
+            position,
+            assigned_team_address: None
+        }
+    }
+
+    #[test]
+    fn picks_follow_order_each_round() {
+        let block_time = mock_env().block.time;
+        let (a, b) = (Addr::unchecked("a"), Addr::unchecked("b"));
+        let mut draft = Draft::new(1, Addr::unchecked("league"),
+                                   vec![player("qb", Positions::QB), player("rb", Positions::RB), player("s", Positions::S)],
+                                   vec![b.clone(), a.clone()], 2, 60, block_time);
+        assert_eq!(vec![b.clone(), a.clone(), b.clone(), a.clone()],
+                   draft.picks.iter().map(|p| p.team.clone()).collect::<Vec<TeamAddr>>());
+
+        //auto-pick skips positions the team already has filled
+        assert_eq!(Some(player("rb", Positions::RB)), draft.best_available(&[Positions::QB]));
+        draft.record_pick(Some(player("rb", Positions::RB)), false, block_time, block_time);
+        assert_eq!(Some(&a), draft.on_the_clock().map(|p| &p.team));
+        assert_eq!(block_time.plus_seconds(60), draft.current_pick_deadline());
+
+        //draft ends when the pool runs out
+        draft.record_pick(Some(player("qb", Positions::QB)), false, block_time, block_time);
+        draft.record_pick(Some(player("s", Positions::S)), true, block_time, block_time);
+        assert_eq!(Some(block_time), draft.completed);
+        assert_eq!(None, draft.on_the_clock());
+    }
+
+    #[test]
+    fn lottery_puts_withheld_reveals_last() {
+        let block_time = mock_env().block.time;
+        let teams: Vec<TeamAddr> = ["a", "b", "c"].iter().map(|t| Addr::unchecked(*t)).collect();
+        let mut draft = Draft::new(1, Addr::unchecked("league"), vec![player("qb", Positions::QB)],
+                                   teams.clone(), 2, 60, block_time.plus_seconds(200));
+        draft.lottery = Some(DraftLottery { commit_deadline: block_time.plus_seconds(100),
+            reveal_deadline: block_time.plus_seconds(200), commitments: vec![], drawn: None });
+        for (team, secret) in [(&teams[0], Some(b"a".to_vec())), (&teams[1], None), (&teams[2], Some(b"c".to_vec()))] {
+            draft.lottery.as_mut().unwrap().commitments.push(SeedCommitment { party: team.clone(),
+                commitment: "x".to_string(), secret: secret.map(|s| s.into()) });
+        }
+        assert!(draft.lottery_pending());
+        assert!(!draft.lottery_due(block_time.plus_seconds(150)));
+        assert!(draft.lottery_due(block_time.plus_seconds(200)));
+
+        draft.draw_lottery(block_time.plus_seconds(200));
+        assert!(!draft.lottery_pending());
+        let order = draft.teams();
+        assert_eq!(teams[1], order[2]);
+        assert_eq!(order, draft.picks.iter().skip(3).map(|p| p.team.clone()).collect::<Vec<TeamAddr>>());
+    }
+
+    #[test]
+    fn lottery_keeps_every_team() {
+        let teams: Vec<TeamAddr> = ["a", "b", "c", "d"].iter().map(|t| Addr::unchecked(*t)).collect();
+        let mut order = lottery_order(teams.clone(), 12345);
+        assert_eq!(order, lottery_order(teams.clone(), 12345));
+        order.sort();
+        assert_eq!(teams, order);
+    }
+}
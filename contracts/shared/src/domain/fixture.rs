@@ -0,0 +1,228 @@
+use cosmwasm_std::{Addr, Binary, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::player_attributes::Positions;
+use crate::utils::{LeagueAddr, PlayerAddr, SeasonId, TeamAddr};
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SeedCommitment {
+    pub party: Addr,
+    //hex sha256 of `party` followed by the secret, see `seed_commitment`
+    pub commitment: String,
+    pub secret: Option<Binary>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LineupSlot {
+    pub player: PlayerAddr,
+    pub position: Positions,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum PlayCallingStrategy {
+    Balanced,
+    RunHeavy,
+    PassHeavy,
+}
+
+impl Default for PlayCallingStrategy {
+    fn default() -> Self {
+        PlayCallingStrategy::Balanced
+    }
+}
+
+
+//A team's game-day decisions for one episode of a season
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GameDayLineup {
+    pub team: TeamAddr,
+    pub season_id: SeasonId,
+    pub episode: u32,
+    pub offense: Vec<LineupSlot>,
+    pub defense: Vec<LineupSlot>,
+    pub play_calling_strategy: PlayCallingStrategy,
+    pub submitted: Timestamp,
+}
+
+impl GameDayLineup {
+    pub fn slots(&self) -> Vec<LineupSlot> {
+        self.offense.iter().chain(self.defense.iter()).cloned().collect()
+    }
+}
+
+
+//A team's lineup as it stood when the fixture's first secret was revealed
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FixtureLineup {
+    pub team: TeamAddr,
+    pub slots: Vec<LineupSlot>,
+    pub strategy: PlayCallingStrategy,
+}
+
+
+//Player attributes never change after instantiation, so `seed`, the two
+//lineups and their strategies are all that's needed to replay a played
+//fixture with the game engine
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum FixtureResult {
+    Played { seed: u64, home_score: u32, away_score: u32, home_lineup: Vec<LineupSlot>, away_lineup: Vec<LineupSlot>,
+        home_strategy: PlayCallingStrategy, away_strategy: PlayCallingStrategy },
+    //teams that didn't commit and reveal in time
+    Forfeit { forfeited: Vec<TeamAddr> },
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Fixture {
+    pub id: u64,
+    pub season_id: SeasonId,
+    //the season episode the fixture is played in; lineups are submitted per episode
+    pub episode: u32,
+    pub league: LeagueAddr,
+    pub home: TeamAddr,
+    pub away: TeamAddr,
+    //the league adds its own secret to the seed
+    pub league_commits: bool,
+    pub commit_deadline: Timestamp,
+    pub reveal_deadline: Timestamp,
+    pub commitments: Vec<SeedCommitment>,
+    //taken on the first reveal, so neither team can change what plays once
+    //a secret is public; rosters aren't locked the way submitted lineups are
+    #[serde(default)]
+    pub lineups: Vec<FixtureLineup>,
+    pub result: Option<FixtureResult>,
+    pub completed: Option<Timestamp>,
+}
+
+impl Fixture {
+    //in the order their secrets are hashed into the seed
+    pub fn parties(&self) -> Vec<Addr> {
+        let mut res = vec![self.home.clone(), self.away.clone()];
+        if self.league_commits {
+            res.push(self.league.clone());
+        }
+        res
+    }
+
+    pub fn commitment(&self, party: &Addr) -> Option<&SeedCommitment> {
+        self.commitments.iter().find(|c| c.party == *party)
+    }
+
+    //Lineups lock `lock_period` seconds before the commit deadline, when the
+    //fixture is considered to kick off
+    pub fn lineup_lock(&self, lock_period: u64) -> Timestamp {
+        Timestamp::from_seconds(self.commit_deadline.seconds().saturating_sub(lock_period))
+    }
+
+    pub fn has_revealed(&self, party: &Addr) -> bool {
+        self.commitment(party).map_or(false, |c| c.secret.is_some())
+    }
+
+    //Reveals open at the commit deadline, after the lineup lock, so no one sees a
+    //secret while they can still pick their own or change their lineup
+    pub fn reveals_open(&self, block_time: Timestamp) -> bool {
+        block_time >= self.commit_deadline && block_time < self.reveal_deadline
+    }
+
+    pub fn lineup(&self, team: &TeamAddr) -> Option<&FixtureLineup> {
+        self.lineups.iter().find(|l| l.team == *team)
+    }
+
+    //Teams that haven't revealed; they forfeit
+    pub fn unrevealed_teams(&self) -> Vec<TeamAddr> {
+        vec![self.home.clone(), self.away.clone()].
+            into_iter().
+            filter(|t| !self.has_revealed(t)).
+            collect()
+    }
+
+    //Parties that committed but kept their secret. A league doing this after both
+    //teams revealed could pick between two known seeds, so the fixture can't be played.
+    pub fn withheld_reveals(&self) -> Vec<Addr> {
+        self.commitments.iter().filter(|c| c.secret.is_none()).map(|c| c.party.clone()).collect()
+    }
+
+    //Every committed party has revealed; nothing is left to wait for
+    pub fn all_revealed(&self) -> bool {
+        self.parties().iter().all(|p| self.has_revealed(p))
+    }
+
+    //Hash of every revealed secret in `parties` order. Only used once nothing is
+    //withheld, so the only secret that can be missing is a league's that never committed.
+    pub fn combined_seed(&self) -> u64 {
+        let mut hasher = Sha256::new();
+        for party in self.parties() {
+            if let Some(secret) = self.commitment(&party).and_then(|c| c.secret.clone()) {
+                hasher.update(secret.as_slice());
+            }
+        }
+        let digest = hasher.finalize();
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&digest[..8]);
+        u64::from_be_bytes(bytes)
+    }
+}
+
+
+//The committing address is hashed in so a party can't copy someone
+//else's commitment and reveal the same secret
+pub fn seed_commitment(party: &Addr, secret: &Binary) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(party.as_bytes());
+    hasher.update(secret.as_slice());
+    hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::testing::mock_env;
+
+    use super::*;
+
+    #[test]
+    fn reveals_wait_for_commitments() {
+        let now = mock_env().block.time;
+        let (home, away) = (Addr::unchecked("home"), Addr::unchecked("away"));
+        let mut fixture = Fixture {
+            id: 1,
+            season_id: 1,
+            episode: 1,
+            league: Addr::unchecked("league"),
+            home: home.clone(),
+            away: away.clone(),
+            league_commits: false,
+            commit_deadline: now.plus_seconds(100),
+            reveal_deadline: now.plus_seconds(200),
+            commitments: vec![],
+            lineups: vec![],
+            result: None,
+            completed: None,
+        };
+        let secret = Binary::from(b"home secret".to_vec());
+        assert_ne!(seed_commitment(&home, &secret), seed_commitment(&away, &secret));
+
+        fixture.commitments.push(SeedCommitment { party: home.clone(), commitment: seed_commitment(&home, &secret), secret: None });
+        assert!(!fixture.reveals_open(now));
+        assert!(fixture.reveals_open(now.plus_seconds(100)));
+        assert!(!fixture.reveals_open(now.plus_seconds(200)));
+
+        //every party having committed doesn't open reveals before the deadline
+        fixture.commitments.push(SeedCommitment { party: away.clone(), commitment: "x".to_string(), secret: None });
+        assert!(!fixture.reveals_open(now));
+        assert!(fixture.lineup_lock(60) <= fixture.commit_deadline);
+        assert_eq!(vec![home.clone(), away.clone()], fixture.unrevealed_teams());
+
+        fixture.commitments[0].secret = Some(secret);
+        assert_eq!(vec![away.clone()], fixture.withheld_reveals());
+        let seed = fixture.combined_seed();
+        fixture.commitments[1].secret = Some(Binary::from(b"away secret".to_vec()));
+        assert!(fixture.all_revealed());
+        assert_ne!(seed, fixture.combined_seed());
+    }
+}
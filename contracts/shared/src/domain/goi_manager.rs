@@ -1,23 +1,31 @@
 use std::collections::HashMap;
 
-use cosmwasm_std::{Addr, Coin, CosmosMsg, ReplyOn, Response, SubMsg, Timestamp, to_binary, WasmMsg};
+use cosmwasm_std::{Addr, Binary, Coin, CosmosMsg, ReplyOn, Response, SubMsg, Timestamp, to_binary, WasmMsg};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::application::AppInfo;
-use crate::display::DisplayInfo;
+use crate::advertising::{AdContent, AdPlacement};
+use crate::display::{DisplayDetails, DisplayInfo};
 use crate::goi_manager::ExecuteMsg::{AddManagedContract, ManagedAssetSoldHook, UpdateAssetForSaleStatusHook};
-use crate::league::LeagueInfo;
+use crate::league::{LeagueInfo, LeagueTypes};
+use crate::leaderboard::StatCategory;
 use crate::manage::{ManagedStatusChangedHookMsg, ManagementFee};
-use crate::player::PlayerInfo;
+use crate::player::{PlayerInfo, PlayerStatus};
 use crate::rewards::Reward;
-use crate::team::TeamInfo;
+use crate::team::{TeamCoaching, TeamInfo};
 use crate::utils::general::AssetTypes;
 use group_admin::messages::receive::ExecuteMsg as GroupAdminHooksMsg;
 use crate::goi_manager;
-use crate::messaging::{JoinSeasonRequestInfo, MessageTypes};
-use crate::season::Season;
-use crate::utils::{MessageId, LeagueAddr, SeasonId, TeamAddr};
+use crate::messaging::{InboxContent, JoinSeasonRequestInfo, MessageTypes};
+use crate::season::{Season, SeasonCapacityPolicy, SeasonTemplate};
+use crate::division::DivisionModelData;
+use crate::draft::{DraftOrderType, DraftPickRef};
+use crate::fixture::{LineupSlot, PlayCallingStrategy};
+use crate::player_contract::PlayerSigning;
+use crate::playoffs::PlayoffFormat;
+use crate::player_attributes::Positions;
+use crate::utils::{DivisionId, MessageId, LeagueAddr, PlayerAddr, SeasonId, TeamAddr};
 
 
 pub fn get_minters() -> Vec<Addr> {
@@ -51,6 +59,8 @@ pub struct GoiMangerContractModel {
 #[serde(rename_all = "snake_case")]
 pub enum GoiManagerQueryMsg {
     GetPlayerByName{first_name: String, last_name: String},
+    GetTeamPlayers { team_addr: TeamAddr },
+    GetPlayersByPosition { position: Positions, start_after: Option<PlayerAddr>, limit: Option<u32> },
     GetManagedContract { contract_address: Addr, contract_type: AssetTypes},
     ManagementQryMessages { management_qry_msg: ManagementQryMsg},
     GetOwnerAssets{ owner_address: Addr},
@@ -64,7 +74,58 @@ pub enum GoiManagerQueryMsg {
     CheckSeasonDateRangeForLeague { start_date: Timestamp, end_date: Timestamp, league_addr: Addr },
     GetMessagesToItem { item_addr: Addr, asset_type: AssetTypes },
     GetMessagesFromItem {  item_addr: Addr, asset_type: AssetTypes },
-    GetLeagueTeams { league_addr: LeagueAddr}
+    GetLeagueTeams { league_addr: LeagueAddr},
+    GetSeasonCapacityPolicy {},
+    GetSeasonDivisions { season_id: SeasonId },
+    GetSeasonTeamsByDivision { season_id: SeasonId },
+    GetSeasonStandingsByDivision { season_id: SeasonId },
+    //a fixture is listed under the division of each of its teams
+    GetSeasonScheduleByDivision { season_id: SeasonId },
+    GetPlayoffs { season_id: SeasonId },
+    GetSeasonChampion { season_id: SeasonId },
+    GetSeasonRescheduleNotices { item_addr: Addr, asset_type: AssetTypes },
+    GetSeasonTemplate { league_addr: LeagueAddr },
+    GetTradeProposal { trade_id: u64 },
+    GetTeamTradeProposals { team_addr: TeamAddr },
+    GetFreeAgents { position: Option<Positions>, start_after: Option<PlayerAddr>, limit: Option<u32> },
+    GetDraft { season_id: SeasonId },
+    GetSalaryCap { league_addr: LeagueAddr },
+    GetPlayerContract { player: PlayerAddr },
+    GetTeamPlayerContracts { team_addr: TeamAddr },
+    GetPlayerStatus { player: PlayerAddr },
+    GetCareerSummary { player: PlayerAddr },
+    CheckLineup { team_addr: TeamAddr, season_id: SeasonId, players: Vec<PlayerAddr> },
+    GetFixture { fixture_id: u64 },
+    GetSeasonFixtures { season_id: SeasonId },
+    //the lineup a fixture in the episode would be played with; the team's
+    //latest earlier lineup when it didn't submit one for the episode
+    GetLineup { team_addr: TeamAddr, season_id: SeasonId, episode: u32 },
+    GetLineupLock { league_addr: LeagueAddr },
+    GetDisplay { display_addr: Addr },
+    GetDisplays { start_after: Option<Addr>, limit: Option<u32> },
+    GetBroadcast { booking_id: u64 },
+    //the display's calendar in slot order, leaving out slots that ended before `from`
+    GetDisplayBroadcasts { display_addr: Addr, from: Option<Timestamp>, limit: Option<u32> },
+    GetLeagueBroadcasts { league_addr: LeagueAddr, start_after: Option<u64>, limit: Option<u32> },
+    CheckBroadcastConflicts { display_addr: Addr, start: Timestamp, end: Timestamp },
+    GetAd { ad_id: u64 },
+    //ads waiting on or approved by a display or league
+    GetAdsByApprover { approver: Addr, start_after: Option<u64>, limit: Option<u32> },
+    GetAdsBySponsor { sponsor: Addr, start_after: Option<u64>, limit: Option<u32> },
+    //newest first, leaving out expired messages
+    GetInbox { addr: Addr, unread_only: bool, start_after: Option<MessageId>, limit: Option<u32> },
+    GetOutbox { addr: Addr, start_after: Option<MessageId>, limit: Option<u32> },
+    GetInboxThread { thread: MessageId },
+    //None sums every season
+    GetPlayerStats { player: PlayerAddr, season_id: Option<SeasonId> },
+    GetTeamStats { team_addr: TeamAddr, season_id: Option<SeasonId> },
+    //All-time boards across every league of `league_type`, best first.
+    //`start_after` is the last item of the previous page.
+    GetTeamLeaderboard { league_type: LeagueTypes, start_after: Option<TeamAddr>, limit: Option<u32> },
+    GetWinStreakLeaderboard { league_type: LeagueTypes, start_after: Option<TeamAddr>, limit: Option<u32> },
+    GetPlayerLeaderboard { league_type: LeagueTypes, category: StatCategory, start_after: Option<PlayerAddr>, limit: Option<u32> },
+    //newest champions first
+    GetHallOfFame { league_type: LeagueTypes, start_after: Option<SeasonId>, limit: Option<u32> },
 }
 
 
@@ -81,11 +142,38 @@ pub enum ManagementQryMsg {
 pub enum ExecuteMsg {
     ManagedStatusChangedHook (ManagedStatusChangedHookMsg),
     AddManagedContract { asset_name: Option<String>, asset_owner: Addr, contract_type: AssetTypes},
+    //sent by a league/team right after `AddManagedContract` when it's instantiated
+    DeclareLeagueType { league_type: LeagueTypes },
+    DeclareTeamCoaching { coaching: TeamCoaching },
+    //sent by the attestation a team's AI coaching declaration names
+    ConfirmAiCoaching { team: TeamAddr },
+    //sent by a display contract when it's instantiated or its details change
+    UpdateDisplayDetails { details: DisplayDetails },
+    DisplayHeartbeat { online: bool },
+    //sent by a league with the payment attached; refunds go back to the payer
+    RequestBroadcast { display: Addr, fixture_id: u64, start: Timestamp, end: Timestamp, payer: Addr },
+    //sent by the booked display
+    RespondToBroadcast { booking_id: u64, accept: bool },
+    //sent by the booking league or the booked display
+    CancelBroadcast { booking_id: u64 },
+    //anyone can settle a booking once its slot has ended, or expire an unanswered one once it's started
+    SettleBroadcast { booking_id: u64 },
+    //sent by a sponsor with the payment attached
+    RequestAd { placement: AdPlacement, content: AdContent },
+    //sent by the display or league the ad is placed on
+    RespondToAd { ad_id: u64, accept: bool, attestor: Option<Addr> },
+    //sent by the sponsor, or while the placement airs by its approver or the ad's attestor; pays out the ad
+    AttestAdAired { ad_id: u64 },
+    WithdrawAd { ad_id: u64 },
+    //sent by any managed asset to another
+    SendInboxMessage { to: Addr, content: InboxContent, body: String, reply_to: Option<MessageId>, expires: Option<Timestamp> },
+    MarkInboxMessageRead { message_id: MessageId, read: bool },
     GroupAdminHooks {group_admin_hooks_msg: GroupAdminHooksMsg },
     UpdateFees { add: Option<Vec<ManagementFee>>, remove: Option<Vec<i32>>},
     AddPlayersToTeam { players: Vec<PlayerInfo>},
     UpdateAssetForSaleStatusHook { for_sale_status: bool, price: Option<Coin> },
     ManagedAssetSoldHook { new_owner: Addr },
+    ManagedAssetSharesTransferredHook { from: Addr, to: Addr, weight: u64 },
     Withdraw{ recipient: Addr, amount: Vec<Coin> },
     AddSeasonToLeague { season: Season },
     //Sending (user, not contract) must own teams being
@@ -95,6 +183,70 @@ pub enum ExecuteMsg {
     JoinLeague { season_id: SeasonId },
     CancelSeasonSpot { season_id: SeasonId},
     JoinLeagueWinnerTakeAll { season_id: SeasonId, fee: Vec<Coin> },
+    UpdateSeasonCapacity { season_id: SeasonId, max_teams_allowed: u32 },
+    //Admin only; sets the range leagues may choose
+    //a season's max_teams_allowed from
+    UpdateSeasonCapacityPolicy { policy: SeasonCapacityPolicy },
+    //Divisions can only be added/edited before the season starts;
+    //accepted teams are assigned to a division as they join
+    AddDivisionToSeason { season_id: SeasonId, division: DivisionModelData },
+    UpdateDivision { division_id: DivisionId, division: DivisionModelData },
+    //Seeds the top `playoff_teams` (all teams when None) from the
+    //season standings once the regular season has ended
+    StartPlayoffs { season_id: SeasonId, format: PlayoffFormat, playoff_teams: Option<u32> },
+    SubmitPlayoffResult { season_id: SeasonId, match_id: u32, winner: TeamAddr },
+    //Moves a season's dates (even once it has started) and notifies every
+    //accepted team, who may then withdraw with a full refund
+    RescheduleSeason { season_id: SeasonId, new_start: Timestamp, new_end: Timestamp, reason: String },
+    WithdrawFromRescheduledSeason { season_id: SeasonId },
+    //None removes the league's template
+    SetSeasonTemplate { template: Option<SeasonTemplate> },
+    //Permissionless; creates the league's next season from its
+    //template once the previous season has ended
+    SpawnNextSeason { league: LeagueAddr },
+    //`offered_funds` has to match the funds sent with the message
+    ProposeTrade { counterparty_team: TeamAddr, offered_players: Vec<PlayerInfo>, requested_players: Vec<PlayerInfo>,
+        offered_funds: Vec<Coin>, #[serde(default)] offered_picks: Vec<DraftPickRef>,
+        #[serde(default)] requested_picks: Vec<DraftPickRef> },
+    RespondToTrade { trade_id: u64, accept: bool },
+    CancelTrade { trade_id: u64 },
+    //sent by the player's owner to set who may sign an unassigned player
+    UpdateFreeAgentTerms { player: PlayerAddr, signing_fee: Option<Coin>, approved_team: Option<TeamAddr> },
+    SignFreeAgent { player: PlayerAddr },
+    ReleasePlayers { players: Vec<PlayerInfo> },
+    CreateDraft { season_id: SeasonId, pool: Vec<PlayerAddr>, rounds: u32, order_type: DraftOrderType,
+        pick_duration: u64, start: Timestamp },
+    MakeDraftPick { season_id: SeasonId, player: PlayerAddr },
+    //commit-reveal for a lottery draft's order
+    CommitDraftSeed { season_id: SeasonId, commitment: String },
+    RevealDraftSeed { season_id: SeasonId, secret: Binary },
+    //sent by the player's owner to put an untracked player in a season's
+    //draft pool; None withdraws it before the draft is created
+    SetDraftOptIn { player: PlayerAddr, season_id: Option<SeasonId> },
+    //permissionless; auto-picks for every team whose time ran out
+    AdvanceDraft { season_id: SeasonId },
+    AddPlayersToTeamWithContracts { signings: Vec<PlayerSigning> },
+    SetSalaryCap { cap: Option<Coin> },
+    //moves the season to its next episode and runs payroll for its teams
+    AdvanceEpisode { season_id: SeasonId },
+    //sent by a league for players on its teams; retirement goes through RetirePlayer
+    SetPlayerStatus { player: PlayerAddr, status: PlayerStatus },
+    //sent by the player's owner
+    RetirePlayer { player: PlayerAddr },
+    //Both teams (and the league when `league_commits`) commit a seed hash before
+    //`commit_deadline` and reveal it before `reveal_deadline`
+    ScheduleFixture { season_id: SeasonId, episode: u32, home: TeamAddr, away: TeamAddr, league_commits: bool,
+        commit_deadline: Timestamp, reveal_deadline: Timestamp },
+    //`commitment` is `fixture::seed_commitment(sender, secret)`
+    CommitFixtureSeed { fixture_id: u64, commitment: String },
+    RevealFixtureSeed { fixture_id: u64, secret: Binary },
+    //permissionless; plays the fixture once every party has revealed, or
+    //forfeits the teams that haven't once the reveal deadline passes
+    ResolveFixture { fixture_id: u64 },
+    //Locks the lineup lock period before the team's first fixture of the episode
+    SubmitLineup { season_id: SeasonId, episode: u32, offense: Vec<LineupSlot>, defense: Vec<LineupSlot>,
+        play_calling_strategy: PlayCallingStrategy },
+    SetLineupLock { seconds: u64 },
 }
 
 
@@ -160,6 +312,23 @@ pub fn on_successful_buy(new_owner: Addr, managing_contract: Addr, response: Res
 }
 
 
+pub fn on_shares_transferred(from: Addr, to: Addr, weight: u64, managing_contract: Addr, response: Response) ->  Response {
+    let shares_transferred_hook_msg = goi_manager::ExecuteMsg::ManagedAssetSharesTransferredHook { from, to, weight };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&shares_transferred_hook_msg).expect("Expected known shares_transferred_hook_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
 pub fn send_add_season_msg_to_goi_manager(season: Season,  managing_contract: Addr, response: Response) ->  Response {
     let add_season_msg =
         goi_manager::ExecuteMsg::AddSeasonToLeague { season: season };
@@ -177,6 +346,125 @@ pub fn send_add_season_msg_to_goi_manager(season: Season,  managing_contract: Ad
     response.add_submessage(res_sub_msg)
 }
 
+pub fn send_update_season_capacity_msg_to_goi_manager(season_id: SeasonId, max_teams_allowed: u32, managing_contract: Addr, response: Response) ->  Response {
+    let update_capacity_msg =
+        goi_manager::ExecuteMsg::UpdateSeasonCapacity { season_id, max_teams_allowed };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&update_capacity_msg).expect("Expected known update_season_capacity msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+pub fn send_add_division_msg_to_goi_manager(season_id: SeasonId, division: DivisionModelData, managing_contract: Addr, response: Response) ->  Response {
+    let add_division_msg =
+        goi_manager::ExecuteMsg::AddDivisionToSeason { season_id, division };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&add_division_msg).expect("Expected known add_division_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+pub fn send_update_division_msg_to_goi_manager(division_id: DivisionId, division: DivisionModelData, managing_contract: Addr, response: Response) ->  Response {
+    let update_division_msg =
+        goi_manager::ExecuteMsg::UpdateDivision { division_id, division };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&update_division_msg).expect("Expected known update_division_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+pub fn send_start_playoffs_msg_to_goi_manager(season_id: SeasonId, format: PlayoffFormat, playoff_teams: Option<u32>, managing_contract: Addr, response: Response) ->  Response {
+    let start_playoffs_msg =
+        goi_manager::ExecuteMsg::StartPlayoffs { season_id, format, playoff_teams };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&start_playoffs_msg).expect("Expected known start_playoffs_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+pub fn send_submit_playoff_result_msg_to_goi_manager(season_id: SeasonId, match_id: u32, winner: TeamAddr, managing_contract: Addr, response: Response) ->  Response {
+    let submit_playoff_result_msg =
+        goi_manager::ExecuteMsg::SubmitPlayoffResult { season_id, match_id, winner };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&submit_playoff_result_msg).expect("Expected known submit_playoff_result_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+pub fn send_reschedule_season_msg_to_goi_manager(season_id: SeasonId, new_start: Timestamp, new_end: Timestamp, reason: String, managing_contract: Addr, response: Response) ->  Response {
+    let reschedule_season_msg =
+        goi_manager::ExecuteMsg::RescheduleSeason { season_id, new_start, new_end, reason };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&reschedule_season_msg).expect("Expected known reschedule_season_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+pub fn send_set_season_template_msg_to_goi_manager(template: Option<SeasonTemplate>, managing_contract: Addr, response: Response) ->  Response {
+    let set_season_template_msg =
+        goi_manager::ExecuteMsg::SetSeasonTemplate { template };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&set_season_template_msg).expect("Expected known set_season_template_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
 pub fn send_add_team_to_league_msg_to_goi_manager(teams: Vec<TeamAddr>, sending_user: Addr,   managing_contract: Addr,  response: Response)  -> Response {
     let add_teams_msg =
         goi_manager::ExecuteMsg::AddTeamsToLeague { teams, sending_user };
@@ -260,22 +548,29 @@ pub fn send_request_to_cancel_season_spot(season_id: SeasonId, managing_contract
 
 
 
-pub fn send_request_to_join_winner_takes_all_season(season_id: SeasonId, fee: Vec<Coin>, managing_contract: Addr, response: Response) -> Response {
-    let res:CosmosMsg =
-        cosmwasm_std::BankMsg::Send
-        {
-            to_address: managing_contract.to_string(),
-            amount: fee.clone()
-        }.into();
-    let res_fee_sub_msg =
+pub fn send_request_to_withdraw_from_rescheduled_season(season_id: SeasonId, managing_contract: Addr, response: Response) -> Response {
+    let msg =
+        goi_manager::ExecuteMsg::WithdrawFromRescheduledSeason {
+            season_id
+        };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&msg).expect("Expected known withdraw_from_rescheduled_season msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
         SubMsg{
             id: 0,
-            msg: res,
+            msg: exc_msg,
             gas_limit: None,
             reply_on: ReplyOn::Never
         };
+    response.add_submessage(res_sub_msg)
+}
+
 
 
+//The entry fee travels only as funds attached to the join message
+pub fn send_request_to_join_winner_takes_all_season(season_id: SeasonId, fee: Vec<Coin>, managing_contract: Addr, response: Response) -> Response {
     let join_league_msg =
         goi_manager::ExecuteMsg::JoinLeagueWinnerTakeAll {
             season_id,
@@ -288,10 +583,553 @@ pub fn send_request_to_join_winner_takes_all_season(season_id: SeasonId, fee: Ve
 
     let res_join_league_sub_msg =
         SubMsg{
-            id: 1,
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_join_league_sub_msg)
+}
+
+
+//offered funds are attached to the message and held until the trade closes
+pub fn send_propose_trade_msg_to_goi_manager(counterparty_team: TeamAddr, offered_players: Vec<PlayerInfo>,
+                                             requested_players: Vec<PlayerInfo>, offered_funds: Vec<Coin>,
+                                             offered_picks: Vec<DraftPickRef>, requested_picks: Vec<DraftPickRef>,
+                                             managing_contract: Addr, response: Response) ->  Response {
+    let propose_trade_msg =
+        goi_manager::ExecuteMsg::ProposeTrade { counterparty_team, offered_players, requested_players,
+            offered_funds: offered_funds.clone(), offered_picks, requested_picks };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&propose_trade_msg).expect("Expected known propose_trade_msg msg")  , funds: offered_funds }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_respond_to_trade_msg_to_goi_manager(trade_id: u64, accept: bool, managing_contract: Addr, response: Response) ->  Response {
+    let respond_to_trade_msg =
+        goi_manager::ExecuteMsg::RespondToTrade { trade_id, accept };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&respond_to_trade_msg).expect("Expected known respond_to_trade_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_cancel_trade_msg_to_goi_manager(trade_id: u64, managing_contract: Addr, response: Response) ->  Response {
+    let cancel_trade_msg =
+        goi_manager::ExecuteMsg::CancelTrade { trade_id };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&cancel_trade_msg).expect("Expected known cancel_trade_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_sign_free_agent_msg_to_goi_manager(player: PlayerAddr, signing_fee: Vec<Coin>,
+                                               managing_contract: Addr, response: Response) ->  Response {
+    let sign_free_agent_msg =
+        goi_manager::ExecuteMsg::SignFreeAgent { player };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&sign_free_agent_msg).expect("Expected known sign_free_agent_msg msg")  , funds: signing_fee }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_release_players_msg_to_goi_manager(players: Vec<PlayerInfo>, managing_contract: Addr, response: Response) ->  Response {
+    let release_players_msg =
+        goi_manager::ExecuteMsg::ReleasePlayers { players };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&release_players_msg).expect("Expected known release_players_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_create_draft_msg_to_goi_manager(season_id: SeasonId, pool: Vec<PlayerAddr>, rounds: u32, order_type: DraftOrderType,
+                                          pick_duration: u64, start: Timestamp, managing_contract: Addr, response: Response) ->  Response {
+    let create_draft_msg =
+        goi_manager::ExecuteMsg::CreateDraft { season_id, pool, rounds, order_type, pick_duration, start };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&create_draft_msg).expect("Expected known create_draft_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_make_draft_pick_msg_to_goi_manager(season_id: SeasonId, player: PlayerAddr, managing_contract: Addr, response: Response) ->  Response {
+    let make_draft_pick_msg =
+        goi_manager::ExecuteMsg::MakeDraftPick { season_id, player };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&make_draft_pick_msg).expect("Expected known make_draft_pick_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_add_players_with_contracts_msg_to_goi_manager(signings: Vec<PlayerSigning>, managing_contract: Addr, response: Response) ->  Response {
+    let add_players_with_contracts_msg =
+        goi_manager::ExecuteMsg::AddPlayersToTeamWithContracts { signings };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&add_players_with_contracts_msg).expect("Expected known add_players_with_contracts_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_set_salary_cap_msg_to_goi_manager(cap: Option<Coin>, managing_contract: Addr, response: Response) ->  Response {
+    let set_salary_cap_msg =
+        goi_manager::ExecuteMsg::SetSalaryCap { cap };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&set_salary_cap_msg).expect("Expected known set_salary_cap_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
             msg: exc_msg,
             gas_limit: None,
             reply_on: ReplyOn::Never
         };
-    response.add_submessages( vec![res_fee_sub_msg, res_join_league_sub_msg])
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_advance_episode_msg_to_goi_manager(season_id: SeasonId, managing_contract: Addr, response: Response) ->  Response {
+    let advance_episode_msg =
+        goi_manager::ExecuteMsg::AdvanceEpisode { season_id };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&advance_episode_msg).expect("Expected known advance_episode_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_set_player_status_msg_to_goi_manager(player: PlayerAddr, status: PlayerStatus,
+                                                 managing_contract: Addr, response: Response) ->  Response {
+    let set_player_status_msg =
+        goi_manager::ExecuteMsg::SetPlayerStatus { player, status };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&set_player_status_msg).expect("Expected known set_player_status_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_schedule_fixture_msg_to_goi_manager(season_id: SeasonId, episode: u32, home: TeamAddr, away: TeamAddr,
+                                                league_commits: bool, commit_deadline: Timestamp, reveal_deadline: Timestamp,
+                                                managing_contract: Addr, response: Response) ->  Response {
+    let schedule_fixture_msg =
+        goi_manager::ExecuteMsg::ScheduleFixture { season_id, episode, home, away, league_commits, commit_deadline, reveal_deadline };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&schedule_fixture_msg).expect("Expected known schedule_fixture_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_commit_draft_seed_msg_to_goi_manager(season_id: SeasonId, commitment: String,
+                                                 managing_contract: Addr, response: Response) ->  Response {
+    let commit_draft_seed_msg =
+        goi_manager::ExecuteMsg::CommitDraftSeed { season_id, commitment };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&commit_draft_seed_msg).expect("Expected known commit_draft_seed_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_reveal_draft_seed_msg_to_goi_manager(season_id: SeasonId, secret: Binary,
+                                                 managing_contract: Addr, response: Response) ->  Response {
+    let reveal_draft_seed_msg =
+        goi_manager::ExecuteMsg::RevealDraftSeed { season_id, secret };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&reveal_draft_seed_msg).expect("Expected known reveal_draft_seed_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_commit_fixture_seed_msg_to_goi_manager(fixture_id: u64, commitment: String,
+                                                   managing_contract: Addr, response: Response) ->  Response {
+    let commit_fixture_seed_msg =
+        goi_manager::ExecuteMsg::CommitFixtureSeed { fixture_id, commitment };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&commit_fixture_seed_msg).expect("Expected known commit_fixture_seed_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_reveal_fixture_seed_msg_to_goi_manager(fixture_id: u64, secret: Binary,
+                                                   managing_contract: Addr, response: Response) ->  Response {
+    let reveal_fixture_seed_msg =
+        goi_manager::ExecuteMsg::RevealFixtureSeed { fixture_id, secret };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&reveal_fixture_seed_msg).expect("Expected known reveal_fixture_seed_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_submit_lineup_msg_to_goi_manager(season_id: SeasonId, episode: u32, offense: Vec<LineupSlot>,
+                                             defense: Vec<LineupSlot>, play_calling_strategy: PlayCallingStrategy,
+                                             managing_contract: Addr, response: Response) ->  Response {
+    let submit_lineup_msg =
+        goi_manager::ExecuteMsg::SubmitLineup { season_id, episode, offense, defense, play_calling_strategy };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&submit_lineup_msg).expect("Expected known submit_lineup_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_set_lineup_lock_msg_to_goi_manager(seconds: u64, managing_contract: Addr, response: Response) ->  Response {
+    let set_lineup_lock_msg =
+        goi_manager::ExecuteMsg::SetLineupLock { seconds };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&set_lineup_lock_msg).expect("Expected known set_lineup_lock_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_declare_league_type_msg_to_goi_manager(league_type: LeagueTypes,
+                                                   managing_contract: Addr, response: Response) ->  Response {
+    let declare_league_type_msg =
+        goi_manager::ExecuteMsg::DeclareLeagueType { league_type };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&declare_league_type_msg).expect("Expected known declare_league_type_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_declare_team_coaching_msg_to_goi_manager(coaching: TeamCoaching,
+                                                     managing_contract: Addr, response: Response) ->  Response {
+    let declare_team_coaching_msg =
+        goi_manager::ExecuteMsg::DeclareTeamCoaching { coaching };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&declare_team_coaching_msg).expect("Expected known declare_team_coaching_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_update_display_details_msg_to_goi_manager(details: DisplayDetails, managing_contract: Addr, response: Response) ->  Response {
+    let update_display_details_msg =
+        goi_manager::ExecuteMsg::UpdateDisplayDetails { details };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&update_display_details_msg).expect("Expected known update_display_details_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_display_heartbeat_msg_to_goi_manager(online: bool, managing_contract: Addr, response: Response) ->  Response {
+    let display_heartbeat_msg =
+        goi_manager::ExecuteMsg::DisplayHeartbeat { online };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&display_heartbeat_msg).expect("Expected known display_heartbeat_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_request_broadcast_msg_to_goi_manager(display: Addr, fixture_id: u64, start: Timestamp, end: Timestamp,
+                                                 payer: Addr, payment: Vec<Coin>, managing_contract: Addr, response: Response) ->  Response {
+    let request_broadcast_msg =
+        goi_manager::ExecuteMsg::RequestBroadcast { display, fixture_id, start, end, payer };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&request_broadcast_msg).expect("Expected known request_broadcast_msg msg")  , funds: payment }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_respond_to_broadcast_msg_to_goi_manager(booking_id: u64, accept: bool, managing_contract: Addr, response: Response) ->  Response {
+    let respond_to_broadcast_msg =
+        goi_manager::ExecuteMsg::RespondToBroadcast { booking_id, accept };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&respond_to_broadcast_msg).expect("Expected known respond_to_broadcast_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_cancel_broadcast_msg_to_goi_manager(booking_id: u64, managing_contract: Addr, response: Response) ->  Response {
+    let cancel_broadcast_msg =
+        goi_manager::ExecuteMsg::CancelBroadcast { booking_id };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&cancel_broadcast_msg).expect("Expected known cancel_broadcast_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_respond_to_ad_msg_to_goi_manager(ad_id: u64, accept: bool, attestor: Option<Addr>,
+                                             managing_contract: Addr, response: Response) ->  Response {
+    let respond_to_ad_msg =
+        goi_manager::ExecuteMsg::RespondToAd { ad_id, accept, attestor };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&respond_to_ad_msg).expect("Expected known respond_to_ad_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_attest_ad_aired_msg_to_goi_manager(ad_id: u64, managing_contract: Addr, response: Response) ->  Response {
+    let attest_ad_aired_msg =
+        goi_manager::ExecuteMsg::AttestAdAired { ad_id };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&attest_ad_aired_msg).expect("Expected known attest_ad_aired_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_inbox_message_msg_to_goi_manager(to: Addr, content: InboxContent, body: String, reply_to: Option<MessageId>,
+                                             expires: Option<Timestamp>, managing_contract: Addr, response: Response) ->  Response {
+    let send_inbox_message_msg =
+        goi_manager::ExecuteMsg::SendInboxMessage { to, content, body, reply_to, expires };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&send_inbox_message_msg).expect("Expected known send_inbox_message_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
+}
+
+
+pub fn send_mark_inbox_message_read_msg_to_goi_manager(message_id: MessageId, read: bool, managing_contract: Addr, response: Response) ->  Response {
+    let mark_inbox_message_read_msg =
+        goi_manager::ExecuteMsg::MarkInboxMessageRead { message_id, read };
+    let exc_msg:CosmosMsg =
+        WasmMsg::Execute { contract_addr: managing_contract.to_string(),
+            msg: to_binary(&mark_inbox_message_read_msg).expect("Expected known mark_inbox_message_read_msg msg")  , funds: vec![] }.into();
+
+    let res_sub_msg =
+        SubMsg{
+            id: 0,
+            msg: exc_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never
+        };
+    response.add_submessage(res_sub_msg)
 }
@@ -0,0 +1,119 @@
+use cosmwasm_std::{Addr, Binary, Decimal, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_VOTING_PERIOD: u64 = 60 * 60 * 24 * 3; //3 days
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GovernanceConfig {
+    //share of the total weight that must vote (yes, no or abstain)
+    pub quorum: Decimal,
+    //share of the non-abstaining votes that must be yes
+    pub threshold: Decimal,
+    //seconds a proposal stays open
+    pub voting_period: u64,
+}
+
+impl GovernanceConfig {
+    pub fn is_valid(&self) -> bool {
+        self.quorum > Decimal::zero() && self.quorum <= Decimal::one() &&
+            self.threshold > Decimal::zero() && self.threshold <= Decimal::one() &&
+            self.voting_period > 0
+    }
+}
+
+impl Default for GovernanceConfig {
+    fn default() -> Self {
+        GovernanceConfig {
+            quorum: Decimal::percent(50),
+            threshold: Decimal::percent(51),
+            voting_period: DEFAULT_VOTING_PERIOD,
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Vote {
+    Yes,
+    No,
+    Abstain,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum ProposalStatus {
+    Open,
+    Passed,
+    Rejected,
+    Executed,
+}
+
+
+//`msg` is the owning contract's own ExecuteMsg, serialized; it runs
+//with the contract admin's authority once the proposal passes
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Proposal {
+    pub id: u64,
+    pub title: String,
+    pub description: String,
+    pub msg: Binary,
+    pub proposer: Addr,
+    //voting power is read from the members snapshot at this height
+    pub start_height: u64,
+    pub expires: Timestamp,
+    pub config: GovernanceConfig,
+    pub total_weight: u64,
+    pub yes: u64,
+    pub no: u64,
+    pub abstain: u64,
+    pub executed: Option<Timestamp>,
+}
+
+impl Proposal {
+    pub fn add_vote(&mut self, vote: Vote, weight: u64) {
+        match vote {
+            Vote::Yes => self.yes += weight,
+            Vote::No => self.no += weight,
+            Vote::Abstain => self.abstain += weight,
+        }
+    }
+
+    fn quorum_met(&self) -> bool {
+        self.total_weight > 0 &&
+            Decimal::from_ratio(self.yes + self.no + self.abstain, self.total_weight) >= self.config.quorum
+    }
+
+    fn threshold_met(&self, counted_weight: u64) -> bool {
+        counted_weight > 0 && Decimal::from_ratio(self.yes, counted_weight) >= self.config.threshold
+    }
+
+    //Passes early once the outstanding votes can no longer change the outcome;
+    //otherwise it's decided on the votes cast when the voting period ends
+    pub fn status(&self, block_time: Timestamp) -> ProposalStatus {
+        match (self.executed.is_some(), block_time >= self.expires) {
+            (true, _) => ProposalStatus::Executed,
+            (false, false) => {
+                match self.quorum_met() && self.threshold_met(self.total_weight - self.abstain) {
+                    true => ProposalStatus::Passed,
+                    false => ProposalStatus::Open
+                }
+            }
+            (false, true) => {
+                match self.quorum_met() && self.threshold_met(self.yes + self.no) {
+                    true => ProposalStatus::Passed,
+                    false => ProposalStatus::Rejected
+                }
+            }
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposalResponse {
+    pub proposal: Proposal,
+    pub status: ProposalStatus,
+}
@@ -0,0 +1,145 @@
+use std::cmp::Ordering;
+
+use cosmwasm_std::Timestamp;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::league::LeagueTypes;
+use crate::stats::PlayerStats;
+use crate::utils::{LeagueAddr, PlayerAddr, SeasonId, TeamAddr};
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum StatCategory {
+    RushingYards,
+    RushingTouchdowns,
+    PassingYards,
+    PassingTouchdowns,
+    ReceivingYards,
+    ReceivingTouchdowns,
+    Tackles,
+    Sacks,
+    Interceptions,
+}
+
+impl StatCategory {
+    pub fn all() -> Vec<StatCategory> {
+        vec![StatCategory::RushingYards, StatCategory::RushingTouchdowns, StatCategory::PassingYards,
+             StatCategory::PassingTouchdowns, StatCategory::ReceivingYards, StatCategory::ReceivingTouchdowns,
+             StatCategory::Tackles, StatCategory::Sacks, StatCategory::Interceptions]
+    }
+
+    pub fn get_u8(&self) -> u8 {
+        match self {
+            StatCategory::RushingYards => 0u8,
+            StatCategory::RushingTouchdowns => 1u8,
+            StatCategory::PassingYards => 2u8,
+            StatCategory::PassingTouchdowns => 3u8,
+            StatCategory::ReceivingYards => 4u8,
+            StatCategory::ReceivingTouchdowns => 5u8,
+            StatCategory::Tackles => 6u8,
+            StatCategory::Sacks => 7u8,
+            StatCategory::Interceptions => 8u8,
+        }
+    }
+
+    pub fn value(&self, stats: &PlayerStats) -> i64 {
+        match self {
+            StatCategory::RushingYards => stats.rushing_yards,
+            StatCategory::RushingTouchdowns => stats.rushing_touchdowns as i64,
+            StatCategory::PassingYards => stats.passing_yards,
+            StatCategory::PassingTouchdowns => stats.passing_touchdowns as i64,
+            StatCategory::ReceivingYards => stats.receiving_yards,
+            StatCategory::ReceivingTouchdowns => stats.receiving_touchdowns as i64,
+            StatCategory::Tackles => stats.tackles as i64,
+            StatCategory::Sacks => stats.sacks as i64,
+            StatCategory::Interceptions => stats.interceptions as i64,
+        }
+    }
+}
+
+
+//A team's all-time fixture record in leagues of one type
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TeamRecord {
+    pub team: TeamAddr,
+    pub league_type: LeagueTypes,
+    pub wins: u32,
+    pub losses: u32,
+    pub ties: u32,
+    pub current_streak: u32,
+    pub longest_streak: u32,
+}
+
+impl TeamRecord {
+    pub fn new(team: TeamAddr, league_type: LeagueTypes) -> Self {
+        TeamRecord { team, league_type, wins: 0, losses: 0, ties: 0, current_streak: 0, longest_streak: 0 }
+    }
+
+    pub fn games(&self) -> u32 {
+        self.wins + self.losses + self.ties
+    }
+
+    //in basis points; a tie counts as half a win
+    pub fn win_percentage(&self) -> u32 {
+        match self.games() {
+            0 => 0,
+            games => ((self.wins as u64 * 2 + self.ties as u64) * 5_000 / games as u64) as u32
+        }
+    }
+
+    //`Greater` is a win
+    pub fn record(&mut self, result: Ordering) {
+        match result {
+            Ordering::Greater => {
+                self.wins += 1;
+                self.current_streak += 1;
+                self.longest_streak = self.longest_streak.max(self.current_streak);
+            }
+            Ordering::Less => {
+                self.losses += 1;
+                self.current_streak = 0;
+            }
+            Ordering::Equal => {
+                self.ties += 1;
+                self.current_streak = 0;
+            }
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PlayerLeaderboardItem {
+    pub player: PlayerAddr,
+    pub value: i64,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct HallOfFameEntry {
+    pub season_id: SeasonId,
+    pub league: LeagueAddr,
+    pub league_type: LeagueTypes,
+    pub champion: TeamAddr,
+    pub crowned: Timestamp,
+}
+
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::Addr;
+
+    use super::*;
+
+    #[test]
+    fn streaks_and_win_percentage() {
+        let mut record = TeamRecord::new(Addr::unchecked("team"), LeagueTypes::AI);
+        for result in [Ordering::Greater, Ordering::Greater, Ordering::Equal, Ordering::Greater, Ordering::Less] {
+            record.record(result);
+        }
+        assert_eq!((3, 1, 1), (record.wins, record.losses, record.ties));
+        assert_eq!((0, 2), (record.current_streak, record.longest_streak));
+        assert_eq!(7_000, record.win_percentage());
+    }
+}
@@ -7,6 +7,7 @@ use thiserror::Error;
 use crate::goi_manager;
 use crate::manage::ManagedStatus;
 use crate::season::Season;
+use crate::team::TeamCoaching;
 use crate::utils::{BlockTime, IManaged, LeagueAddr, StartDate, TeamAddr};
 use crate::utils::general::AssetTypes;
 
@@ -53,6 +54,30 @@ impl SeasonActiveStatus {
 }
 
 
+impl LeagueTypes {
+    pub fn get_u8(&self) -> u8 {
+        match &self {
+            LeagueTypes::NotSet => 0u8,
+            LeagueTypes::Human => 1u8,
+            LeagueTypes::AI => 2u8,
+            LeagueTypes::Mixed => 3u8,
+        }
+    }
+
+    //Leagues that never declared a type take anyone; a declared type
+    //only admits teams that declared a matching coaching type
+    pub fn admits(&self, coaching: &TeamCoaching) -> bool {
+        match (self, coaching) {
+            (LeagueTypes::NotSet, _) => true,
+            (LeagueTypes::Human, TeamCoaching::Human) => true,
+            (LeagueTypes::AI, TeamCoaching::AI { .. }) => true,
+            (LeagueTypes::Mixed, TeamCoaching::Human) | (LeagueTypes::Mixed, TeamCoaching::AI { .. }) => true,
+            _ => false
+        }
+    }
+}
+
+
 impl Default for LeagueTypes {
     fn default() -> Self {
         LeagueTypes::NotSet
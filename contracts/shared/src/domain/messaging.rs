@@ -13,6 +13,21 @@ pub struct JoinSeasonRequestInfo {
 }
 
 
+//Sent to every accepted team when a league reschedules a season.
+//`conflicting_seasons` lists the team's other seasons overlapping the new dates.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SeasonRescheduleInfo {
+    pub season_id: SeasonId,
+    pub previous_start: Timestamp,
+    pub previous_end: Timestamp,
+    pub new_start: Timestamp,
+    pub new_end: Timestamp,
+    pub reason: String,
+    pub conflicting_seasons: Vec<SeasonId>,
+    pub withdrawn: bool,
+}
+
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum MessageTypes {
@@ -20,6 +35,39 @@ pub enum MessageTypes {
     Accepted {},
 }
 
+pub const MAX_INBOX_BODY_LENGTH: usize = 1024;
+
+
+//What an inbox message is about. The body carries any free text.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum InboxContent {
+    Invitation { season_id: SeasonId },
+    TradeOffer { trade_id: u64 },
+    Notice { subject: String },
+    Text {},
+}
+
+
+//A message one managed asset sends another through the goi manager's inbox
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InboxMessageInfo {
+    pub content: InboxContent,
+    pub body: String,
+    pub reply_to: Option<MessageId>,
+    //id of the message that started the thread; its own id when it starts one
+    pub thread: MessageId,
+    pub read: bool,
+    pub expires: Option<Timestamp>,
+}
+
+impl InboxMessageInfo {
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        self.expires.map_or(false, |e| now >= e)
+    }
+}
+
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct DeliveryPacket {
         pub asset_type: AssetTypes,
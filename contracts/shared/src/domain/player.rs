@@ -1,9 +1,9 @@
-use cosmwasm_std::{Addr};
+use cosmwasm_std::{Addr, Coin, CosmosMsg, Timestamp, to_binary, WasmMsg};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{player_attributes::{Positions, PlayerAttributes}, utils::general::GameItemTypes};
-use crate::utils::PlayerAddr;
+use crate::utils::{PlayerAddr, SeasonId, TeamAddr};
 
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -26,6 +26,11 @@ pub struct PlayerInfo {
     pub assigned_team_address: Option<Addr>,
 }
 
+//Player names are unique once case-folded and trimmed
+pub fn normalized_player_name(first_name: &str, last_name: &str) -> String {
+    format!("{} {}", first_name.trim(), last_name.trim()).to_lowercase()
+}
+
 impl PlayerInfo {
     pub fn new (player_addr: PlayerAddr, first_name: String, last_name: String, position: Positions, assigned_team_address: Option<Addr>) -> Self {
         Self {
@@ -41,6 +46,18 @@ impl PlayerInfo {
 
 
 
+//A player not on any roster. Teams can sign them if the owner
+//approved that team or by paying the listed signing fee.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FreeAgent {
+    pub player: PlayerInfo,
+    pub owner: Addr,
+    pub signing_fee: Option<Coin>,
+    pub approved_team: Option<Addr>,
+    pub listed: Timestamp,
+}
+
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct Player {
@@ -49,7 +66,59 @@ pub struct Player {
     pub owner: Addr,
     pub position: Positions,
     pub attributes: PlayerAttributes,
+    //players instantiated before statuses existed are active
+    #[serde(default)]
+    pub status: PlayerStatus,
+
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum PlayerStatus {
+    Active,
+    //out until the season reaches `until_episode`
+    Injured { until_episode: u32 },
+    Suspended,
+    Retired,
+}
+
+impl Default for PlayerStatus {
+    fn default() -> Self {
+        PlayerStatus::Active
+    }
+}
+
+impl PlayerStatus {
+    //`episode` is the current episode of the season being played
+    pub fn is_available(&self, episode: u32) -> bool {
+        match self {
+            PlayerStatus::Active => true,
+            PlayerStatus::Injured { until_episode } => episode >= *until_episode,
+            PlayerStatus::Suspended | PlayerStatus::Retired => false
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CareerSummary {
+    pub player: PlayerAddr,
+    pub first_name: String,
+    pub last_name: String,
+    pub position: Positions,
+    //team the player was on when they retired
+    pub last_team: Option<TeamAddr>,
+    //seasons paid under the player's last contract
+    pub seasons: Vec<SeasonId>,
+    pub episodes_paid: u32,
+    pub retired: Timestamp,
+}
 
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LineupCheckResponse {
+    //players not on the team or not available for the season's current episode
+    pub unavailable: Vec<PlayerAddr>,
 }
 
 
@@ -67,6 +136,50 @@ pub struct InstantiateMsg {
 }
 
 
+//Players instantiated before status updates have no managing contract on record
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrateMsg {
+    pub managing_contract_address: Addr
+}
+
+
+
+//Only accepted from the player's managing contract
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    UpdateStatus { status: PlayerStatus },
+}
+
+
+pub fn update_player_status_msg(player: PlayerAddr, status: PlayerStatus) -> CosmosMsg {
+    WasmMsg::Execute {
+        contract_addr: player.to_string(),
+        msg: to_binary(&ExecuteMsg::UpdateStatus { status })
+            .expect("Expected known update_status msg"),
+        funds: vec![]
+    }.into()
+}
+
+
+//Mirrors the team contract's `ExecuteMsg::RemoveRetiredPlayer` so the managing
+//contract can free the roster slot without depending on the team crate
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamRetirementExecuteMsg {
+    RemoveRetiredPlayer { player: PlayerInfo },
+}
+
+
+pub fn remove_retired_player_msg(team_addr: TeamAddr, player: PlayerInfo) -> CosmosMsg {
+    WasmMsg::Execute {
+        contract_addr: team_addr.to_string(),
+        msg: to_binary(&TeamRetirementExecuteMsg::RemoveRetiredPlayer { player })
+            .expect("Expected known remove_retired_player msg"),
+        funds: vec![]
+    }.into()
+}
+
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -0,0 +1,88 @@
+use cosmwasm_std::{Addr, Coin, CosmosMsg, Timestamp, to_binary, WasmMsg};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::player::PlayerInfo;
+use crate::utils::{PlayerAddr, SeasonId, TeamAddr};
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ContractTerms {
+    pub salary_per_episode: Coin,
+    pub term_seasons: u32,
+    //paid from the team treasury to the player owner when signed
+    pub signing_bonus: Option<Coin>,
+}
+
+impl ContractTerms {
+    pub fn is_valid(&self) -> bool {
+        self.term_seasons > 0 &&
+            match &self.signing_bonus {
+                Some(bonus) => !bonus.amount.is_zero(),
+                None => true
+            }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PlayerSigning {
+    pub player: PlayerInfo,
+    pub terms: ContractTerms,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PlayerContract {
+    pub player: PlayerAddr,
+    pub team: TeamAddr,
+    //player owner; receives the salary
+    pub owner: Addr,
+    pub terms: ContractTerms,
+    pub signed: Timestamp,
+    //seasons salary has been paid in, oldest first
+    pub seasons: Vec<SeasonId>,
+    pub episodes_paid: u32,
+}
+
+impl PlayerContract {
+    //A season counts toward the term once the first salary in it is paid.
+    //Returns false when the term has already been used up by other seasons.
+    pub fn covers_season(&mut self, season_id: SeasonId) -> bool {
+        match (self.seasons.contains(&season_id), self.seasons.len() < self.terms.term_seasons as usize) {
+            (true, _) => true,
+            (false, true) => {
+                self.seasons.push(season_id);
+                true
+            }
+            (false, false) => false
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SalaryPayment {
+    pub player: PlayerAddr,
+    pub owner: Addr,
+    pub amount: Coin,
+}
+
+
+//Mirrors the team contract's `ExecuteMsg::PaySalaries` so the managing
+//contract can trigger payroll without depending on the team crate
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamSalaryExecuteMsg {
+    PaySalaries { season_id: SeasonId, episode: u32, payments: Vec<SalaryPayment> },
+}
+
+
+pub fn pay_salaries_msg(team_addr: TeamAddr, season_id: SeasonId, episode: u32, payments: Vec<SalaryPayment>) -> CosmosMsg {
+    WasmMsg::Execute {
+        contract_addr: team_addr.to_string(),
+        msg: to_binary(&TeamSalaryExecuteMsg::PaySalaries { season_id, episode, payments })
+            .expect("Expected known pay_salaries msg"),
+        funds: vec![]
+    }.into()
+}
@@ -0,0 +1,232 @@
+use cosmwasm_std::Timestamp;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::{LeagueAddr, SeasonId, TeamAddr};
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum PlayoffFormat {
+    SingleElimination,
+    DoubleElimination,
+}
+
+impl PlayoffFormat {
+    //losses that knock a team out of the bracket
+    pub fn max_losses(&self) -> u32 {
+        match self {
+            PlayoffFormat::SingleElimination => 1,
+            PlayoffFormat::DoubleElimination => 2,
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PlayoffTeam {
+    pub team: TeamAddr,
+    pub seed: u32,
+    pub losses: u32,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PlayoffMatch {
+    pub id: u32,
+    pub round: u32,
+    pub home: TeamAddr,
+    pub away: TeamAddr,
+    pub winner: Option<TeamAddr>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PlayoffBye {
+    pub round: u32,
+    pub team: TeamAddr,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Playoffs {
+    pub season_id: SeasonId,
+    pub league: LeagueAddr,
+    pub format: PlayoffFormat,
+    pub teams: Vec<PlayoffTeam>,
+    pub matches: Vec<PlayoffMatch>,
+    pub byes: Vec<PlayoffBye>,
+    pub current_round: u32,
+    pub champion: Option<TeamAddr>,
+    pub started: Timestamp,
+    pub completed: Option<Timestamp>,
+}
+
+impl Playoffs {
+    //`seeded_teams` is ordered best to worst; round one is generated right away
+    pub fn new(season_id: SeasonId, league: LeagueAddr, format: PlayoffFormat,
+               seeded_teams: Vec<TeamAddr>, block_time: Timestamp) -> Self {
+        let teams =
+            seeded_teams.
+                into_iter().
+                enumerate().
+                map(|(i, team)| PlayoffTeam { team, seed: i as u32 + 1, losses: 0 }).
+                collect();
+        let mut res = Playoffs {
+            season_id,
+            league,
+            format,
+            teams,
+            matches: vec![],
+            byes: vec![],
+            current_round: 0,
+            champion: None,
+            started: block_time,
+            completed: None,
+        };
+        res.advance(block_time);
+        res
+    }
+
+    pub fn current_round_matches(&self) -> Vec<PlayoffMatch> {
+        self.matches.
+            iter().
+            filter(|m| m.round == self.current_round).
+            cloned().
+            collect()
+    }
+
+    //Records the winner of an open match in the current round and, once every
+    //match in the round is decided, generates the next round (or the champion).
+    //Returns false if the match isn't open or the winner didn't play in it.
+    pub fn record_result(&mut self, match_id: u32, winner: TeamAddr, block_time: Timestamp) -> bool {
+        let current_round = self.current_round;
+        let a_match =
+            self.matches.
+                iter_mut().
+                find(|m| m.id == match_id && m.round == current_round && m.winner.is_none());
+        let loser = match a_match {
+            Some(m) if m.home == winner => {
+                m.winner = Some(winner);
+                m.away.clone()
+            }
+            Some(m) if m.away == winner => {
+                m.winner = Some(winner);
+                m.home.clone()
+            }
+            _ => return false
+        };
+
+        if let Some(t) = self.teams.iter_mut().find(|t| t.team == loser) {
+            t.losses += 1;
+        }
+
+        if self.current_round_matches().iter().all(|m| m.winner.is_some()) {
+            self.advance(block_time);
+        }
+        true
+    }
+
+    fn alive_teams(&self) -> Vec<PlayoffTeam> {
+        let max_losses = self.format.max_losses();
+        let mut res: Vec<PlayoffTeam> =
+            self.teams.
+                iter().
+                filter(|t| t.losses < max_losses).
+                cloned().
+                collect();
+        res.sort_by_key(|t| t.seed);
+        res
+    }
+
+    fn advance(&mut self, block_time: Timestamp) {
+        let alive = self.alive_teams();
+        if alive.len() < 2 {
+            self.champion = alive.first().map(|t| t.team.clone());
+            self.completed = Some(block_time);
+            return;
+        }
+
+        self.current_round += 1;
+        let mut byes: Vec<PlayoffTeam> = vec![];
+        let mut pairings: Vec<(TeamAddr, TeamAddr)> = vec![];
+        match self.current_round == 1 {
+            true => {
+                //top seeds sit out round one so the remaining
+                //field is a power of two
+                let bye_count = alive.len().next_power_of_two() - alive.len();
+                byes.extend_from_slice(&alive[..bye_count]);
+                pairings.extend(pair_by_seed(&alive[bye_count..]));
+            }
+            false => {
+                //teams only play others with the same number of losses
+                //(winners/losers brackets); the top seed of an odd group gets a bye
+                for losses in 0..self.format.max_losses() {
+                    let group: Vec<PlayoffTeam> =
+                        alive.iter().filter(|t| t.losses == losses).cloned().collect();
+                    let bye_count = group.len() % 2;
+                    byes.extend_from_slice(&group[..bye_count]);
+                    pairings.extend(pair_by_seed(&group[bye_count..]));
+                }
+                //last team standing in each bracket meet in the final
+                if pairings.is_empty() {
+                    byes.clear();
+                    pairings.extend(pair_by_seed(&alive));
+                }
+            }
+        }
+
+        for bye in byes {
+            self.byes.push(PlayoffBye { round: self.current_round, team: bye.team });
+        }
+        for (home, away) in pairings {
+            let id = self.matches.len() as u32 + 1;
+            self.matches.push(PlayoffMatch { id, round: self.current_round, home, away, winner: None });
+        }
+    }
+}
+
+
+//highest seed plays lowest seed
+fn pair_by_seed(teams: &[PlayoffTeam]) -> Vec<(TeamAddr, TeamAddr)> {
+    let count = teams.len();
+    (0..count / 2).
+        map(|i| (teams[i].team.clone(), teams[count - 1 - i].team.clone())).
+        collect()
+}
+
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::Addr;
+    use cosmwasm_std::testing::mock_env;
+
+    use super::*;
+
+    #[test]
+    fn double_elimination_with_bye_and_bracket_reset() {
+        let block_time = mock_env().block.time;
+        let (a, b, c) = (Addr::unchecked("a"), Addr::unchecked("b"), Addr::unchecked("c"));
+        let mut playoffs = Playoffs::new(1, Addr::unchecked("league"), PlayoffFormat::DoubleElimination,
+                                         vec![a.clone(), b.clone(), c.clone()], block_time);
+
+        //top seed gets the only bye
+        assert_eq!(vec![PlayoffBye { round: 1, team: a.clone() }], playoffs.byes);
+        assert!(!playoffs.record_result(1, a.clone(), block_time));
+        assert!(playoffs.record_result(1, c.clone(), block_time));
+
+        //winners bracket: a vs c, losers bracket: b waits
+        assert_eq!((a.clone(), c.clone()), (playoffs.matches[1].home.clone(), playoffs.matches[1].away.clone()));
+        assert!(playoffs.record_result(2, a.clone(), block_time));
+
+        //losers bracket final knocks c out
+        assert!(playoffs.record_result(3, b.clone(), block_time));
+
+        //b beats unbeaten a in the final, forcing a deciding game
+        assert!(playoffs.record_result(4, b.clone(), block_time));
+        assert_eq!(None, playoffs.champion);
+        assert!(playoffs.record_result(5, a.clone(), block_time));
+
+        assert_eq!(Some(a), playoffs.champion);
+        assert_eq!(5, playoffs.matches.len());
+    }
+}
@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, Coin};
+use cosmwasm_std::{Addr, Coin, Timestamp};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +9,15 @@ pub struct Saleable {
     pub for_sale: bool,
 }
 
+//Part of a co-owner's weight offered for sale at a price per unit of weight
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ShareListing {
+    pub seller: Addr,
+    pub weight: u64,
+    pub price_per_unit: Coin,
+    pub listed: Timestamp,
+}
+
 pub enum DistributionType {
     Owner,
     Services,
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::data::ModelItem;
 use crate::league::SeasonActiveStatus;
 use crate::messaging::DeliveryInfo;
-use crate::utils::{EndDate, LeagueAddr, SeasonId, StartDate, TeamAddr, MAX_TEAMS_ALLOWED};
+use crate::utils::{EndDate, LeagueAddr, SeasonId, StartDate, TeamAddr, DEFAULT_SEASON_EPISODES, MAX_TEAMS_ALLOWED, MIN_TEAMS_ALLOWED};
 use crate::utils::general::merge_data;
 
 
@@ -38,6 +38,34 @@ impl Default for SeasonStatus {
 }
 
 
+//Manager-defined bounds a league's requested
+//season capacity (max_teams_allowed) must fall within
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SeasonCapacityPolicy {
+    pub min_teams_allowed: u32,
+    pub max_teams_allowed: u32,
+}
+
+impl Default for SeasonCapacityPolicy {
+    fn default() -> Self {
+        SeasonCapacityPolicy {
+            min_teams_allowed: MIN_TEAMS_ALLOWED,
+            max_teams_allowed: MAX_TEAMS_ALLOWED,
+        }
+    }
+}
+
+impl SeasonCapacityPolicy {
+    pub fn is_valid(&self) -> bool {
+        self.min_teams_allowed > 0 && self.min_teams_allowed <= self.max_teams_allowed
+    }
+
+    pub fn is_capacity_allowed(&self, capacity: u32) -> bool {
+        capacity >= self.min_teams_allowed && capacity <= self.max_teams_allowed
+    }
+}
+
+
 
 
 
@@ -49,6 +77,57 @@ impl Default for SeasonStatus {
         pub access_type: ModelItem<Option<SeasonAccessTypes>>,
         pub status: ModelItem<Option<SeasonStatus>>,
         pub max_teams_allowed: ModelItem<Option<u32>>,
+        #[serde(default)]
+        pub episodes: ModelItem<Option<u32>>,
+    }
+
+
+    //How many seasons a league's template keeps spawning
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+    pub enum SeasonRecurrence {
+        Indefinitely,
+        Times { count: u32 },
+        Until { date: Timestamp },
+    }
+
+
+    //Blueprint for seasons spawned by `SpawnNextSeason`. Entry fees come
+    //from `access_type` (WinnerTakeAll); `duration`/`gap` are in seconds.
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+    pub struct SeasonTemplate {
+        pub name: String,
+        pub description: Option<String>,
+        pub duration: u64,
+        pub gap: u64,
+        pub access_type: SeasonAccessTypes,
+        pub max_teams_allowed: u32,
+        pub recurrence: SeasonRecurrence,
+    }
+
+    impl SeasonTemplate {
+        pub fn validate(&self, capacity_policy: &SeasonCapacityPolicy) -> bool {
+            !self.name.is_empty() &&
+                self.duration > 0 &&
+                capacity_policy.is_capacity_allowed(self.max_teams_allowed)
+        }
+
+        //false once the recurrence rule has run its course
+        pub fn allows_next(&self, spawned_count: u32, next_start: Timestamp) -> bool {
+            match self.recurrence {
+                SeasonRecurrence::Indefinitely => true,
+                SeasonRecurrence::Times { count } => spawned_count < count,
+                SeasonRecurrence::Until { date } => next_start <= date,
+            }
+        }
+    }
+
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+    pub struct LeagueSeasonTemplate {
+        pub league: LeagueAddr,
+        pub template: SeasonTemplate,
+        pub spawned_count: u32,
+        pub last_spawned_season: Option<SeasonId>,
     }
 
 
@@ -75,10 +154,18 @@ impl Default for SeasonStatus {
         pub access_type: Option<SeasonAccessTypes>,
         pub status: Option<SeasonStatus>,
         pub max_teams_allowed: Option<u32>,
+        //episodes are spread evenly between the start and end dates
+        #[serde(default = "default_season_episodes")]
+        pub episodes: u32,
+    }
+
+    fn default_season_episodes() -> u32 {
+        DEFAULT_SEASON_EPISODES
     }
 
     impl Season {
-        pub fn new(id: u64, owning_league: Addr, name: String, description: Option<String>, block: BlockInfo) -> Self {
+        pub fn new(id: u64, owning_league: Addr, name: String, description: Option<String>,
+                   block: BlockInfo, capacity_policy: &SeasonCapacityPolicy) -> Self {
             let res = 
                     Season {
                         id,
@@ -90,9 +177,10 @@ impl Default for SeasonStatus {
                         end_date: Default::default(),
                         access_type: Default::default(),
                         status: Default::default(),
-                        max_teams_allowed: Some(MAX_TEAMS_ALLOWED),
+                        max_teams_allowed: Some(capacity_policy.max_teams_allowed),
+                        episodes: DEFAULT_SEASON_EPISODES,
                     };
-            if !res.validate(block){
+            if !res.validate(block, capacity_policy){
                 panic!("Invalid season!")
             }
             else {
@@ -101,7 +189,7 @@ impl Default for SeasonStatus {
                     
         }
 
-        pub fn validate(&self, block: BlockInfo) -> bool{
+        pub fn validate(&self, block: BlockInfo, capacity_policy: &SeasonCapacityPolicy) -> bool{
             let mut error_count:u32 = 0;
 
             //updates are nolonger possible after season starts
@@ -126,18 +214,30 @@ impl Default for SeasonStatus {
                         false => ()
                     }
 
-
-                    match self.max_teams_allowed == Some(MAX_TEAMS_ALLOWED) {
-                        true =>  (),
+                    match self.episodes > 0 {
+                        true => (),
                         false => error_count  = error_count + 1,
                     }
+
+
+                    match self.max_teams_allowed {
+                        Some(capacity) if capacity_policy.is_capacity_allowed(capacity) =>  (),
+                        _ => error_count  = error_count + 1,
+                    }
                 }
             }
 
             error_count == 0
         }
 
-        pub fn merge_updates(&self, model: SeasonModelData, block: BlockInfo) -> Self
+        //time from which the given episode (numbered from 1) can be played
+        pub fn episode_start(&self, episode: u32) -> Timestamp {
+            let interval = (self.end_date.seconds() - self.start_date.seconds()) / self.episodes.max(1) as u64;
+            self.start_date.plus_seconds(interval * episode.saturating_sub(1) as u64)
+        }
+
+        pub fn merge_updates(&self, model: SeasonModelData, block: BlockInfo,
+                             capacity_policy: &SeasonCapacityPolicy) -> Self
         {
             let res = 
                     Season {
@@ -178,11 +278,16 @@ impl Default for SeasonStatus {
                         status:
                         merge_data(self.status.clone(),
                                 model.status),
-                        max_teams_allowed: Some(MAX_TEAMS_ALLOWED),
+                        max_teams_allowed:
+                        merge_data(self.max_teams_allowed,
+                                model.max_teams_allowed),
+                        episodes:
+                        merge_data(Some(self.episodes),
+                                model.episodes).unwrap_or(self.episodes),
 
                         
                     };
-            if !res.validate(block){
+            if !res.validate(block, capacity_policy){
                 panic!("Invalid season!")
             }
             else {
@@ -0,0 +1,92 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::player_attributes::Positions;
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct PlayerStats {
+    pub games: u32,
+    //position in the player's most recent game
+    pub position: Option<Positions>,
+
+    pub rushing_attempts: u32,
+    pub rushing_yards: i64,
+    pub rushing_touchdowns: u32,
+    pub fumbles_lost: u32,
+
+    pub passing_attempts: u32,
+    pub completions: u32,
+    pub passing_yards: i64,
+    pub passing_touchdowns: u32,
+    pub interceptions_thrown: u32,
+    pub sacks_taken: u32,
+
+    pub receptions: u32,
+    pub receiving_yards: i64,
+    pub receiving_touchdowns: u32,
+
+    pub tackles: u32,
+    pub sacks: u32,
+    pub interceptions: u32,
+}
+
+impl PlayerStats {
+    pub fn add(&mut self, other: &PlayerStats) {
+        self.games += other.games;
+        if other.position.is_some() {
+            self.position = other.position.clone();
+        }
+        self.rushing_attempts += other.rushing_attempts;
+        self.rushing_yards += other.rushing_yards;
+        self.rushing_touchdowns += other.rushing_touchdowns;
+        self.fumbles_lost += other.fumbles_lost;
+        self.passing_attempts += other.passing_attempts;
+        self.completions += other.completions;
+        self.passing_yards += other.passing_yards;
+        self.passing_touchdowns += other.passing_touchdowns;
+        self.interceptions_thrown += other.interceptions_thrown;
+        self.sacks_taken += other.sacks_taken;
+        self.receptions += other.receptions;
+        self.receiving_yards += other.receiving_yards;
+        self.receiving_touchdowns += other.receiving_touchdowns;
+        self.tackles += other.tackles;
+        self.sacks += other.sacks;
+        self.interceptions += other.interceptions;
+    }
+}
+
+
+//Totals from played games only; forfeits show up in the season standings
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct TeamStats {
+    pub games: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub ties: u32,
+    pub points_for: u64,
+    pub points_against: u64,
+    pub rushing_yards: i64,
+    pub passing_yards: i64,
+    pub touchdowns: u32,
+    pub field_goals: u32,
+    pub turnovers: u32,
+    pub sacks_allowed: u32,
+}
+
+impl TeamStats {
+    pub fn add(&mut self, other: &TeamStats) {
+        self.games += other.games;
+        self.wins += other.wins;
+        self.losses += other.losses;
+        self.ties += other.ties;
+        self.points_for += other.points_for;
+        self.points_against += other.points_against;
+        self.rushing_yards += other.rushing_yards;
+        self.passing_yards += other.passing_yards;
+        self.touchdowns += other.touchdowns;
+        self.field_goals += other.field_goals;
+        self.turnovers += other.turnovers;
+        self.sacks_allowed += other.sacks_allowed;
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use cosmwasm_std::{Addr, Coin, CosmosMsg, Timestamp, to_binary, WasmMsg};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use crate::league::LeagueTeamAssignment;
@@ -12,6 +13,25 @@ use crate::utils::general::AssetTypes;
 
 
 
+//Who calls the plays; declared by the team's admin, at instantiation or later.
+//A human declaration is taken on trust like a league's type. An AI one only
+//counts once its attestation confirms it, see `TeamInfo::admitted_coaching`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum TeamCoaching {
+    NotSet,
+    Human,
+    //`attestation` vouches that the team is run by an AI agent
+    AI { attestation: Addr },
+}
+
+impl Default for TeamCoaching {
+    fn default() -> Self {
+        TeamCoaching::NotSet
+    }
+}
+
+
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct TeamInfo {
     pub address: TeamAddr,
@@ -19,6 +39,11 @@ pub struct TeamInfo {
     pub name: String,
     pub created: BlockTime,
     pub owner: OwnerAddr,
+    #[serde(default)]
+    pub coaching: TeamCoaching,
+    //set when the attestation of an AI declaration confirms it
+    #[serde(default)]
+    pub ai_confirmed: bool,
 
 
 }
@@ -26,13 +51,15 @@ pub struct TeamInfo {
 impl TeamInfo {
     // Constructs a new instance of [`Second`].
     // Note this is an associated function - no self.
-    pub fn new(league_assigned: Option<LeagueTeamAssignment>, team_addr: TeamAddr, name: String, player: PlayerInfo, owner: OwnerAddr, block_time: BlockTime) -> Self {
+    pub fn new(league_assigned: Option<LeagueTeamAssignment>, team_addr: TeamAddr, name: String, player: PlayerInfo, owner: OwnerAddr, coaching: TeamCoaching, block_time: BlockTime) -> Self {
         Self {
 
             address: team_addr,
             league_assigned,
             name,
             owner,
+            coaching,
+            ai_confirmed: false,
             created: BlockTime
                 {
                     height: block_time.height,
@@ -43,4 +70,72 @@ impl TeamInfo {
 
         }
     }
-}
\ No newline at end of file
+
+    //Coaching as league types see it; an unconfirmed AI declaration counts as undeclared
+    pub fn admitted_coaching(&self) -> TeamCoaching {
+        match &self.coaching {
+            TeamCoaching::AI { .. } if !self.ai_confirmed => TeamCoaching::NotSet,
+            c => c.clone()
+        }
+    }
+}
+
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum TreasuryFlow {
+    Inflow,
+    Outflow,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TreasuryLedgerEntry {
+    pub id: u64,
+    pub flow: TreasuryFlow,
+    pub counterparty: Addr,
+    pub amount: Vec<Coin>,
+    pub memo: String,
+    pub timestamp: Timestamp,
+}
+
+
+//Withdrawal requested by a co-owner; paid out once owners holding
+//more than half of the team's total weight have voted for it.
+//Votes are weighed as of `start_height`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TreasuryWithdrawalProposal {
+    pub id: u64,
+    pub proposer: OwnerAddr,
+    pub recipient: Addr,
+    pub amount: Vec<Coin>,
+    pub voters: Vec<OwnerAddr>,
+    pub yes_weight: u64,
+    pub created: Timestamp,
+    pub executed: Option<Timestamp>,
+    //proposals stored before these fields existed load as already expired
+    #[serde(default)]
+    pub start_height: u64,
+    #[serde(default)]
+    pub total_weight: u64,
+    #[serde(default)]
+    pub expires: Timestamp,
+}
+
+
+//Mirrors the team contract's `ExecuteMsg::Deposit {}` so other contracts
+//can fund a team's treasury without depending on the team crate
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamTreasuryExecuteMsg {
+    Deposit {},
+}
+
+
+pub fn deposit_to_team_treasury_msg(team_addr: TeamAddr, amount: Vec<Coin>) -> CosmosMsg {
+    WasmMsg::Execute {
+        contract_addr: team_addr.to_string(),
+        msg: to_binary(&TeamTreasuryExecuteMsg::Deposit {}).expect("Expected known deposit msg"),
+        funds: amount
+    }.into()
+}
@@ -0,0 +1,56 @@
+use cosmwasm_std::{Coin, CosmosMsg, Timestamp, to_binary, WasmMsg};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::draft::DraftPickRef;
+use crate::player::PlayerInfo;
+use crate::utils::TeamAddr;
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum TradeStatus {
+    Proposed,
+    Accepted,
+    Rejected,
+    Cancelled,
+}
+
+
+//`offered_funds` are escrowed by the managing contract until the
+//trade is accepted (paid to the counterparty) or closed (refunded)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TradeProposal {
+    pub id: u64,
+    pub proposing_team: TeamAddr,
+    pub counterparty_team: TeamAddr,
+    pub offered_players: Vec<PlayerInfo>,
+    pub requested_players: Vec<PlayerInfo>,
+    pub offered_funds: Vec<Coin>,
+    #[serde(default)]
+    pub offered_picks: Vec<DraftPickRef>,
+    #[serde(default)]
+    pub requested_picks: Vec<DraftPickRef>,
+    pub status: TradeStatus,
+    pub created: Timestamp,
+    pub updated: Timestamp,
+}
+
+
+//Mirrors the team contract's `ExecuteMsg::ApplyTrade` so the managing
+//contract can update both rosters without depending on the team crate
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamTradeExecuteMsg {
+    ApplyTrade { trade_id: u64, outgoing_players: Vec<PlayerInfo>, incoming_players: Vec<PlayerInfo> },
+}
+
+
+pub fn apply_trade_msg(team_addr: TeamAddr, trade_id: u64, outgoing_players: Vec<PlayerInfo>,
+                       incoming_players: Vec<PlayerInfo>) -> CosmosMsg {
+    WasmMsg::Execute {
+        contract_addr: team_addr.to_string(),
+        msg: to_binary(&TeamTradeExecuteMsg::ApplyTrade { trade_id, outgoing_players, incoming_players })
+            .expect("Expected known apply_trade msg"),
+        funds: vec![]
+    }.into()
+}
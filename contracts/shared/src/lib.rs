@@ -16,6 +16,21 @@ pub mod league;
 #[path = "./domain/season.rs"]
 pub mod season;
 
+#[path = "./domain/division.rs"]
+pub mod division;
+
+#[path = "./domain/playoffs.rs"]
+pub mod playoffs;
+
+#[path = "./domain/fixture.rs"]
+pub mod fixture;
+
+#[path = "./domain/stats.rs"]
+pub mod stats;
+
+#[path = "./domain/leaderboard.rs"]
+pub mod leaderboard;
+
 #[path = "./domain/data.rs"]
 pub mod data;
 
@@ -29,6 +44,21 @@ pub mod rewards;
 #[path = "./domain/team.rs"]
 pub mod team;
 
+#[path = "./domain/dividends.rs"]
+pub mod dividends;
+
+#[path = "./domain/governance.rs"]
+pub mod governance;
+
+#[path = "./domain/trade.rs"]
+pub mod trade;
+
+#[path = "./domain/draft.rs"]
+pub mod draft;
+
+#[path = "./domain/player_contract.rs"]
+pub mod player_contract;
+
 
 #[path = "domain/application.rs"]
 pub mod application;
@@ -50,6 +80,12 @@ pub mod saleable;
 #[path = "./domain/display.rs"]
 pub mod display;
 
+#[path = "./domain/broadcast.rs"]
+pub mod broadcast;
+
+#[path = "./domain/advertising.rs"]
+pub mod advertising;
+
 #[path = "./domain/link_team_player.rs"]
 pub mod link_team_player;
 
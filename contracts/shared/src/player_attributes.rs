@@ -62,6 +62,16 @@ pub enum Positions {
 }
 
 impl Positions {
+    pub fn to_u8(&self) -> u8 {
+        self.clone() as u8
+    }
+    pub fn side_of_ball(&self) -> SideOfBall {
+        match self {
+            Positions::RB | Positions::QB | Positions::WR1 | Positions::WR2 |
+            Positions::CO | Positions::GL | Positions::GR => SideOfBall::Offense,
+            _ => SideOfBall::Defense
+        }
+    }
     pub fn from_string (item: &String) -> Option<Positions> {
         match item.to_lowercase().as_ref() {
             //Offense
@@ -24,6 +24,7 @@ pub type TeamPlayerAddr = (TeamAddr, PlayerAddr);
 pub type LeagueAddr = Addr;
 pub type SeasonId = u64;
 pub type SeasonDepositId = u64;
+pub type DivisionId = u64;
 pub type SeasonActiveStatusValue = u8; //works in concert with enum SeasonActiveStatus
 pub type BlockChainTimeValue = u64;   //TimeStamp
 pub type StartDate = u64; //TimeStamp
@@ -37,8 +38,11 @@ pub const PRIOR_TO_SEASON_START_PADDING: u64 = 900;
 pub const THIRTY_MINUTES: u64 = 1800;
 pub const FIFTEEN_MINUTES: u64 = 900;
 pub const MAX_TEAMS_ALLOWED: u32 = 300;
+pub const MIN_TEAMS_ALLOWED: u32 = 2;
 
 pub const ONE_MINUTE: u64 = 60;
+//episodes in a season that doesn't set its own count
+pub const DEFAULT_SEASON_EPISODES: u32 = 10;
 
 
 
@@ -139,6 +143,9 @@ pub struct OwnershipHistory {
     pub owners: Addr,
     pub purchased: BlockInfo,
     pub sold: Option<BlockInfo>,
+    //set for partial transfers between co-owners; None means the whole asset
+    pub weight: Option<u64>,
+    pub transferred_from: Option<Addr>,
 }
 
 
@@ -223,7 +230,7 @@ pub mod general {
     use crate::utils::Fee;
 
 
-    fn payment_distribution(payees: Vec<Member>,
+    pub fn payment_distribution(payees: Vec<Member>,
                                     fees: Option<Vec<Fee>>, balance: Coin )
                                     -> Result<Option<Vec<SubMsg>>, GoiError> {
 
@@ -383,6 +390,21 @@ pub mod general {
     }
 
 
+    //Drops zero coins, merges repeated denoms and sorts by denom (the order
+    //bank funds arrive in), so two normalized amounts compare with `==`
+    pub fn normalize_coins(amount: Vec<Coin>) -> Vec<Coin> {
+        let mut res: Vec<Coin> = vec![];
+        for coin in amount.into_iter().filter(|c| !c.amount.is_zero()) {
+            match res.iter_mut().find(|c| c.denom == coin.denom) {
+                Some(c) => c.amount += coin.amount,
+                None => res.push(coin)
+            }
+        }
+        res.sort_by(|a, b| a.denom.cmp(&b.denom));
+        res
+    }
+
+
 
 
 }
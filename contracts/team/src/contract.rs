@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, Api, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult, to_binary, WasmMsg, CosmosMsg, SubMsg, ReplyOn, Coin};
+use cosmwasm_std::{Addr, Api, Binary, from_binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult, to_binary, WasmMsg, CosmosMsg, SubMsg, ReplyOn, Coin, BankMsg};
 #[cfg(not
 (feature = "library"))]
 use cosmwasm_std::entry_point;
@@ -12,23 +12,34 @@ use group_admin::GroupAdminError;
 use group_admin::service::list_members;
 use managed::ManagedServiceError;
 use managed::queries::query_manageable_info;
+use managed::governance::{execute_propose, execute_update_governance_config, execute_vote, get_governance_config,
+                           query_list_proposals, query_proposal, query_vote, take_passed_proposal_msg};
+use managed::shares::query_share_listings;
 
 use saleable::queries::query_saleable_info;
 use shared::goi_manager::ExecuteMsg::AddPlayersToTeam;
-use shared::goi_manager::{get_minters, on_successful_buy, on_successful_forsale_update, on_successful_init_processing, send_request_to_cancel_season_spot, send_request_to_join_open_season, send_request_to_join_winner_takes_all_season, update_messaging_item_msg_to_goi_manager};
+use shared::team::TeamCoaching;
+use shared::goi_manager::{get_minters, send_inbox_message_msg_to_goi_manager, send_mark_inbox_message_read_msg_to_goi_manager, send_declare_team_coaching_msg_to_goi_manager, on_successful_buy, on_successful_forsale_update, on_successful_init_processing, send_request_to_cancel_season_spot, send_commit_fixture_seed_msg_to_goi_manager, send_reveal_fixture_seed_msg_to_goi_manager, send_commit_draft_seed_msg_to_goi_manager, send_reveal_draft_seed_msg_to_goi_manager, send_submit_lineup_msg_to_goi_manager, send_request_to_join_open_season, send_request_to_withdraw_from_rescheduled_season, send_request_to_join_winner_takes_all_season, update_messaging_item_msg_to_goi_manager,
+                          send_propose_trade_msg_to_goi_manager, send_respond_to_trade_msg_to_goi_manager, send_cancel_trade_msg_to_goi_manager,
+                          send_sign_free_agent_msg_to_goi_manager, send_release_players_msg_to_goi_manager,
+                          send_make_draft_pick_msg_to_goi_manager,
+                          send_add_players_with_contracts_msg_to_goi_manager};
 
 use shared::manage::Manageable;
 use shared::player::{PlayerInfo, self};
 use shared::player_attributes::{PlayerAttributes, SideOfBall};
 use shared::query_response_info::{InfoManagedResponse, NameResponse};
 use shared::saleable::Saleable;
-use shared::utils::general::AssetTypes;
+use shared::utils::general::{AssetTypes, normalize_coins};
 
 use crate::error::TeamError;
 use crate::msg::{ExecuteMsg, InstantiateTeamMsg, PlayerResponse, PlayersResponse,
                  QueryMsg};
 use crate::state::{ADMIN, HOOKS, MANAGEABLE_SERVICE, SALEABLE_SERVICE, State, STATE};
 use crate::team_attributes::TeamPlayers;
+use crate::treasury::{allocate_dividends, claim_dividends, deposit, distribute_dividends, pay_salaries, propose_treasury_withdrawal, query_dividend_claims, query_salary_arrears, query_treasury_balances, query_treasury_ledger,
+                      query_treasury_withdrawal_proposal, reconcile_treasury, spend_from_treasury,
+                      vote_treasury_withdrawal, withdraw_from_treasury};
 use crate::TeamError::UnauthorizedSender;
 
 // version info for migration info
@@ -48,6 +59,9 @@ pub fn instantiate(
 
     match is_minter {
         true => {
+            if let TeamCoaching::AI { attestation } = &msg.coaching {
+                deps.api.addr_validate(attestation.as_str())?;
+            }
             let state = State {
                 name: msg.name.clone(),
                 players: TeamPlayers {
@@ -66,6 +80,7 @@ pub fn instantiate(
                     tr: None,
                     tl: None
                 },
+                coaching: msg.coaching.clone(),
             };
 
             set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
@@ -78,7 +93,10 @@ pub fn instantiate(
                 }, Some(SALEABLE_SERVICE), Some(msg.admin.clone()), msg.price, msg.for_sale, Some(msg.name.clone()),  Some(on_successful_init_processing));
             match init_res {
                 Ok(r) => {
-                    Ok(r)
+                    match msg.managing_contract {
+                        Some(mc) => Ok(send_declare_team_coaching_msg_to_goi_manager(msg.coaching, mc, r)),
+                        None => Ok(r)
+                    }
                 }
                 Err(e) => {
                     Err(TeamError::ManagableServiceError(e))
@@ -93,7 +111,7 @@ pub fn instantiate(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
-    deps: DepsMut,
+    mut deps: DepsMut,
     _env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
@@ -150,6 +168,49 @@ pub fn execute(
                 },
             }
         }
+        ExecuteMsg::SendMessage { to, content, body, reply_to, expires } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    let to = deps.api.addr_validate(&to)?;
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("Team is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_inbox_message_msg_to_goi_manager(to, content, body, reply_to, expires, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(UnauthorizedSender { sender: info.sender})
+                },
+            }
+        },
+        ExecuteMsg::MarkMessageRead { message_id, read } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("Team is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_mark_inbox_message_read_msg_to_goi_manager(message_id, read, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(UnauthorizedSender { sender: info.sender})
+                },
+            }
+        },
         ExecuteMsg::JoinLeague { season_id } => {
             let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
             let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
@@ -184,6 +245,9 @@ pub fn execute(
                             panic!("Team is currently unmanaged.")
                         }
                         Some(mc) => {
+                            //entry fee is paid out of the team treasury
+                            spend_from_treasury(deps.storage, mc.clone(), fee.clone(),
+                                                "season entry fee", _env.block.time)?;
                             res = send_request_to_join_winner_takes_all_season(season_id, fee, mc, res.clone());
                         }
                     }
@@ -195,8 +259,52 @@ pub fn execute(
             }
         },
         ExecuteMsg::Deposit {  } => {
-            Err(UnauthorizedSender { sender: info.sender })
-        }
+            deposit(deps, _env, info)
+        },
+        ExecuteMsg::WithdrawFromTreasury { recipient, amount } => {
+            withdraw_from_treasury(deps, _env, info, recipient, amount)
+        },
+        ExecuteMsg::ProposeTreasuryWithdrawal { recipient, amount } => {
+            propose_treasury_withdrawal(deps, _env, info, recipient, amount)
+        },
+        ExecuteMsg::VoteTreasuryWithdrawal { proposal_id } => {
+            vote_treasury_withdrawal(deps, _env, info, proposal_id)
+        },
+        ExecuteMsg::ReconcileTreasury {} => {
+            reconcile_treasury(deps, _env, info)
+        },
+        ExecuteMsg::DistributeDividends { denom } => {
+            distribute_dividends(deps, _env, info, denom)
+        },
+        ExecuteMsg::AllocateDividends { denom } => {
+            allocate_dividends(deps, _env, info, denom)
+        },
+        ExecuteMsg::ClaimDividends { denom } => {
+            claim_dividends(deps, info, denom)
+        },
+        ExecuteMsg::Propose { title, description, msg } => {
+            match is_governable_msg(&msg) {
+                true => Ok(execute_propose(deps, _env, info, title, description, to_binary(&msg)?)?),
+                false => Err(ManagedServiceError::ProposalMsgNotAllowed {}.into())
+            }
+        },
+        ExecuteMsg::VoteOnProposal { proposal_id, vote } => {
+            Ok(execute_vote(deps, _env, info, proposal_id, vote)?)
+        },
+        ExecuteMsg::ExecuteProposal { proposal_id } => {
+            //passed proposals run with the admin's authority
+            let proposal_msg: ExecuteMsg = from_binary(&take_passed_proposal_msg(deps.branch(), &_env, proposal_id)?)?;
+            match ADMIN.get(deps.as_ref())? {
+                None => Err(TeamError::Unauthorized {}),
+                Some(admin) => {
+                    let res = execute(deps, _env, MessageInfo { sender: admin, funds: vec![] }, proposal_msg)?;
+                    Ok(res.add_attribute("proposal_id", proposal_id.to_string()))
+                }
+            }
+        },
+        ExecuteMsg::UpdateGovernanceConfig { config } => {
+            Ok(execute_update_governance_config(deps, info, config)?)
+        },
         ExecuteMsg::CancelSeasonSpot { season_id } => {
             let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
             let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
@@ -217,11 +325,424 @@ pub fn execute(
                     Err(UnauthorizedSender { sender: info.sender })
                 },
             }
+        },
+        ExecuteMsg::WithdrawFromRescheduledSeason { season_id } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("Team is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_request_to_withdraw_from_rescheduled_season(season_id, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(UnauthorizedSender { sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::ProposeTrade { counterparty_team, offered_players, requested_players, offered_funds,
+            offered_picks, requested_picks } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("Team is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            let counterparty_team = deps.api.addr_validate(&counterparty_team)?;
+                            //offered players must all be on the current roster
+                            STATE.load(deps.storage)?.players.remove_players_from_positions(offered_players.clone())?;
+                            //offered funds are escrowed by the managing contract until the trade closes
+                            let offered_funds = normalize_coins(offered_funds);
+                            match offered_funds.len() > 0 {
+                                true => spend_from_treasury(deps.storage, mc.clone(), offered_funds.clone(),
+                                                            "trade escrow", _env.block.time)?,
+                                false => ()
+                            }
+                            res = send_propose_trade_msg_to_goi_manager(counterparty_team, offered_players,
+                                                                        requested_players, offered_funds,
+                                                                        offered_picks, requested_picks, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(UnauthorizedSender { sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::RespondToTrade { trade_id, accept } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("Team is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_respond_to_trade_msg_to_goi_manager(trade_id, accept, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(UnauthorizedSender { sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::CancelTrade { trade_id } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("Team is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_cancel_trade_msg_to_goi_manager(trade_id, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(UnauthorizedSender { sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::SignFreeAgent { player, signing_fee } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("Team is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            add_players_to_roster(&mut deps, vec![player.clone()])?;
+                            //signing fee is paid out of the team treasury
+                            match signing_fee.len() > 0 {
+                                true => spend_from_treasury(deps.storage, mc.clone(), signing_fee.clone(),
+                                                            "free agent signing fee", _env.block.time)?,
+                                false => ()
+                            }
+                            res = send_sign_free_agent_msg_to_goi_manager(player.address, signing_fee, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(UnauthorizedSender { sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::CommitFixtureSeed { fixture_id, commitment } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("Team is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_commit_fixture_seed_msg_to_goi_manager(fixture_id, commitment, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(UnauthorizedSender { sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::CommitDraftSeed { season_id, commitment } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("Team is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_commit_draft_seed_msg_to_goi_manager(season_id, commitment, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(UnauthorizedSender { sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::SubmitLineup { season_id, episode, offense, defense, play_calling_strategy } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("Team is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_submit_lineup_msg_to_goi_manager(season_id, episode, offense, defense,
+                                                                        play_calling_strategy, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(UnauthorizedSender { sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::RevealFixtureSeed { fixture_id, secret } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("Team is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_reveal_fixture_seed_msg_to_goi_manager(fixture_id, secret, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(UnauthorizedSender { sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::RevealDraftSeed { season_id, secret } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("Team is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            res = send_reveal_draft_seed_msg_to_goi_manager(season_id, secret, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(UnauthorizedSender { sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::DeclareCoaching { coaching } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("Team is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            if let TeamCoaching::AI { attestation } = &coaching {
+                                deps.api.addr_validate(attestation.as_str())?;
+                            }
+                            STATE.update(deps.storage, |mut state| -> StdResult<_> {
+                                state.coaching = coaching.clone();
+                                Ok(state)
+                            })?;
+                            res = send_declare_team_coaching_msg_to_goi_manager(coaching, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(UnauthorizedSender { sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::MakeDraftPick { season_id, player } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("Team is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            let player = deps.api.addr_validate(&player)?;
+                            res = send_make_draft_pick_msg_to_goi_manager(season_id, player, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(UnauthorizedSender { sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::AddDraftedPlayers { season_id, players } => {
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            match manager_info.managing_contract {
+                Some(mc) if mc == info.sender => {
+                    add_players_to_roster(&mut deps, players)?;
+                    Ok(Response::new()
+                        .add_attribute("action", "add_drafted_players")
+                        .add_attribute("season_id", season_id.to_string()))
+                },
+                _ => {
+                    Err(UnauthorizedSender { sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::AddPlayersToTeamWithContracts { signings } => {
+            let is_admin_res = ADMIN.assert_admin(deps.as_ref(), &info.sender.clone());
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            let mut res = Response::new();
+            match is_admin_res {
+                Ok(_) => {
+                    match manager_info.managing_contract {
+                        None => {
+                            panic!("Team is currently unmanaged.")
+                        }
+                        Some(mc) => {
+                            add_players_to_roster(&mut deps, signings.iter().map(|s| s.player.clone()).collect())?;
+                            //signing bonuses go straight from the treasury to each player owner
+                            for signing in signings.iter() {
+                                if let Some(bonus) = &signing.terms.signing_bonus {
+                                    let owner = query_player_owner(&deps, &signing.player.address)?;
+                                    spend_from_treasury(deps.storage, owner.clone(), vec![bonus.clone()],
+                                                        "signing bonus", _env.block.time)?;
+                                    res = res.add_message(BankMsg::Send {
+                                        to_address: owner.to_string(),
+                                        amount: vec![bonus.clone()]
+                                    });
+                                }
+                            }
+                            res = send_add_players_with_contracts_msg_to_goi_manager(signings, mc, res.clone());
+                        }
+                    }
+                    Ok(res)
+                },
+                Err(_) => {
+                    Err(UnauthorizedSender { sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::PaySalaries { season_id, episode, payments } => {
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            match manager_info.managing_contract {
+                Some(mc) if mc == info.sender => {
+                    pay_salaries(deps, _env, season_id, episode, payments)
+                },
+                _ => {
+                    Err(UnauthorizedSender { sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::RemoveRetiredPlayer { player } => {
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            match manager_info.managing_contract {
+                Some(mc) if mc == info.sender => {
+                    STATE.update(deps.storage, |mut state| -> Result<_, TeamError> {
+                        state.players.remove_players_from_positions(vec![player.clone()])?;
+                        Ok(state)
+                    })?;
+                    Ok(Response::new()
+                        .add_attribute("action", "remove_retired_player")
+                        .add_attribute("player", player.address))
+                },
+                _ => {
+                    Err(UnauthorizedSender { sender: info.sender })
+                },
+            }
+        },
+        ExecuteMsg::ApplyTrade { trade_id, outgoing_players, incoming_players } => {
+            let manager_info = MANAGEABLE_SERVICE.get(deps.as_ref())?;
+            match manager_info.managing_contract {
+                Some(mc) if mc == info.sender => {
+                    apply_trade(deps, trade_id, outgoing_players, incoming_players)
+                },
+                _ => {
+                    Err(UnauthorizedSender { sender: info.sender })
+                },
+            }
         }
     }
 }
 
 
+//Player tracking was already updated by the managing contract, so only the roster changes here
+fn apply_trade(mut deps: DepsMut, trade_id: u64, outgoing_players: Vec<PlayerInfo>,
+               incoming_players: Vec<PlayerInfo>) -> Result<Response, TeamError> {
+    STATE.update(deps.storage, |mut state| -> Result<_, TeamError> {
+        state.players.remove_players_from_positions(outgoing_players)?;
+        Ok(state)
+    })?;
+    match incoming_players.len() > 0 {
+        true => add_players_to_roster(&mut deps, incoming_players)?,
+        false => ()
+    }
+    Ok(Response::new()
+        .add_attribute("action", "apply_trade")
+        .add_attribute("trade_id", trade_id.to_string()))
+}
+
+
+//Roster-only update for players the managing contract already assigned to this team
+fn add_players_to_roster(deps: &mut DepsMut, players: Vec<PlayerInfo>) -> Result<(), TeamError> {
+    let items = verify_position_assignments(deps, players)?;
+    let mut state = STATE.load(deps.storage)?;
+    for i in items {
+        state.players.add_player_to_team(i.address, i.position)?;
+    }
+    STATE.save(deps.storage, &state)?;
+    Ok(())
+}
+
+
+fn query_player_owner(deps: &DepsMut, player: &Addr) -> StdResult<Addr> {
+    let res: player::InfoResponse =
+        deps.querier.query_wasm_smart(player.clone(), &player::QueryMsg::GetInfo {})?;
+    Ok(res.player.owner)
+}
+
+
+//Proposals can wrap any message except the governance ones themselves
+fn is_governable_msg(msg: &ExecuteMsg) -> bool {
+    match msg {
+        ExecuteMsg::Propose { .. } | ExecuteMsg::VoteOnProposal { .. } | ExecuteMsg::ExecuteProposal { .. } => false,
+        _ => true
+    }
+}
+
+
 
 
 
@@ -371,13 +892,20 @@ pub fn remove_players_from_team(deps: DepsMut, sender: &Addr, players:Vec<Player
     ADMIN.assert_admin(deps.as_ref(), &sender)?;
     let res =
             STATE.update(deps.storage, |mut state| -> Result<_, TeamError> {
-                match state.players.remove_players_from_positions(players) {
+                match state.players.remove_players_from_positions(players.clone()) {
                     Ok(_) => Ok(state),
                     Err(e) => Err(e)
                 }
             });
     match res {
-        Ok(_) => Ok(Response::new().add_attribute("method", "remove_players_from_team")),
+        Ok(_) => {
+            //dropped players go back to the managing contract's free agent pool
+            let res = Response::new().add_attribute("method", "remove_players_from_team");
+            match MANAGEABLE_SERVICE.get(deps.as_ref())?.managing_contract {
+                Some(mc) => Ok(send_release_players_msg_to_goi_manager(players, mc, res)),
+                None => Ok(res)
+            }
+        },
         Err(e) => Err(e)
     }
 }
@@ -397,6 +925,16 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::GetOffense {} => to_binary(&query_offense_players(deps)?),
         QueryMsg::GetAllPlayers {} => to_binary(&query_all_players(deps)?),
         QueryMsg::GetInfo {} => to_binary(&query_info(deps)?),
+        QueryMsg::GetTreasuryBalances {} => to_binary(&query_treasury_balances(deps)?),
+        QueryMsg::GetTreasuryLedger { start_after, limit } => to_binary(&query_treasury_ledger(deps, start_after, limit)?),
+        QueryMsg::GetTreasuryWithdrawalProposal { proposal_id } => to_binary(&query_treasury_withdrawal_proposal(deps, proposal_id)?),
+        QueryMsg::GetDividendClaims { owner } => to_binary(&query_dividend_claims(deps, owner)?),
+        QueryMsg::GetShareListings {} => to_binary(&query_share_listings(deps)?),
+        QueryMsg::GetProposal { proposal_id } => to_binary(&query_proposal(deps, _env, proposal_id)?),
+        QueryMsg::ListProposals { start_after, limit } => to_binary(&query_list_proposals(deps, _env, start_after, limit)?),
+        QueryMsg::GetVote { proposal_id, voter } => to_binary(&query_vote(deps, proposal_id, voter)?),
+        QueryMsg::GetGovernanceConfig {} => to_binary(&get_governance_config(deps)?),
+        QueryMsg::GetSalaryArrears { owner } => to_binary(&query_salary_arrears(deps, owner)?),
     }
 }
 
@@ -98,6 +98,37 @@ pub enum TeamError {
     ErrorCreatingPlayer { msg: InstantiateMsg},
 
 
+    #[error("NoFundsSent")]
+    NoFundsSent {},
+
+    #[error("InvalidTreasuryAmount")]
+    InvalidTreasuryAmount {},
+
+    #[error("InsufficientTreasuryFunds")]
+    InsufficientTreasuryFunds { denom: String },
+
+    #[error("NotTeamOwner")]
+    NotTeamOwner { sender: Addr },
+
+    #[error("WithdrawalProposalNotFound")]
+    WithdrawalProposalNotFound {},
+
+    #[error("WithdrawalProposalAlreadyExecuted")]
+    WithdrawalProposalAlreadyExecuted {},
+
+    #[error("WithdrawalProposalExpired")]
+    WithdrawalProposalExpired {},
+
+    #[error("AlreadyVoted")]
+    AlreadyVoted {},
+
+    #[error("NoDividendsToDistribute")]
+    NoDividendsToDistribute { denom: String },
+
+    #[error("NoDividendsToClaim")]
+    NoDividendsToClaim { denom: String },
+
+
 }
 
 
@@ -5,5 +5,6 @@ mod error;
 pub mod msg;
 pub mod state;
 mod team_attributes;
+pub mod treasury;
 //mod integration_tests;
 
@@ -1,11 +1,16 @@
-use cosmwasm_std::{Addr, Coin};
+use cosmwasm_std::{Addr, Binary, Coin, Timestamp};
 use cw4::Member;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use managed::messages::ManagedExecuteMsg;
-use shared::messaging::MessageTypes;
+use shared::draft::DraftPickRef;
+use shared::fixture::{LineupSlot, PlayCallingStrategy};
+use shared::governance::{GovernanceConfig, Vote};
+use shared::messaging::{InboxContent, MessageTypes};
 use shared::player::PlayerInfo;
+use shared::player_contract::{PlayerSigning, SalaryPayment};
+use shared::team::TeamCoaching;
 use shared::utils::{MessageId, SeasonId};
 
 use crate::team_attributes::TeamPosition;
@@ -19,6 +24,8 @@ pub struct InstantiateTeamMsg {
     //pub managing_contract_active_status: bool,
     pub for_sale: bool,
     pub price: Option<Coin>,
+    #[serde(default)]
+    pub coaching: TeamCoaching,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -31,11 +38,52 @@ pub enum ExecuteMsg {
     UpdateMessageStatus {  message_id: MessageId,
         updated_message_status: MessageTypes
     },
+    //inbox messages to other managed assets, see `GoiManagerQueryMsg::GetInbox`
+    SendMessage { to: String, content: InboxContent, body: String, reply_to: Option<MessageId>, expires: Option<Timestamp> },
+    MarkMessageRead { message_id: MessageId, read: bool },
 
     JoinLeague { season_id: SeasonId },
     CancelSeasonSpot { season_id: SeasonId },
     JoinLeagueWinnerTakeAll { season_id: SeasonId, fee: Vec<Coin> },
-    Deposit{}
+    WithdrawFromRescheduledSeason { season_id: SeasonId },
+    Deposit{},
+    WithdrawFromTreasury { recipient: String, amount: Vec<Coin> },
+    ProposeTreasuryWithdrawal { recipient: String, amount: Vec<Coin> },
+    VoteTreasuryWithdrawal { proposal_id: u64 },
+    ReconcileTreasury {},
+    DistributeDividends { denom: String },
+    AllocateDividends { denom: String },
+    ClaimDividends { denom: String },
+    Propose { title: String, description: String, msg: Box<ExecuteMsg> },
+    VoteOnProposal { proposal_id: u64, vote: Vote },
+    ExecuteProposal { proposal_id: u64 },
+    UpdateGovernanceConfig { config: GovernanceConfig },
+    ProposeTrade { counterparty_team: String, offered_players: Vec<PlayerInfo>,
+        requested_players: Vec<PlayerInfo>, offered_funds: Vec<Coin>,
+        #[serde(default)] offered_picks: Vec<DraftPickRef>, #[serde(default)] requested_picks: Vec<DraftPickRef> },
+    RespondToTrade { trade_id: u64, accept: bool },
+    CancelTrade { trade_id: u64 },
+    SignFreeAgent { player: PlayerInfo, signing_fee: Vec<Coin> },
+    //sent by the managing contract once a trade is accepted
+    ApplyTrade { trade_id: u64, outgoing_players: Vec<PlayerInfo>, incoming_players: Vec<PlayerInfo> },
+    MakeDraftPick { season_id: SeasonId, player: String },
+    //`commitment` is `fixture::seed_commitment(team contract, secret)`
+    CommitDraftSeed { season_id: SeasonId, commitment: String },
+    RevealDraftSeed { season_id: SeasonId, secret: Binary },
+    //for teams that didn't declare when instantiated; a declared type can't change
+    DeclareCoaching { coaching: TeamCoaching },
+    //sent by the managing contract for each pick this team makes
+    AddDraftedPlayers { season_id: SeasonId, players: Vec<PlayerInfo> },
+    AddPlayersToTeamWithContracts { signings: Vec<PlayerSigning> },
+    //sent by the managing contract each episode of a season the team plays in
+    PaySalaries { season_id: SeasonId, episode: u32, payments: Vec<SalaryPayment> },
+    //sent by the managing contract when a rostered player retires
+    RemoveRetiredPlayer { player: PlayerInfo },
+    //`commitment` is `fixture::seed_commitment(team contract, secret)`
+    CommitFixtureSeed { fixture_id: u64, commitment: String },
+    RevealFixtureSeed { fixture_id: u64, secret: Binary },
+    SubmitLineup { season_id: SeasonId, episode: u32, offense: Vec<LineupSlot>, defense: Vec<LineupSlot>,
+        play_calling_strategy: PlayCallingStrategy },
 
 }
 
@@ -50,6 +98,16 @@ pub enum QueryMsg {
     GetOffense {},
     GetDefense {},
     GetInfo {},
+    GetTreasuryBalances {},
+    GetTreasuryLedger { start_after: Option<u64>, limit: Option<u32> },
+    GetTreasuryWithdrawalProposal { proposal_id: u64 },
+    GetDividendClaims { owner: String },
+    GetShareListings {},
+    GetProposal { proposal_id: u64 },
+    ListProposals { start_after: Option<u64>, limit: Option<u32> },
+    GetVote { proposal_id: u64, voter: String },
+    GetGovernanceConfig {},
+    GetSalaryArrears { owner: String },
 }
 
 
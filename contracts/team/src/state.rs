@@ -1,12 +1,14 @@
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Uint128};
 use cw4::TOTAL_KEY;
 use cw_controllers::{Admin, Hooks};
-use cw_storage_plus::{Item, SnapshotMap, Strategy};
+use cw_storage_plus::{Item, Map, SnapshotMap, Strategy};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use managed::service::ManagedService;
 use saleable::service::SaleableService;
+use shared::dividends::DividendClaims;
+use shared::team::{TeamCoaching, TreasuryLedgerEntry, TreasuryWithdrawalProposal};
 
 use crate::team_attributes::TeamPlayers;
 
@@ -14,6 +16,8 @@ use crate::team_attributes::TeamPlayers;
 pub struct State {
     pub name: String,
     pub players: TeamPlayers,
+    #[serde(default)]
+    pub coaching: TeamCoaching,
 }
 
 
@@ -22,6 +26,7 @@ pub const STATE: Item<State> = Item::new("state");
 pub const SALEABLE_SERVICE: SaleableService = SaleableService::new("saleable_service");
 pub const MANAGEABLE_SERVICE: ManagedService = ManagedService::new("manageable_service");
 pub const ADMIN: Admin = Admin::new("admin");
+pub const DIVIDEND_CLAIMS: DividendClaims = DividendClaims::new("dividend_claims", "dividends_outstanding");
 pub const HOOKS: Hooks = Hooks::new("cw4-hooks");
 pub const TOTAL: Item<u64> = Item::new(TOTAL_KEY);
 
@@ -31,3 +36,13 @@ pub const MEMBERS: SnapshotMap<&Addr, u64> = SnapshotMap::new(
     cw4::MEMBERS_CHANGELOG,
     Strategy::EveryBlock,
 );
+
+
+//treasury balances keyed by denom
+pub const TREASURY_BALANCES: Map<&str, Uint128> = Map::new("treasury_balances");
+pub const TREASURY_LEDGER: Map<u64, TreasuryLedgerEntry> = Map::new("treasury_ledger");
+pub const TREASURY_LEDGER_COUNT: Item<u64> = Item::new("treasury_ledger_count");
+pub const TREASURY_WITHDRAWAL_PROPOSALS: Map<u64, TreasuryWithdrawalProposal> = Map::new("treasury_withdrawal_proposals");
+pub const TREASURY_WITHDRAWAL_PROPOSAL_COUNT: Item<u64> = Item::new("treasury_withdrawal_proposal_count");
+//unpaid salary owed to player owners, keyed by (owner, denom)
+pub const SALARY_ARREARS: Map<(&Addr, &str), Uint128> = Map::new("salary_arrears");
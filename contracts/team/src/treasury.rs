@@ -0,0 +1,387 @@
+use cosmwasm_std::{Addr, BankMsg, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::Bound;
+use cw4_group::state::{MEMBERS, TOTAL};
+
+use managed::governance::get_governance_config;
+use shared::dividends::{dividend_payout_messages, split_by_weight, total_of_shares};
+use shared::player_contract::SalaryPayment;
+use shared::team::{TreasuryFlow, TreasuryLedgerEntry, TreasuryWithdrawalProposal};
+
+use crate::error::TeamError;
+use shared::utils::SeasonId;
+use shared::utils::general::normalize_coins;
+
+use crate::state::{ADMIN, DIVIDEND_CLAIMS, SALARY_ARREARS, TREASURY_BALANCES, TREASURY_LEDGER, TREASURY_LEDGER_COUNT,
+                   TREASURY_WITHDRAWAL_PROPOSAL_COUNT, TREASURY_WITHDRAWAL_PROPOSALS};
+
+const DEFAULT_LEDGER_LIMIT: u32 = 10;
+const MAX_LEDGER_LIMIT: u32 = 30;
+
+
+fn credit_treasury(store: &mut dyn Storage, amount: &[Coin]) -> StdResult<()> {
+    for coin in amount {
+        TREASURY_BALANCES.update(store, coin.denom.as_str(), |balance| -> StdResult<_> {
+            Ok(balance.unwrap_or_default() + coin.amount)
+        })?;
+    }
+    Ok(())
+}
+
+
+//Debits every coin or none of them
+pub fn debit_treasury(store: &mut dyn Storage, amount: &[Coin]) -> Result<(), TeamError> {
+    for coin in amount {
+        let balance = TREASURY_BALANCES.may_load(store, coin.denom.as_str())?.unwrap_or_default();
+        match balance >= coin.amount {
+            true => continue,
+            false => return Err(TeamError::InsufficientTreasuryFunds { denom: coin.denom.clone() })
+        }
+    }
+    for coin in amount {
+        let balance = TREASURY_BALANCES.load(store, coin.denom.as_str())?;
+        TREASURY_BALANCES.save(store, coin.denom.as_str(), &(balance - coin.amount))?;
+    }
+    Ok(())
+}
+
+
+pub fn record_treasury_entry(store: &mut dyn Storage, flow: TreasuryFlow, counterparty: Addr,
+                             amount: Vec<Coin>, memo: &str, timestamp: Timestamp) -> StdResult<u64> {
+    let id = TREASURY_LEDGER_COUNT.may_load(store)?.unwrap_or_default() + 1;
+    TREASURY_LEDGER_COUNT.save(store, &id)?;
+    TREASURY_LEDGER.save(store, id, &TreasuryLedgerEntry {
+        id,
+        flow,
+        counterparty,
+        amount,
+        memo: memo.to_string(),
+        timestamp
+    })?;
+    Ok(id)
+}
+
+
+//Moves funds out of the treasury, e.g. a season entry fee sent to the managing contract
+pub fn spend_from_treasury(store: &mut dyn Storage, counterparty: Addr, amount: Vec<Coin>,
+                           memo: &str, timestamp: Timestamp) -> Result<(), TeamError> {
+    let amount = normalize_coins(amount);
+    match amount.len() > 0 {
+        true => {
+            debit_treasury(store, &amount)?;
+            record_treasury_entry(store, TreasuryFlow::Outflow, counterparty, amount, memo, timestamp)?;
+            Ok(())
+        }
+        false => Err(TeamError::InvalidTreasuryAmount {})
+    }
+}
+
+
+// Anyone may fund the team; refunds and winnings from the managing contract arrive here too
+pub fn deposit(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, TeamError> {
+    let amount = normalize_coins(info.funds);
+    match amount.len() > 0 {
+        true => {
+            credit_treasury(deps.storage, &amount)?;
+            let entry_id = record_treasury_entry(deps.storage, TreasuryFlow::Inflow, info.sender.clone(),
+                                                 amount, "deposit", env.block.time)?;
+            Ok(Response::new()
+                .add_attribute("action", "deposit")
+                .add_attribute("sender", info.sender)
+                .add_attribute("ledger_entry_id", entry_id.to_string()))
+        }
+        false => Err(TeamError::NoFundsSent {})
+    }
+}
+
+
+fn send_from_treasury(deps: DepsMut, env: Env, recipient: Addr, amount: Vec<Coin>,
+                      memo: &str, response: Response) -> Result<Response, TeamError> {
+    spend_from_treasury(deps.storage, recipient.clone(), amount.clone(), memo, env.block.time)?;
+    let msg: CosmosMsg =
+        BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: normalize_coins(amount)
+        }.into();
+    Ok(response
+        .add_message(msg)
+        .add_attribute("recipient", recipient))
+}
+
+
+// Team admin withdraws directly
+pub fn withdraw_from_treasury(deps: DepsMut, env: Env, info: MessageInfo,
+                              recipient: String, amount: Vec<Coin>) -> Result<Response, TeamError> {
+    match ADMIN.assert_admin(deps.as_ref(), &info.sender) {
+        Ok(_) => {
+            let recipient = deps.api.addr_validate(&recipient)?;
+            send_from_treasury(deps, env, recipient, amount, "withdrawal",
+                               Response::new().add_attribute("action", "withdraw_from_treasury"))
+        }
+        Err(_) => Err(TeamError::UnauthorizedSender { sender: info.sender })
+    }
+}
+
+
+//Weight as of the start of the proposal's block, so shares moved while
+//a proposal is open can't vote on it twice
+fn get_owner_weight(deps: Deps, owner: &Addr, height: u64) -> Result<u64, TeamError> {
+    match MEMBERS.may_load_at_height(deps.storage, owner, height)? {
+        Some(weight) if weight > 0 => Ok(weight),
+        _ => Err(TeamError::NotTeamOwner { sender: owner.clone() })
+    }
+}
+
+
+//Pays out the proposal once its yes weight is more than half of the total weight
+fn execute_withdrawal_proposal_if_passed(deps: DepsMut, env: Env, mut proposal: TreasuryWithdrawalProposal,
+                                         response: Response) -> Result<Response, TeamError> {
+    match proposal.yes_weight * 2 > proposal.total_weight {
+        true => {
+            proposal.executed = Some(env.block.time);
+            TREASURY_WITHDRAWAL_PROPOSALS.save(deps.storage, proposal.id, &proposal)?;
+            send_from_treasury(deps, env, proposal.recipient, proposal.amount, "owner approved withdrawal",
+                               response.add_attribute("status", "executed"))
+        }
+        false => {
+            TREASURY_WITHDRAWAL_PROPOSALS.save(deps.storage, proposal.id, &proposal)?;
+            Ok(response.add_attribute("status", "open"))
+        }
+    }
+}
+
+
+// Co-owner requests a withdrawal; the proposer's weight counts as the first vote
+pub fn propose_treasury_withdrawal(deps: DepsMut, env: Env, info: MessageInfo,
+                                   recipient: String, amount: Vec<Coin>) -> Result<Response, TeamError> {
+    //proposer must already have held weight before this block
+    let weight = get_owner_weight(deps.as_ref(), &info.sender, env.block.height)?;
+    let voting_period = get_governance_config(deps.as_ref())?.voting_period;
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let amount = normalize_coins(amount);
+    match amount.len() > 0 {
+        true => {
+            let id = TREASURY_WITHDRAWAL_PROPOSAL_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+            TREASURY_WITHDRAWAL_PROPOSAL_COUNT.save(deps.storage, &id)?;
+            let proposal = TreasuryWithdrawalProposal {
+                id,
+                proposer: info.sender.clone(),
+                recipient,
+                amount,
+                voters: vec![info.sender.clone()],
+                yes_weight: weight,
+                created: env.block.time,
+                executed: None,
+                start_height: env.block.height,
+                total_weight: TOTAL.load(deps.storage)?,
+                expires: env.block.time.plus_seconds(voting_period)
+            };
+            execute_withdrawal_proposal_if_passed(deps, env, proposal,
+                                                  Response::new()
+                                                      .add_attribute("action", "propose_treasury_withdrawal")
+                                                      .add_attribute("proposal_id", id.to_string()))
+        }
+        false => Err(TeamError::InvalidTreasuryAmount {})
+    }
+}
+
+
+pub fn vote_treasury_withdrawal(deps: DepsMut, env: Env, info: MessageInfo,
+                                proposal_id: u64) -> Result<Response, TeamError> {
+    let mut proposal =
+        match TREASURY_WITHDRAWAL_PROPOSALS.may_load(deps.storage, proposal_id)? {
+            None => return Err(TeamError::WithdrawalProposalNotFound {}),
+            Some(p) => p
+        };
+    let weight = get_owner_weight(deps.as_ref(), &info.sender, proposal.start_height)?;
+    match (proposal.executed.is_some(), env.block.time >= proposal.expires, proposal.voters.contains(&info.sender)) {
+        (true, _, _) => Err(TeamError::WithdrawalProposalAlreadyExecuted {}),
+        (false, true, _) => Err(TeamError::WithdrawalProposalExpired {}),
+        (false, false, true) => Err(TeamError::AlreadyVoted {}),
+        (false, false, false) => {
+            proposal.voters.push(info.sender);
+            proposal.yes_weight += weight;
+            execute_withdrawal_proposal_if_passed(deps, env, proposal,
+                                                  Response::new()
+                                                      .add_attribute("action", "vote_treasury_withdrawal")
+                                                      .add_attribute("proposal_id", proposal_id.to_string()))
+        }
+    }
+}
+
+
+// Credits funds that reached the contract without going through Deposit
+// (e.g. plain bank sends) so they can be withdrawn
+pub fn reconcile_treasury(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, TeamError> {
+    match ADMIN.assert_admin(deps.as_ref(), &info.sender) {
+        Ok(_) => {
+            let bank_balances = deps.querier.query_all_balances(env.contract.address.clone())?;
+            let mut untracked: Vec<Coin> = vec![];
+            for coin in bank_balances {
+                //allocated dividends are still held by the contract until claimed
+                let tracked = TREASURY_BALANCES.may_load(deps.storage, coin.denom.as_str())?.unwrap_or_default() +
+                    DIVIDEND_CLAIMS.outstanding(deps.storage, coin.denom.as_str())?;
+                match coin.amount > tracked {
+                    true => untracked.push(Coin { denom: coin.denom, amount: coin.amount - tracked }),
+                    false => continue
+                }
+            }
+            let mut res = Response::new().add_attribute("action", "reconcile_treasury");
+            match untracked.len() > 0 {
+                true => {
+                    credit_treasury(deps.storage, &untracked)?;
+                    let entry_id = record_treasury_entry(deps.storage, TreasuryFlow::Inflow, env.contract.address,
+                                                         untracked, "reconciled", env.block.time)?;
+                    res = res.add_attribute("ledger_entry_id", entry_id.to_string());
+                }
+                false => {}
+            }
+            Ok(res)
+        }
+        Err(_) => Err(TeamError::UnauthorizedSender { sender: info.sender })
+    }
+}
+
+
+fn get_owner_dividend_shares(deps: Deps, denom: &str) -> Result<Vec<(Addr, Uint128)>, TeamError> {
+    let owners: Vec<(Addr, u64)> =
+        MEMBERS.range(deps.storage, None, None, Order::Ascending).collect::<StdResult<_>>()?;
+    let balance = TREASURY_BALANCES.may_load(deps.storage, denom)?.unwrap_or_default();
+    let shares = split_by_weight(balance, &owners);
+    match shares.len() > 0 {
+        true => Ok(shares),
+        false => Err(TeamError::NoDividendsToDistribute { denom: denom.to_string() })
+    }
+}
+
+
+// Pays the treasury balance of `denom` straight out to the current owners by weight
+pub fn distribute_dividends(deps: DepsMut, env: Env, info: MessageInfo, denom: String) -> Result<Response, TeamError> {
+    match ADMIN.assert_admin(deps.as_ref(), &info.sender) {
+        Ok(_) => {
+            let shares = get_owner_dividend_shares(deps.as_ref(), &denom)?;
+            for (owner, amount) in shares.iter() {
+                spend_from_treasury(deps.storage, owner.clone(),
+                                    vec![Coin { denom: denom.clone(), amount: *amount }], "dividend", env.block.time)?;
+            }
+            Ok(Response::new()
+                .add_messages(dividend_payout_messages(&denom, &shares))
+                .add_attribute("action", "distribute_dividends")
+                .add_attribute("total", total_of_shares(&shares).to_string()))
+        }
+        Err(_) => Err(TeamError::UnauthorizedSender { sender: info.sender })
+    }
+}
+
+
+// Same split as distribute_dividends, but each owner claims their share later
+pub fn allocate_dividends(deps: DepsMut, env: Env, info: MessageInfo, denom: String) -> Result<Response, TeamError> {
+    match ADMIN.assert_admin(deps.as_ref(), &info.sender) {
+        Ok(_) => {
+            let shares = get_owner_dividend_shares(deps.as_ref(), &denom)?;
+            let total = total_of_shares(&shares);
+            spend_from_treasury(deps.storage, env.contract.address.clone(),
+                                vec![Coin { denom: denom.clone(), amount: total }], "dividends allocated", env.block.time)?;
+            DIVIDEND_CLAIMS.allocate(deps.storage, &denom, &shares)?;
+            Ok(Response::new()
+                .add_attribute("action", "allocate_dividends")
+                .add_attribute("total", total.to_string()))
+        }
+        Err(_) => Err(TeamError::UnauthorizedSender { sender: info.sender })
+    }
+}
+
+
+pub fn claim_dividends(deps: DepsMut, info: MessageInfo, denom: String) -> Result<Response, TeamError> {
+    match DIVIDEND_CLAIMS.claim(deps.storage, &info.sender, &denom)? {
+        Some(amount) => {
+            let msg: CosmosMsg =
+                BankMsg::Send {
+                    to_address: info.sender.to_string(),
+                    amount: vec![amount]
+                }.into();
+            Ok(Response::new()
+                .add_message(msg)
+                .add_attribute("action", "claim_dividends")
+                .add_attribute("owner", info.sender))
+        }
+        None => Err(TeamError::NoDividendsToClaim { denom })
+    }
+}
+
+
+pub fn query_treasury_balances(deps: Deps) -> StdResult<Vec<Coin>> {
+    TREASURY_BALANCES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount): (String, Uint128)| Coin { denom, amount }))
+        .collect()
+}
+
+
+pub fn query_treasury_ledger(deps: Deps, start_after: Option<u64>, limit: Option<u32>) -> StdResult<Vec<TreasuryLedgerEntry>> {
+    let limit = limit.unwrap_or(DEFAULT_LEDGER_LIMIT).min(MAX_LEDGER_LIMIT) as usize;
+    TREASURY_LEDGER
+        .range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, entry)| entry))
+        .collect()
+}
+
+
+// Sent by the managing contract each episode. Never fails for lack of funds:
+// whatever the treasury can't cover is carried as arrears for the player owner
+// and paid together with the next salary the treasury can afford.
+pub fn pay_salaries(deps: DepsMut, env: Env, season_id: SeasonId, episode: u32,
+                    payments: Vec<SalaryPayment>) -> Result<Response, TeamError> {
+    let mut res = Response::new()
+        .add_attribute("action", "pay_salaries")
+        .add_attribute("season_id", season_id.to_string())
+        .add_attribute("episode", episode.to_string());
+    for payment in payments {
+        let denom = payment.amount.denom.clone();
+        let arrears = SALARY_ARREARS.may_load(deps.storage, (&payment.owner, denom.as_str()))?.unwrap_or_default();
+        let due = Coin { denom: denom.clone(), amount: payment.amount.amount + arrears };
+        let balance = TREASURY_BALANCES.may_load(deps.storage, denom.as_str())?.unwrap_or_default();
+        match due.amount.is_zero() {
+            true => continue,
+            false => ()
+        }
+        match balance >= due.amount {
+            true => {
+                spend_from_treasury(deps.storage, payment.owner.clone(), vec![due.clone()], "salary", env.block.time)?;
+                SALARY_ARREARS.remove(deps.storage, (&payment.owner, denom.as_str()));
+                let msg: CosmosMsg =
+                    BankMsg::Send {
+                        to_address: payment.owner.to_string(),
+                        amount: vec![due]
+                    }.into();
+                res = res.add_message(msg);
+            }
+            false => {
+                SALARY_ARREARS.save(deps.storage, (&payment.owner, denom.as_str()), &due.amount)?;
+                res = res.add_attribute("unpaid", payment.player);
+            }
+        }
+    }
+    Ok(res)
+}
+
+
+pub fn query_salary_arrears(deps: Deps, owner: String) -> StdResult<Vec<Coin>> {
+    let owner = deps.api.addr_validate(&owner)?;
+    SALARY_ARREARS
+        .prefix(&owner)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount): (String, Uint128)| Coin { denom, amount }))
+        .collect()
+}
+
+
+pub fn query_treasury_withdrawal_proposal(deps: Deps, proposal_id: u64) -> StdResult<Option<TreasuryWithdrawalProposal>> {
+    TREASURY_WITHDRAWAL_PROPOSALS.may_load(deps.storage, proposal_id)
+}
+
+
+pub fn query_dividend_claims(deps: Deps, owner: String) -> StdResult<Vec<Coin>> {
+    let owner = deps.api.addr_validate(&owner)?;
+    DIVIDEND_CLAIMS.query_claims(deps.storage, &owner)
+}
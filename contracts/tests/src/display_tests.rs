@@ -0,0 +1,429 @@
+use std::borrow::BorrowMut;
+use cosmwasm_std::{Addr, Coin, Timestamp, Uint128};
+use cosmwasm_std::testing::mock_env;
+use cw_multi_test::{App, AppResponse, Executor};
+use display::DisplayError;
+use display::msg::ExecuteMsg as DisplayExecuteMsg;
+use goi_manager::ContractError;
+use managed::messages::ManagedExecuteMsg;
+use saleable::messages::receive::ExecuteMsg::{Buy, Update};
+use shared::advertising::{AdContent, AdPlacement, AdSlot, AdStatus};
+use shared::broadcast::{BroadcastBooking, BroadcastStatus, BROADCAST_CANCELLATION_NOTICE, MAX_BROADCAST_SLOT};
+use shared::display::{DisplayDetails, DisplayKind, DisplayStatusResponse, HEARTBEAT_TIMEOUT, OperatingHours, Resolution};
+use shared::fixture::Fixture;
+use shared::goi_manager::GoiManagerQueryMsg;
+use shared::utils::general::AssetTypes;
+use shared::utils::{ONE_MINUTE, PRIOR_TO_SEASON_START_PADDING, SeasonId, THIRTY_MINUTES};
+
+use crate::shared_utils::{TOKEN, mock_app_by_user, USER1, USER2, USER3, OWNER, instantiate_management_contract, get_display_for_user,
+                          get_league_for_users, get_team_for_users, add_season_to_league, get_season, team_request_to_join_league};
+
+
+fn billboard_details() -> DisplayDetails {
+    DisplayDetails {
+        kind: DisplayKind::Physical,
+        geo_location: "40.7580,-73.9855".to_string(),
+        resolution: Resolution { width: 1920, height: 1080 },
+        //midnight to noon UTC
+        operating_hours: Some(OperatingHours { opens: 0, closes: 43_200 }),
+    }
+}
+
+
+fn query_display(app: &App, goi_manager_addr: &Addr, display_addr: &Addr) -> Option<DisplayStatusResponse> {
+    app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                                &GoiManagerQueryMsg::GetDisplay { display_addr: display_addr.clone() }).unwrap()
+}
+
+
+#[test]
+fn display_registration_and_heartbeats() {
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2], &[init_balance.clone()]);
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let screen_key = Addr::unchecked("screen_key");
+    let display_addr = get_display_for_user(Addr::unchecked(USER1), billboard_details(),
+                                            Some(screen_key.to_string()), goi_manager_addr.clone(), app.borrow_mut());
+
+    let managed: Option<shared::utils::ManagedItemResponse> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+        &GoiManagerQueryMsg::GetManagedContract { contract_address: display_addr.clone(), contract_type: AssetTypes::Display }).unwrap();
+    assert_eq!(AssetTypes::Display, managed.unwrap().asset_type);
+
+    //registered with its details but no heartbeat yet
+    let status = query_display(&app, &goi_manager_addr, &display_addr).unwrap();
+    assert_eq!("Main Street Screen", status.display.name);
+    assert_eq!(billboard_details().resolution, status.display.resolution);
+    assert!(!status.online);
+    assert!(status.open);
+
+    //only the admin or the reporter key can report
+    match app.execute_contract(Addr::unchecked(USER2), display_addr.clone(),
+                               &DisplayExecuteMsg::Heartbeat { online: true }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(DisplayError::Unauthorized { sender: Addr::unchecked(USER2) }, e.downcast().unwrap())
+    }
+    app.execute_contract(screen_key.clone(), display_addr.clone(),
+                         &DisplayExecuteMsg::Heartbeat { online: true }, &[]).unwrap();
+    assert!(query_display(&app, &goi_manager_addr, &display_addr).unwrap().online);
+
+    //a silent screen drops offline once the heartbeat times out
+    app.update_block(|b| b.time = b.time.plus_seconds(HEARTBEAT_TIMEOUT + 1));
+    assert!(!query_display(&app, &goi_manager_addr, &display_addr).unwrap().online);
+
+    app.execute_contract(Addr::unchecked(USER1), display_addr.clone(),
+                         &DisplayExecuteMsg::Heartbeat { online: false }, &[]).unwrap();
+    let status = query_display(&app, &goi_manager_addr, &display_addr).unwrap();
+    assert!(!status.online);
+    assert_eq!(false, status.display.last_heartbeat.unwrap().online);
+
+    //the reporter can be revoked by the admin
+    app.execute_contract(Addr::unchecked(USER1), display_addr.clone(),
+                         &DisplayExecuteMsg::SetReporter { reporter: None }, &[]).unwrap();
+    assert!(app.execute_contract(screen_key.clone(), display_addr.clone(),
+                                 &DisplayExecuteMsg::Heartbeat { online: true }, &[]).is_err());
+
+    //a physical screen needs a location
+    let mut details = billboard_details();
+    details.geo_location = "".to_string();
+    match app.execute_contract(Addr::unchecked(USER1), display_addr.clone(),
+                               &DisplayExecuteMsg::UpdateDetails { details }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(DisplayError::InvalidDisplayDetails {}, e.downcast().unwrap())
+    }
+
+    //goi manager only takes details from managed displays
+    match app.execute_contract(Addr::unchecked(USER1), goi_manager_addr.clone(),
+                               &shared::goi_manager::ExecuteMsg::DisplayHeartbeat { online: true }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::Unauthorized { sender: Addr::unchecked(USER1) }, e.downcast().unwrap())
+    }
+
+    let mut details = billboard_details();
+    details.kind = DisplayKind::Virtual;
+    details.geo_location = "".to_string();
+    details.operating_hours = Some(OperatingHours { opens: 43_200, closes: 50_000 });
+    app.execute_contract(Addr::unchecked(USER1), display_addr.clone(),
+                         &DisplayExecuteMsg::UpdateDetails { details: details.clone() }, &[]).unwrap();
+
+    let displays: Vec<DisplayStatusResponse> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+        &GoiManagerQueryMsg::GetDisplays { start_after: None, limit: None }).unwrap();
+    assert_eq!(1, displays.len());
+    assert_eq!(DisplayKind::Virtual, displays[0].display.kind);
+    assert!(!displays[0].open);
+}
+
+
+#[test]
+fn display_sale_revokes_reporter() {
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2], &[init_balance.clone()]);
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let screen_key = Addr::unchecked("screen_key");
+    let display_addr = get_display_for_user(Addr::unchecked(USER1), billboard_details(),
+                                            Some(screen_key.to_string()), goi_manager_addr.clone(), app.borrow_mut());
+
+    let price = Coin { denom: TOKEN.to_string(), amount: Uint128::from(500000u128) };
+    app.execute_contract(Addr::unchecked(USER1), display_addr.clone(),
+                         &DisplayExecuteMsg::ManagedServiceMessage { message: ManagedExecuteMsg::Saleable {
+                             saleable_msg: Update { for_sale_status: true, price: Some(price.clone()) } } }, &[]).unwrap();
+    app.execute_contract(Addr::unchecked(USER2), display_addr.clone(),
+                         &DisplayExecuteMsg::ManagedServiceMessage { message: ManagedExecuteMsg::Saleable {
+                             saleable_msg: Buy {} } }, &[price]).unwrap();
+
+    //the seller's screen key no longer reports for the display
+    let reporter: Option<Addr> = app.wrap().query_wasm_smart(display_addr.clone(),
+                                                             &display::msg::QueryMsg::GetReporter {}).unwrap();
+    assert_eq!(None, reporter);
+    match app.execute_contract(screen_key.clone(), display_addr.clone(),
+                               &DisplayExecuteMsg::Heartbeat { online: true }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(DisplayError::Unauthorized { sender: screen_key.clone() }, e.downcast().unwrap())
+    }
+}
+
+
+// USER1's league with a season of `season_length` seconds that USER1's and USER3's
+// teams joined, and a fixture between them. The block is moved into the season.
+fn league_with_fixture(app: &mut App, goi_manager_addr: &Addr, season_length: u64) -> (Addr, SeasonId, u64) {
+    let block_time =  mock_env().block.time.clone();
+    let league_addr = get_league_for_users(vec![Addr::unchecked(USER1)], goi_manager_addr.clone(), app)[0].clone();
+    let teams = get_team_for_users(vec![Addr::unchecked(USER1), Addr::unchecked(USER3)], goi_manager_addr.clone(), app);
+
+    let season_id = 1u64;
+    let season_start = block_time.plus_seconds(PRIOR_TO_SEASON_START_PADDING + 300);
+    add_season_to_league(app, USER1, get_season(season_id, season_start, season_start.plus_seconds(season_length)),
+                         league_addr.clone()).unwrap();
+    team_request_to_join_league(app, USER1, teams[0].clone(), season_id).unwrap();
+    team_request_to_join_league(app, USER3, teams[1].clone(), season_id).unwrap();
+    app.update_block(|b| b.time = season_start.plus_seconds(ONE_MINUTE));
+    let now = app.block_info().time;
+    app.execute_contract(Addr::unchecked(USER1), league_addr.clone(),
+                         &league::msg::ExecuteMsg::ScheduleFixture { season_id, episode: 1, home: teams[0].to_string(),
+                             away: teams[1].to_string(), league_commits: false,
+                             commit_deadline: now.plus_seconds(3 * 86_400),
+                             reveal_deadline: now.plus_seconds(4 * 86_400) }, &[]).unwrap();
+    let fixtures: Vec<Fixture> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                                        &GoiManagerQueryMsg::GetSeasonFixtures { season_id }).unwrap();
+    (league_addr, season_id, fixtures[0].id)
+}
+
+
+#[test]
+fn broadcast_booking_escrow_and_refunds() {
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let display_addr = get_display_for_user(Addr::unchecked(USER2), billboard_details(), None,
+                                            goi_manager_addr.clone(), app.borrow_mut());
+    let (league_addr, _, fixture_id) = league_with_fixture(app.borrow_mut(), &goi_manager_addr, THIRTY_MINUTES);
+    let now = app.block_info().time;
+
+    let fee = Coin { denom: TOKEN.to_string(), amount: Uint128::from(1000u128) };
+    let book = |app: &mut App, start: Timestamp, hours: u64, payment: Vec<Coin>| -> anyhow::Result<AppResponse> {
+        app.execute_contract(Addr::unchecked(USER1), league_addr.clone(),
+                             &league::msg::ExecuteMsg::BookBroadcast { display: display_addr.to_string(), fixture_id,
+                                 start, end: start.plus_seconds(hours * 3_600) }, &payment)
+    };
+    let respond = |app: &mut App, booking_id: u64, accept: bool| {
+        app.execute_contract(Addr::unchecked(USER2), display_addr.clone(),
+                             &DisplayExecuteMsg::RespondToBroadcast { booking_id, accept }, &[]).unwrap();
+    };
+    let booking = |app: &App, booking_id: u64| -> BroadcastBooking {
+        let res: Option<BroadcastBooking> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                                        &GoiManagerQueryMsg::GetBroadcast { booking_id }).unwrap();
+        res.unwrap()
+    };
+    let latest_booking_id = |app: &App| -> u64 {
+        let res: Vec<BroadcastBooking> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                                        &GoiManagerQueryMsg::GetLeagueBroadcasts { league_addr: league_addr.clone(),
+                                            start_after: None, limit: Some(30) }).unwrap();
+        res.last().unwrap().id
+    };
+    let balance = |app: &App, addr: &str| app.wrap().query_balance(addr, TOKEN).unwrap().amount;
+
+    match book(app.borrow_mut(), now.plus_seconds(7_200), 1, vec![]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::BroadcastPaymentRequired {}, e.downcast().unwrap())
+    }
+    match book(app.borrow_mut(), now.plus_seconds(7_200), MAX_BROADCAST_SLOT / 3_600 + 1, vec![fee.clone()]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::InvalidBroadcastSlot {}, e.downcast().unwrap())
+    }
+    let escrow_balance = balance(&app, goi_manager_addr.as_str());
+    book(app.borrow_mut(), now.plus_seconds(7_200), 1, vec![fee.clone()]).unwrap();
+    let first = latest_booking_id(&app);
+    assert_eq!(escrow_balance + fee.amount, balance(&app, goi_manager_addr.as_str()));
+
+    //the requested slot is held until the display answers
+    match book(app.borrow_mut(), now.plus_seconds(9_000), 1, vec![fee.clone()]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::BroadcastSlotConflict { bookings: vec![first] }, e.downcast().unwrap())
+    }
+    let conflicts: Option<Vec<BroadcastBooking>> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+        &GoiManagerQueryMsg::CheckBroadcastConflicts { display_addr: display_addr.clone(),
+            start: now.plus_seconds(10_800), end: now.plus_seconds(18_000) }).unwrap();
+    assert_eq!(None, conflicts);
+    respond(app.borrow_mut(), first, true);
+
+    //a rejected request goes straight back to the admin who paid, not the league's shareholders
+    book(app.borrow_mut(), now.plus_seconds(18_000), 1, vec![fee.clone()]).unwrap();
+    let rejected = latest_booking_id(&app);
+    assert_eq!(Addr::unchecked(USER1), booking(&app, rejected).payer);
+    let league_balance = balance(&app, league_addr.as_str());
+    let payer_balance = balance(&app, USER1);
+    respond(app.borrow_mut(), rejected, false);
+    assert_eq!(BroadcastStatus::Rejected, booking(&app, rejected).status);
+    assert_eq!(payer_balance + fee.amount, balance(&app, USER1));
+    assert_eq!(league_balance, balance(&app, league_addr.as_str()));
+
+    //cancelling an accepted slot with enough notice is refunded, a late cancellation pays the display owner
+    let owner_balance = balance(&app, USER2);
+    book(app.borrow_mut(), now.plus_seconds(2 * BROADCAST_CANCELLATION_NOTICE), 1, vec![fee.clone()]).unwrap();
+    let early = latest_booking_id(&app);
+    book(app.borrow_mut(), now.plus_seconds(25_200), 1, vec![fee.clone()]).unwrap();
+    let late = latest_booking_id(&app);
+    respond(app.borrow_mut(), early, true);
+    respond(app.borrow_mut(), late, true);
+    let payer_balance = balance(&app, USER1);
+    for booking_id in vec![early, late] {
+        app.execute_contract(Addr::unchecked(USER1), league_addr.clone(),
+                             &league::msg::ExecuteMsg::CancelBroadcast { booking_id }, &[]).unwrap();
+    }
+    assert_eq!(payer_balance + fee.amount, balance(&app, USER1));
+    assert_eq!(owner_balance + fee.amount, balance(&app, USER2));
+
+    //the calendar lists the display's slots in order
+    let calendar: Vec<BroadcastBooking> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+        &GoiManagerQueryMsg::GetDisplayBroadcasts { display_addr: display_addr.clone(), from: None, limit: None }).unwrap();
+    assert_eq!(vec![first, rejected, late, early], calendar.iter().map(|b| b.id).collect::<Vec<u64>>());
+    let calendar_from = |app: &App, from: Timestamp| -> Vec<u64> {
+        let calendar: Vec<BroadcastBooking> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+            &GoiManagerQueryMsg::GetDisplayBroadcasts { display_addr: display_addr.clone(), from: Some(from), limit: None }).unwrap();
+        calendar.iter().map(|b| b.id).collect()
+    };
+    assert_eq!(vec![first, rejected, late, early], calendar_from(&app, now.plus_seconds(9_000)));
+    assert_eq!(vec![rejected, late, early], calendar_from(&app, now.plus_seconds(10_800)));
+
+    //the display owner is paid once the broadcast is over
+    let settle = shared::goi_manager::ExecuteMsg::SettleBroadcast { booking_id: first };
+    match app.execute_contract(Addr::unchecked(USER3), goi_manager_addr.clone(), &settle, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::BroadcastNotOver {}, e.downcast().unwrap())
+    }
+    app.update_block(|b| b.time = now.plus_seconds(3 * 3_600));
+    app.execute_contract(Addr::unchecked(USER3), goi_manager_addr.clone(), &settle, &[]).unwrap();
+    assert_eq!(BroadcastStatus::Settled, booking(&app, first).status);
+    assert_eq!(owner_balance + fee.amount + fee.amount, balance(&app, USER2));
+    assert_eq!(escrow_balance, balance(&app, goi_manager_addr.as_str()));
+}
+
+
+#[test]
+fn ad_slot_marketplace_with_attested_payouts() {
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let display_addr = get_display_for_user(Addr::unchecked(USER2), billboard_details(), None,
+                                            goi_manager_addr.clone(), app.borrow_mut());
+    let (league_addr, season_id, fixture_id) = league_with_fixture(app.borrow_mut(), &goi_manager_addr, 86_400);
+    let now = app.block_info().time;
+    let fee = Coin { denom: TOKEN.to_string(), amount: Uint128::from(1000u128) };
+    app.execute_contract(Addr::unchecked(USER1), league_addr.clone(),
+                         &league::msg::ExecuteMsg::BookBroadcast { display: display_addr.to_string(), fixture_id,
+                             start: now.plus_seconds(7_200), end: now.plus_seconds(10_800) }, &[fee.clone()]).unwrap();
+    let bookings: Vec<BroadcastBooking> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+        &GoiManagerQueryMsg::GetDisplayBroadcasts { display_addr: display_addr.clone(), from: None, limit: None }).unwrap();
+    let booking_id = bookings[0].id;
+    app.execute_contract(Addr::unchecked(USER2), display_addr.clone(),
+                         &DisplayExecuteMsg::RespondToBroadcast { booking_id, accept: true }, &[]).unwrap();
+
+    let content = AdContent { hash: "ab".repeat(32), uri: "ipfs://sponsor/halftime.mp4".to_string() };
+    let halftime = AdPlacement::Broadcast { booking_id, slot_name: "halftime".to_string() };
+    let request = |app: &mut App, sponsor: &str, placement: AdPlacement, content: AdContent, payment: Vec<Coin>| {
+        app.execute_contract(Addr::unchecked(sponsor), goi_manager_addr.clone(),
+                             &shared::goi_manager::ExecuteMsg::RequestAd { placement, content }, &payment)
+    };
+    let sponsor_ads = |app: &App, sponsor: &str| -> Vec<AdSlot> {
+        app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+            &GoiManagerQueryMsg::GetAdsBySponsor { sponsor: Addr::unchecked(sponsor), start_after: None, limit: None }).unwrap()
+    };
+    let ad = |app: &App, ad_id: u64| -> AdSlot {
+        let res: Option<AdSlot> = app.wrap().query_wasm_smart(goi_manager_addr.clone(), &GoiManagerQueryMsg::GetAd { ad_id }).unwrap();
+        res.unwrap()
+    };
+    let balance = |app: &App, addr: &str| app.wrap().query_balance(addr, TOKEN).unwrap().amount;
+
+    match request(app.borrow_mut(), USER3, halftime.clone(), AdContent { hash: "xyz".to_string(), uri: content.uri.clone() }, vec![fee.clone()]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::InvalidAdContent {}, e.downcast().unwrap())
+    }
+    match request(app.borrow_mut(), USER3, halftime.clone(), content.clone(), vec![]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::AdPaymentRequired {}, e.downcast().unwrap())
+    }
+
+    //two sponsors bid for the halftime slot and the display takes one
+    request(app.borrow_mut(), USER3, halftime.clone(), content.clone(), vec![fee.clone()]).unwrap();
+    request(app.borrow_mut(), OWNER, halftime.clone(), content.clone(), vec![fee.clone()]).unwrap();
+    let (winner, loser) = (sponsor_ads(&app, USER3)[0].id, sponsor_ads(&app, OWNER)[0].id);
+    assert_eq!(display_addr, ad(&app, winner).approver);
+    app.execute_contract(Addr::unchecked(USER2), display_addr.clone(),
+                         &DisplayExecuteMsg::RespondToAd { ad_id: winner, accept: true, attestor: None }, &[]).unwrap();
+    match app.execute_contract(Addr::unchecked(USER2), display_addr.clone(),
+                               &DisplayExecuteMsg::RespondToAd { ad_id: loser, accept: true, attestor: None }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::AdSlotTaken { ad_id: winner }, e.downcast().unwrap())
+    }
+    let owner_balance = balance(&app, OWNER);
+    app.execute_contract(Addr::unchecked(USER2), display_addr.clone(),
+                         &DisplayExecuteMsg::RespondToAd { ad_id: loser, accept: false, attestor: None }, &[]).unwrap();
+    assert_eq!(owner_balance + fee.amount, balance(&app, OWNER));
+
+    //an attestor the display owner names itself can't attest before the broadcast airs either
+    let pregame_break = AdPlacement::Broadcast { booking_id, slot_name: "pregame".to_string() };
+    request(app.borrow_mut(), OWNER, pregame_break, content.clone(), vec![fee.clone()]).unwrap();
+    let self_attested = sponsor_ads(&app, OWNER)[1].id;
+    app.execute_contract(Addr::unchecked(USER2), display_addr.clone(),
+                         &DisplayExecuteMsg::RespondToAd { ad_id: self_attested, accept: true,
+                             attestor: Some(USER2.to_string()) }, &[]).unwrap();
+    match app.execute_contract(Addr::unchecked(USER2), goi_manager_addr.clone(),
+                               &shared::goi_manager::ExecuteMsg::AttestAdAired { ad_id: self_attested }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::AdNotAttestable {}, e.downcast().unwrap())
+    }
+
+    //a title sponsor for the season, attested by an xnode task
+    let title = AdPlacement::Season { season_id, slot_name: "title sponsor".to_string() };
+    request(app.borrow_mut(), USER3, title.clone(), content.clone(), vec![fee.clone()]).unwrap();
+    let title_ad = sponsor_ads(&app, USER3)[1].id;
+    assert_eq!(league_addr, ad(&app, title_ad).approver);
+    app.execute_contract(Addr::unchecked(USER1), league_addr.clone(),
+                         &league::msg::ExecuteMsg::RespondToAd { ad_id: title_ad, accept: true,
+                             attestor: Some("xnode_task".to_string()) }, &[]).unwrap();
+    match app.execute_contract(Addr::unchecked(USER3), goi_manager_addr.clone(),
+                               &shared::goi_manager::ExecuteMsg::WithdrawAd { ad_id: title_ad }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::AdPlacementNotOver {}, e.downcast().unwrap())
+    }
+    match app.execute_contract(Addr::unchecked(USER2), goi_manager_addr.clone(),
+                               &shared::goi_manager::ExecuteMsg::AttestAdAired { ad_id: title_ad }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::Unauthorized { sender: Addr::unchecked(USER2) }, e.downcast().unwrap())
+    }
+
+    //the owners are paid what's left after the management fees
+    let management: shared::manage::receive::ManagementInfoResponse = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+        &GoiManagerQueryMsg::ManagementQryMessages { management_qry_msg: shared::goi_manager::ManagementQryMsg::GetManagementInfo {} }).unwrap();
+    let owner_share = fee.amount - fee.amount * management.fees.unwrap()[0].fees.percent;
+    let league_owner_balance = balance(&app, USER1);
+    app.execute_contract(Addr::unchecked("xnode_task"), goi_manager_addr.clone(),
+                         &shared::goi_manager::ExecuteMsg::AttestAdAired { ad_id: title_ad }, &[]).unwrap();
+    assert_eq!(AdStatus::Paid, ad(&app, title_ad).status);
+    assert_eq!(league_owner_balance + owner_share, balance(&app, USER1));
+
+    //the display can only attest while the broadcast is on and it's online
+    let attest = DisplayExecuteMsg::AttestAdAired { ad_id: winner };
+    match app.execute_contract(Addr::unchecked(USER2), display_addr.clone(), &attest, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::AdNotAttestable {}, e.downcast().unwrap())
+    }
+    app.update_block(|b| b.time = now.plus_seconds(7_500));
+    app.execute_contract(Addr::unchecked(USER2), display_addr.clone(),
+                         &DisplayExecuteMsg::Heartbeat { online: true }, &[]).unwrap();
+    let display_owner_balance = balance(&app, USER2);
+    app.execute_contract(Addr::unchecked(USER2), display_addr.clone(), &attest, &[]).unwrap();
+    assert_eq!(display_owner_balance + owner_share, balance(&app, USER2));
+    assert_eq!(Some(now.plus_seconds(7_500)), ad(&app, winner).aired);
+    app.execute_contract(Addr::unchecked(USER2), goi_manager_addr.clone(),
+                         &shared::goi_manager::ExecuteMsg::AttestAdAired { ad_id: self_attested }, &[]).unwrap();
+    assert_eq!(AdStatus::Paid, ad(&app, self_attested).status);
+
+    //without an attestor the league attests its own season's ads once the season is on
+    let pregame = AdPlacement::Season { season_id, slot_name: "pregame".to_string() };
+    request(app.borrow_mut(), OWNER, pregame, content.clone(), vec![fee.clone()]).unwrap();
+    let pregame_ad = sponsor_ads(&app, OWNER)[2].id;
+    app.execute_contract(Addr::unchecked(USER1), league_addr.clone(),
+                         &league::msg::ExecuteMsg::RespondToAd { ad_id: pregame_ad, accept: true, attestor: None }, &[]).unwrap();
+    let league_owner_balance = balance(&app, USER1);
+    app.execute_contract(Addr::unchecked(USER1), league_addr.clone(),
+                         &league::msg::ExecuteMsg::AttestAdAired { ad_id: pregame_ad }, &[]).unwrap();
+    assert_eq!(AdStatus::Paid, ad(&app, pregame_ad).status);
+    assert_eq!(league_owner_balance + owner_share, balance(&app, USER1));
+
+    //an approved ad that never aired goes back to the sponsor once the season is over
+    let postgame = AdPlacement::Season { season_id, slot_name: "postgame".to_string() };
+    request(app.borrow_mut(), OWNER, postgame, content.clone(), vec![fee.clone()]).unwrap();
+    let postgame_ad = sponsor_ads(&app, OWNER)[3].id;
+    app.execute_contract(Addr::unchecked(USER1), league_addr.clone(),
+                         &league::msg::ExecuteMsg::RespondToAd { ad_id: postgame_ad, accept: true, attestor: None }, &[]).unwrap();
+    app.update_block(|b| b.time = now.plus_seconds(2 * 86_400));
+    let owner_balance = balance(&app, OWNER);
+    app.execute_contract(Addr::unchecked(OWNER), goi_manager_addr.clone(),
+                         &shared::goi_manager::ExecuteMsg::WithdrawAd { ad_id: postgame_ad }, &[]).unwrap();
+    assert_eq!(owner_balance + fee.amount, balance(&app, OWNER));
+    assert_eq!(AdStatus::Withdrawn, ad(&app, postgame_ad).status);
+}
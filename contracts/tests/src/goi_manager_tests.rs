@@ -19,7 +19,7 @@ use shared::goi_manager::ExecuteMsg::{Withdraw, self, UpdateSeasonStatus};
 use shared::goi_manager::GoiManagerQueryMsg;
 use shared::goi_manager::GoiManagerQueryMsg::{GetActiveSeasonsForLeague, GetAllSeasonsForLeague, GetLeagueTeams, GetMessagesToItem};
 use shared::manage::receive::ManagementInfoResponse;
-use shared::messaging::{JoinSeasonRequestInfo, Message, MessageTypes};
+use shared::messaging::{InboxContent, InboxMessageInfo, JoinSeasonRequestInfo, Message, MessageTypes};
 use shared::query_response_info::InfoManagedResponse;
 use shared::rewards::RewardTypes::League;
 use shared::season::{Season, SeasonAccessTypes, SeasonStatus, SeasonModelData};
@@ -29,7 +29,7 @@ use shared::utils::{AssetSaleItem, ManagedItemResponse, MangedItem, PRIOR_TO_SEA
 use team::msg::ExecuteMsg::{ManagedServiceMessage, JoinLeague};
 use team::TeamError;
 
-use crate::shared_utils::{TOKEN, mock_app_by_user, USER1, OWNER, instantiate_management_contract, USER2, instantiate_team_with_managed_contract, member, mock_app, instantiate_management_contract_with_user, get_team_for_sale, buy_team, instantiate_league_with_managed_contract, add_owner_teams_to_league, instantiate_team_with_managed_contract_with_sender_admin, USER3};
+use crate::shared_utils::{TOKEN, mock_app_by_user, USER1, OWNER, instantiate_management_contract, USER2, instantiate_team_with_managed_contract, member, mock_app, instantiate_management_contract_with_user, get_team_for_sale, buy_team, instantiate_league_with_managed_contract, add_owner_teams_to_league, instantiate_team_with_managed_contract_with_sender_admin, USER3, get_team_for_users, get_league_for_users};
 
 
 
@@ -99,6 +99,7 @@ fn get_active_seasons() {
             access_type: ModelItem { update: true, data: Some(SeasonAccessTypes::Open) },
             status: ModelItem { update: true, data: Some(SeasonStatus::Active) },
             max_teams_allowed: ModelItem { update: true, data: Some(MAX_TEAMS_ALLOWED) },
+            episodes: ModelItem { update: false, data: None },
           
         };
 
@@ -161,6 +162,7 @@ fn add_teams_to_league() {
             access_type: ModelItem { update: true, data: Some(SeasonAccessTypes::Open) },
             status: ModelItem { update: true, data: Some(SeasonStatus::Active) },
             max_teams_allowed: ModelItem { update: true, data: Some(MAX_TEAMS_ALLOWED)},
+            episodes: ModelItem { update: false, data: None },
         };
 
 
@@ -176,6 +178,8 @@ fn add_teams_to_league() {
 
             max_teams_allowed: ModelItem { update: true, data: Some(MAX_TEAMS_ALLOWED) },
 
+            episodes: ModelItem { update: false, data: None },
+
         };
 
 
@@ -190,6 +194,7 @@ fn add_teams_to_league() {
             access_type: ModelItem { update: true, data: Some(SeasonAccessTypes::Open) },
             status: ModelItem { update: true, data: Some(SeasonStatus::Active) },
             max_teams_allowed: ModelItem { update: true, data: Some(MAX_TEAMS_ALLOWED) },
+            episodes: ModelItem { update: false, data: None },
         };
 
     let add_season_to_league_msg =
@@ -485,6 +490,7 @@ fn add_teams_to_league() {
             status: ModelItem { update: true, data: Some(SeasonStatus::Active) },
             content_status: ModelItem { update: true, data: Some( Visibility::Published) },
             max_teams_allowed: ModelItem { update: true, data: Some(32) },
+            episodes: ModelItem { update: false, data: None },
             number_of_episodes: ModelItem { update: true, data: Some(20) }
         };
 
@@ -499,6 +505,7 @@ fn add_teams_to_league() {
             status: ModelItem { update: true, data: Some(SeasonStatus::Active) },
             content_status: ModelItem { update: true, data: Some( Visibility::Published) },
             max_teams_allowed: ModelItem { update: true, data: Some(32) },
+            episodes: ModelItem { update: false, data: None },
             number_of_episodes: ModelItem { update: true, data: Some(20) }
         };
 
@@ -627,4 +634,120 @@ fn get_teams_for_sale() {
         }
     }
 
-}
\ No newline at end of file
+}
+
+
+
+fn query_inbox(app: &App, goi_manager_addr: &Addr, addr: &Addr, unread_only: bool) -> Vec<Message<InboxMessageInfo>> {
+    app.wrap()
+        .query_wasm_smart(goi_manager_addr,
+                          &GoiManagerQueryMsg::GetInbox { addr: addr.clone(), unread_only, start_after: None, limit: None })
+        .unwrap()
+}
+
+
+#[test]
+fn inbox_messages_between_assets() {
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let team = get_team_for_users(vec![Addr::unchecked(USER1)], goi_manager_addr.clone(), app.borrow_mut()).pop().unwrap();
+    let league = get_league_for_users(vec![Addr::unchecked(USER2)], goi_manager_addr.clone(), app.borrow_mut()).pop().unwrap();
+    let other_team = get_team_for_users(vec![Addr::unchecked(USER3)], goi_manager_addr.clone(), app.borrow_mut()).pop().unwrap();
+
+    // only the asset's admin can send
+    let send_msg = team::msg::ExecuteMsg::SendMessage {
+        to: league.to_string(),
+        content: InboxContent::Notice { subject: "Scheduling".to_string() },
+        body: "Can we move week 3?".to_string(),
+        reply_to: None,
+        expires: None
+    };
+    match app.execute_contract(Addr::unchecked(USER2), team.clone(), &send_msg, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(TeamError::UnauthorizedSender { sender: Addr::unchecked(USER2) }, e.downcast().unwrap()),
+    }
+    app.execute_contract(Addr::unchecked(USER1), team.clone(), &send_msg, &[]).unwrap();
+
+    // recipients must be managed assets and bodies are capped
+    let to_user = team::msg::ExecuteMsg::SendMessage {
+        to: USER2.to_string(), content: InboxContent::Text {}, body: "hi".to_string(), reply_to: None, expires: None
+    };
+    match app.execute_contract(Addr::unchecked(USER1), team.clone(), &to_user, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::InboxRecipientNotFound {}, e.downcast().unwrap()),
+    }
+    let too_long = team::msg::ExecuteMsg::SendMessage {
+        to: league.to_string(), content: InboxContent::Text {}, body: "x".repeat(1025), reply_to: None, expires: None
+    };
+    match app.execute_contract(Addr::unchecked(USER1), team.clone(), &too_long, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::InvalidInboxMessage {}, e.downcast().unwrap()),
+    }
+
+    let inbox = query_inbox(&app, &goi_manager_addr, &league, true);
+    assert_eq!(inbox.len(), 1);
+    let first = inbox[0].clone();
+    assert_eq!(first.delivery.from.address, team);
+    assert_eq!(first.delivery.from.asset_type, AssetTypes::Team);
+    assert_eq!(first.data.thread, first.id);
+
+    // the league replies in the same thread
+    app.execute_contract(Addr::unchecked(USER2), league.clone(), &league::msg::ExecuteMsg::SendMessage {
+        to: team.to_string(),
+        content: InboxContent::Text {},
+        body: "Week 3 moved.".to_string(),
+        reply_to: Some(first.id),
+        expires: None
+    }, &[]).unwrap();
+
+    // outsiders can't reply into the thread
+    match app.execute_contract(Addr::unchecked(USER3), other_team.clone(), &team::msg::ExecuteMsg::SendMessage {
+        to: league.to_string(), content: InboxContent::Text {}, body: "me too".to_string(), reply_to: Some(first.id), expires: None
+    }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::Unauthorized { sender: other_team.clone() }, e.downcast().unwrap()),
+    }
+
+    let thread: Vec<Message<InboxMessageInfo>> = app.wrap()
+        .query_wasm_smart(&goi_manager_addr, &GoiManagerQueryMsg::GetInboxThread { thread: first.id })
+        .unwrap();
+    assert_eq!(thread.len(), 2);
+    assert_eq!(thread[1].data.reply_to, Some(first.id));
+    assert_eq!(thread[1].delivery.to.address, team);
+
+    let outbox: Vec<Message<InboxMessageInfo>> = app.wrap()
+        .query_wasm_smart(&goi_manager_addr, &GoiManagerQueryMsg::GetOutbox { addr: league.clone(), start_after: None, limit: None })
+        .unwrap();
+    assert_eq!(outbox.len(), 1);
+
+    // only the recipient can mark a message read
+    match app.execute_contract(Addr::unchecked(USER1), team.clone(),
+                               &team::msg::ExecuteMsg::MarkMessageRead { message_id: first.id, read: true }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::Unauthorized { sender: team.clone() }, e.downcast().unwrap()),
+    }
+    app.execute_contract(Addr::unchecked(USER2), league.clone(),
+                         &league::msg::ExecuteMsg::MarkMessageRead { message_id: first.id, read: true }, &[]).unwrap();
+    assert_eq!(query_inbox(&app, &goi_manager_addr, &league, true).len(), 0);
+    assert_eq!(query_inbox(&app, &goi_manager_addr, &league, false).len(), 1);
+
+    // expired messages drop out of the inbox and can't be replied to
+    let expires = app.block_info().time.plus_seconds(THIRTY_MINUTES);
+    app.execute_contract(Addr::unchecked(USER3), other_team.clone(), &team::msg::ExecuteMsg::SendMessage {
+        to: league.to_string(), content: InboxContent::Text {}, body: "Scrimmage today?".to_string(), reply_to: None, expires: Some(expires)
+    }, &[]).unwrap();
+    let inbox = query_inbox(&app, &goi_manager_addr, &league, false);
+    assert_eq!(inbox.len(), 2);
+    let expiring = inbox[0].clone();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(THIRTY_MINUTES));
+    assert_eq!(query_inbox(&app, &goi_manager_addr, &league, false).len(), 1);
+    match app.execute_contract(Addr::unchecked(USER2), league.clone(), &league::msg::ExecuteMsg::SendMessage {
+        to: other_team.to_string(), content: InboxContent::Text {}, body: "Sure".to_string(), reply_to: Some(expiring.id), expires: None
+    }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::InboxMessageExpired {}, e.downcast().unwrap()),
+    }
+}
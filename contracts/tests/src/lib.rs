@@ -1,5 +1,6 @@
 pub mod team_tests;
 pub mod season_tests;
 pub mod goi_manager_tests;
+pub mod display_tests;
 pub mod shared_utils;
 
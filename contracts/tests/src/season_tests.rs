@@ -1,16 +1,31 @@
 use std::borrow::BorrowMut;
 use anyhow::Error;
 
-use cosmwasm_std::{Addr, Coin, Uint128};
+use cosmwasm_std::{Addr, Binary, Coin, Timestamp, Uint128};
 use cosmwasm_std::testing::{mock_env, MockStorage};
 use cw_multi_test::{App, AppResponse, Executor};
 use goi_manager::ContractError;
-use shared::messaging::MessageTypes;
-use shared::season::SeasonAccessTypes;
-use shared::utils::{PRIOR_TO_SEASON_START_PADDING, THIRTY_MINUTES, FIFTEEN_MINUTES, ONE_MINUTE};
+use shared::messaging::{Message, MessageTypes, SeasonRescheduleInfo};
+use shared::season::{LeagueSeasonTemplate, Season, SeasonAccessTypes, SeasonCapacityPolicy, SeasonRecurrence, SeasonTemplate};
+use shared::data::ModelItem;
+use shared::division::{Division, DivisionGroup, DivisionModelData, TeamStanding, TeamStandingItem};
+use shared::utils::TeamAddr;
+use shared::playoffs::{PlayoffFormat, Playoffs};
+use shared::fixture::{Fixture, FixtureResult, GameDayLineup, LineupSlot, PlayCallingStrategy, seed_commitment};
+use shared::stats::{PlayerStats, TeamStats};
+use shared::leaderboard::{HallOfFameEntry, PlayerLeaderboardItem, StatCategory, TeamRecord};
+use shared::league::LeagueTypes;
+use shared::team::TeamCoaching;
+use shared::draft::{Draft, DraftOrderType, DraftPickRef};
+use shared::player::{CareerSummary, FreeAgent, LineupCheckResponse, PlayerStatus};
+use shared::player_contract::{ContractTerms, PlayerContract, PlayerSigning};
+use shared::player_attributes::Positions;
+use shared::trade::TradeProposal;
+use cosmwasm_std::{BankMsg, CosmosMsg};
+use shared::utils::{PRIOR_TO_SEASON_START_PADDING, DEFAULT_SEASON_EPISODES, THIRTY_MINUTES, FIFTEEN_MINUTES, ONE_MINUTE, MIN_TEAMS_ALLOWED, MAX_TEAMS_ALLOWED};
 use shared::utils::general::AssetTypes;
 use team::msg::ExecuteMsg;
-use crate::shared_utils::{get_league_for_users, get_season, get_team_for_users, instantiate_management_contract, mock_app_by_user, OWNER, TOKEN, USER1, USER2, USER3, add_season_to_league, team_request_to_join_league, update_message_status, get_messages, get_season_with_custom_settings};
+use crate::shared_utils::{get_league_for_users, get_season, get_team_for_users, instantiate_management_contract, mock_app_by_user, OWNER, TOKEN, USER1, USER2, USER3, add_season_to_league, team_request_to_join_league, update_message_status, get_messages, get_season_with_custom_settings, update_season_capacity, add_division_to_season, build_player_contracts, get_player_instantiate_msg, add_owner_teams_to_league, get_coached_team_for_users, get_league_of_type_for_users, update_contract_storage, migrate_player_contract};
 
 #[test]
 fn league_season_scheduling_conflicts() {
@@ -198,3 +213,1535 @@ fn team_season_join_success() {
 
 
 }
+
+
+
+#[test]
+fn league_type_gates_team_admission() {
+
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+    let block_time =  mock_env().block.time.clone();
+
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let human_team = get_coached_team_for_users(vec![Addr::unchecked(USER2)], TeamCoaching::Human,
+                                                goi_manager_addr.clone(), app.borrow_mut())[0].clone();
+    let attestation = Addr::unchecked("xnode_task");
+    let ai_team = get_coached_team_for_users(vec![Addr::unchecked(USER3)],
+                                             TeamCoaching::AI { attestation: attestation.clone() },
+                                             goi_manager_addr.clone(), app.borrow_mut())[0].clone();
+    let undeclared_team = get_team_for_users(vec![Addr::unchecked(USER2)],
+                                             goi_manager_addr.clone(), app.borrow_mut())[0].clone();
+
+    let ai_league = get_league_of_type_for_users(vec![Addr::unchecked(USER1)], LeagueTypes::AI,
+                                                 goi_manager_addr.clone(), app.borrow_mut())[0].clone();
+    let mixed_league = get_league_of_type_for_users(vec![Addr::unchecked(USER2)], LeagueTypes::Mixed,
+                                                    goi_manager_addr.clone(), app.borrow_mut())[0].clone();
+
+    let season_id = 1u64;
+    let season_start = block_time.plus_seconds( PRIOR_TO_SEASON_START_PADDING + 300);
+    let season = get_season(season_id, season_start, season_start.plus_seconds(THIRTY_MINUTES));
+    add_season_to_league(app.borrow_mut(), USER1, season, ai_league.clone()).unwrap();
+
+    //a human-coached team can't ask to join an AI league
+    match team_request_to_join_league(app.borrow_mut(), USER2, human_team.clone(), season_id) {
+        Ok(_) => assert!(false),
+        Err(e) => {
+            assert_eq!(ContractError::LeagueTypeMismatch { league_type: LeagueTypes::AI, team: human_team.clone() },
+                       e.downcast::<ContractError>().unwrap())
+        }
+    }
+
+    //an AI-coached one can once the attestation it named confirms it, and no one else can
+    match team_request_to_join_league(app.borrow_mut(), USER3, ai_team.clone(), season_id) {
+        Ok(_) => assert!(false),
+        Err(e) => {
+            assert_eq!(ContractError::LeagueTypeMismatch { league_type: LeagueTypes::AI, team: ai_team.clone() },
+                       e.downcast::<ContractError>().unwrap())
+        }
+    }
+    let confirm = shared::goi_manager::ExecuteMsg::ConfirmAiCoaching { team: ai_team.clone() };
+    match app.execute_contract(Addr::unchecked(USER3), goi_manager_addr.clone(), &confirm, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => {
+            assert_eq!(ContractError::NotCoachingAttestor { sender: Addr::unchecked(USER3) },
+                       e.downcast::<ContractError>().unwrap())
+        }
+    }
+    app.execute_contract(attestation.clone(), goi_manager_addr.clone(), &confirm, &[]).unwrap();
+    team_request_to_join_league(app.borrow_mut(), USER3, ai_team.clone(), season_id).unwrap();
+
+    //mixed leagues take either kind of declared team, but not an undeclared one
+    match add_owner_teams_to_league(app.borrow_mut(), vec![human_team.clone(), undeclared_team.clone()],
+                                    mixed_league.clone(), Addr::unchecked(USER2)) {
+        Ok(_) => assert!(false),
+        Err(e) => {
+            assert_eq!(ContractError::LeagueTypeMismatch { league_type: LeagueTypes::Mixed, team: undeclared_team.clone() },
+                       e.downcast::<ContractError>().unwrap())
+        }
+    }
+    add_owner_teams_to_league(app.borrow_mut(), vec![human_team.clone()],
+                              mixed_league.clone(), Addr::unchecked(USER2)).unwrap();
+
+    //a team stored before coaching existed declares later, but only once
+    app.execute_contract(Addr::unchecked(USER2), undeclared_team.clone(),
+                         &ExecuteMsg::DeclareCoaching { coaching: TeamCoaching::Human }, &[]).unwrap();
+    match app.execute_contract(Addr::unchecked(USER2), undeclared_team.clone(),
+                               &ExecuteMsg::DeclareCoaching { coaching: TeamCoaching::AI { attestation: attestation.clone() } }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => {
+            assert_eq!(ContractError::TypeAlreadyDeclared {}, e.downcast::<ContractError>().unwrap())
+        }
+    }
+    add_owner_teams_to_league(app.borrow_mut(), vec![undeclared_team.clone()],
+                              mixed_league.clone(), Addr::unchecked(USER2)).unwrap();
+
+    //same for a league's type
+    let undeclared_league = get_league_for_users(vec![Addr::unchecked(USER3)],
+                                                 goi_manager_addr.clone(), app.borrow_mut())[0].clone();
+    app.execute_contract(Addr::unchecked(USER3), undeclared_league.clone(),
+                         &league::msg::ExecuteMsg::DeclareLeagueType { league_type: LeagueTypes::AI }, &[]).unwrap();
+    match app.execute_contract(Addr::unchecked(USER3), undeclared_league.clone(),
+                               &league::msg::ExecuteMsg::DeclareLeagueType { league_type: LeagueTypes::Mixed }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => {
+            assert_eq!(ContractError::TypeAlreadyDeclared {}, e.downcast::<ContractError>().unwrap())
+        }
+    }
+}
+
+
+
+#[test]
+fn league_season_custom_capacity() {
+
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+    let block_time =  mock_env().block.time.clone();
+
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let user1_league_addr = get_league_for_users(vec![Addr::unchecked(USER1)],
+                                                 goi_manager_addr.clone(), app.borrow_mut());
+
+    //small invitational season
+    let season_1_start_date = block_time.plus_seconds( PRIOR_TO_SEASON_START_PADDING + 300);
+    let season_1_end_date = season_1_start_date.plus_seconds(THIRTY_MINUTES);
+    let mut season_1 = get_season(1u64, season_1_start_date.clone(), season_1_end_date.clone());
+    season_1.max_teams_allowed.data = Some(8);
+
+    add_season_to_league(app.borrow_mut(), USER1,  season_1, user1_league_addr[0].clone()).unwrap();
+
+    //season below the manager's minimum capacity
+    let season_2_start_date = season_1_end_date.plus_seconds(FIFTEEN_MINUTES);
+    let season_2_end_date = season_2_start_date.plus_seconds(THIRTY_MINUTES);
+    let mut season_2 = get_season(2u64, season_2_start_date, season_2_end_date);
+    season_2.max_teams_allowed.data = Some(MIN_TEAMS_ALLOWED - 1);
+
+    match add_season_to_league(app.borrow_mut(), USER1,  season_2, user1_league_addr[0].clone()) {
+        Ok(_) => {
+            assert!(false)
+        }
+        Err(e) => {
+            assert_eq!(goi_manager::ContractError::SeasonCapacityOutOfRange { min_teams_allowed: MIN_TEAMS_ALLOWED,
+                max_teams_allowed: MAX_TEAMS_ALLOWED }, e.downcast::<ContractError>().unwrap())
+        }
+    }
+
+    //manager admin tightens the policy
+    let policy = SeasonCapacityPolicy { min_teams_allowed: 4, max_teams_allowed: 16 };
+    app.execute_contract(Addr::unchecked(OWNER), goi_manager_addr.clone(),
+                         &shared::goi_manager::ExecuteMsg::UpdateSeasonCapacityPolicy { policy: policy.clone() }, &[]).unwrap();
+
+    let saved_policy: SeasonCapacityPolicy = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetSeasonCapacityPolicy {}).unwrap();
+    assert_eq!(policy, saved_policy);
+
+    match app.execute_contract(Addr::unchecked(USER1), goi_manager_addr.clone(),
+                         &shared::goi_manager::ExecuteMsg::UpdateSeasonCapacityPolicy { policy }, &[]) {
+        Ok(_) => {
+            assert!(false)
+        }
+        Err(e) => {
+            assert_eq!(goi_manager::ContractError::Unauthorized { sender: Addr::unchecked(USER1) }, e.downcast::<ContractError>().unwrap())
+        }
+    }
+
+    match update_season_capacity(app.borrow_mut(), USER1, user1_league_addr[0].clone(), 1u64, 32) {
+        Ok(_) => {
+            assert!(false)
+        }
+        Err(e) => {
+            assert_eq!(goi_manager::ContractError::SeasonCapacityOutOfRange { min_teams_allowed: 4,
+                max_teams_allowed: 16 }, e.downcast::<ContractError>().unwrap())
+        }
+    }
+
+}
+
+
+#[test]
+fn league_season_capacity_below_accepted_teams() {
+
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+    let block_time =  mock_env().block.time.clone();
+
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let user1_team_addr = get_team_for_users(vec![Addr::unchecked(USER1)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+    let user2_team_addr = get_team_for_users(vec![Addr::unchecked(USER2)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+    let user3_team_addr = get_team_for_users(vec![Addr::unchecked(USER3)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+
+    let user1_league_addr = get_league_for_users(vec![Addr::unchecked(USER1)],
+                                                 goi_manager_addr.clone(), app.borrow_mut());
+
+    let season_id = 1u64;
+    let season_1_start_date = block_time.plus_seconds( PRIOR_TO_SEASON_START_PADDING + 300);
+    let season_1_end_date = season_1_start_date.plus_seconds(THIRTY_MINUTES);
+    let mut season_1 = get_season(season_id, season_1_start_date.clone(), season_1_end_date.clone());
+    season_1.max_teams_allowed.data = Some(8);
+
+    add_season_to_league(app.borrow_mut(), USER1,  season_1, user1_league_addr[0].clone()).unwrap();
+
+    team_request_to_join_league(app.borrow_mut(), USER1, user1_team_addr[0].clone(),  season_id).unwrap();
+    team_request_to_join_league(app.borrow_mut(), USER2, user2_team_addr[0].clone(),  season_id).unwrap();
+    team_request_to_join_league(app.borrow_mut(), USER3, user3_team_addr[0].clone(),  season_id).unwrap();
+
+    match update_season_capacity(app.borrow_mut(), USER1, user1_league_addr[0].clone(), season_id, 2) {
+        Ok(_) => {
+            assert!(false)
+        }
+        Err(e) => {
+            assert_eq!(goi_manager::ContractError::SeasonCapacityBelowAcceptedTeams { accepted_teams: 3 },
+                       e.downcast::<ContractError>().unwrap())
+        }
+    }
+
+    update_season_capacity(app.borrow_mut(), USER1, user1_league_addr[0].clone(), season_id, 3).unwrap();
+
+}
+
+
+
+#[test]
+fn division_added_after_teams_join_takes_them() {
+
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+    let block_time =  mock_env().block.time.clone();
+
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let user1_team_addr = get_team_for_users(vec![Addr::unchecked(USER1)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+    let user2_team_addr = get_team_for_users(vec![Addr::unchecked(USER2)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+    let user1_league_addr = get_league_for_users(vec![Addr::unchecked(USER1)],
+                                                 goi_manager_addr.clone(), app.borrow_mut());
+
+    let season_id = 1u64;
+    let season_1_start_date = block_time.plus_seconds( PRIOR_TO_SEASON_START_PADDING + 300);
+    let season_1 = get_season(season_id, season_1_start_date, season_1_start_date.plus_seconds(THIRTY_MINUTES));
+    add_season_to_league(app.borrow_mut(), USER1,  season_1, user1_league_addr[0].clone()).unwrap();
+
+    team_request_to_join_league(app.borrow_mut(), USER1, user1_team_addr[0].clone(),  season_id).unwrap();
+    team_request_to_join_league(app.borrow_mut(), USER2, user2_team_addr[0].clone(),  season_id).unwrap();
+
+    //the teams already in the season have to fit in the new division
+    match add_division_to_season(app.borrow_mut(), USER1, user1_league_addr[0].clone(), season_id,
+                                 "North", None, 1) {
+        Ok(_) => {
+            assert!(false)
+        }
+        Err(e) => {
+            assert_eq!(goi_manager::ContractError::SeasonDivisionsAtCapacity {}, e.downcast::<ContractError>().unwrap())
+        }
+    }
+    add_division_to_season(app.borrow_mut(), USER1, user1_league_addr[0].clone(), season_id,
+                           "North", None, 2).unwrap();
+
+    let season_divisions: Option<Vec<Division>> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetSeasonDivisions { season_id }).unwrap();
+    let teams_by_division: Vec<DivisionGroup<TeamAddr>> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetSeasonTeamsByDivision { season_id }).unwrap();
+    assert_eq!(vec![
+        DivisionGroup { division: Some(season_divisions.unwrap()[0].clone()),
+            items: vec![user1_team_addr[0].clone(), user2_team_addr[0].clone()] },
+    ], teams_by_division);
+}
+
+
+#[test]
+fn league_season_divisions() {
+
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+    let block_time =  mock_env().block.time.clone();
+
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let user1_team_addr = get_team_for_users(vec![Addr::unchecked(USER1)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+    let user2_team_addr = get_team_for_users(vec![Addr::unchecked(USER2)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+    let user3_team_addr = get_team_for_users(vec![Addr::unchecked(USER3)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+
+    let user1_league_addr = get_league_for_users(vec![Addr::unchecked(USER1)],
+                                                 goi_manager_addr.clone(), app.borrow_mut());
+
+    let season_id = 1u64;
+    let season_1_start_date = block_time.plus_seconds( PRIOR_TO_SEASON_START_PADDING + 300);
+    let season_1_end_date = season_1_start_date.plus_seconds(THIRTY_MINUTES);
+    let season_1 = get_season(season_id, season_1_start_date.clone(), season_1_end_date.clone());
+
+    add_season_to_league(app.borrow_mut(), USER1,  season_1, user1_league_addr[0].clone()).unwrap();
+
+    add_division_to_season(app.borrow_mut(), USER1, user1_league_addr[0].clone(), season_id,
+                           "North", Some("East".to_string()), 1).unwrap();
+    add_division_to_season(app.borrow_mut(), USER1, user1_league_addr[0].clone(), season_id,
+                           "South", Some("East".to_string()), 1).unwrap();
+
+    match add_division_to_season(app.borrow_mut(), USER1, user1_league_addr[0].clone(), season_id,
+                           "", None, 1) {
+        Ok(_) => {
+            assert!(false)
+        }
+        Err(e) => {
+            assert_eq!(goi_manager::ContractError::InvalidDivision {}, e.downcast::<ContractError>().unwrap())
+        }
+    }
+
+    let season_divisions: Option<Vec<Division>> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetSeasonDivisions { season_id }).unwrap();
+    let season_divisions = season_divisions.unwrap();
+    assert_eq!(2, season_divisions.len());
+
+    team_request_to_join_league(app.borrow_mut(), USER1, user1_team_addr[0].clone(),  season_id).unwrap();
+    team_request_to_join_league(app.borrow_mut(), USER2, user2_team_addr[0].clone(),  season_id).unwrap();
+
+    match team_request_to_join_league(app.borrow_mut(), USER3, user3_team_addr[0].clone(),  season_id) {
+        Ok(_) => {
+            assert!(false)
+        }
+        Err(e) => {
+            assert_eq!(goi_manager::ContractError::SeasonDivisionsAtCapacity {}, e.downcast::<ContractError>().unwrap())
+        }
+    }
+
+    let teams_by_division: Vec<DivisionGroup<TeamAddr>> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetSeasonTeamsByDivision { season_id }).unwrap();
+    assert_eq!(vec![
+        DivisionGroup { division: Some(season_divisions[0].clone()), items: vec![user1_team_addr[0].clone()] },
+        DivisionGroup { division: Some(season_divisions[1].clone()), items: vec![user2_team_addr[0].clone()] },
+    ], teams_by_division);
+
+    let standings: Vec<DivisionGroup<TeamStandingItem>> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetSeasonStandingsByDivision { season_id }).unwrap();
+    assert_eq!(TeamStandingItem { team: user1_team_addr[0].clone(), standing: TeamStanding::default() },
+               standings[0].items[0]);
+
+    //a division needs room for at least one team
+    let update_division_msg = league::msg::ExecuteMsg::UpdateDivision { division_id: season_divisions[0].id,
+        division: DivisionModelData {
+            name: ModelItem { update: false, data: None },
+            conference: ModelItem { update: false, data: None },
+            max_teams: ModelItem { update: true, data: Some(0) },
+        } };
+    match app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &update_division_msg, &[]) {
+        Ok(_) => {
+            assert!(false)
+        }
+        Err(e) => {
+            assert_eq!(goi_manager::ContractError::InvalidDivision {}, e.downcast::<ContractError>().unwrap())
+        }
+    }
+
+    let update_division_msg = league::msg::ExecuteMsg::UpdateDivision { division_id: season_divisions[0].id,
+        division: DivisionModelData {
+            name: ModelItem { update: false, data: None },
+            conference: ModelItem { update: false, data: None },
+            max_teams: ModelItem { update: true, data: Some(2) },
+        } };
+    app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &update_division_msg, &[]).unwrap();
+
+    team_request_to_join_league(app.borrow_mut(), USER3, user3_team_addr[0].clone(),  season_id).unwrap();
+
+    //North: team 1 and 3, South: team 2
+    let now = app.block_info().time;
+    for (home, away) in [(&user1_team_addr[0], &user3_team_addr[0]), (&user2_team_addr[0], &user1_team_addr[0])] {
+        app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(),
+                             &league::msg::ExecuteMsg::ScheduleFixture { season_id, episode: 1, home: home.to_string(),
+                                 away: away.to_string(), league_commits: false,
+                                 commit_deadline: now.plus_seconds(ONE_MINUTE),
+                                 reveal_deadline: now.plus_seconds(FIFTEEN_MINUTES) }, &[]).unwrap();
+    }
+    let schedule: Vec<DivisionGroup<Fixture>> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetSeasonScheduleByDivision { season_id }).unwrap();
+    assert_eq!(2, schedule.len());
+    assert_eq!(2, schedule[0].items.len());
+    assert_eq!(1, schedule[1].items.len());
+    assert_eq!((user2_team_addr[0].clone(), user1_team_addr[0].clone()),
+               (schedule[1].items[0].home.clone(), schedule[1].items[0].away.clone()));
+
+}
+
+
+
+#[test]
+fn winner_take_all_season_playoffs() {
+
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+    let block_time =  mock_env().block.time.clone();
+
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let users = vec![USER1, USER2, USER3];
+    let mut season_teams: Vec<TeamAddr> = vec![];
+    for user in users.clone() {
+        let team_addr = get_team_for_users(vec![Addr::unchecked(user)],
+                                           goi_manager_addr.clone(), app.borrow_mut());
+        season_teams.push(team_addr[0].clone());
+    }
+
+    let user1_league_addr = get_league_for_users(vec![Addr::unchecked(USER1)],
+                                                 goi_manager_addr.clone(), app.borrow_mut());
+
+    let season_id = 1u64;
+    let entry_fee = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(1000u128) };
+    let season_1_start_date = block_time.plus_seconds( PRIOR_TO_SEASON_START_PADDING + 300);
+    let season_1_end_date = season_1_start_date.plus_seconds(THIRTY_MINUTES);
+    let season_1 = get_season_with_custom_settings(season_id, SeasonAccessTypes::WinnerTakeAll { coin: entry_fee.clone() },
+                                                   season_1_start_date.clone(), season_1_end_date.clone());
+
+    add_season_to_league(app.borrow_mut(), USER1,  season_1, user1_league_addr[0].clone()).unwrap();
+
+    //entry fees are paid out of each team's treasury
+    for (user, team_addr) in users.clone().into_iter().zip(season_teams.clone()) {
+        app.execute_contract(Addr::unchecked(user), team_addr.clone(),
+                             &ExecuteMsg::Deposit {}, &[entry_fee.clone()]).unwrap();
+        app.execute_contract(Addr::unchecked(user), team_addr,
+                             &ExecuteMsg::JoinLeagueWinnerTakeAll { season_id, fee: vec![entry_fee.clone()] }, &[]).unwrap();
+    }
+
+    let start_playoffs_msg = league::msg::ExecuteMsg::StartPlayoffs { season_id,
+        format: PlayoffFormat::SingleElimination, playoff_teams: None };
+    match app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &start_playoffs_msg, &[]) {
+        Ok(_) => {
+            assert!(false)
+        }
+        Err(e) => {
+            assert_eq!(goi_manager::ContractError::RegularSeasonNotComplete {}, e.downcast::<ContractError>().unwrap())
+        }
+    }
+
+    app.update_block(|b| b.time = season_1_end_date.plus_seconds(ONE_MINUTE));
+    app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &start_playoffs_msg, &[]).unwrap();
+
+    //three teams: top seed gets a bye, 2 vs 3 in round one
+    let submit_result = |app: &mut App, match_id: u32, winner: TeamAddr| {
+        app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(),
+                             &league::msg::ExecuteMsg::SubmitPlayoffResult { season_id, match_id, winner }, &[])
+    };
+    match submit_result(app.borrow_mut(), 1, season_teams[0].clone()) {
+        Ok(_) => {
+            assert!(false)
+        }
+        Err(e) => {
+            assert_eq!(goi_manager::ContractError::InvalidPlayoffResult { match_id: 1 }, e.downcast::<ContractError>().unwrap())
+        }
+    }
+    submit_result(app.borrow_mut(), 1, season_teams[2].clone()).unwrap();
+    submit_result(app.borrow_mut(), 2, season_teams[0].clone()).unwrap();
+
+    let playoffs: Option<Playoffs> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetPlayoffs { season_id }).unwrap();
+    assert_eq!(2, playoffs.unwrap().current_round);
+
+    let champion: Option<TeamAddr> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetSeasonChampion { season_id }).unwrap();
+    assert_eq!(Some(season_teams[0].clone()), champion);
+
+    //champion collects every entry fee into its treasury
+    let champion_balance = app.wrap().query_balance(season_teams[0].clone(), TOKEN).unwrap();
+    assert_eq!(entry_fee.amount * Uint128::from(3u128), champion_balance.amount);
+    let champion_treasury: Vec<Coin> = app.wrap().query_wasm_smart(season_teams[0].clone(),
+                         &team::msg::QueryMsg::GetTreasuryBalances {}).unwrap();
+    assert_eq!(vec![Coin { denom: TOKEN.to_string(), amount: entry_fee.amount * Uint128::from(3u128) }], champion_treasury);
+
+    //champions go into the hall of fame for their league type
+    let hall_of_fame: Vec<HallOfFameEntry> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetHallOfFame { league_type: LeagueTypes::NotSet,
+                             start_after: None, limit: None }).unwrap();
+    assert_eq!(vec![(season_id, season_teams[0].clone())],
+               hall_of_fame.into_iter().map(|e| (e.season_id, e.champion)).collect::<Vec<_>>());
+    let ai_hall_of_fame: Vec<HallOfFameEntry> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetHallOfFame { league_type: LeagueTypes::AI,
+                             start_after: None, limit: None }).unwrap();
+    assert!(ai_hall_of_fame.is_empty());
+}
+
+
+
+#[test]
+fn reschedule_season_notifies_teams() {
+
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+    let block_time =  mock_env().block.time.clone();
+
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let user1_team_addr = get_team_for_users(vec![Addr::unchecked(USER1)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+    let user2_team_addr = get_team_for_users(vec![Addr::unchecked(USER2)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+
+    let user1_league_addr = get_league_for_users(vec![Addr::unchecked(USER1)],
+                                                 goi_manager_addr.clone(), app.borrow_mut());
+    let user2_league_addr = get_league_for_users(vec![Addr::unchecked(USER2)],
+                                                 goi_manager_addr.clone(), app.borrow_mut());
+
+    let season_1_id = 1u64;
+    let season_1_start_date = block_time.plus_seconds( PRIOR_TO_SEASON_START_PADDING + 300);
+    let season_1_end_date = season_1_start_date.plus_seconds(THIRTY_MINUTES);
+    let season_1 = get_season(season_1_id, season_1_start_date.clone(), season_1_end_date.clone());
+    add_season_to_league(app.borrow_mut(), USER1,  season_1, user1_league_addr[0].clone()).unwrap();
+
+    let season_2_id = 2u64;
+    let season_2_start_date = season_1_end_date.plus_seconds(THIRTY_MINUTES);
+    let season_2_end_date = season_2_start_date.plus_seconds(THIRTY_MINUTES);
+    let season_2 = get_season(season_2_id, season_2_start_date.clone(), season_2_end_date.clone());
+    add_season_to_league(app.borrow_mut(), USER2,  season_2, user2_league_addr[0].clone()).unwrap();
+
+    team_request_to_join_league(app.borrow_mut(), USER2, user2_team_addr[0].clone(),  season_1_id).unwrap();
+    team_request_to_join_league(app.borrow_mut(), USER2, user2_team_addr[0].clone(),  season_2_id).unwrap();
+
+    //postpone season 1 so it now runs into season 2
+    let new_start = season_2_start_date.minus_seconds(FIFTEEN_MINUTES);
+    let new_end = new_start.plus_seconds(THIRTY_MINUTES);
+    let reschedule_msg = league::msg::ExecuteMsg::RescheduleSeason { season_id: season_1_id,
+        new_start, new_end, reason: "Venue unavailable".to_string() };
+    app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &reschedule_msg, &[]).unwrap();
+
+    //the league's own copy follows goi-manager's
+    let league_season: Option<Season> = app.wrap().query_wasm_smart(user1_league_addr[0].clone(),
+                         &league::msg::QueryMsg::GetSeason { season_id: season_1_id }).unwrap();
+    let league_season = league_season.unwrap();
+    assert_eq!((new_start, new_end), (league_season.start_date, league_season.end_date));
+
+    let notices: Option<Vec<Message<SeasonRescheduleInfo>>> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetSeasonRescheduleNotices {
+                             item_addr: user2_team_addr[0].clone(), asset_type: AssetTypes::Team }).unwrap();
+    let notices = notices.unwrap();
+    assert_eq!(1, notices.len());
+    assert_eq!(vec![season_2_id], notices[0].data.conflicting_seasons);
+    assert_eq!((season_1_start_date, new_start), (notices[0].data.previous_start, notices[0].data.new_start));
+
+    //only notified teams can use the reschedule withdrawal
+    match app.execute_contract(Addr::unchecked(USER1), user1_team_addr[0].clone(),
+                               &ExecuteMsg::WithdrawFromRescheduledSeason { season_id: season_1_id }, &[]) {
+        Ok(_) => {
+            assert!(false)
+        }
+        Err(e) => {
+            assert_eq!(goi_manager::ContractError::NoPendingRescheduleNotice {}, e.downcast::<ContractError>().unwrap())
+        }
+    }
+
+    app.execute_contract(Addr::unchecked(USER2), user2_team_addr[0].clone(),
+                         &ExecuteMsg::WithdrawFromRescheduledSeason { season_id: season_1_id }, &[]).unwrap();
+
+    let notices: Option<Vec<Message<SeasonRescheduleInfo>>> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetSeasonRescheduleNotices {
+                             item_addr: user2_team_addr[0].clone(), asset_type: AssetTypes::Team }).unwrap();
+    assert!(notices.unwrap()[0].data.withdrawn);
+
+    let season_1_teams: Vec<DivisionGroup<TeamAddr>> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetSeasonTeamsByDivision { season_id: season_1_id }).unwrap();
+    assert!(season_1_teams.is_empty());
+
+}
+
+
+
+#[test]
+fn spawn_seasons_from_league_template() {
+
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let user1_league_addr = get_league_for_users(vec![Addr::unchecked(USER1)],
+                                                 goi_manager_addr.clone(), app.borrow_mut());
+
+    let spawn_next_season_msg = shared::goi_manager::ExecuteMsg::SpawnNextSeason { league: user1_league_addr[0].clone() };
+    match app.execute_contract(Addr::unchecked(OWNER), goi_manager_addr.clone(), &spawn_next_season_msg, &[]) {
+        Ok(_) => {
+            assert!(false)
+        }
+        Err(e) => {
+            assert_eq!(goi_manager::ContractError::SeasonTemplateNotFound {}, e.downcast::<ContractError>().unwrap())
+        }
+    }
+
+    let template = SeasonTemplate {
+        name: "Weekly Ladder".to_string(),
+        description: None,
+        duration: THIRTY_MINUTES,
+        gap: FIFTEEN_MINUTES,
+        access_type: SeasonAccessTypes::Open,
+        max_teams_allowed: 16,
+        recurrence: SeasonRecurrence::Times { count: 2 },
+    };
+    app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(),
+                         &league::msg::ExecuteMsg::SetSeasonTemplate { template: Some(template.clone()) }, &[]).unwrap();
+
+    //anyone can spawn the next season
+    app.execute_contract(Addr::unchecked(USER3), goi_manager_addr.clone(), &spawn_next_season_msg, &[]).unwrap();
+
+    let league_template: Option<LeagueSeasonTemplate> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetSeasonTemplate { league_addr: user1_league_addr[0].clone() }).unwrap();
+    let league_template = league_template.unwrap();
+    assert_eq!(1, league_template.spawned_count);
+    let first_season_id = league_template.last_spawned_season.unwrap();
+
+    match app.execute_contract(Addr::unchecked(USER3), goi_manager_addr.clone(), &spawn_next_season_msg, &[]) {
+        Ok(_) => {
+            assert!(false)
+        }
+        Err(e) => {
+            assert_eq!(goi_manager::ContractError::PreviousSeasonNotFinished { season_id: first_season_id },
+                       e.downcast::<ContractError>().unwrap())
+        }
+    }
+
+    let first_season: Option<Season> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetSeasonById { season_id: first_season_id }).unwrap();
+    let first_season = first_season.unwrap();
+    assert_eq!("Weekly Ladder 1".to_string(), first_season.name);
+    assert_eq!(Some(16), first_season.max_teams_allowed);
+
+    app.update_block(|b| b.time = first_season.end_date);
+    app.execute_contract(Addr::unchecked(USER3), goi_manager_addr.clone(), &spawn_next_season_msg, &[]).unwrap();
+
+    let all_seasons: Option<Vec<Season>> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetAllSeasonsForLeague { league_address: user1_league_addr[0].clone() }).unwrap();
+    let all_seasons = all_seasons.unwrap();
+    assert_eq!(2, all_seasons.len());
+    assert_eq!(first_season.end_date.plus_seconds(PRIOR_TO_SEASON_START_PADDING), all_seasons[1].start_date);
+
+    //recurrence rule only allows two seasons
+    app.update_block(|b| b.time = all_seasons[1].end_date);
+    match app.execute_contract(Addr::unchecked(USER3), goi_manager_addr.clone(), &spawn_next_season_msg, &[]) {
+        Ok(_) => {
+            assert!(false)
+        }
+        Err(e) => {
+            assert_eq!(goi_manager::ContractError::SeasonRecurrenceComplete {}, e.downcast::<ContractError>().unwrap())
+        }
+    }
+
+}
+
+
+#[test]
+fn season_draft_with_traded_pick_and_auto_pick() {
+
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+    let block_time =  mock_env().block.time.clone();
+
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let users = vec![USER1, USER2, USER3];
+    let mut season_teams: Vec<(&str, TeamAddr)> = vec![];
+    for user in users.clone() {
+        let team_addr = get_team_for_users(vec![Addr::unchecked(user)],
+                                           goi_manager_addr.clone(), app.borrow_mut());
+        season_teams.push((user, team_addr[0].clone()));
+    }
+
+    let user1_league_addr = get_league_for_users(vec![Addr::unchecked(USER1)],
+                                                 goi_manager_addr.clone(), app.borrow_mut());
+
+    let season_id = 1u64;
+    let entry_fee = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(1000u128) };
+    let season_1_start_date = block_time.plus_seconds( PRIOR_TO_SEASON_START_PADDING + 300);
+    let season_1_end_date = season_1_start_date.plus_seconds(THIRTY_MINUTES);
+    let season_1 = get_season_with_custom_settings(season_id, SeasonAccessTypes::WinnerTakeAll { coin: entry_fee.clone() },
+                                                   season_1_start_date.clone(), season_1_end_date.clone());
+    add_season_to_league(app.borrow_mut(), USER1,  season_1, user1_league_addr[0].clone()).unwrap();
+    for (user, team_addr) in season_teams.clone() {
+        app.execute_contract(Addr::unchecked(user), team_addr.clone(),
+                             &ExecuteMsg::Deposit {}, &[entry_fee.clone()]).unwrap();
+        app.execute_contract(Addr::unchecked(user), team_addr,
+                             &ExecuteMsg::JoinLeagueWinnerTakeAll { season_id, fee: vec![entry_fee.clone()] }, &[]).unwrap();
+    }
+
+    let pool =
+        build_player_contracts(app.borrow_mut(), vec![
+            get_player_instantiate_msg("draft".to_string(), "qb".to_string(), Positions::QB, goi_manager_addr.clone()),
+            get_player_instantiate_msg("draft".to_string(), "rb".to_string(), Positions::RB, goi_manager_addr.clone()),
+            get_player_instantiate_msg("draft".to_string(), "s".to_string(), Positions::S, goi_manager_addr.clone()),
+            get_player_instantiate_msg("draft".to_string(), "lb".to_string(), Positions::LB, goi_manager_addr.clone())],
+                               OWNER).unwrap();
+    let lottery = DraftOrderType::Lottery { commit_deadline: block_time.plus_seconds(ONE_MINUTE),
+        reveal_deadline: block_time.plus_seconds(2 * ONE_MINUTE) };
+    let draft_start = block_time.plus_seconds(2 * ONE_MINUTE);
+    let create_draft = |start: Timestamp| league::msg::ExecuteMsg::CreateDraft {
+        season_id,
+        pool: pool.iter().map(|p| p.address.to_string()).collect(),
+        rounds: 1,
+        order_type: lottery.clone(),
+        pick_duration: ONE_MINUTE,
+        start
+    };
+
+    //only the player's owner can put it in a draft pool
+    match app.execute_contract(Addr::unchecked(USER1), goi_manager_addr.clone(),
+                               &shared::goi_manager::ExecuteMsg::SetDraftOptIn { player: pool[0].address.clone(), season_id: Some(season_id) }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::NotPlayerOwner { sender: Addr::unchecked(USER1) }, e.downcast().unwrap())
+    }
+    for p in pool.iter().take(3) {
+        app.execute_contract(Addr::unchecked(OWNER), goi_manager_addr.clone(),
+                             &shared::goi_manager::ExecuteMsg::SetDraftOptIn { player: p.address.clone(), season_id: Some(season_id) }, &[]).unwrap();
+    }
+    match app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &create_draft(draft_start), &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::DraftPlayerNotOptedIn { player: pool[3].address.clone() }, e.downcast().unwrap())
+    }
+    app.execute_contract(Addr::unchecked(OWNER), goi_manager_addr.clone(),
+                         &shared::goi_manager::ExecuteMsg::SetDraftOptIn { player: pool[3].address.clone(), season_id: Some(season_id) }, &[]).unwrap();
+
+    //the draft has to start before the season does, and no earlier than the lottery's reveals close
+    match app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &create_draft(season_1_start_date), &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::InvalidDraft {}, e.downcast().unwrap())
+    }
+    match app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &create_draft(block_time), &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::InvalidDraft {}, e.downcast().unwrap())
+    }
+
+    //names have to be unique across the pool, or the second player could never be tracked
+    let namesake =
+        build_player_contracts(app.borrow_mut(), vec![
+            get_player_instantiate_msg("Draft".to_string(), "QB".to_string(), Positions::QB, goi_manager_addr.clone())],
+                               OWNER).unwrap();
+    app.execute_contract(Addr::unchecked(OWNER), goi_manager_addr.clone(),
+                         &shared::goi_manager::ExecuteMsg::SetDraftOptIn { player: namesake[0].address.clone(), season_id: Some(season_id) }, &[]).unwrap();
+    match app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(),
+                               &league::msg::ExecuteMsg::CreateDraft {
+                                   season_id,
+                                   pool: vec![pool[0].address.to_string(), namesake[0].address.to_string()],
+                                   rounds: 1,
+                                   order_type: lottery.clone(),
+                                   pick_duration: ONE_MINUTE,
+                                   start: draft_start
+                               }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::PlayerNameAlreadyInUse { first_name: "Draft".to_string(), last_name: "QB".to_string() },
+                             e.downcast().unwrap())
+    }
+    app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &create_draft(draft_start), &[]).unwrap();
+
+    let get_draft = |app: &App| -> Draft {
+        let draft: Option<Draft> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                             &shared::goi_manager::GoiManagerQueryMsg::GetDraft { season_id }).unwrap();
+        draft.unwrap()
+    };
+
+    //picks can't be traded before the lottery decides which slot each one is
+    match app.execute_contract(Addr::unchecked(USER2), season_teams[1].1.clone(),
+                               &ExecuteMsg::ProposeTrade { counterparty_team: season_teams[0].1.to_string(), offered_players: vec![],
+                                   requested_players: vec![], offered_funds: vec![],
+                                   offered_picks: vec![DraftPickRef { season_id, pick_number: 2 }], requested_picks: vec![] }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::DraftLotteryNotDrawn {}, e.downcast().unwrap())
+    }
+
+    //every team commits to a secret; no one reveals until commits close
+    let secret = |user: &str| Binary::from(format!("{} draft secret", user).into_bytes());
+    for (user, team) in season_teams.iter() {
+        app.execute_contract(Addr::unchecked(*user), team.clone(),
+                             &ExecuteMsg::CommitDraftSeed { season_id, commitment: seed_commitment(team, &secret(user)) }, &[]).unwrap();
+    }
+    match app.execute_contract(Addr::unchecked(USER1), season_teams[0].1.clone(),
+                               &ExecuteMsg::RevealDraftSeed { season_id, secret: secret(USER1) }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::SeedRevealNotOpen {}, e.downcast().unwrap())
+    }
+    app.update_block(|b| b.time = b.time.plus_seconds(ONE_MINUTE));
+    match app.execute_contract(Addr::unchecked(USER1), season_teams[0].1.clone(),
+                               &ExecuteMsg::CommitDraftSeed { season_id, commitment: seed_commitment(&season_teams[0].1, &secret(USER2)) }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::SeedCommitPeriodEnded {}, e.downcast().unwrap())
+    }
+    for (user, team) in season_teams.iter().take(2) {
+        app.execute_contract(Addr::unchecked(*user), team.clone(),
+                             &ExecuteMsg::RevealDraftSeed { season_id, secret: secret(user) }, &[]).unwrap();
+    }
+    assert!(get_draft(&app).lottery_pending());
+
+    //the third team withholds its secret and picks last
+    app.update_block(|b| b.time = b.time.plus_seconds(ONE_MINUTE));
+    app.execute_contract(Addr::unchecked(OWNER), goi_manager_addr.clone(),
+                         &shared::goi_manager::ExecuteMsg::AdvanceDraft { season_id }, &[]).unwrap();
+    assert!(!get_draft(&app).lottery_pending());
+    let order: Vec<(&str, TeamAddr)> =
+        get_draft(&app).picks.iter().
+            map(|p| season_teams.iter().find(|t| t.1 == p.team).unwrap().clone()).
+            collect();
+    assert_eq!(USER3, order[2].0);
+
+    //pool players can't skip the draft
+    match app.execute_contract(Addr::unchecked(order[0].0), order[0].1.clone(),
+                               &ExecuteMsg::AddPlayersToTeam { players: vec![pool[0].clone()] }, &[]) {
+        Ok(_) => assert!(false),
+        Err(_) => assert!(true)
+    }
+
+    //the second team trades its pick for the first one; nothing moves until the first team accepts
+    let first_pick = DraftPickRef { season_id, pick_number: 1 };
+    let second_pick = DraftPickRef { season_id, pick_number: 2 };
+    match app.execute_contract(Addr::unchecked(order[1].0), order[1].1.clone(),
+                               &ExecuteMsg::ProposeTrade { counterparty_team: order[0].1.to_string(), offered_players: vec![],
+                                   requested_players: vec![], offered_funds: vec![],
+                                   offered_picks: vec![first_pick.clone()], requested_picks: vec![] }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::NotDraftPickOwner { sender: order[1].1.clone() }, e.downcast().unwrap())
+    }
+    app.execute_contract(Addr::unchecked(order[1].0), order[1].1.clone(),
+                         &ExecuteMsg::ProposeTrade { counterparty_team: order[0].1.to_string(), offered_players: vec![],
+                             requested_players: vec![], offered_funds: vec![],
+                             offered_picks: vec![second_pick], requested_picks: vec![first_pick] }, &[]).unwrap();
+    assert_eq!(order[0].1, get_draft(&app).picks[0].team);
+    let trades: Option<Vec<TradeProposal>> =
+        app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                                    &shared::goi_manager::GoiManagerQueryMsg::GetTeamTradeProposals { team_addr: order[0].1.clone() }).unwrap();
+    app.execute_contract(Addr::unchecked(order[0].0), order[0].1.clone(),
+                         &ExecuteMsg::RespondToTrade { trade_id: trades.unwrap()[0].id, accept: true }, &[]).unwrap();
+    match app.execute_contract(Addr::unchecked(order[0].0), order[0].1.clone(),
+                               &ExecuteMsg::MakeDraftPick { season_id, player: pool[0].address.to_string() }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::NotDraftPickOwner { sender: order[0].1.clone() }, e.downcast().unwrap())
+    }
+    app.execute_contract(Addr::unchecked(order[1].0), order[1].1.clone(),
+                         &ExecuteMsg::MakeDraftPick { season_id, player: pool[0].address.to_string() }, &[]).unwrap();
+
+    //first team lets the pick it traded for time out; the RB is the best available
+    app.update_block(|b| b.time = b.time.plus_seconds(ONE_MINUTE + 1));
+    app.execute_contract(Addr::unchecked(OWNER), goi_manager_addr.clone(),
+                         &shared::goi_manager::ExecuteMsg::AdvanceDraft { season_id }, &[]).unwrap();
+    app.execute_contract(Addr::unchecked(order[2].0), order[2].1.clone(),
+                         &ExecuteMsg::MakeDraftPick { season_id, player: pool[2].address.to_string() }, &[]).unwrap();
+
+    let draft = get_draft(&app);
+    assert!(draft.completed.is_some());
+    assert_eq!(vec![Some(pool[0].address.clone()), Some(pool[1].address.clone()), Some(pool[2].address.clone())],
+               draft.picks.iter().map(|p| p.player.as_ref().map(|i| i.address.clone())).collect::<Vec<Option<Addr>>>());
+    assert_eq!(vec![false, true, false], draft.picks.iter().map(|p| p.auto_picked).collect::<Vec<bool>>());
+
+    let roster: team::msg::PlayerResponse = app.wrap().query_wasm_smart(order[0].1.clone(),
+                         &team::msg::QueryMsg::GetPlayer { addr: pool[1].address.to_string() }).unwrap();
+    assert!(roster.players.is_some());
+
+    //undrafted players go to the free agent pool
+    let free_agents: Vec<FreeAgent> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetFreeAgents { position: None, start_after: None, limit: None }).unwrap();
+    assert_eq!(vec![pool[3].address.clone()], free_agents.into_iter().map(|f| f.player.address).collect::<Vec<Addr>>());
+}
+
+
+#[test]
+fn player_contracts_salary_cap_and_payroll() {
+
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+    let block_time =  mock_env().block.time.clone();
+
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let user1_team_addr = get_team_for_users(vec![Addr::unchecked(USER1)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+    let user1_league_addr = get_league_for_users(vec![Addr::unchecked(USER1)],
+                                                 goi_manager_addr.clone(), app.borrow_mut());
+    add_owner_teams_to_league(app.borrow_mut(), user1_team_addr.clone(),
+                              user1_league_addr[0].clone(), Addr::unchecked(USER1)).unwrap();
+
+    let coin = |amount: u128| Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(amount) };
+    app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(),
+                         &league::msg::ExecuteMsg::SetSalaryCap { cap: Some(coin(100)) }, &[]).unwrap();
+    app.execute_contract(Addr::unchecked(USER1), user1_team_addr[0].clone(),
+                         &ExecuteMsg::Deposit {}, &[coin(150)]).unwrap();
+
+    let players =
+        build_player_contracts(app.borrow_mut(), vec![
+            get_player_instantiate_msg("paid".to_string(), "qb".to_string(), Positions::QB, goi_manager_addr.clone()),
+            get_player_instantiate_msg("paid".to_string(), "rb".to_string(), Positions::RB, goi_manager_addr.clone())],
+                               OWNER).unwrap();
+    let terms = ContractTerms { salary_per_episode: coin(60), term_seasons: 1, signing_bonus: Some(coin(10)) };
+    let signings: Vec<PlayerSigning> =
+        players.iter().map(|p| PlayerSigning { player: p.clone(), terms: terms.clone() }).collect();
+
+    //two 60 salaries break the 100 cap
+    match app.execute_contract(Addr::unchecked(USER1), user1_team_addr[0].clone(),
+                               &ExecuteMsg::AddPlayersToTeamWithContracts { signings: signings.clone() }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::SalaryCapExceeded { team: user1_team_addr[0].clone(), cap: coin(100),
+                                                                payroll: Uint128::from(120u128) }, e.downcast().unwrap())
+    }
+
+    let owner_balance = app.wrap().query_balance(OWNER, TOKEN).unwrap().amount;
+    app.execute_contract(Addr::unchecked(USER1), user1_team_addr[0].clone(),
+                         &ExecuteMsg::AddPlayersToTeamWithContracts { signings: vec![signings[0].clone()] }, &[]).unwrap();
+    let contract: Option<PlayerContract> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetPlayerContract { player: players[0].address.clone() }).unwrap();
+    assert_eq!(Some(user1_team_addr[0].clone()), contract.map(|c| c.team));
+    assert_eq!(owner_balance + Uint128::from(10u128), app.wrap().query_balance(OWNER, TOKEN).unwrap().amount);
+
+    let season_id = 1u64;
+    let season_1_start_date = block_time.plus_seconds( PRIOR_TO_SEASON_START_PADDING + 300);
+    let season_1_end_date = season_1_start_date.plus_seconds(THIRTY_MINUTES);
+    add_season_to_league(app.borrow_mut(), USER1, get_season(season_id, season_1_start_date, season_1_end_date),
+                         user1_league_addr[0].clone()).unwrap();
+    team_request_to_join_league(app.borrow_mut(), USER1, user1_team_addr[0].clone(), season_id).unwrap();
+
+    //episodes can only advance while the season is running
+    let advance_episode = league::msg::ExecuteMsg::AdvanceEpisode { season_id };
+    match app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &advance_episode, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::SeasonNotInProgress {}, e.downcast().unwrap())
+    }
+    app.update_block(|b| b.time = season_1_start_date.plus_seconds(ONE_MINUTE));
+
+    //treasury holds 140: two salaries are paid, the third is carried as arrears
+    let episode_interval = THIRTY_MINUTES / DEFAULT_SEASON_EPISODES as u64;
+    for _ in 0..3 {
+        app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &advance_episode, &[]).unwrap();
+        app.update_block(|b| b.time = b.time.plus_seconds(episode_interval));
+    }
+    assert_eq!(owner_balance + Uint128::from(130u128), app.wrap().query_balance(OWNER, TOKEN).unwrap().amount);
+    let arrears: Vec<Coin> = app.wrap().query_wasm_smart(user1_team_addr[0].clone(),
+                         &team::msg::QueryMsg::GetSalaryArrears { owner: OWNER.to_string() }).unwrap();
+    assert_eq!(vec![coin(60)], arrears);
+
+    //arrears are paid with the next salary once the treasury can cover both
+    app.execute_contract(Addr::unchecked(USER1), user1_team_addr[0].clone(),
+                         &ExecuteMsg::Deposit {}, &[coin(200)]).unwrap();
+    app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &advance_episode, &[]).unwrap();
+    assert_eq!(owner_balance + Uint128::from(250u128), app.wrap().query_balance(OWNER, TOKEN).unwrap().amount);
+    let arrears: Vec<Coin> = app.wrap().query_wasm_smart(user1_team_addr[0].clone(),
+                         &team::msg::QueryMsg::GetSalaryArrears { owner: OWNER.to_string() }).unwrap();
+    assert!(arrears.is_empty());
+
+    //episodes follow the schedule; the next one can't be run early
+    match app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &advance_episode, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::EpisodeNotDue { start: season_1_start_date.plus_seconds(4 * episode_interval) },
+                             e.downcast().unwrap())
+    }
+
+    //salary follows the player once it is sold
+    update_contract_storage(app.borrow_mut(), &players[0].address, |store| {
+        let mut player = player::state::STATE.load(store).unwrap();
+        player.owner = Addr::unchecked(USER3);
+        player::state::STATE.save(store, &player).unwrap();
+    });
+    let user3_balance = app.wrap().query_balance(USER3, TOKEN).unwrap().amount;
+    app.update_block(|b| b.time = b.time.plus_seconds(episode_interval));
+    app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &advance_episode, &[]).unwrap();
+    assert_eq!(user3_balance + Uint128::from(60u128), app.wrap().query_balance(USER3, TOKEN).unwrap().amount);
+    assert_eq!(owner_balance + Uint128::from(250u128), app.wrap().query_balance(OWNER, TOKEN).unwrap().amount);
+}
+
+
+#[test]
+fn salary_goes_to_new_owner_after_mid_contract_sale() {
+
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+    let block_time =  mock_env().block.time.clone();
+
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let user1_team_addr = get_team_for_users(vec![Addr::unchecked(USER1)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+    let user1_league_addr = get_league_for_users(vec![Addr::unchecked(USER1)],
+                                                 goi_manager_addr.clone(), app.borrow_mut());
+    add_owner_teams_to_league(app.borrow_mut(), user1_team_addr.clone(),
+                              user1_league_addr[0].clone(), Addr::unchecked(USER1)).unwrap();
+
+    let coin = |amount: u128| Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(amount) };
+    app.execute_contract(Addr::unchecked(USER1), user1_team_addr[0].clone(),
+                         &ExecuteMsg::Deposit {}, &[coin(500)]).unwrap();
+    let players =
+        build_player_contracts(app.borrow_mut(), vec![
+            get_player_instantiate_msg("sold".to_string(), "qb".to_string(), Positions::QB, goi_manager_addr.clone())],
+                               OWNER).unwrap();
+    let terms = ContractTerms { salary_per_episode: coin(60), term_seasons: 2, signing_bonus: None };
+    app.execute_contract(Addr::unchecked(USER1), user1_team_addr[0].clone(),
+                         &ExecuteMsg::AddPlayersToTeamWithContracts {
+                             signings: vec![PlayerSigning { player: players[0].clone(), terms }] }, &[]).unwrap();
+
+    let season_id = 1u64;
+    let season_1_start_date = block_time.plus_seconds( PRIOR_TO_SEASON_START_PADDING + 300);
+    let season_1_end_date = season_1_start_date.plus_seconds(THIRTY_MINUTES);
+    add_season_to_league(app.borrow_mut(), USER1, get_season(season_id, season_1_start_date, season_1_end_date),
+                         user1_league_addr[0].clone()).unwrap();
+    team_request_to_join_league(app.borrow_mut(), USER1, user1_team_addr[0].clone(), season_id).unwrap();
+    app.update_block(|b| b.time = season_1_start_date.plus_seconds(ONE_MINUTE));
+
+    let advance_episode = league::msg::ExecuteMsg::AdvanceEpisode { season_id };
+    let episode_interval = THIRTY_MINUTES / DEFAULT_SEASON_EPISODES as u64;
+    let (owner_balance, user3_balance) =
+        (app.wrap().query_balance(OWNER, TOKEN).unwrap().amount, app.wrap().query_balance(USER3, TOKEN).unwrap().amount);
+    app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &advance_episode, &[]).unwrap();
+    assert_eq!(owner_balance + Uint128::from(60u128), app.wrap().query_balance(OWNER, TOKEN).unwrap().amount);
+
+    //the player is sold to USER3 with a season of its contract still to run
+    update_contract_storage(app.borrow_mut(), &players[0].address, |store| {
+        let mut player = player::state::STATE.load(store).unwrap();
+        player.owner = Addr::unchecked(USER3);
+        player::state::STATE.save(store, &player).unwrap();
+    });
+    for _ in 0..2 {
+        app.update_block(|b| b.time = b.time.plus_seconds(episode_interval));
+        app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &advance_episode, &[]).unwrap();
+    }
+    assert_eq!(user3_balance + Uint128::from(120u128), app.wrap().query_balance(USER3, TOKEN).unwrap().amount);
+    assert_eq!(owner_balance + Uint128::from(60u128), app.wrap().query_balance(OWNER, TOKEN).unwrap().amount);
+    let contract: Option<PlayerContract> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetPlayerContract { player: players[0].address.clone() }).unwrap();
+    let contract = contract.unwrap();
+    assert_eq!(Addr::unchecked(USER3), contract.owner);
+    assert_eq!(3, contract.episodes_paid);
+}
+
+
+#[test]
+fn player_injuries_and_retirement() {
+
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+    let block_time =  mock_env().block.time.clone();
+
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let user1_team_addr = get_team_for_users(vec![Addr::unchecked(USER1)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+    let user1_league_addr = get_league_for_users(vec![Addr::unchecked(USER1)],
+                                                 goi_manager_addr.clone(), app.borrow_mut());
+    add_owner_teams_to_league(app.borrow_mut(), user1_team_addr.clone(),
+                              user1_league_addr[0].clone(), Addr::unchecked(USER1)).unwrap();
+    let players =
+        build_player_contracts(app.borrow_mut(), vec![
+            get_player_instantiate_msg("status".to_string(), "qb".to_string(), Positions::QB, goi_manager_addr.clone()),
+            get_player_instantiate_msg("status".to_string(), "rb".to_string(), Positions::RB, goi_manager_addr.clone())],
+                               OWNER).unwrap();
+    app.execute_contract(Addr::unchecked(USER1), user1_team_addr[0].clone(),
+                         &ExecuteMsg::AddPlayersToTeam { players: players.clone() }, &[]).unwrap();
+
+    let season_id = 1u64;
+    let season_1_start_date = block_time.plus_seconds( PRIOR_TO_SEASON_START_PADDING + 300);
+    let season_1_end_date = season_1_start_date.plus_seconds(THIRTY_MINUTES);
+    add_season_to_league(app.borrow_mut(), USER1, get_season(season_id, season_1_start_date, season_1_end_date),
+                         user1_league_addr[0].clone()).unwrap();
+    team_request_to_join_league(app.borrow_mut(), USER1, user1_team_addr[0].clone(), season_id).unwrap();
+    app.update_block(|b| b.time = season_1_start_date.plus_seconds(ONE_MINUTE));
+
+    let lineup: Vec<Addr> = players.iter().map(|p| p.address.clone()).collect();
+    let check_lineup = |app: &App| -> Vec<Addr> {
+        let res: LineupCheckResponse = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                             &shared::goi_manager::GoiManagerQueryMsg::CheckLineup {
+                                 team_addr: user1_team_addr[0].clone(), season_id, players: lineup.clone() }).unwrap();
+        res.unavailable
+    };
+
+    //injured players sit out until the season reaches their return episode
+    let injured = PlayerStatus::Injured { until_episode: 2 };
+    app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(),
+                         &league::msg::ExecuteMsg::SetPlayerStatus { player: players[0].address.to_string(),
+                             status: injured.clone() }, &[]).unwrap();
+    let info: shared::player::InfoResponse = app.wrap().query_wasm_smart(players[0].address.clone(),
+                         &shared::player::QueryMsg::GetInfo {}).unwrap();
+    assert_eq!(injured, info.player.status);
+    assert_eq!(vec![players[0].address.clone()], check_lineup(&app));
+    for _ in 0..2 {
+        app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(),
+                             &league::msg::ExecuteMsg::AdvanceEpisode { season_id }, &[]).unwrap();
+        app.update_block(|b| b.time = b.time.plus_seconds(THIRTY_MINUTES / DEFAULT_SEASON_EPISODES as u64));
+    }
+    assert!(check_lineup(&app).is_empty());
+
+    //leagues can't retire players
+    match app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(),
+                               &league::msg::ExecuteMsg::SetPlayerStatus { player: players[1].address.to_string(),
+                                   status: PlayerStatus::Retired }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::InvalidPlayerStatus {}, e.downcast().unwrap())
+    }
+    let retire = shared::goi_manager::ExecuteMsg::RetirePlayer { player: players[1].address.clone() };
+    match app.execute_contract(Addr::unchecked(USER1), goi_manager_addr.clone(), &retire, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::NotPlayerOwner { sender: Addr::unchecked(USER1) }, e.downcast().unwrap())
+    }
+    app.execute_contract(Addr::unchecked(OWNER), goi_manager_addr.clone(), &retire, &[]).unwrap();
+
+    //retirement frees the roster slot and can't be undone
+    let roster: team::msg::PlayerResponse = app.wrap().query_wasm_smart(user1_team_addr[0].clone(),
+                         &team::msg::QueryMsg::GetPlayer { addr: players[1].address.to_string() }).unwrap();
+    assert!(roster.players.is_none());
+    let summary: Option<CareerSummary> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetCareerSummary { player: players[1].address.clone() }).unwrap();
+    assert_eq!(Some(user1_team_addr[0].clone()), summary.and_then(|s| s.last_team));
+    assert_eq!(vec![players[1].address.clone()], check_lineup(&app));
+    match app.execute_contract(Addr::unchecked(USER1), user1_team_addr[0].clone(),
+                               &ExecuteMsg::AddPlayersToTeam { players: vec![players[1].clone()] }, &[]) {
+        Ok(_) => assert!(false),
+        Err(_) => assert!(true)
+    }
+    match app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(),
+                               &league::msg::ExecuteMsg::SetPlayerStatus { player: players[1].address.to_string(),
+                                   status: PlayerStatus::Active }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::PlayerRetired { player: players[1].address.clone() }, e.downcast().unwrap())
+    }
+}
+
+
+#[test]
+fn legacy_player_accepts_status_after_migration() {
+
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let user1_team_addr = get_team_for_users(vec![Addr::unchecked(USER1)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+    let user1_league_addr = get_league_for_users(vec![Addr::unchecked(USER1)],
+                                                 goi_manager_addr.clone(), app.borrow_mut());
+    add_owner_teams_to_league(app.borrow_mut(), user1_team_addr.clone(),
+                              user1_league_addr[0].clone(), Addr::unchecked(USER1)).unwrap();
+    let players =
+        build_player_contracts(app.borrow_mut(), vec![
+            get_player_instantiate_msg("legacy".to_string(), "qb".to_string(), Positions::QB, goi_manager_addr.clone())],
+                               OWNER).unwrap();
+    app.execute_contract(Addr::unchecked(USER1), user1_team_addr[0].clone(),
+                         &ExecuteMsg::AddPlayersToTeam { players: players.clone() }, &[]).unwrap();
+
+    //deployed before the player recorded its managing contract
+    update_contract_storage(app.borrow_mut(), &players[0].address, |store| {
+        player::state::MANAGING_CONTRACT.remove(store);
+    });
+    let set_status = league::msg::ExecuteMsg::SetPlayerStatus { player: players[0].address.to_string(),
+        status: PlayerStatus::Injured { until_episode: 1 } };
+    match app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &set_status, &[]) {
+        Ok(_) => assert!(false),
+        Err(_) => assert!(true)
+    }
+
+    migrate_player_contract(app.borrow_mut(), players[0].address.clone(), OWNER, goi_manager_addr.clone()).unwrap();
+    app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(), &set_status, &[]).unwrap();
+    let info: shared::player::InfoResponse = app.wrap().query_wasm_smart(players[0].address.clone(),
+                         &shared::player::QueryMsg::GetInfo {}).unwrap();
+    assert_eq!(PlayerStatus::Injured { until_episode: 1 }, info.player.status);
+}
+
+
+#[test]
+fn fixture_seed_commit_reveal_and_forfeit() {
+
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+    let block_time =  mock_env().block.time.clone();
+
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let user1_team_addr = get_team_for_users(vec![Addr::unchecked(USER1)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+    let user2_team_addr = get_team_for_users(vec![Addr::unchecked(USER2)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+    let user1_league_addr = get_league_for_users(vec![Addr::unchecked(USER1)],
+                                                 goi_manager_addr.clone(), app.borrow_mut());
+    let players =
+        build_player_contracts(app.borrow_mut(), vec![
+            get_player_instantiate_msg("fixture".to_string(), "qb".to_string(), Positions::QB, goi_manager_addr.clone()),
+            get_player_instantiate_msg("fixture".to_string(), "rb".to_string(), Positions::RB, goi_manager_addr.clone())],
+                               OWNER).unwrap();
+    app.execute_contract(Addr::unchecked(USER1), user1_team_addr[0].clone(),
+                         &ExecuteMsg::AddPlayersToTeam { players: players.clone() }, &[]).unwrap();
+
+    let season_id = 1u64;
+    let season_1_start_date = block_time.plus_seconds( PRIOR_TO_SEASON_START_PADDING + 300);
+    let season_1_end_date = season_1_start_date.plus_seconds(THIRTY_MINUTES);
+    add_season_to_league(app.borrow_mut(), USER1, get_season(season_id, season_1_start_date, season_1_end_date),
+                         user1_league_addr[0].clone()).unwrap();
+    team_request_to_join_league(app.borrow_mut(), USER1, user1_team_addr[0].clone(), season_id).unwrap();
+    team_request_to_join_league(app.borrow_mut(), USER2, user2_team_addr[0].clone(), season_id).unwrap();
+    app.update_block(|b| b.time = season_1_start_date.plus_seconds(ONE_MINUTE));
+
+    let (home, away) = (user1_team_addr[0].clone(), user2_team_addr[0].clone());
+    let now = app.block_info().time;
+    let schedule = |app: &mut App| {
+        app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(),
+                             &league::msg::ExecuteMsg::ScheduleFixture { season_id, episode: 1, home: home.to_string(),
+                                 away: away.to_string(), league_commits: false,
+                                 commit_deadline: now.plus_seconds(ONE_MINUTE),
+                                 reveal_deadline: now.plus_seconds(FIFTEEN_MINUTES) }, &[])
+    };
+    let season_fixtures = |app: &App| -> Vec<Fixture> {
+        app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                                    &shared::goi_manager::GoiManagerQueryMsg::GetSeasonFixtures { season_id }).unwrap()
+    };
+    schedule(app.borrow_mut()).unwrap();
+    schedule(app.borrow_mut()).unwrap();
+    let (played_id, forfeit_id) = (season_fixtures(&app)[0].id, season_fixtures(&app)[1].id);
+
+    let commit = |app: &mut App, user: &str, team: &Addr, fixture_id: u64, secret: &Binary| {
+        app.execute_contract(Addr::unchecked(user), team.clone(),
+                             &ExecuteMsg::CommitFixtureSeed { fixture_id, commitment: seed_commitment(team, secret) }, &[])
+    };
+    let reveal = |app: &mut App, user: &str, team: &Addr, fixture_id: u64, secret: &Binary| {
+        app.execute_contract(Addr::unchecked(user), team.clone(),
+                             &ExecuteMsg::RevealFixtureSeed { fixture_id, secret: secret.clone() }, &[])
+    };
+    let (home_secret, away_secret) = (Binary::from(b"home secret".to_vec()), Binary::from(b"away secret".to_vec()));
+
+    //no one reveals while the other team can still pick its secret
+    commit(app.borrow_mut(), USER1, &home, played_id, &home_secret).unwrap();
+    match reveal(app.borrow_mut(), USER1, &home, played_id, &home_secret) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::SeedRevealNotOpen {}, e.downcast().unwrap())
+    }
+    commit(app.borrow_mut(), USER2, &away, played_id, &away_secret).unwrap();
+    //nor once both have committed; lineups can still change until the deadline
+    match reveal(app.borrow_mut(), USER1, &home, played_id, &home_secret) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::SeedRevealNotOpen {}, e.downcast().unwrap())
+    }
+    commit(app.borrow_mut(), USER1, &home, forfeit_id, &home_secret).unwrap();
+    app.update_block(|b| b.time = now.plus_seconds(ONE_MINUTE));
+    match reveal(app.borrow_mut(), USER1, &home, played_id, &away_secret) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::InvalidSeedReveal {}, e.downcast().unwrap())
+    }
+    reveal(app.borrow_mut(), USER1, &home, played_id, &home_secret).unwrap();
+    match app.execute_contract(Addr::unchecked(USER3), goi_manager_addr.clone(),
+                               &shared::goi_manager::ExecuteMsg::ResolveFixture { fixture_id: played_id }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::FixtureNotReady {}, e.downcast().unwrap())
+    }
+
+    //the last reveal plays the game with the combined seed
+    reveal(app.borrow_mut(), USER2, &away, played_id, &away_secret).unwrap();
+    let played = season_fixtures(&app)[0].clone();
+    assert!(played.completed.is_some());
+    let (seed, home_score, away_score, home_lineup) =
+        match played.result.clone() {
+            Some(FixtureResult::Played { seed, home_score, away_score, home_lineup, away_lineup, home_strategy, .. }) => {
+                assert!(away_lineup.is_empty());
+                assert_eq!(PlayCallingStrategy::Balanced, home_strategy);
+                (seed, home_score, away_score, home_lineup)
+            }
+            _ => panic!("expected a played fixture")
+        };
+    assert_eq!(played.combined_seed(), seed);
+    assert_eq!(2, home_lineup.len());
+
+    //anyone can replay the stored seed and lineups
+    let replay_lineup = |team: &Addr, slots: &Vec<shared::fixture::LineupSlot>| game_engine::Lineup {
+        team: team.clone(),
+        players: slots.iter().map(|s| {
+            let info: shared::player::InfoResponse = app.wrap().query_wasm_smart(s.player.clone(),
+                                 &shared::player::QueryMsg::GetInfo {}).unwrap();
+            game_engine::LineupPlayer { player: s.player.clone(), position: s.position.clone(), attributes: info.player.attributes }
+        }).collect(),
+        strategy: PlayCallingStrategy::Balanced
+    };
+    let replay = game_engine::simulate_game(&replay_lineup(&home, &home_lineup), &replay_lineup(&away, &vec![]), seed);
+    assert_eq!((home_score, away_score), (replay.home_score, replay.away_score));
+
+    //the away team never commits and forfeits once the reveal deadline passes
+    match commit(app.borrow_mut(), USER2, &away, forfeit_id, &away_secret) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::SeedCommitPeriodEnded {}, e.downcast().unwrap())
+    }
+    reveal(app.borrow_mut(), USER1, &home, forfeit_id, &home_secret).unwrap();
+    app.update_block(|b| b.time = now.plus_seconds(FIFTEEN_MINUTES));
+    app.execute_contract(Addr::unchecked(USER3), goi_manager_addr.clone(),
+                         &shared::goi_manager::ExecuteMsg::ResolveFixture { fixture_id: forfeit_id }, &[]).unwrap();
+    assert_eq!(Some(FixtureResult::Forfeit { forfeited: vec![away.clone()] }), season_fixtures(&app)[1].result);
+
+    let standings: Vec<DivisionGroup<TeamStandingItem>> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetSeasonStandingsByDivision { season_id }).unwrap();
+    let standing = |team: &Addr| standings[0].items.iter().find(|i| i.team == *team).unwrap().standing.clone();
+    let (home_standing, away_standing) = (standing(&home), standing(&away));
+    let home_won_game = (home_score > away_score) as u32;
+    assert_eq!(2, home_standing.wins + home_standing.losses + home_standing.ties);
+    assert_eq!(1 + home_won_game, home_standing.wins);
+    assert_eq!(1 + home_won_game, away_standing.losses);
+
+    //stats only count the played fixture
+    let team_stats = |team: &Addr, season: Option<u64>| -> TeamStats {
+        app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                                    &shared::goi_manager::GoiManagerQueryMsg::GetTeamStats { team_addr: team.clone(),
+                                        season_id: season }).unwrap()
+    };
+    let home_stats = team_stats(&home, Some(season_id));
+    assert_eq!((1, home_score as u64, away_score as u64), (home_stats.games, home_stats.points_for, home_stats.points_against));
+    assert_eq!(home_stats, team_stats(&home, None));
+    assert_eq!(TeamStats::default(), team_stats(&home, Some(season_id + 1)));
+
+    let player_stats = |player: &Addr| -> PlayerStats {
+        app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                                    &shared::goi_manager::GoiManagerQueryMsg::GetPlayerStats { player: player.clone(),
+                                        season_id: Some(season_id) }).unwrap()
+    };
+    let (qb_stats, rb_stats) = (player_stats(&players[0].address), player_stats(&players[1].address));
+    assert_eq!((1, Some(Positions::QB)), (qb_stats.games, qb_stats.position));
+    assert_eq!(home_stats.rushing_yards, rb_stats.rushing_yards);
+    assert_eq!(home_stats.passing_yards, qb_stats.passing_yards);
+
+    //all-time boards, paged best first
+    let team_board = |app: &App, start_after: Option<Addr>| -> Vec<TeamRecord> {
+        app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                                    &shared::goi_manager::GoiManagerQueryMsg::GetTeamLeaderboard {
+                                        league_type: LeagueTypes::NotSet, start_after, limit: Some(1) }).unwrap()
+    };
+    let first = team_board(&app, None);
+    let second = team_board(&app, Some(first[0].team.clone()));
+    assert!(first[0].win_percentage() >= second[0].win_percentage());
+    assert!(team_board(&app, Some(second[0].team.clone())).is_empty());
+    let home_record = vec![first[0].clone(), second[0].clone()].into_iter().find(|r| r.team == home).unwrap();
+    assert_eq!((1 + home_won_game, 1 + home_won_game), (home_record.wins, home_record.longest_streak));
+
+    let rushing: Vec<PlayerLeaderboardItem> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetPlayerLeaderboard { league_type: LeagueTypes::NotSet,
+                             category: StatCategory::RushingYards, start_after: None, limit: None }).unwrap();
+    assert!(rushing.iter().any(|i| i.player == players[1].address && i.value == rb_stats.rushing_yards) ||
+        rb_stats.rushing_yards == 0);
+    assert!(rushing.windows(2).all(|w| w[0].value >= w[1].value));
+    let ai_board: Vec<TeamRecord> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetTeamLeaderboard {
+                             league_type: LeagueTypes::AI, start_after: None, limit: None }).unwrap();
+    assert!(ai_board.is_empty());
+}
+
+
+#[test]
+fn withheld_league_reveal_reschedules_fixture() {
+
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+    let block_time =  mock_env().block.time.clone();
+
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let user1_team_addr = get_team_for_users(vec![Addr::unchecked(USER1)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+    let user2_team_addr = get_team_for_users(vec![Addr::unchecked(USER2)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+    let user1_league_addr = get_league_for_users(vec![Addr::unchecked(USER1)],
+                                                 goi_manager_addr.clone(), app.borrow_mut());
+
+    let season_id = 1u64;
+    let season_1_start_date = block_time.plus_seconds( PRIOR_TO_SEASON_START_PADDING + 300);
+    let season_1_end_date = season_1_start_date.plus_seconds(THIRTY_MINUTES);
+    add_season_to_league(app.borrow_mut(), USER1, get_season(season_id, season_1_start_date, season_1_end_date),
+                         user1_league_addr[0].clone()).unwrap();
+    team_request_to_join_league(app.borrow_mut(), USER1, user1_team_addr[0].clone(), season_id).unwrap();
+    team_request_to_join_league(app.borrow_mut(), USER2, user2_team_addr[0].clone(), season_id).unwrap();
+    app.update_block(|b| b.time = season_1_start_date.plus_seconds(ONE_MINUTE));
+
+    let (home, away, league) = (user1_team_addr[0].clone(), user2_team_addr[0].clone(), user1_league_addr[0].clone());
+    let now = app.block_info().time;
+    app.execute_contract(Addr::unchecked(USER1), league.clone(),
+                         &league::msg::ExecuteMsg::ScheduleFixture { season_id, episode: 1, home: home.to_string(),
+                             away: away.to_string(), league_commits: true,
+                             commit_deadline: now.plus_seconds(ONE_MINUTE),
+                             reveal_deadline: now.plus_seconds(FIFTEEN_MINUTES) }, &[]).unwrap();
+    let fixture = |app: &App| -> Fixture {
+        let fixtures: Vec<Fixture> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                                    &shared::goi_manager::GoiManagerQueryMsg::GetSeasonFixtures { season_id }).unwrap();
+        fixtures[0].clone()
+    };
+    let fixture_id = fixture(&app).id;
+
+    let secret = |party: &str| Binary::from(party.as_bytes().to_vec());
+    let team_commit = |app: &mut App| {
+        for (user, team) in [(USER1, &home), (USER2, &away)] {
+            app.execute_contract(Addr::unchecked(user), team.clone(),
+                                 &ExecuteMsg::CommitFixtureSeed { fixture_id, commitment: seed_commitment(team, &secret(user)) }, &[]).unwrap();
+        }
+    };
+    team_commit(app.borrow_mut());
+    app.execute_contract(Addr::unchecked(USER1), league.clone(),
+                         &league::msg::ExecuteMsg::CommitFixtureSeed { fixture_id,
+                             commitment: seed_commitment(&league, &secret("league")) }, &[]).unwrap();
+    app.update_block(|b| b.time = now.plus_seconds(ONE_MINUTE));
+    for (user, team) in [(USER1, &home), (USER2, &away)] {
+        app.execute_contract(Addr::unchecked(user), team.clone(),
+                             &ExecuteMsg::RevealFixtureSeed { fixture_id, secret: secret(user) }, &[]).unwrap();
+    }
+
+    //the league keeps its secret once both teams' are known; the fixture isn't played
+    app.update_block(|b| b.time = now.plus_seconds(FIFTEEN_MINUTES));
+    app.execute_contract(Addr::unchecked(USER3), goi_manager_addr.clone(),
+                         &shared::goi_manager::ExecuteMsg::ResolveFixture { fixture_id }, &[]).unwrap();
+    let rescheduled = fixture(&app);
+    assert_eq!(None, rescheduled.result);
+    assert!(rescheduled.completed.is_none() && rescheduled.commitments.is_empty() && !rescheduled.league_commits);
+    let period = FIFTEEN_MINUTES - ONE_MINUTE;
+    assert_eq!(now.plus_seconds(FIFTEEN_MINUTES + period), rescheduled.commit_deadline);
+
+    //the teams play it with new secrets and no say for the league
+    match app.execute_contract(Addr::unchecked(USER1), league.clone(),
+                               &league::msg::ExecuteMsg::CommitFixtureSeed { fixture_id,
+                                   commitment: seed_commitment(&league, &secret("league")) }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::NotFixtureParty { sender: league.clone() }, e.downcast().unwrap())
+    }
+    team_commit(app.borrow_mut());
+    app.update_block(|b| b.time = rescheduled.commit_deadline);
+    for (user, team) in [(USER1, &home), (USER2, &away)] {
+        app.execute_contract(Addr::unchecked(user), team.clone(),
+                             &ExecuteMsg::RevealFixtureSeed { fixture_id, secret: secret(user) }, &[]).unwrap();
+    }
+    match fixture(&app).result {
+        Some(FixtureResult::Played { .. }) => assert!(true),
+        _ => assert!(false)
+    }
+}
+
+#[test]
+fn game_day_lineup_submission_and_lock() {
+
+    let init_balance = Coin { denom: TOKEN.parse().unwrap(), amount: Uint128::from(5000000000000u128) };
+    let mut app: App = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+    let block_time =  mock_env().block.time.clone();
+
+    let goi_manager_addr = instantiate_management_contract(&mut app);
+
+    let user1_team_addr = get_team_for_users(vec![Addr::unchecked(USER1)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+    let user2_team_addr = get_team_for_users(vec![Addr::unchecked(USER2)],
+                                             goi_manager_addr.clone(), app.borrow_mut());
+    let user1_league_addr = get_league_for_users(vec![Addr::unchecked(USER1)],
+                                                 goi_manager_addr.clone(), app.borrow_mut());
+    let players =
+        build_player_contracts(app.borrow_mut(), vec![
+            get_player_instantiate_msg("lineup".to_string(), "qb".to_string(), Positions::QB, goi_manager_addr.clone()),
+            get_player_instantiate_msg("lineup".to_string(), "rb".to_string(), Positions::RB, goi_manager_addr.clone()),
+            get_player_instantiate_msg("lineup".to_string(), "lb".to_string(), Positions::LB, goi_manager_addr.clone())],
+                               OWNER).unwrap();
+    app.execute_contract(Addr::unchecked(USER1), user1_team_addr[0].clone(),
+                         &ExecuteMsg::AddPlayersToTeam { players: players[..2].to_vec() }, &[]).unwrap();
+
+    let season_id = 1u64;
+    let season_1_start_date = block_time.plus_seconds( PRIOR_TO_SEASON_START_PADDING + 300);
+    let season_1_end_date = season_1_start_date.plus_seconds(THIRTY_MINUTES);
+    add_season_to_league(app.borrow_mut(), USER1, get_season(season_id, season_1_start_date, season_1_end_date),
+                         user1_league_addr[0].clone()).unwrap();
+    team_request_to_join_league(app.borrow_mut(), USER1, user1_team_addr[0].clone(), season_id).unwrap();
+    team_request_to_join_league(app.borrow_mut(), USER2, user2_team_addr[0].clone(), season_id).unwrap();
+    app.update_block(|b| b.time = season_1_start_date.plus_seconds(ONE_MINUTE));
+
+    let (home, away) = (user1_team_addr[0].clone(), user2_team_addr[0].clone());
+    let (qb, rb, lb) = (players[0].address.clone(), players[1].address.clone(), players[2].address.clone());
+    let slot = |player: &Addr, position: Positions| LineupSlot { player: player.clone(), position };
+    let submit = |app: &mut App, offense: Vec<LineupSlot>, defense: Vec<LineupSlot>, play_calling_strategy: PlayCallingStrategy| {
+        app.execute_contract(Addr::unchecked(USER1), home.clone(),
+                             &ExecuteMsg::SubmitLineup { season_id, episode: 1, offense, defense, play_calling_strategy }, &[])
+    };
+    let lineup = |app: &App, episode: u32| -> Option<GameDayLineup> {
+        app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                                    &shared::goi_manager::GoiManagerQueryMsg::GetLineup { team_addr: home.clone(),
+                                        season_id, episode }).unwrap()
+    };
+
+    //offense and defense slots must be on the right side of the ball, filled
+    //once each and by players on the team
+    for (offense, defense) in vec![
+        (vec![slot(&qb, Positions::LB)], vec![]),
+        (vec![slot(&qb, Positions::QB), slot(&qb, Positions::RB)], vec![])] {
+        match submit(app.borrow_mut(), offense, defense, PlayCallingStrategy::Balanced) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(ContractError::InvalidLineup {}, e.downcast().unwrap())
+        }
+    }
+    match submit(app.borrow_mut(), vec![slot(&qb, Positions::QB)], vec![slot(&lb, Positions::LB)], PlayCallingStrategy::Balanced) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::LineupPlayersUnavailable { players: vec![lb.clone()] }, e.downcast().unwrap())
+    }
+
+    //the running back lines up at quarterback and the team keeps it on the ground
+    submit(app.borrow_mut(), vec![slot(&rb, Positions::QB)], vec![], PlayCallingStrategy::RunHeavy).unwrap();
+    assert_eq!(vec![slot(&rb, Positions::QB)], lineup(&app, 1).unwrap().offense);
+    assert_eq!(Some(1), lineup(&app, 3).map(|l| l.episode));
+    assert_eq!(None, lineup(&app, 0));
+
+    app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(),
+                         &league::msg::ExecuteMsg::SetLineupLock { seconds: 30 }, &[]).unwrap();
+    let lock: u64 = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetLineupLock { league_addr: user1_league_addr[0].clone() }).unwrap();
+    assert_eq!(30, lock);
+
+    let now = app.block_info().time;
+    app.execute_contract(Addr::unchecked(USER1), user1_league_addr[0].clone(),
+                         &league::msg::ExecuteMsg::ScheduleFixture { season_id, episode: 1, home: home.to_string(),
+                             away: away.to_string(), league_commits: false,
+                             commit_deadline: now.plus_seconds(ONE_MINUTE),
+                             reveal_deadline: now.plus_seconds(FIFTEEN_MINUTES) }, &[]).unwrap();
+
+    app.update_block(|b| b.time = now.plus_seconds(30));
+    match submit(app.borrow_mut(), vec![slot(&qb, Positions::QB)], vec![], PlayCallingStrategy::PassHeavy) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::LineupLocked {}, e.downcast().unwrap())
+    }
+
+    //the fixture is played with the locked lineup
+    let fixtures: Vec<Fixture> = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetSeasonFixtures { season_id }).unwrap();
+    let fixture_id = fixtures[0].id;
+    for (user, team, secret) in vec![(USER1, &home, Binary::from(b"home".to_vec())), (USER2, &away, Binary::from(b"away".to_vec()))] {
+        app.execute_contract(Addr::unchecked(user), team.clone(),
+                             &ExecuteMsg::CommitFixtureSeed { fixture_id, commitment: seed_commitment(team, &secret) }, &[]).unwrap();
+    }
+    app.update_block(|b| b.time = now.plus_seconds(ONE_MINUTE));
+    app.execute_contract(Addr::unchecked(USER1), home.clone(),
+                         &ExecuteMsg::RevealFixtureSeed { fixture_id, secret: Binary::from(b"home".to_vec()) }, &[]).unwrap();
+
+    //with the home secret public the away team can't change what it plays,
+    //neither its lineup nor the roster it falls back on
+    match app.execute_contract(Addr::unchecked(USER2), away.clone(),
+                               &ExecuteMsg::SubmitLineup { season_id, episode: 1, offense: vec![],
+                                   defense: vec![slot(&lb, Positions::LB)], play_calling_strategy: PlayCallingStrategy::PassHeavy }, &[]) {
+        Ok(_) => assert!(false),
+        Err(e) => assert_eq!(ContractError::LineupLocked {}, e.downcast().unwrap())
+    }
+    app.execute_contract(Addr::unchecked(USER2), away.clone(),
+                         &ExecuteMsg::AddPlayersToTeam { players: vec![players[2].clone()] }, &[]).unwrap();
+    app.execute_contract(Addr::unchecked(USER2), away.clone(),
+                         &ExecuteMsg::RevealFixtureSeed { fixture_id, secret: Binary::from(b"away".to_vec()) }, &[]).unwrap();
+    let fixture: Fixture = app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                         &shared::goi_manager::GoiManagerQueryMsg::GetFixture { fixture_id }).unwrap();
+    match fixture.result {
+        Some(FixtureResult::Played { home_lineup, away_lineup, home_strategy, away_strategy, .. }) => {
+            assert_eq!(vec![slot(&rb, Positions::QB)], home_lineup);
+            assert!(away_lineup.is_empty());
+            assert_eq!((PlayCallingStrategy::RunHeavy, PlayCallingStrategy::Balanced), (home_strategy, away_strategy));
+        }
+        _ => panic!("expected a played fixture")
+    }
+}
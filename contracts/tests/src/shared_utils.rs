@@ -2,6 +2,7 @@ use std::borrow::BorrowMut;
 use anyhow::Error;
 use cosmwasm_std::{Addr, Api, Coin, DepsMut, Empty, OwnedDeps, Querier, StdResult, Storage, Timestamp, Uint128};
 use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_storage::PrefixedStorage;
 use cw4::Member;
 use cw_multi_test::{App, AppBuilder, AppResponse, Contract, ContractWrapper, Executor};
 use cw4_group::state::MEMBERS;
@@ -22,6 +23,10 @@ use shared::data::ModelItem;
 use shared::goi_manager::GoiManagerQueryMsg::GetMessagesToItem;
 use shared::messaging::{JoinSeasonRequestInfo, Message, MessageTypes};
 use shared::season::{Season, SeasonAccessTypes, SeasonModelData, SeasonStatus};
+use shared::division::DivisionModelData;
+use shared::league::LeagueTypes;
+use shared::team::TeamCoaching;
+use shared::display::DisplayDetails;
 use shared::utils::general::AssetTypes;
 
 
@@ -136,10 +141,10 @@ pub fn all_players_with_duplicate_name(managing_contract_address: Addr) -> Vec<p
 
 pub fn contract_player() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
-        player::contract::instantiate,
+        player::contract::execute,
         player::contract::instantiate,
         player::contract::query,
-    );
+    ).with_migrate(player::contract::migrate);
     Box::new(contract)
 }
 
@@ -165,12 +170,78 @@ pub fn contract_league() -> Box<dyn Contract<Empty>> {
 }
 
 
+pub fn contract_display() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        display::contract::execute,
+        display::contract::instantiate,
+        display::contract::query,
+    );
+    Box::new(contract)
+}
+
+
+// uploads code and returns address of display contract
+pub fn instantiate_display(app: &mut App, details: DisplayDetails, reporter: Option<String>,
+                           manager_contract_address: Option<Addr>) -> Addr {
+    let display_id = app.store_code(contract_display());
+    let msg = display::msg::InstantiateDisplayMsg {
+        name: "Main Street Screen".to_string(),
+        admin: OWNER.into(),
+        members: vec![Member { addr: OWNER.to_string(), weight: 100 }],
+        managing_contract: manager_contract_address,
+        for_sale: false,
+        price: None,
+        details,
+        reporter
+    };
+    app.instantiate_contract
+    (display_id, Addr::unchecked(OWNER), &msg,
+     &[], "Display", None)
+        .unwrap()
+}
+
+
+// the display is registered by the goi manager's owner and bought by `user`
+pub fn get_display_for_user(user: Addr, details: DisplayDetails, reporter: Option<String>,
+                            goi_manager_addr: Addr, app: &mut App) -> Addr {
+    let display_addr = instantiate_display(app, details, None, Some(goi_manager_addr));
+    let display_sell_price = Coin { denom: TOKEN.to_string(), amount: Uint128::from(500000u128) };
+    let for_sale_msg =
+        ManagedServiceMessage {
+            message: ManagedExecuteMsg::Saleable {
+                saleable_msg: Update {
+                    for_sale_status: true,
+                    price: Some(display_sell_price.clone())
+                }
+            }
+        };
+    app.execute_contract(Addr::unchecked(OWNER),
+                         display_addr.clone(), &for_sale_msg, &[]).unwrap();
+
+    let buy_display_msg = ManagedServiceMessage { message: ManagedExecuteMsg::Saleable { saleable_msg: Buy {} } };
+    app.execute_contract(user.clone(),
+                         display_addr.clone(), &buy_display_msg,
+                         &[display_sell_price]).unwrap();
+    //a sale drops the reporter, so the buyer sets its own screen key
+    if reporter.is_some() {
+        app.execute_contract(user, display_addr.clone(),
+                             &display::msg::ExecuteMsg::SetReporter { reporter }, &[]).unwrap();
+    }
+    display_addr
+}
+
 
 
 
 // uploads code and returns address of team contract
 pub fn instantiate_league_with_managed_contract(app: &mut App, admin: String, members: Vec<Member>,
                                               manager_contract_address: Option<Addr>) -> Addr {
+    instantiate_league_of_type(app, admin, members, manager_contract_address, LeagueTypes::NotSet)
+}
+
+
+pub fn instantiate_league_of_type(app: &mut App, admin: String, members: Vec<Member>,
+                                  manager_contract_address: Option<Addr>, league_type: LeagueTypes) -> Addr {
     let league_id = app.store_code(contract_league());
     let msg = league::msg::InstantiateLeagueMsg {
         name: "Big Bang League".to_string(),
@@ -178,7 +249,8 @@ pub fn instantiate_league_with_managed_contract(app: &mut App, admin: String, me
         members,
         managing_contract: manager_contract_address,
         for_sale: false,
-        price: None
+        price: None,
+        league_type
     };
     app.instantiate_contract
     (league_id, Addr::unchecked(OWNER), &msg,
@@ -198,7 +270,8 @@ pub fn instantiate_team_with_managed_contract(app: &mut App, members: Vec<Member
         members,
         managing_contract: manager_contract_address,
         for_sale: false,
-        price: None
+        price: None,
+        coaching: TeamCoaching::NotSet
     };
     app.instantiate_contract
     (group_id, Addr::unchecked(OWNER), &msg,
@@ -209,6 +282,12 @@ pub fn instantiate_team_with_managed_contract(app: &mut App, members: Vec<Member
 
 pub fn instantiate_team_with_managed_contract_with_sender_admin(app: &mut App, user: &str, members: Vec<Member>,
                                                                 manager_contract_address: Option<Addr>) -> Addr {
+    instantiate_team_with_coaching(app, user, members, manager_contract_address, TeamCoaching::NotSet)
+}
+
+
+pub fn instantiate_team_with_coaching(app: &mut App, user: &str, members: Vec<Member>,
+                                      manager_contract_address: Option<Addr>, coaching: TeamCoaching) -> Addr {
     let group_id = app.store_code(contract_team());
     let msg = team::msg::InstantiateTeamMsg {
         name: "Big Bang".to_string(),
@@ -216,7 +295,8 @@ pub fn instantiate_team_with_managed_contract_with_sender_admin(app: &mut App, u
         members,
         managing_contract: manager_contract_address,
         for_sale: false,
-        price: None
+        price: None,
+        coaching
     };
     app.instantiate_contract
     (group_id, Addr::unchecked(user), &msg,
@@ -234,7 +314,7 @@ pub fn instantiate_player(app: &mut App, msg: plIntantiateMsg, sender: &str) ->
     let f_name = &String::from(msg.last_name.clone());
     app.instantiate_contract
     (player_id, Addr::unchecked(sender),
-     &msg, &[], f_name.to_owned() + space + &l_name, None)
+     &msg, &[], f_name.to_owned() + space + &l_name, Some(sender.to_string()))
 
 }
 
@@ -337,6 +417,31 @@ pub fn add_season_to_league(app: &mut App, user: &str, season: SeasonModelData,
 }
 
 
+pub fn update_season_capacity(app: &mut App, user: &str, league_addr: LeagueAddr, season_id: SeasonId,
+                              max_teams_allowed: u32) -> anyhow::Result<AppResponse> {
+    let update_season_capacity_msg =
+            league::msg::ExecuteMsg::UpdateSeasonCapacity { season_id, max_teams_allowed };
+
+    app.execute_contract(Addr::unchecked(user),
+    league_addr.clone(),
+                        &update_season_capacity_msg, &[])
+}
+
+pub fn add_division_to_season(app: &mut App, user: &str, league_addr: LeagueAddr, season_id: SeasonId,
+                              name: &str, conference: Option<String>, max_teams: u32) -> anyhow::Result<AppResponse> {
+    let add_division_msg =
+            league::msg::ExecuteMsg::AddDivisionToSeason { season_id, division: DivisionModelData {
+                name: ModelItem { update: true, data: Some(name.to_string()) },
+                conference: ModelItem { update: true, data: conference },
+                max_teams: ModelItem { update: true, data: Some(max_teams) },
+            } };
+
+    app.execute_contract(Addr::unchecked(user),
+    league_addr.clone(),
+                        &add_division_msg, &[])
+}
+
+
 
 pub fn get_season_with_custom_settings(season_id: u64, access_type: SeasonAccessTypes,
                                        start_date: Timestamp, end_date: Timestamp) -> SeasonModelData {
@@ -348,6 +453,7 @@ pub fn get_season_with_custom_settings(season_id: u64, access_type: SeasonAccess
             access_type: ModelItem { update: true, data: Some(access_type) },
             status: ModelItem { update: true, data: Some(SeasonStatus::Active) },
             max_teams_allowed: ModelItem { update: true, data: Some(MAX_TEAMS_ALLOWED) },
+            episodes: ModelItem { update: false, data: None },
 
         };
 
@@ -363,6 +469,7 @@ pub fn get_season(season_id: u64, start_date: Timestamp, end_date: Timestamp) ->
             access_type: ModelItem { update: true, data: Some(SeasonAccessTypes::Open) },
             status: ModelItem { update: true, data: Some(SeasonStatus::Active) },
             max_teams_allowed: ModelItem { update: true, data: Some(MAX_TEAMS_ALLOWED) },
+            episodes: ModelItem { update: false, data: None },
         };
     
     res
@@ -370,10 +477,15 @@ pub fn get_season(season_id: u64, start_date: Timestamp, end_date: Timestamp) ->
 
 
 pub fn get_team_for_users(users: Vec<Addr>, goi_manager_addr: Addr, app: &mut App) -> Vec<TeamAddr> {
+    get_coached_team_for_users(users, TeamCoaching::NotSet, goi_manager_addr, app)
+}
+
+
+pub fn get_coached_team_for_users(users: Vec<Addr>, coaching: TeamCoaching, goi_manager_addr: Addr, app: &mut App) -> Vec<TeamAddr> {
     let mut teams: Vec<Addr> = vec![];
     for a_user in users {
-        let team_addr = instantiate_team_with_managed_contract
-            (app , vec![member(OWNER, 100)], Some(goi_manager_addr.clone()));
+        let team_addr = instantiate_team_with_coaching
+            (app, OWNER, vec![member(OWNER, 100)], Some(goi_manager_addr.clone()), coaching.clone());
 
         let team_sell_price = Coin { denom: TOKEN.to_string(), amount: Uint128::from(500000u128) };
         let for_sale_msg =
@@ -405,10 +517,16 @@ pub fn get_team_for_users(users: Vec<Addr>, goi_manager_addr: Addr, app: &mut Ap
 
 
 pub fn get_league_for_users(users: Vec<Addr>, goi_manager_addr: Addr,app: &mut App) -> Vec<LeagueAddr> {
+    get_league_of_type_for_users(users, LeagueTypes::NotSet, goi_manager_addr, app)
+}
+
+
+pub fn get_league_of_type_for_users(users: Vec<Addr>, league_type: LeagueTypes, goi_manager_addr: Addr, app: &mut App) -> Vec<LeagueAddr> {
     let mut leagues: Vec<Addr> = vec![];
     for a_user in users {
-        let league_addr = instantiate_league_with_managed_contract(app, OWNER.clone().to_string(),
-                                                               vec![Member { addr: OWNER.clone().to_string(), weight: 100 }], Some(goi_manager_addr.clone()));
+        let league_addr = instantiate_league_of_type(app, OWNER.clone().to_string(),
+                                                     vec![Member { addr: OWNER.clone().to_string(), weight: 100 }], Some(goi_manager_addr.clone()),
+                                                     league_type.clone());
 
         let league_sell_price = Coin { denom: TOKEN.to_string(), amount: Uint128::from(500000u128) };
         let for_sale_msg =
@@ -458,7 +576,8 @@ pub fn do_instantiate_team(deps: DepsMut, for_sale: bool, price: Option<Coin>,
         managing_contract,
 
         for_sale,
-        price
+        price,
+        coaching: TeamCoaching::NotSet
     };
     let info = mock_info("creator", &[]);
     team::contract::instantiate(deps, mock_env(), info, msg).unwrap();
@@ -515,10 +634,37 @@ pub fn contract_management() -> Box<dyn Contract<Empty>> {
         goi_manager::contract::execute,
         goi_manager::contract::instantiate,
         goi_manager::contract::query,
-    );
+    ).with_migrate(goi_manager::contract::migrate);
     Box::new(contract)
 }
 
+
+//Re-runs the managing contract's migration, as an upgrade would
+pub fn migrate_management_contract(app: &mut App, goi_manager_addr: Addr) -> AnyResult<AppResponse> {
+    let code_id = app.store_code(contract_management());
+    app.migrate_contract(Addr::unchecked(OWNER), goi_manager_addr,
+                         &goi_manager::msg::MigrateMsg {}, code_id)
+}
+
+
+pub fn migrate_player_contract(app: &mut App, player: Addr, sender: &str, goi_manager_addr: Addr) -> AnyResult<AppResponse> {
+    let code_id = app.store_code(contract_player());
+    app.migrate_contract(Addr::unchecked(sender), player,
+                         &shared::player::MigrateMsg { managing_contract_address: goi_manager_addr }, code_id)
+}
+
+
+//Writes straight into a contract's storage, e.g. to set up state
+//left behind by an older version of the contract
+pub fn update_contract_storage<F: FnOnce(&mut dyn Storage)>(app: &mut App, contract: &Addr, update: F) {
+    app.init_modules(|_, _, storage| {
+        let mut namespace = b"contract_data/".to_vec();
+        namespace.extend_from_slice(contract.as_bytes());
+        let mut contract_storage = PrefixedStorage::multilevel(storage, &[b"wasm", &namespace]);
+        update(&mut contract_storage);
+    });
+}
+
 pub fn mock_app(init_funds: &[Coin]) -> App {
     mock_app_by_user(vec![OWNER], init_funds)
 }
@@ -553,7 +699,7 @@ pub fn instantiate_management_contract_with_user(app: &mut App, user: String)
     };
     app.instantiate_contract
     (code_id, Addr::unchecked(user.to_string()),
-     &msg, &[], "goi_manager", None)
+     &msg, &[], "goi_manager", Some(OWNER.to_string()))
 }
 
 pub fn get_team_for_sale(app: &mut App, goi_manager_addr1: Addr, team_sell_price: Coin) -> Option<Addr>{
@@ -90,7 +90,7 @@ mod tests {
     use std::ops::{Mul, Sub};
 
     use anyhow::Error;
-    use cosmwasm_std::{Coin, Empty, from_slice, OwnedDeps, Querier, Storage, SubMsg, Uint128, Addr, DepsMut, Api, BankMsg, coins, CosmosMsg};
+    use cosmwasm_std::{Coin, Empty, from_slice, OwnedDeps, Querier, Storage, SubMsg, Uint128, Addr, DepsMut, Api, BankMsg, coin, coins, CosmosMsg};
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
     use cw4::{Member, member_key, MemberChangedHookMsg, MemberDiff, TOTAL_KEY};
     use cw4_group::contract::update_members;
@@ -109,6 +109,7 @@ mod tests {
     use saleable::error::SaleableItemError;
     use saleable::messages::receive::ExecuteMsg::{Buy, Update};
     use shared::goi_manager::GoiManagerQueryMsg;
+    use shared::goi_manager::ExecuteMsg as GoiExecuteMsg;
     use shared::goi_manager::ManagementQryMsg::GetManagementInfo;
     use shared::manage::receive::ManagementInfoResponse;
     use shared::player_attributes::{AttrItem, PlayerAttributes, Positions};
@@ -118,8 +119,17 @@ mod tests {
     use team::TeamError;
     use team::msg::InstantiateTeamMsg;
 
-    use team::msg::ExecuteMsg::{AddPlayersToTeam, ManagedServiceMessage, RemovePlayersFromTeam};
-    use crate::shared_utils::{all_players, all_players_with_duplicate_name, assert_users, build_player_contracts, do_instantiate_team, get_player_instantiate_msg, INIT_ADMIN, instantiate_management_contract, instantiate_management_contract_with_user, instantiate_player, instantiate_team_with_managed_contract, member, mock_app, mock_app_by_user, OWNER, TOKEN, USER1, USER2, USER3};
+    use team::msg::ExecuteMsg::{AddPlayersToTeam, AllocateDividends, ClaimDividends, Deposit, DistributeDividends, ExecuteProposal, Propose, VoteOnProposal, ManagedServiceMessage, ProposeTreasuryWithdrawal, ReconcileTreasury,
+                                RemovePlayersFromTeam, VoteTreasuryWithdrawal, WithdrawFromTreasury, ProposeTrade, RespondToTrade, CancelTrade, SignFreeAgent};
+    use team::msg::QueryMsg::{GetDividendClaims, GetPlayer, GetShareListings, GetTreasuryBalances, GetTreasuryLedger, GetTreasuryWithdrawalProposal};
+    use shared::team::{TreasuryFlow, TreasuryLedgerEntry, TreasuryWithdrawalProposal};
+    use shared::saleable::ShareListing;
+    use shared::governance::{GovernanceConfig, ProposalResponse, ProposalStatus, Vote};
+    use shared::trade::{TradeProposal, TradeStatus};
+    use shared::player::FreeAgent;
+    use team::msg::PlayerResponse;
+    use goi_manager::ContractError;
+    use crate::shared_utils::{all_players, all_players_with_duplicate_name, assert_users, build_player_contracts, do_instantiate_team, get_player_instantiate_msg, INIT_ADMIN, instantiate_management_contract, instantiate_management_contract_with_user, instantiate_player, instantiate_team_with_managed_contract, member, migrate_management_contract, update_contract_storage, mock_app, mock_app_by_user, OWNER, TOKEN, USER1, USER2, USER3};
 
 
 
@@ -1080,4 +1090,519 @@ mod tests {
     }
 
 
+    #[test]
+    fn treasury_vote_counts_weight_held_when_proposed() {
+        let init_balance = Coin { denom: TOKEN.to_string(), amount: Uint128::from(1000000u128) };
+        let mut app = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+        let goi_manager_addr = instantiate_management_contract(&mut app);
+        let team_addr =
+            instantiate_team_with_managed_contract
+                (&mut app, vec![member(OWNER, 50), member(USER1, 30), member(USER2, 20)], Some(goi_manager_addr));
+        app.execute_contract(Addr::unchecked(USER3), team_addr.clone(), &Deposit {}, &[coins(1000, TOKEN)[0].clone()]).unwrap();
+
+        app.update_block(|b| b.height += 1);
+        app.execute_contract(Addr::unchecked(USER1), team_addr.clone(),
+                             &ProposeTreasuryWithdrawal { recipient: USER1.to_string(), amount: coins(400, TOKEN) }, &[]).unwrap();
+
+        //OWNER sells most of its stake while the proposal is open; its vote still carries the 50 it held
+        app.update_block(|b| b.height += 1);
+        let transfer_msg = ManagedServiceMessage { message: ManagedExecuteMsg::TransferShares { to: USER3.to_string(), weight: 40 } };
+        app.execute_contract(Addr::unchecked(OWNER), team_addr.clone(), &transfer_msg, &[]).unwrap();
+        let user1_balance = app.wrap().query_balance(USER1, TOKEN).unwrap().amount;
+        app.execute_contract(Addr::unchecked(OWNER), team_addr.clone(), &VoteTreasuryWithdrawal { proposal_id: 1 }, &[]).unwrap();
+
+        let proposal: TreasuryWithdrawalProposal = app.wrap().query_wasm_smart(team_addr.clone(),
+                                                       &GetTreasuryWithdrawalProposal { proposal_id: 1 }).unwrap();
+        assert_eq!(80, proposal.yes_weight);
+        assert!(proposal.executed.is_some());
+        assert_eq!(user1_balance + Uint128::from(400u128), app.wrap().query_balance(USER1, TOKEN).unwrap().amount);
+    }
+
+
+    #[test]
+    fn team_treasury_deposits_and_withdrawals() {
+        let init_balance = Coin { denom: TOKEN.to_string(), amount: Uint128::from(1000000u128) };
+        let mut app = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+        let goi_manager_addr = instantiate_management_contract(&mut app);
+        let team_addr =
+            instantiate_team_with_managed_contract
+                (&mut app, vec![member(OWNER, 50), member(USER1, 30), member(USER2, 20)], Some(goi_manager_addr));
+
+        //anyone can fund the treasury
+        let deposit = Coin { denom: TOKEN.to_string(), amount: Uint128::from(1000u128) };
+        app.execute_contract(Addr::unchecked(USER3), team_addr.clone(), &Deposit {}, &[deposit.clone()]).unwrap();
+
+        match app.execute_contract(Addr::unchecked(USER1), team_addr.clone(),
+                                   &WithdrawFromTreasury { recipient: USER1.to_string(), amount: vec![deposit.clone()] }, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(TeamError::UnauthorizedSender { sender: Addr::unchecked(USER1) }, e.downcast().unwrap())
+        }
+
+        //co-owner proposal passes once more than half of the weight votes for it
+        app.update_block(|b| b.height += 1);
+        let amount = Coin { denom: TOKEN.to_string(), amount: Uint128::from(400u128) };
+        app.execute_contract(Addr::unchecked(USER1), team_addr.clone(),
+                             &ProposeTreasuryWithdrawal { recipient: USER3.to_string(), amount: vec![amount.clone()] }, &[]).unwrap();
+        match app.execute_contract(Addr::unchecked(USER3), team_addr.clone(), &VoteTreasuryWithdrawal { proposal_id: 1 }, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(TeamError::NotTeamOwner { sender: Addr::unchecked(USER3) }, e.downcast().unwrap())
+        }
+        app.execute_contract(Addr::unchecked(USER2), team_addr.clone(), &VoteTreasuryWithdrawal { proposal_id: 1 }, &[]).unwrap();
+        let user3_balance = app.wrap().query_balance(USER3, TOKEN).unwrap();
+        assert_eq!(init_balance.amount - deposit.amount, user3_balance.amount);
+
+        //shares moved after the proposal was made can't vote on it again
+        app.update_block(|b| b.height += 1);
+        let transfer_msg = ManagedServiceMessage { message: ManagedExecuteMsg::TransferShares { to: USER3.to_string(), weight: 20 } };
+        app.execute_contract(Addr::unchecked(USER2), team_addr.clone(), &transfer_msg, &[]).unwrap();
+        match app.execute_contract(Addr::unchecked(USER3), team_addr.clone(), &VoteTreasuryWithdrawal { proposal_id: 1 }, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(TeamError::NotTeamOwner { sender: Addr::unchecked(USER3) }, e.downcast().unwrap())
+        }
+
+        app.execute_contract(Addr::unchecked(OWNER), team_addr.clone(), &VoteTreasuryWithdrawal { proposal_id: 1 }, &[]).unwrap();
+        let user3_balance = app.wrap().query_balance(USER3, TOKEN).unwrap();
+        assert_eq!(init_balance.amount - deposit.amount + amount.amount, user3_balance.amount);
+
+        match app.execute_contract(Addr::unchecked(OWNER), team_addr.clone(),
+                                   &WithdrawFromTreasury { recipient: OWNER.to_string(), amount: vec![deposit.clone()] }, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(TeamError::InsufficientTreasuryFunds { denom: TOKEN.to_string() }, e.downcast().unwrap())
+        }
+
+        //funds sent straight to the contract are picked up by reconciling
+        app.execute(Addr::unchecked(USER2), CosmosMsg::Bank(BankMsg::Send {
+            to_address: team_addr.to_string(),
+            amount: coins(250, TOKEN)
+        })).unwrap();
+        app.execute_contract(Addr::unchecked(OWNER), team_addr.clone(), &ReconcileTreasury {}, &[]).unwrap();
+        app.execute_contract(Addr::unchecked(OWNER), team_addr.clone(),
+                             &WithdrawFromTreasury { recipient: OWNER.to_string(), amount: coins(850, TOKEN) }, &[]).unwrap();
+
+        let balances: Vec<Coin> = app.wrap().query_wasm_smart(team_addr.clone(), &GetTreasuryBalances {}).unwrap();
+        assert_eq!(vec![Coin { denom: TOKEN.to_string(), amount: Uint128::zero() }], balances);
+
+        let ledger: Vec<TreasuryLedgerEntry> = app.wrap().query_wasm_smart(team_addr.clone(),
+                                                  &GetTreasuryLedger { start_after: None, limit: None }).unwrap();
+        assert_eq!(vec![TreasuryFlow::Inflow, TreasuryFlow::Outflow, TreasuryFlow::Inflow, TreasuryFlow::Outflow],
+                   ledger.into_iter().map(|e| e.flow).collect::<Vec<TreasuryFlow>>());
+
+        //open proposals lapse after the governance voting period
+        app.execute_contract(Addr::unchecked(USER1), team_addr.clone(),
+                             &ProposeTreasuryWithdrawal { recipient: USER1.to_string(), amount: coins(1, TOKEN) }, &[]).unwrap();
+        app.update_block(|b| b.time = b.time.plus_seconds(GovernanceConfig::default().voting_period));
+        match app.execute_contract(Addr::unchecked(OWNER), team_addr.clone(), &VoteTreasuryWithdrawal { proposal_id: 2 }, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(TeamError::WithdrawalProposalExpired {}, e.downcast().unwrap())
+        }
+    }
+
+
+    #[test]
+    fn team_dividends_direct_and_claimed() {
+        let init_balance = Coin { denom: TOKEN.to_string(), amount: Uint128::from(1000000u128) };
+        let mut app = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+        let goi_manager_addr = instantiate_management_contract(&mut app);
+        let team_addr =
+            instantiate_team_with_managed_contract
+                (&mut app, vec![member(OWNER, 50), member(USER1, 30), member(USER2, 20)], Some(goi_manager_addr));
+
+        match app.execute_contract(Addr::unchecked(OWNER), team_addr.clone(), &DistributeDividends { denom: TOKEN.to_string() }, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(TeamError::NoDividendsToDistribute { denom: TOKEN.to_string() }, e.downcast().unwrap())
+        }
+
+        //prize money paid straight out by weight
+        app.execute_contract(Addr::unchecked(USER3), team_addr.clone(), &Deposit {}, &coins(1000, TOKEN)).unwrap();
+        app.execute_contract(Addr::unchecked(OWNER), team_addr.clone(), &DistributeDividends { denom: TOKEN.to_string() }, &[]).unwrap();
+        let user1_balance = app.wrap().query_balance(USER1, TOKEN).unwrap();
+        assert_eq!(init_balance.amount + Uint128::from(300u128), user1_balance.amount);
+
+        //claim based; rounding dust stays in the treasury
+        app.execute_contract(Addr::unchecked(USER3), team_addr.clone(), &Deposit {}, &coins(1001, TOKEN)).unwrap();
+        app.execute_contract(Addr::unchecked(OWNER), team_addr.clone(), &AllocateDividends { denom: TOKEN.to_string() }, &[]).unwrap();
+        let claims: Vec<Coin> = app.wrap().query_wasm_smart(team_addr.clone(),
+                                   &GetDividendClaims { owner: USER2.to_string() }).unwrap();
+        assert_eq!(coins(200, TOKEN), claims);
+
+        app.execute_contract(Addr::unchecked(USER2), team_addr.clone(), &ClaimDividends { denom: TOKEN.to_string() }, &[]).unwrap();
+        let user2_balance = app.wrap().query_balance(USER2, TOKEN).unwrap();
+        assert_eq!(init_balance.amount + Uint128::from(400u128), user2_balance.amount);
+        match app.execute_contract(Addr::unchecked(USER2), team_addr.clone(), &ClaimDividends { denom: TOKEN.to_string() }, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(TeamError::NoDividendsToClaim { denom: TOKEN.to_string() }, e.downcast().unwrap())
+        }
+
+        //unclaimed dividends aren't picked up again as untracked funds
+        app.execute_contract(Addr::unchecked(OWNER), team_addr.clone(), &ReconcileTreasury {}, &[]).unwrap();
+        let balances: Vec<Coin> = app.wrap().query_wasm_smart(team_addr.clone(), &GetTreasuryBalances {}).unwrap();
+        assert_eq!(coins(1, TOKEN), balances);
+    }
+
+
+    #[test]
+    fn transfer_and_sell_fractional_shares() {
+        let init_balance = Coin { denom: TOKEN.to_string(), amount: Uint128::from(1000000u128) };
+        let mut app = mock_app_by_user(vec![OWNER, USER1, USER2, USER3], &[init_balance.clone()]);
+        let goi_manager_addr = instantiate_management_contract(&mut app);
+        let team_addr =
+            instantiate_team_with_managed_contract
+                (&mut app, vec![member(OWNER, 100)], Some(goi_manager_addr.clone()));
+
+        let transfer_msg = ManagedServiceMessage { message: ManagedExecuteMsg::TransferShares { to: USER1.to_string(), weight: 30 } };
+        app.execute_contract(Addr::unchecked(OWNER), team_addr.clone(), &transfer_msg, &[]).unwrap();
+
+        let price_per_unit = Coin { denom: TOKEN.to_string(), amount: Uint128::from(10u128) };
+        let list_msg = ManagedServiceMessage { message: ManagedExecuteMsg::ListSharesForSale { weight: 40, price_per_unit: price_per_unit.clone() } };
+        match app.execute_contract(Addr::unchecked(USER1), team_addr.clone(), &list_msg, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(TeamError::ManagableServiceError(ManagedServiceError::InsufficientShares { available: 30 }), e.downcast().unwrap())
+        }
+        let list_msg = ManagedServiceMessage { message: ManagedExecuteMsg::ListSharesForSale { weight: 20, price_per_unit: price_per_unit.clone() } };
+        app.execute_contract(Addr::unchecked(USER1), team_addr.clone(), &list_msg, &[]).unwrap();
+
+        let buy_msg = ManagedServiceMessage { message: ManagedExecuteMsg::BuyShares { seller: USER1.to_string(), weight: 10 } };
+        match app.execute_contract(Addr::unchecked(USER2), team_addr.clone(), &buy_msg, &coins(50, TOKEN)) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(TeamError::ManagableServiceError(ManagedServiceError::IncorrectSharePayment { expected: coin(100, TOKEN) }),
+                                 e.downcast().unwrap())
+        }
+        app.execute_contract(Addr::unchecked(USER2), team_addr.clone(), &buy_msg, &coins(100, TOKEN)).unwrap();
+        let user1_balance = app.wrap().query_balance(USER1, TOKEN).unwrap();
+        assert_eq!(init_balance.amount + Uint128::from(100u128), user1_balance.amount);
+
+        let info: InfoManagedResponse<team::state::State> = app.wrap().query_wasm_smart(team_addr.clone(), &team::msg::QueryMsg::GetInfo {}).unwrap();
+        assert_eq!(vec![member(OWNER, 70), member(USER2, 10), member(USER1, 20)], info.owners);
+
+        let listings: Vec<ShareListing> = app.wrap().query_wasm_smart(team_addr.clone(), &GetShareListings {}).unwrap();
+        assert_eq!(1, listings.len());
+        assert_eq!(10, listings[0].weight);
+
+        //partial changes show up in the asset's ownership history
+        let managed_items: Option<Vec<ManagedItemResponse>> = app.wrap().query_wasm_smart(&goi_manager_addr,
+                                   &GoiManagerQueryMsg::GetOwnerAssets { owner_address: Addr::unchecked(OWNER) }).unwrap();
+        let history = managed_items.unwrap()[0].ownership_history.clone();
+        assert_eq!(2, history.len());
+        assert_eq!((Addr::unchecked(USER1), Some(30), Some(Addr::unchecked(OWNER))),
+                   (history[0].owners.clone(), history[0].weight, history[0].transferred_from.clone()));
+        assert_eq!((Addr::unchecked(USER2), Some(10), Some(Addr::unchecked(USER1))),
+                   (history[1].owners.clone(), history[1].weight, history[1].transferred_from.clone()));
+    }
+
+
+    #[test]
+    fn co_owner_proposal_lists_team_for_sale() {
+        let mut app = mock_app(&[]);
+        let goi_manager_addr = instantiate_management_contract(&mut app);
+        let team_addr =
+            instantiate_team_with_managed_contract
+                (&mut app, vec![member(OWNER, 60), member(USER1, 25), member(USER2, 15)], Some(goi_manager_addr));
+        app.update_block(|b| b.height += 1);
+
+        let for_sale_msg = ManagedServiceMessage {
+            message: ManagedExecuteMsg::Saleable {
+                saleable_msg: Update { for_sale_status: true, price: Some(coin(500000, TOKEN)) }
+            }
+        };
+        let propose_msg = Propose { title: "Sell the team".to_string(), description: "".to_string(), msg: Box::new(for_sale_msg) };
+        app.execute_contract(Addr::unchecked(USER1), team_addr.clone(), &propose_msg, &[]).unwrap();
+        app.execute_contract(Addr::unchecked(USER2), team_addr.clone(),
+                             &VoteOnProposal { proposal_id: 1, vote: Vote::Yes }, &[]).unwrap();
+
+        match app.execute_contract(Addr::unchecked(USER2), team_addr.clone(), &ExecuteProposal { proposal_id: 1 }, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(TeamError::ManagableServiceError(ManagedServiceError::ProposalNotPassed { status: ProposalStatus::Open }),
+                                 e.downcast().unwrap())
+        }
+
+        //weight picked up after the proposal was created doesn't vote
+        app.update_block(|b| b.height += 1);
+        let transfer_msg = ManagedServiceMessage { message: ManagedExecuteMsg::TransferShares { to: USER3.to_string(), weight: 10 } };
+        app.execute_contract(Addr::unchecked(OWNER), team_addr.clone(), &transfer_msg, &[]).unwrap();
+        match app.execute_contract(Addr::unchecked(USER3), team_addr.clone(), &VoteOnProposal { proposal_id: 1, vote: Vote::No }, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(TeamError::ManagableServiceError(ManagedServiceError::NoVotingPower { voter: Addr::unchecked(USER3) }),
+                                 e.downcast().unwrap())
+        }
+
+        //quorum reached; all non-abstaining weight is yes
+        app.execute_contract(Addr::unchecked(OWNER), team_addr.clone(),
+                             &VoteOnProposal { proposal_id: 1, vote: Vote::Abstain }, &[]).unwrap();
+        let proposal: Option<ProposalResponse> = app.wrap().query_wasm_smart(team_addr.clone(),
+                                   &team::msg::QueryMsg::GetProposal { proposal_id: 1 }).unwrap();
+        let proposal = proposal.unwrap();
+        assert_eq!(ProposalStatus::Passed, proposal.status);
+        assert_eq!((40, 0, 60), (proposal.proposal.yes, proposal.proposal.no, proposal.proposal.abstain));
+
+        app.execute_contract(Addr::unchecked(USER3), team_addr.clone(), &ExecuteProposal { proposal_id: 1 }, &[]).unwrap();
+        let info: InfoManagedResponse<team::state::State> = app.wrap().query_wasm_smart(team_addr.clone(), &team::msg::QueryMsg::GetInfo {}).unwrap();
+        assert!(info.sale_info.for_sale);
+
+        match app.execute_contract(Addr::unchecked(USER3), team_addr.clone(), &ExecuteProposal { proposal_id: 1 }, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(TeamError::ManagableServiceError(ManagedServiceError::ProposalNotPassed { status: ProposalStatus::Executed }),
+                                 e.downcast().unwrap())
+        }
+    }
+
+
+    #[test]
+    fn trade_players_between_teams() {
+        let init_balance = Coin { denom: TOKEN.to_string(), amount: Uint128::from(1000000u128) };
+        let mut app = mock_app_by_user(vec![OWNER], &[init_balance.clone()]);
+        let goi_manager_addr = instantiate_management_contract(&mut app);
+        let team_a =
+            instantiate_team_with_managed_contract
+                (&mut app, vec![member(OWNER, 100)], Some(goi_manager_addr.clone()));
+        let team_b =
+            instantiate_team_with_managed_contract
+                (&mut app, vec![member(USER1, 100)], Some(goi_manager_addr.clone()));
+
+        let players_a =
+            build_player_contracts(&mut app, vec![
+                get_player_instantiate_msg("safety".to_string(), "one".to_string(),
+                                           Positions::S, goi_manager_addr.clone())], OWNER).unwrap();
+        let players_b =
+            build_player_contracts(&mut app, vec![
+                get_player_instantiate_msg("running".to_string(), "back".to_string(),
+                                           Positions::RB, goi_manager_addr.clone())], OWNER).unwrap();
+        app.execute_contract(Addr::unchecked(OWNER), team_a.clone(),
+                             &AddPlayersToTeam { players: players_a.clone() }, &[]).unwrap();
+        app.execute_contract(Addr::unchecked(OWNER), team_b.clone(),
+                             &AddPlayersToTeam { players: players_b.clone() }, &[]).unwrap();
+        app.execute_contract(Addr::unchecked(OWNER), team_a.clone(), &Deposit {}, &coins(500, TOKEN)).unwrap();
+
+        //a team can only offer players on its own roster
+        match app.execute_contract(Addr::unchecked(OWNER), team_a.clone(),
+                                   &ProposeTrade { counterparty_team: team_b.to_string(), offered_players: players_b.clone(),
+                                       requested_players: vec![], offered_funds: vec![],
+                                       offered_picks: vec![], requested_picks: vec![] }, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(TeamError::PositionNotAssigned { position: Positions::RB }, e.downcast().unwrap())
+        }
+
+        //rejected trade refunds the escrowed funds
+        let propose = ProposeTrade { counterparty_team: team_b.to_string(), offered_players: players_a.clone(),
+            requested_players: players_b.clone(), offered_funds: coins(200, TOKEN),
+            offered_picks: vec![], requested_picks: vec![] };
+        app.execute_contract(Addr::unchecked(OWNER), team_a.clone(), &propose, &[]).unwrap();
+        let balances: Vec<Coin> = app.wrap().query_wasm_smart(team_a.clone(), &GetTreasuryBalances {}).unwrap();
+        assert_eq!(coins(300, TOKEN), balances);
+        match app.execute_contract(Addr::unchecked(OWNER), team_a.clone(), &RespondToTrade { trade_id: 1, accept: true }, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(ContractError::Unauthorized { sender: team_a.clone() }, e.downcast().unwrap())
+        }
+        app.execute_contract(Addr::unchecked(OWNER), team_b.clone(), &RespondToTrade { trade_id: 1, accept: false }, &[]).unwrap();
+        let balances: Vec<Coin> = app.wrap().query_wasm_smart(team_a.clone(), &GetTreasuryBalances {}).unwrap();
+        assert_eq!(coins(500, TOKEN), balances);
+        match app.execute_contract(Addr::unchecked(OWNER), team_a.clone(), &CancelTrade { trade_id: 1 }, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(ContractError::TradeNotOpen { status: TradeStatus::Rejected }, e.downcast().unwrap())
+        }
+
+        //accepted trade swaps the players and pays the counterparty;
+        //offered funds are normalized before they're escrowed
+        let propose = ProposeTrade { counterparty_team: team_b.to_string(), offered_players: players_a.clone(),
+            requested_players: players_b.clone(), offered_funds: vec![coin(150, TOKEN), coin(0, TOKEN), coin(50, TOKEN)],
+            offered_picks: vec![], requested_picks: vec![] };
+        app.execute_contract(Addr::unchecked(OWNER), team_a.clone(), &propose, &[]).unwrap();
+        let trades: Option<Vec<TradeProposal>> =
+            app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                                        &GoiManagerQueryMsg::GetTeamTradeProposals { team_addr: team_b.clone() }).unwrap();
+        assert_eq!(vec![TradeStatus::Rejected, TradeStatus::Proposed],
+                   trades.unwrap().into_iter().map(|t| t.status).collect::<Vec<TradeStatus>>());
+        app.execute_contract(Addr::unchecked(OWNER), team_b.clone(), &RespondToTrade { trade_id: 2, accept: true }, &[]).unwrap();
+
+        let player: PlayerResponse = app.wrap().query_wasm_smart(team_b.clone(),
+                                         &GetPlayer { addr: players_a[0].address.to_string() }).unwrap();
+        assert!(player.players.is_some());
+        let player: PlayerResponse = app.wrap().query_wasm_smart(team_a.clone(),
+                                         &GetPlayer { addr: players_b[0].address.to_string() }).unwrap();
+        assert!(player.players.is_some());
+        let player: PlayerResponse = app.wrap().query_wasm_smart(team_a.clone(),
+                                         &GetPlayer { addr: players_a[0].address.to_string() }).unwrap();
+        assert_eq!(None, player.players);
+
+        let balances: Vec<Coin> = app.wrap().query_wasm_smart(team_b.clone(), &GetTreasuryBalances {}).unwrap();
+        assert_eq!(coins(200, TOKEN), balances);
+        let trade: Option<TradeProposal> =
+            app.wrap().query_wasm_smart(goi_manager_addr, &GoiManagerQueryMsg::GetTradeProposal { trade_id: 2 }).unwrap();
+        let trade = trade.unwrap();
+        assert_eq!(TradeStatus::Accepted, trade.status);
+        assert_eq!(coins(200, TOKEN), trade.offered_funds);
+    }
+
+
+    #[test]
+    fn sign_free_agents() {
+        let init_balance = Coin { denom: TOKEN.to_string(), amount: Uint128::from(1000000u128) };
+        let mut app = mock_app_by_user(vec![OWNER, USER1], &[init_balance.clone()]);
+        let goi_manager_addr = instantiate_management_contract(&mut app);
+        let team_a =
+            instantiate_team_with_managed_contract
+                (&mut app, vec![member(OWNER, 100)], Some(goi_manager_addr.clone()));
+        let team_b =
+            instantiate_team_with_managed_contract
+                (&mut app, vec![member(USER1, 100)], Some(goi_manager_addr.clone()));
+
+        let players =
+            build_player_contracts(&mut app, vec![
+                get_player_instantiate_msg("free".to_string(), "safety".to_string(),
+                                           Positions::S, goi_manager_addr.clone()),
+                get_player_instantiate_msg("free".to_string(), "back".to_string(),
+                                           Positions::RB, goi_manager_addr.clone())], OWNER).unwrap();
+
+        //released players join the pool
+        app.execute_contract(Addr::unchecked(OWNER), team_a.clone(),
+                             &AddPlayersToTeam { players: vec![players[0].clone()] }, &[]).unwrap();
+        app.execute_contract(Addr::unchecked(OWNER), team_a.clone(),
+                             &RemovePlayersFromTeam { players: vec![players[0].clone()] }, &[]).unwrap();
+        let free_agents: Vec<FreeAgent> =
+            app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                                        &GoiManagerQueryMsg::GetFreeAgents { position: Some(Positions::S),
+                                            start_after: None, limit: None }).unwrap();
+        assert_eq!(vec![players[0].address.clone()],
+                   free_agents.into_iter().map(|f| f.player.address).collect::<Vec<Addr>>());
+
+        //no signing without the owner's terms
+        match app.execute_contract(Addr::unchecked(OWNER), team_b.clone(),
+                                   &SignFreeAgent { player: players[0].clone(), signing_fee: vec![] }, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(ContractError::FreeAgentSigningNotApproved { player: players[0].address.clone() },
+                                 e.downcast().unwrap())
+        }
+        match app.execute_contract(Addr::unchecked(USER1), goi_manager_addr.clone(),
+                                   &GoiExecuteMsg::UpdateFreeAgentTerms { player: players[0].address.clone(),
+                                       signing_fee: Some(coin(100, TOKEN)), approved_team: None }, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(ContractError::NotPlayerOwner { sender: Addr::unchecked(USER1) }, e.downcast().unwrap())
+        }
+        app.execute_contract(Addr::unchecked(OWNER), goi_manager_addr.clone(),
+                             &GoiExecuteMsg::UpdateFreeAgentTerms { player: players[0].address.clone(),
+                                 signing_fee: Some(coin(100, TOKEN)), approved_team: None }, &[]).unwrap();
+
+        //signing fee comes out of the team treasury and goes to the player owner
+        app.execute_contract(Addr::unchecked(USER1), team_b.clone(), &Deposit {}, &coins(100, TOKEN)).unwrap();
+        app.execute_contract(Addr::unchecked(OWNER), team_b.clone(),
+                             &SignFreeAgent { player: players[0].clone(), signing_fee: coins(100, TOKEN) }, &[]).unwrap();
+        let owner_balance = app.wrap().query_balance(OWNER, TOKEN).unwrap();
+        assert_eq!(init_balance.amount + Uint128::from(100u128), owner_balance.amount);
+        let player: PlayerResponse = app.wrap().query_wasm_smart(team_b.clone(),
+                                         &GetPlayer { addr: players[0].address.to_string() }).unwrap();
+        assert!(player.players.is_some());
+
+        //a player that was never on a roster can be offered to one team for free
+        app.execute_contract(Addr::unchecked(OWNER), goi_manager_addr.clone(),
+                             &GoiExecuteMsg::UpdateFreeAgentTerms { player: players[1].address.clone(),
+                                 signing_fee: None, approved_team: Some(team_a.clone()) }, &[]).unwrap();
+        match app.execute_contract(Addr::unchecked(OWNER), team_b.clone(),
+                                   &AddPlayersToTeam { players: vec![players[1].clone()] }, &[]) {
+            Ok(_) => assert!(false),
+            Err(_) => assert!(true)
+        }
+        app.execute_contract(Addr::unchecked(OWNER), team_a.clone(),
+                             &SignFreeAgent { player: players[1].clone(), signing_fee: vec![] }, &[]).unwrap();
+        let free_agents: Vec<FreeAgent> =
+            app.wrap().query_wasm_smart(goi_manager_addr,
+                                        &GoiManagerQueryMsg::GetFreeAgents { position: None,
+                                            start_after: None, limit: None }).unwrap();
+        assert_eq!(0, free_agents.len());
+    }
+
+
+    #[test]
+    fn free_agents_backfilled_and_stale_terms_void() {
+        let init_balance = Coin { denom: TOKEN.to_string(), amount: Uint128::from(1000000u128) };
+        let mut app = mock_app_by_user(vec![OWNER, USER1], &[init_balance.clone()]);
+        let goi_manager_addr = instantiate_management_contract(&mut app);
+        let team =
+            instantiate_team_with_managed_contract
+                (&mut app, vec![member(USER1, 100)], Some(goi_manager_addr.clone()));
+        let players =
+            build_player_contracts(&mut app, vec![
+                get_player_instantiate_msg("legacy".to_string(), "back".to_string(),
+                                           Positions::RB, goi_manager_addr.clone())], OWNER).unwrap();
+
+        //tracked and unassigned from before the pool existed
+        update_contract_storage(&mut app, &goi_manager_addr, |store| {
+            goi_manager::state::players().save(store, players[0].address.clone(), &players[0]).unwrap();
+        });
+        let free_agents = |app: &App| -> Vec<FreeAgent> {
+            app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                                        &GoiManagerQueryMsg::GetFreeAgents { position: None,
+                                            start_after: None, limit: None }).unwrap()
+        };
+        assert_eq!(0, free_agents(&app).len());
+        migrate_management_contract(&mut app, goi_manager_addr.clone()).unwrap();
+        let backfilled = free_agents(&app);
+        assert_eq!(1, backfilled.len());
+        assert_eq!(Addr::unchecked(OWNER), backfilled[0].owner);
+
+        //terms recorded under anyone but the current owner don't let a team sign
+        update_contract_storage(&mut app, &goi_manager_addr, |store| {
+            goi_manager::state::free_agents().save(store, players[0].address.clone(), &FreeAgent {
+                owner: Addr::unchecked(USER1),
+                signing_fee: None,
+                approved_team: Some(team.clone()),
+                ..backfilled[0].clone()
+            }).unwrap();
+        });
+        match app.execute_contract(Addr::unchecked(OWNER), team.clone(),
+                                   &SignFreeAgent { player: players[0].clone(), signing_fee: vec![] }, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(ContractError::FreeAgentSigningNotApproved { player: players[0].address.clone() },
+                                 e.downcast().unwrap())
+        }
+        app.execute_contract(Addr::unchecked(OWNER), goi_manager_addr.clone(),
+                             &GoiExecuteMsg::UpdateFreeAgentTerms { player: players[0].address.clone(),
+                                 signing_fee: None, approved_team: Some(team.clone()) }, &[]).unwrap();
+        app.execute_contract(Addr::unchecked(OWNER), team.clone(),
+                             &SignFreeAgent { player: players[0].clone(), signing_fee: vec![] }, &[]).unwrap();
+    }
+
+
+    #[test]
+    fn player_names_unique_across_teams() {
+        let mut app = mock_app(&[]);
+        let goi_manager_addr = instantiate_management_contract(&mut app);
+        let team_a =
+            instantiate_team_with_managed_contract
+                (&mut app, vec![member(OWNER, 100)], Some(goi_manager_addr.clone()));
+        let team_b =
+            instantiate_team_with_managed_contract
+                (&mut app, vec![member(USER1, 100)], Some(goi_manager_addr.clone()));
+
+        let players =
+            build_player_contracts(&mut app, vec![
+                get_player_instantiate_msg("Sam".to_string(), "Rivers".to_string(),
+                                           Positions::QB, goi_manager_addr.clone()),
+                get_player_instantiate_msg(" SAM".to_string(), "rivers ".to_string(),
+                                           Positions::WR1, goi_manager_addr.clone())], OWNER).unwrap();
+        app.execute_contract(Addr::unchecked(OWNER), team_a.clone(),
+                             &AddPlayersToTeam { players: vec![players[0].clone()] }, &[]).unwrap();
+
+        //names match once case-folded and trimmed
+        match app.execute_contract(Addr::unchecked(OWNER), team_b.clone(),
+                                   &AddPlayersToTeam { players: vec![players[1].clone()] }, &[]) {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(ContractError::AddPlayerErrors { players_assigned_to_another_team: vec![],
+                source_dupe_name_count: 1, unauthorized_request: false }, e.downcast().unwrap())
+        }
+
+        let by_name: Option<PlayerInfo> =
+            app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                                        &GoiManagerQueryMsg::GetPlayerByName { first_name: "sam".to_string(),
+                                            last_name: "RIVERS".to_string() }).unwrap();
+        assert_eq!(Some(players[0].address.clone()), by_name.map(|p| p.address));
+        let team_players: Vec<PlayerInfo> =
+            app.wrap().query_wasm_smart(goi_manager_addr.clone(),
+                                        &GoiManagerQueryMsg::GetTeamPlayers { team_addr: team_a.clone() }).unwrap();
+        assert_eq!(vec![Some(team_a.clone())],
+                   team_players.into_iter().map(|p| p.assigned_team_address).collect::<Vec<Option<Addr>>>());
+        let quarterbacks: Vec<PlayerInfo> =
+            app.wrap().query_wasm_smart(goi_manager_addr,
+                                        &GoiManagerQueryMsg::GetPlayersByPosition { position: Positions::QB,
+                                            start_after: None, limit: None }).unwrap();
+        assert_eq!(1, quarterbacks.len());
+    }
 }